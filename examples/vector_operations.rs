@@ -92,9 +92,9 @@ fn main() {
     println!("========================================");
 
     // Example of Swizzling
-    println!("Swizzled Vector2 (yx): {:?}", v2_a.swizzle(1, 0)); // Swap x and y
-    println!("Swizzled Vector3 (yxz): {:?}", v3_a.swizzle(1, 0, 2)); // Swap x and y
-    println!("Swizzled Vector4 (wzyx): {:?}", v4_a.swizzle(3, 2, 1, 0)); // Reverse order
+    println!("Swizzled Vector2 (yx): {:?}", v2_a.yx()); // Swap x and y
+    println!("Swizzled Vector3 (yxz): {:?}", v3_a.yxz()); // Swap x and y
+    println!("Swizzled Vector4 (wzyx): {:?}", v4_a.wzyx()); // Reverse order
 
     println!("========================================");
 
@@ -0,0 +1,470 @@
+//! Approximate equality for floating-point values and the types built from
+//! them.
+//!
+//! Rounding makes exact comparisons of computed floats fragile, so
+//! [`ApproxEq`] offers three tolerances:
+//!
+//! * **Absolute**: values are equal when they differ by at most `epsilon`.
+//!   Right for values of a known scale, especially near zero.
+//! * **Relative**: values are equal when they differ by at most
+//!   `max_relative` times the larger magnitude, which scales with the
+//!   values themselves.
+//! * **ULPs**: values are equal when at most `max_ulps` representable floats
+//!   lie between them, the finest tolerance there is.
+//!
+//! The relative and ULP comparisons also accept values within an absolute
+//! `epsilon`, since neither works well for values that should be zero.
+//! Compound types such as vectors, matrices and quaternions are equal when
+//! every component is.
+//!
+//! The [`assert_approx_eq!`](crate::assert_approx_eq) and
+//! [`assert_relative_eq!`](crate::assert_relative_eq) macros wrap these
+//! comparisons in assertions that show both values and their largest
+//! difference on failure.
+
+use core::fmt::Debug;
+
+use crate::color::oklab::Oklab;
+use crate::color::rgb::{PremultipliedRgba, Rgb, Rgba, Srgb, Srgba};
+use crate::color::xyz::{Lab, Xyz};
+use crate::matrices::mat2::Mat2;
+use crate::matrices::mat3::Mat3;
+use crate::matrices::mat4::Mat4;
+use crate::matrices::matrixmn::MatrixMN;
+use crate::quaternion::Quaternion;
+use crate::scalar::{Real, Scalar};
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::transforms::transform::Transform;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+use crate::vectors::vectorn::VectorN;
+
+/// Comparison within a tolerance.
+///
+/// Implemented for `f32` and `f64`, arrays of comparable values, and the
+/// vector, matrix, quaternion, transform and color types of this crate.
+/// Colors with a hue are left out, since their hues wrap around.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::approx::ApproxEq;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let a = Vector2::new(0.1 + 0.2, 1.0);
+/// let b = Vector2::new(0.3, 1.0);
+/// assert_ne!(a, b);
+/// assert!(a.abs_diff_eq(&b, 1e-12));
+/// assert!(a.ulps_eq(&b, 0.0, 1));
+/// ```
+pub trait ApproxEq {
+    /// The type of the tolerances and differences.
+    type Scalar: Real;
+
+    /// Returns the largest absolute difference between corresponding
+    /// components, or NaN if any component is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::approx::ApproxEq;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(1.5, 2.0, 1.0);
+    /// assert_eq!(a.max_abs_diff(&b), 2.0);
+    /// ```
+    fn max_abs_diff(&self, other: &Self) -> Self::Scalar;
+
+    /// Returns `true` if every component differs by at most `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::approx::ApproxEq;
+    ///
+    /// assert!(1.0_f64.abs_diff_eq(&1.05, 0.1));
+    /// assert!(!1.0_f64.abs_diff_eq(&1.2, 0.1));
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Scalar) -> bool;
+
+    /// Returns `true` if every component differs by at most `epsilon`, or
+    /// by at most `max_relative` times the larger of the two magnitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::approx::ApproxEq;
+    ///
+    /// // One part in a million, whatever the scale.
+    /// assert!(1.0e9_f64.relative_eq(&1.0000005e9, 0.0, 1e-6));
+    /// assert!(1.0e-9_f64.relative_eq(&1.0000005e-9, 0.0, 1e-6));
+    /// assert!(!1.0_f64.relative_eq(&1.00001, 0.0, 1e-6));
+    /// ```
+    fn relative_eq(&self, other: &Self, epsilon: Self::Scalar, max_relative: Self::Scalar) -> bool;
+
+    /// Returns `true` if every component differs by at most `epsilon`, or
+    /// has at most `max_ulps` representable values between them.
+    ///
+    /// Values of opposite sign are only equal within `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::approx::ApproxEq;
+    ///
+    /// let next = f32::from_bits(1.0f32.to_bits() + 1);
+    /// assert!(1.0f32.ulps_eq(&next, 0.0, 1));
+    /// assert!(!1.0f32.ulps_eq(&next, 0.0, 0));
+    /// ```
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Scalar, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq_for_float {
+    ($($t:ident),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Scalar = $t;
+
+                fn max_abs_diff(&self, other: &Self) -> $t {
+                    if self == other {
+                        0.0
+                    } else {
+                        (self - other).abs()
+                    }
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    self.max_abs_diff(other) <= epsilon
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let difference = (self - other).abs();
+                    difference <= epsilon
+                        || difference <= self.abs().max(other.abs()) * max_relative
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_nan()
+                        || other.is_nan()
+                        || self.is_sign_negative() != other.is_sign_negative()
+                    {
+                        return false;
+                    }
+                    // Floats of one sign are ordered like their bit patterns.
+                    self.to_bits().abs_diff(other.to_bits()) <= max_ulps.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_for_float!(f32, f64);
+
+impl<A: ApproxEq, const N: usize> ApproxEq for [A; N] {
+    type Scalar = A::Scalar;
+
+    fn max_abs_diff(&self, other: &Self) -> A::Scalar {
+        self.iter()
+            .zip(other)
+            .fold(A::Scalar::ZERO, |largest, (a, b)| {
+                larger(largest, a.max_abs_diff(b))
+            })
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: A::Scalar) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: A::Scalar, max_relative: A::Scalar) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: A::Scalar, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// Implements [`ApproxEq`] for structs by comparing the listed fields.
+macro_rules! impl_approx_eq_for_fields {
+    ($($ty:ident { $($field:ident),+ }),* $(,)?) => {
+        $(
+            impl<T: Real + ApproxEq<Scalar = T>> ApproxEq for $ty<T> {
+                type Scalar = T;
+
+                fn max_abs_diff(&self, other: &Self) -> T {
+                    let mut largest = T::ZERO;
+                    $(largest = larger(largest, self.$field.max_abs_diff(&other.$field));)+
+                    largest
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+                    $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+                    $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+                    $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_for_fields!(
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector4 { x, y, z, w },
+    Quaternion { x, y, z, w },
+    Mat2 { cols },
+    Mat3 { cols },
+    Mat4 { cols },
+    Affine2 {
+        matrix,
+        translation
+    },
+    Affine3 {
+        matrix,
+        translation
+    },
+    Transform {
+        translation,
+        rotation,
+        scale
+    },
+    Rgb { r, g, b },
+    Rgba { r, g, b, a },
+    PremultipliedRgba { r, g, b, a },
+    Srgb { r, g, b },
+    Srgba { r, g, b, a },
+    Xyz { x, y, z },
+    Lab { l, a, b },
+    Oklab { l, a, b },
+);
+
+impl<T: Real + ApproxEq<Scalar = T>, const N: usize> ApproxEq for VectorN<T, N> {
+    type Scalar = T;
+
+    fn max_abs_diff(&self, other: &Self) -> T {
+        self.components.max_abs_diff(&other.components)
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.components.abs_diff_eq(&other.components, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.components
+            .relative_eq(&other.components, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.components
+            .ulps_eq(&other.components, epsilon, max_ulps)
+    }
+}
+
+impl<T: Real + ApproxEq<Scalar = T>, const R: usize, const C: usize> ApproxEq
+    for MatrixMN<T, R, C>
+{
+    type Scalar = T;
+
+    fn max_abs_diff(&self, other: &Self) -> T {
+        self.cols.max_abs_diff(&other.cols)
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.cols.abs_diff_eq(&other.cols, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.cols.relative_eq(&other.cols, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.cols.ulps_eq(&other.cols, epsilon, max_ulps)
+    }
+}
+
+/// Returns the larger of two differences, or NaN if either is NaN.
+fn larger<T: Real>(a: T, b: T) -> T {
+    if a.is_nan() || a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Checks `left` against `right` within an absolute tolerance, panicking
+/// with both values if they differ. Used by [`assert_approx_eq!`].
+///
+/// [`assert_approx_eq!`]: crate::assert_approx_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn check_abs_diff<A: ApproxEq + Debug + ?Sized>(
+    left: &A,
+    right: &A,
+    epsilon: Option<A::Scalar>,
+) {
+    let epsilon = epsilon.unwrap_or(A::Scalar::EPSILON);
+    if !left.abs_diff_eq(right, epsilon) {
+        fail(left, right, format_args!("epsilon: {epsilon:?}"));
+    }
+}
+
+/// Checks `left` against `right` within a number of units in the last place,
+/// panicking with both values if they differ. Used by
+/// [`assert_approx_eq!`].
+///
+/// [`assert_approx_eq!`]: crate::assert_approx_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn check_ulps<A: ApproxEq + Debug + ?Sized>(
+    left: &A,
+    right: &A,
+    epsilon: Option<A::Scalar>,
+    max_ulps: u32,
+) {
+    let epsilon = epsilon.unwrap_or(A::Scalar::EPSILON);
+    if !left.ulps_eq(right, epsilon, max_ulps) {
+        fail(
+            left,
+            right,
+            format_args!("epsilon: {epsilon:?}, max_ulps: {max_ulps}"),
+        );
+    }
+}
+
+/// Checks `left` against `right` within a relative tolerance, panicking with
+/// both values if they differ. Used by [`assert_relative_eq!`].
+///
+/// [`assert_relative_eq!`]: crate::assert_relative_eq
+#[doc(hidden)]
+#[track_caller]
+pub fn check_relative<A: ApproxEq + Debug + ?Sized>(
+    left: &A,
+    right: &A,
+    epsilon: Option<A::Scalar>,
+    max_relative: Option<A::Scalar>,
+) {
+    let epsilon = epsilon.unwrap_or(A::Scalar::EPSILON);
+    let max_relative = max_relative.unwrap_or(A::Scalar::EPSILON);
+    if !left.relative_eq(right, epsilon, max_relative) {
+        fail(
+            left,
+            right,
+            format_args!("epsilon: {epsilon:?}, max_relative: {max_relative:?}"),
+        );
+    }
+}
+
+/// Panics with both sides of a failed assertion and their largest
+/// difference.
+#[cold]
+#[track_caller]
+fn fail<A: ApproxEq + Debug + ?Sized>(left: &A, right: &A, tolerance: core::fmt::Arguments) -> ! {
+    panic!(
+        "assertion `left \u{2248} right` failed\n  left: {left:?}\n right: {right:?}\n  diff: {:?} ({tolerance})",
+        left.max_abs_diff(right),
+    )
+}
+
+/// Asserts that two values are equal within an absolute tolerance, or within
+/// a number of units in the last place.
+///
+/// Both values must implement [`ApproxEq`](crate::approx::ApproxEq) and
+/// `Debug`. The tolerance defaults to the machine epsilon of the scalar
+/// type; pass `epsilon = ..` to widen it, or `ulps = ..` to also accept
+/// values only a few representable floats apart. On failure both values are
+/// printed along with their largest difference.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let v = Vector3::new(3.0, 4.0, 0.0).normalize();
+/// assert_approx_eq!(v, Vector3::new(0.6, 0.8, 0.0), epsilon = 1e-12);
+/// assert_approx_eq!(v.length(), 1.0, ulps = 2);
+/// assert_approx_eq!(0.1 + 0.2, 0.3);
+/// ```
+///
+/// A failure shows the values and how far apart they are:
+///
+/// ```should_panic
+/// use vexel::assert_approx_eq;
+///
+/// // assertion `left ≈ right` failed
+/// //   left: 1.0
+/// //  right: 1.1
+/// //   diff: 0.10000000000000009 (epsilon: 0.01)
+/// assert_approx_eq!(1.0, 1.1, epsilon = 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::approx::check_abs_diff(&$left, &$right, None)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::approx::check_abs_diff(&$left, &$right, Some($epsilon))
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::approx::check_ulps(&$left, &$right, None, $ulps)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::approx::check_ulps(&$left, &$right, Some($epsilon), $ulps)
+    };
+}
+
+/// Asserts that two values are equal within a tolerance relative to their
+/// magnitude.
+///
+/// Both values must implement [`ApproxEq`](crate::approx::ApproxEq) and
+/// `Debug`. The relative tolerance defaults to the machine epsilon of the
+/// scalar type; pass `max_relative = ..` to widen it, and `epsilon = ..` to
+/// also accept any absolute difference up to that size, which helps near
+/// zero. On failure both values are printed along with their largest
+/// difference.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_relative_eq;
+///
+/// let year = 365.25 * 24.0 * 3600.0;
+/// assert_relative_eq!(year, 3.156e7, max_relative = 1e-3);
+/// assert_relative_eq!(1e-20, 0.0, epsilon = 1e-12, max_relative = 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::approx::check_relative(&$left, &$right, None, None)
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::approx::check_relative(&$left, &$right, None, Some($max_relative))
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::approx::check_relative(&$left, &$right, Some($epsilon), Some($max_relative))
+    };
+}
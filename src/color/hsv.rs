@@ -0,0 +1,323 @@
+//! Hue, saturation and value or lightness: cylindrical views of sRGB.
+//!
+//! Both spaces describe gamma-encoded [`Srgb`] by the angle of its hue on
+//! the color wheel and how vivid and how bright it is, which is how color
+//! pickers present colors. They are not perceptually uniform: yellow and
+//! blue of the same value look very different in brightness. Use
+//! [`Oklch`](super::oklab::Oklch) when that matters.
+
+use crate::scalar::Real;
+
+use super::rgb::{Rgb, Srgb};
+use super::{impl_vector3_conversions, wrap_degrees};
+
+/// A color as hue, saturation and value.
+///
+/// Value is the largest encoded component, so every fully saturated hue has
+/// a value of 1.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::hsv::Hsv;
+/// use vexel::color::rgb::Srgb;
+///
+/// let orange = Srgb::from(Hsv::new(30.0, 1.0, 1.0));
+/// assert_eq!(orange, Srgb::new(1.0, 0.5, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv<T> {
+    /// The hue in degrees, in `[0, 360)`, starting from red.
+    pub h: T,
+    /// The saturation, from 0 for grey to 1 for fully vivid.
+    pub s: T,
+    /// The value, from 0 for black to 1 for the brightest color of the hue.
+    pub v: T,
+}
+
+/// A color as hue, saturation and lightness.
+///
+/// Lightness runs from black at 0 through the pure hue at ½ to white at 1.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::hsv::Hsl;
+/// use vexel::color::rgb::Srgb;
+///
+/// let pink = Srgb::from(Hsl::new(0.0, 1.0, 0.75));
+/// assert_eq!(pink, Srgb::new(1.0, 0.5, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl<T> {
+    /// The hue in degrees, in `[0, 360)`, starting from red.
+    pub h: T,
+    /// The saturation, from 0 for grey to 1 for fully vivid.
+    pub s: T,
+    /// The lightness, from 0 for black to 1 for white.
+    pub l: T,
+}
+
+impl<T> Hsv<T> {
+    /// Creates a color from its hue in degrees, saturation and value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsv;
+    ///
+    /// let green = Hsv::new(120.0, 1.0, 1.0);
+    /// assert_eq!(green.h, 120.0);
+    /// ```
+    pub fn new(h: T, s: T, v: T) -> Self {
+        Self { h, s, v }
+    }
+}
+
+impl<T> Hsl<T> {
+    /// Creates a color from its hue in degrees, saturation and lightness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsl;
+    ///
+    /// let green = Hsl::new(120.0, 1.0, 0.5);
+    /// assert_eq!(green.l, 0.5);
+    /// ```
+    pub fn new(h: T, s: T, l: T) -> Self {
+        Self { h, s, l }
+    }
+}
+
+impl<T: Real> From<Srgb<T>> for Hsv<T> {
+    /// Converts an encoded color to hue, saturation and value. Greys have a
+    /// hue of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsv;
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Hsv::from(Srgb::new(0.0, 0.5, 0.5)), Hsv::new(180.0, 1.0, 0.5));
+    /// assert_eq!(Hsv::from(Srgb::new(0.25, 0.25, 0.25)), Hsv::new(0.0, 0.0, 0.25));
+    /// ```
+    fn from(c: Srgb<T>) -> Self {
+        let (h, max, min) = hue(c);
+        let s = if max > T::ZERO {
+            (max - min) / max
+        } else {
+            T::ZERO
+        };
+        Self::new(h, s, max)
+    }
+}
+
+impl<T: Real> From<Hsv<T>> for Srgb<T> {
+    /// Converts hue, saturation and value to an encoded color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsv;
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Srgb::from(Hsv::new(240.0, 0.5, 1.0)), Srgb::new(0.5, 0.5, 1.0));
+    /// ```
+    fn from(c: Hsv<T>) -> Self {
+        let chroma = c.v * c.s;
+        from_hue(c.h, chroma, c.v - chroma)
+    }
+}
+
+impl<T: Real> From<Srgb<T>> for Hsl<T> {
+    /// Converts an encoded color to hue, saturation and lightness. Greys
+    /// have a hue of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsl;
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Hsl::from(Srgb::new(0.5, 0.0, 0.5)), Hsl::new(300.0, 1.0, 0.25));
+    /// ```
+    fn from(c: Srgb<T>) -> Self {
+        let (h, max, min) = hue(c);
+        let l = (max + min) * T::HALF;
+        let spread = T::ONE - (l * T::TWO - T::ONE).abs();
+        let s = if spread > T::ZERO {
+            (max - min) / spread
+        } else {
+            T::ZERO
+        };
+        Self::new(h, s, l)
+    }
+}
+
+impl<T: Real> From<Hsl<T>> for Srgb<T> {
+    /// Converts hue, saturation and lightness to an encoded color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsl;
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Srgb::from(Hsl::new(60.0, 1.0, 0.5)), Srgb::new(1.0, 1.0, 0.0));
+    /// ```
+    fn from(c: Hsl<T>) -> Self {
+        let chroma = (T::ONE - (c.l * T::TWO - T::ONE).abs()) * c.s;
+        from_hue(c.h, chroma, c.l - chroma * T::HALF)
+    }
+}
+
+impl<T: Real> From<Hsv<T>> for Hsl<T> {
+    /// Converts between the two cylindrical forms without going through
+    /// sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::{Hsl, Hsv};
+    ///
+    /// assert_eq!(Hsl::from(Hsv::new(90.0, 1.0, 1.0)), Hsl::new(90.0, 1.0, 0.5));
+    /// ```
+    fn from(c: Hsv<T>) -> Self {
+        let l = c.v * (T::ONE - c.s * T::HALF);
+        let room = l.min(T::ONE - l);
+        let s = if room > T::ZERO {
+            (c.v - l) / room
+        } else {
+            T::ZERO
+        };
+        Self::new(c.h, s, l)
+    }
+}
+
+impl<T: Real> From<Hsl<T>> for Hsv<T> {
+    /// Converts between the two cylindrical forms without going through
+    /// sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::{Hsl, Hsv};
+    ///
+    /// assert_eq!(Hsv::from(Hsl::new(90.0, 1.0, 0.75)), Hsv::new(90.0, 0.5, 1.0));
+    /// ```
+    fn from(c: Hsl<T>) -> Self {
+        let v = c.l + c.s * c.l.min(T::ONE - c.l);
+        let s = if v > T::ZERO {
+            T::TWO * (T::ONE - c.l / v)
+        } else {
+            T::ZERO
+        };
+        Self::new(c.h, s, v)
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Hsv<T> {
+    /// Encodes a linear color and converts it to hue, saturation and value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsv;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// assert_eq!(Hsv::from(Rgb::new(0.0, 0.0, 1.0)), Hsv::new(240.0, 1.0, 1.0));
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl<T: Real> From<Hsv<T>> for Rgb<T> {
+    /// Converts hue, saturation and value to a linear color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsv;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// assert_eq!(Rgb::from(Hsv::new(0.0, 1.0, 1.0)), Rgb::new(1.0, 0.0, 0.0));
+    /// ```
+    fn from(c: Hsv<T>) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Hsl<T> {
+    /// Encodes a linear color and converts it to hue, saturation and
+    /// lightness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsl;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// assert_eq!(Hsl::from(Rgb::new(0.0, 1.0, 0.0)), Hsl::new(120.0, 1.0, 0.5));
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl<T: Real> From<Hsl<T>> for Rgb<T> {
+    /// Converts hue, saturation and lightness to a linear color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::hsv::Hsl;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// assert_eq!(Rgb::from(Hsl::new(0.0, 0.0, 1.0)), Rgb::new(1.0, 1.0, 1.0));
+    /// ```
+    fn from(c: Hsl<T>) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl_vector3_conversions!(Hsv, h, s, v);
+impl_vector3_conversions!(Hsl, h, s, l);
+
+/// Returns the hue of `c` in degrees with its largest and smallest
+/// components.
+fn hue<T: Real>(c: Srgb<T>) -> (T, T, T) {
+    let max = c.r.max(c.g).max(c.b);
+    let min = c.r.min(c.g).min(c.b);
+    let chroma = max - min;
+    if chroma <= T::ZERO {
+        return (T::ZERO, max, min);
+    }
+    let sector = if max == c.r {
+        (c.g - c.b) / chroma
+    } else if max == c.g {
+        (c.b - c.r) / chroma + T::TWO
+    } else {
+        (c.r - c.g) / chroma + T::from_f64(4.0)
+    };
+    (wrap_degrees(sector * T::from_f64(60.0)), max, min)
+}
+
+/// Builds an encoded color from its hue in degrees, its chroma and the
+/// amount `m` added to every component.
+fn from_hue<T: Real>(h: T, chroma: T, m: T) -> Srgb<T> {
+    let sector = wrap_degrees(h) / T::from_f64(60.0);
+    let folded = sector - (sector * T::HALF).floor() * T::TWO;
+    let x = chroma * (T::ONE - (folded - T::ONE).abs());
+    let (r, g, b) = match sector.floor().to_f64() as u8 {
+        0 => (chroma, x, T::ZERO),
+        1 => (x, chroma, T::ZERO),
+        2 => (T::ZERO, chroma, x),
+        3 => (T::ZERO, x, chroma),
+        4 => (x, T::ZERO, chroma),
+        _ => (chroma, T::ZERO, x),
+    };
+    Srgb::new(r + m, g + m, b + m)
+}
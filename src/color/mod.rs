@@ -0,0 +1,120 @@
+//! Colors and conversions between color spaces.
+//!
+//! Lighting and blending are only physically meaningful on linear light, so
+//! [`Rgb`] and [`Rgba`] hold linear sRGB components. Images, color pickers
+//! and most file formats store gamma-encoded values instead, which are
+//! [`Srgb`] and [`Srgba`]. The other spaces are reached from these with
+//! `From` conversions:
+//!
+//! * [`Hsv`] and [`Hsl`] are cylindrical views of encoded sRGB, for color
+//!   pickers.
+//! * [`Xyz`] is CIE 1931 XYZ and [`Lab`] is CIE L\*a\*b\*, both relative to
+//!   the D65 white point of sRGB.
+//! * [`Oklab`] and its polar form [`Oklch`] are perceptually uniform: equal
+//!   steps look equally different, which makes them the best spaces for
+//!   gradients and [perceptual blending][`Rgb::mix`].
+//!
+//! Hues are in degrees, in `[0, 360)`. Conversions do not clamp, so colors
+//! outside the sRGB gamut survive a round trip; clamp when encoding to bytes
+//! with [`Srgb::to_u8`].
+//!
+//! Every color converts to and from a [`Vector3`], or a [`Vector4`] with
+//! alpha in `w`, taking its components in the order of its fields.
+//!
+//! [`Hsl`]: hsv::Hsl
+//! [`Hsv`]: hsv::Hsv
+//! [`Lab`]: xyz::Lab
+//! [`Oklab`]: oklab::Oklab
+//! [`Oklch`]: oklab::Oklch
+//! [`Rgb`]: rgb::Rgb
+//! [`Rgb::mix`]: rgb::Rgb::mix
+//! [`Rgba`]: rgb::Rgba
+//! [`Srgb`]: rgb::Srgb
+//! [`Srgb::to_u8`]: rgb::Srgb::to_u8
+//! [`Srgba`]: rgb::Srgba
+//! [`Vector3`]: crate::vectors::vector3::Vector3
+//! [`Vector4`]: crate::vectors::vector4::Vector4
+//! [`Xyz`]: xyz::Xyz
+
+pub mod hsv;
+pub mod oklab;
+pub mod rgb;
+pub mod xyz;
+
+use crate::scalar::Real;
+
+/// Multiplies the column `v` by the matrix `m`.
+fn transform<T: Real>(m: &[[f64; 3]; 3], v: [T; 3]) -> [T; 3] {
+    m.map(|row| {
+        T::from_f64(row[0]) * v[0] + T::from_f64(row[1]) * v[1] + T::from_f64(row[2]) * v[2]
+    })
+}
+
+/// Returns the real cube root of `x`, which is negative for negative `x`.
+fn cbrt<T: Real>(x: T) -> T {
+    x.abs().powf(T::from_f64(1.0 / 3.0)).copysign(x)
+}
+
+/// Wraps an angle in degrees into `[0, 360)`.
+fn wrap_degrees<T: Real>(degrees: T) -> T {
+    let turn = T::from_f64(360.0);
+    let wrapped = degrees - (degrees / turn).floor() * turn;
+    // Rounding can land exactly on a full turn for tiny negative angles.
+    if wrapped >= turn {
+        wrapped - turn
+    } else {
+        wrapped
+    }
+}
+
+/// Implements conversions between a three-component color and `Vector3`.
+macro_rules! impl_vector3_conversions {
+    ($color:ident, $a:ident, $b:ident, $c:ident) => {
+        impl<T> From<crate::vectors::vector3::Vector3<T>> for $color<T> {
+            #[doc = concat!("Creates a color from `x`, `y` and `z` as `", stringify!($a), "`, `", stringify!($b), "` and `", stringify!($c), "`.")]
+            fn from(v: crate::vectors::vector3::Vector3<T>) -> Self {
+                Self {
+                    $a: v.x,
+                    $b: v.y,
+                    $c: v.z,
+                }
+            }
+        }
+
+        impl<T> From<$color<T>> for crate::vectors::vector3::Vector3<T> {
+            #[doc = concat!("Creates a vector from `", stringify!($a), "`, `", stringify!($b), "` and `", stringify!($c), "` as `x`, `y` and `z`.")]
+            fn from(color: $color<T>) -> Self {
+                crate::vectors::vector3::Vector3::new(color.$a, color.$b, color.$c)
+            }
+        }
+    };
+}
+
+/// Implements conversions between a color with alpha and `Vector4`.
+macro_rules! impl_vector4_conversions {
+    ($color:ident) => {
+        impl<T> From<crate::vectors::vector4::Vector4<T>> for $color<T> {
+            /// Creates a color from `x`, `y`, `z` and `w` as `r`, `g`, `b`
+            /// and `a`.
+            fn from(v: crate::vectors::vector4::Vector4<T>) -> Self {
+                Self {
+                    r: v.x,
+                    g: v.y,
+                    b: v.z,
+                    a: v.w,
+                }
+            }
+        }
+
+        impl<T> From<$color<T>> for crate::vectors::vector4::Vector4<T> {
+            /// Creates a vector from `r`, `g`, `b` and `a` as `x`, `y`, `z`
+            /// and `w`.
+            fn from(color: $color<T>) -> Self {
+                crate::vectors::vector4::Vector4::new(color.r, color.g, color.b, color.a)
+            }
+        }
+    };
+}
+
+use impl_vector3_conversions;
+use impl_vector4_conversions;
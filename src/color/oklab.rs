@@ -0,0 +1,341 @@
+//! Oklab and Oklch, perceptually uniform color spaces.
+//!
+//! Oklab, by Björn Ottosson, predicts perceived lightness, chroma and hue
+//! better than L\*a\*b\* and is cheap to compute from linear sRGB. Straight
+//! lines in it make smooth, even gradients, and Euclidean distances in it
+//! approximate how different two colors look. Oklch is the same space in
+//! polar form: lightness, chroma and a hue angle in degrees.
+
+use crate::scalar::Real;
+
+use super::rgb::Rgb;
+use super::{cbrt, impl_vector3_conversions, transform, wrap_degrees};
+
+/// The matrix from linear sRGB to the cone responses Oklab is built on.
+const SRGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+    [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+    [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+
+/// The inverse of [`SRGB_TO_LMS`].
+const LMS_TO_SRGB: [[f64; 3]; 3] = [
+    [
+        4.076_741_661_347_994,
+        -3.307_711_590_408_193_3,
+        0.230_969_928_729_427_9,
+    ],
+    [
+        -1.268_438_004_092_176,
+        2.609_757_400_663_371_5,
+        -0.341_319_396_310_219_6,
+    ],
+    [
+        -0.004_196_086_541_837_109,
+        -0.703_418_614_459_449_6,
+        1.707_614_700_930_944_8,
+    ],
+];
+
+/// The matrix from compressed cone responses to Oklab.
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.210_454_255_3, 0.793_617_785, -0.004_072_046_8],
+    [1.977_998_495_1, -2.428_592_205, 0.450_593_709_9],
+    [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766],
+];
+
+/// The inverse of [`LMS_TO_OKLAB`].
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [
+        0.999_999_998_450_519_8,
+        0.396_337_792_173_767_86,
+        0.215_803_758_060_758_8,
+    ],
+    [
+        1.000_000_008_881_760_9,
+        -0.105_561_342_323_656_35,
+        -0.063_854_174_771_705_91,
+    ],
+    [
+        1.000_000_054_672_410_8,
+        -0.089_484_182_094_965_75,
+        -1.291_485_537_864_091_7,
+    ],
+];
+
+/// A color in Oklab.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::color::oklab::Oklab;
+/// use vexel::color::rgb::Rgb;
+///
+/// let white = Oklab::from(Rgb::new(1.0, 1.0, 1.0));
+/// assert_approx_eq!(white.l, 1.0, epsilon = 1e-6);
+/// assert_approx_eq!(white.a, 0.0, epsilon = 1e-6);
+/// assert_approx_eq!(white.b, 0.0, epsilon = 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab<T> {
+    /// The perceived lightness, from 0 for black to 1 for white.
+    pub l: T,
+    /// The position between green, negative, and red, positive.
+    pub a: T,
+    /// The position between blue, negative, and yellow, positive.
+    pub b: T,
+}
+
+/// A color in Oklch, the polar form of [`Oklab`].
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::color::oklab::Oklch;
+/// use vexel::color::rgb::Rgb;
+///
+/// // Turning the hue keeps lightness and chroma.
+/// let red = Oklch::from(Rgb::new(0.8_f64, 0.1, 0.1));
+/// let turned = Oklch::new(red.l, red.c, red.h + 120.0);
+/// let back = Oklch::from(Rgb::from(turned));
+/// assert_approx_eq!(back.l, red.l, epsilon = 1e-9);
+/// assert_approx_eq!(back.c, red.c, epsilon = 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch<T> {
+    /// The perceived lightness, from 0 for black to 1 for white.
+    pub l: T,
+    /// The chroma, how far the color is from grey.
+    pub c: T,
+    /// The hue in degrees, in `[0, 360)`.
+    pub h: T,
+}
+
+impl<T> Oklab<T> {
+    /// Creates a color from its lightness and `a` and `b` positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::Oklab;
+    ///
+    /// let c = Oklab::new(0.5, 0.1, -0.05);
+    /// assert_eq!(c.a, 0.1);
+    /// ```
+    pub fn new(l: T, a: T, b: T) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl<T: Real> Oklab<T> {
+    /// Linearly interpolates between this color and another, a perceptually
+    /// even blend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::Oklab;
+    ///
+    /// let a = Oklab::new(0.2, 0.1, 0.0);
+    /// let b = Oklab::new(0.8, -0.1, 0.2);
+    /// assert_eq!(a.lerp(&b, 0.5), Oklab::new(0.5, 0.0, 0.1));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self::new(
+            self.l + (other.l - self.l) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+
+    /// Returns how different this color looks from another, the Euclidean
+    /// distance between them.
+    ///
+    /// A difference of about 0.02 is just noticeable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::Oklab;
+    ///
+    /// let a = Oklab::new(0.5, 0.0, 0.0);
+    /// let b = Oklab::new(0.5, 0.03, 0.04);
+    /// assert_approx_eq!(a.distance(&b), 0.05, epsilon = 1e-12);
+    /// ```
+    pub fn distance(&self, other: &Self) -> T {
+        let (dl, da, db) = (other.l - self.l, other.a - self.a, other.b - self.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl<T> Oklch<T> {
+    /// Creates a color from its lightness, chroma and hue in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::Oklch;
+    ///
+    /// let c = Oklch::new(0.7, 0.1, 150.0);
+    /// assert_eq!(c.h, 150.0);
+    /// ```
+    pub fn new(l: T, c: T, h: T) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl<T: Real> Oklch<T> {
+    /// Interpolates between this color and another, turning the hue the
+    /// shorter way around the color wheel.
+    ///
+    /// Unlike [`Oklab::lerp`], the chroma does not dip between
+    /// complementary colors, so the blend stays vivid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::Oklch;
+    ///
+    /// let a = Oklch::new(0.6, 0.1, 350.0);
+    /// let b = Oklch::new(0.8, 0.2, 30.0);
+    /// let half = a.lerp(&b, 0.5);
+    /// assert_approx_eq!(half.h, 10.0, epsilon = 1e-12);
+    /// assert_approx_eq!(half.l, 0.7, epsilon = 1e-12);
+    /// assert_approx_eq!(half.c, 0.15, epsilon = 1e-12);
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        let half_turn = T::from_f64(180.0);
+        let mut turn = wrap_degrees(other.h - self.h);
+        if turn > half_turn {
+            turn -= half_turn * T::TWO;
+        }
+        Self::new(
+            self.l + (other.l - self.l) * t,
+            self.c + (other.c - self.c) * t,
+            wrap_degrees(self.h + turn * t),
+        )
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Oklab<T> {
+    /// Converts a linear sRGB color to Oklab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::Oklab;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let blue = Oklab::from(Rgb::new(0.0, 0.0, 1.0));
+    /// assert_approx_eq!(blue.l, 0.452, epsilon = 1e-3);
+    /// assert!(blue.b < 0.0);
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        let lms = transform(&SRGB_TO_LMS, [c.r, c.g, c.b]).map(cbrt);
+        let [l, a, b] = transform(&LMS_TO_OKLAB, lms);
+        Self::new(l, a, b)
+    }
+}
+
+impl<T: Real> From<Oklab<T>> for Rgb<T> {
+    /// Converts an Oklab color to linear sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::Oklab;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let back = Rgb::from(Oklab::from(Rgb::new(0.2, 0.4, 0.6)));
+    /// assert_approx_eq!(back.r, 0.2, epsilon = 1e-12);
+    /// ```
+    fn from(c: Oklab<T>) -> Self {
+        let lms = transform(&OKLAB_TO_LMS, [c.l, c.a, c.b]).map(|x| x * x * x);
+        let [r, g, b] = transform(&LMS_TO_SRGB, lms);
+        Self::new(r, g, b)
+    }
+}
+
+impl<T: Real> From<Oklab<T>> for Oklch<T> {
+    /// Converts to polar form. Greys have a hue of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::{Oklab, Oklch};
+    ///
+    /// let c = Oklch::from(Oklab::new(0.5, 0.0, 0.1));
+    /// assert_eq!((c.l, c.c, c.h), (0.5, 0.1, 90.0));
+    /// ```
+    fn from(c: Oklab<T>) -> Self {
+        let chroma = (c.a * c.a + c.b * c.b).sqrt();
+        let hue = if chroma > T::ZERO {
+            wrap_degrees(c.b.atan2(c.a) * T::from_f64(180.0) / T::PI)
+        } else {
+            T::ZERO
+        };
+        Self::new(c.l, chroma, hue)
+    }
+}
+
+impl<T: Real> From<Oklch<T>> for Oklab<T> {
+    /// Converts from polar form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::{Oklab, Oklch};
+    ///
+    /// let c = Oklab::from(Oklch::new(0.5, 0.1, 180.0));
+    /// assert_approx_eq!(c.a, -0.1, epsilon = 1e-12);
+    /// assert_approx_eq!(c.b, 0.0, epsilon = 1e-12);
+    /// ```
+    fn from(c: Oklch<T>) -> Self {
+        let (sin, cos) = (c.h * T::PI / T::from_f64(180.0)).sin_cos();
+        Self::new(c.l, c.c * cos, c.c * sin)
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Oklch<T> {
+    /// Converts a linear sRGB color to Oklch through Oklab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::Oklch;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let grey = Oklch::from(Rgb::new(0.5, 0.5, 0.5));
+    /// assert!(grey.c < 1e-6);
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+impl<T: Real> From<Oklch<T>> for Rgb<T> {
+    /// Converts an Oklch color to linear sRGB through Oklab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::oklab::Oklch;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let black = Rgb::from(Oklch::new(0.0, 0.0, 0.0));
+    /// assert_eq!(black, Rgb::new(0.0, 0.0, 0.0));
+    /// ```
+    fn from(c: Oklch<T>) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+impl_vector3_conversions!(Oklab, l, a, b);
+impl_vector3_conversions!(Oklch, l, c, h);
@@ -0,0 +1,582 @@
+//! Linear and gamma-encoded sRGB colors, with and without alpha.
+
+use crate::scalar::Real;
+
+use super::oklab::Oklab;
+use super::{impl_vector3_conversions, impl_vector4_conversions};
+
+/// A color in linear sRGB, where components are proportional to the light
+/// emitted.
+///
+/// Components are nominally in `[0, 1]`; values outside that range are
+/// brighter than white or outside the sRGB gamut.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::color::rgb::{Rgb, Srgb};
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let encoded = Srgb::new(0.5, 0.5, 0.5);
+/// let linear = Rgb::from(encoded);
+/// assert_approx_eq!(linear.r, 0.214, epsilon = 1e-3);
+///
+/// let v: Vector3<f64> = linear.into();
+/// assert_eq!(v, Vector3::new(linear.r, linear.g, linear.b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb<T> {
+    /// The red component.
+    pub r: T,
+    /// The green component.
+    pub g: T,
+    /// The blue component.
+    pub b: T,
+}
+
+/// A color in linear sRGB with straight, not premultiplied, alpha.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::rgb::Rgba;
+/// use vexel::vectors::vector4::Vector4;
+///
+/// let tint = Rgba::from(Vector4::new(1.0, 0.5, 0.25, 0.5));
+/// assert_eq!(tint, Rgba::new(1.0, 0.5, 0.25, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgba<T> {
+    /// The red component.
+    pub r: T,
+    /// The green component.
+    pub g: T,
+    /// The blue component.
+    pub b: T,
+    /// The opacity, from 0 for transparent to 1 for opaque.
+    pub a: T,
+}
+
+/// A color in linear sRGB whose components are already multiplied by its
+/// alpha.
+///
+/// Premultiplied colors filter, blend and composite correctly: a
+/// transparent pixel has no color to bleed into its neighbours.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::rgb::{PremultipliedRgba, Rgba};
+///
+/// let glass = PremultipliedRgba::from(Rgba::new(0.2, 0.4, 0.8, 0.5));
+/// assert_eq!(glass, PremultipliedRgba::new(0.1, 0.2, 0.4, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PremultipliedRgba<T> {
+    /// The red component times alpha.
+    pub r: T,
+    /// The green component times alpha.
+    pub g: T,
+    /// The blue component times alpha.
+    pub b: T,
+    /// The opacity, from 0 for transparent to 1 for opaque.
+    pub a: T,
+}
+
+/// A color in gamma-encoded sRGB, as stored in images and shown by color
+/// pickers.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::rgb::Srgb;
+///
+/// let orange = Srgb::<f32>::from_u8(255, 128, 0);
+/// assert_eq!(orange.to_u8(), [255, 128, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Srgb<T> {
+    /// The encoded red component.
+    pub r: T,
+    /// The encoded green component.
+    pub g: T,
+    /// The encoded blue component.
+    pub b: T,
+}
+
+/// A color in gamma-encoded sRGB with straight alpha.
+///
+/// Alpha is never gamma-encoded, so it carries over unchanged to and from
+/// [`Rgba`].
+///
+/// # Examples
+///
+/// ```
+/// use vexel::color::rgb::{Rgba, Srgba};
+///
+/// let encoded = Srgba::new(1.0, 1.0, 1.0, 0.25);
+/// assert_eq!(Rgba::from(encoded), Rgba::new(1.0, 1.0, 1.0, 0.25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Srgba<T> {
+    /// The encoded red component.
+    pub r: T,
+    /// The encoded green component.
+    pub g: T,
+    /// The encoded blue component.
+    pub b: T,
+    /// The opacity, from 0 for transparent to 1 for opaque.
+    pub a: T,
+}
+
+impl<T> Rgb<T> {
+    /// Creates a color from its linear components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let red = Rgb::new(1.0, 0.0, 0.0);
+    /// assert_eq!(red.r, 1.0);
+    /// ```
+    pub fn new(r: T, g: T, b: T) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Adds an alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Rgb, Rgba};
+    ///
+    /// let color = Rgb::new(1.0, 0.5, 0.0).with_alpha(0.75);
+    /// assert_eq!(color, Rgba::new(1.0, 0.5, 0.0, 0.75));
+    /// ```
+    pub fn with_alpha(self, a: T) -> Rgba<T> {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+}
+
+impl<T: Real> Rgb<T> {
+    /// Returns the relative luminance, the `Y` of [`Xyz`](super::xyz::Xyz),
+    /// with white at 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// // Green looks far brighter than blue of the same intensity.
+    /// let green = Rgb::new(0.0, 1.0, 0.0).luminance();
+    /// let blue = Rgb::new(0.0, 0.0, 1.0).luminance();
+    /// assert!(green > 9.0 * blue);
+    /// assert_approx_eq!(Rgb::new(1.0, 1.0, 1.0).luminance(), 1.0, epsilon = 1e-12);
+    /// ```
+    pub fn luminance(&self) -> T {
+        super::xyz::Xyz::from(*self).y
+    }
+
+    /// Linearly interpolates the light of this color and another, as when
+    /// two lights mix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let black = Rgb::new(0.0, 0.0, 0.0);
+    /// let white = Rgb::new(1.0, 1.0, 1.0);
+    /// assert_eq!(black.lerp(&white, 0.5), Rgb::new(0.5, 0.5, 0.5));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+
+    /// Blends this color with another through [`Oklab`], so the blend moves
+    /// through evenly spaced perceived colors.
+    ///
+    /// Blending linear light from blue to white passes through purple and
+    /// looks uneven in brightness; a perceptual blend does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::oklab::Oklab;
+    /// use vexel::color::rgb::Rgb;
+    ///
+    /// let black = Rgb::new(0.0, 0.0, 0.0);
+    /// let white = Rgb::new(1.0, 1.0, 1.0);
+    /// let grey = black.mix(&white, 0.5);
+    /// assert_approx_eq!(Oklab::from(grey).l, 0.5, epsilon = 1e-6);
+    /// // Half lightness is far less than half the light.
+    /// assert!(grey.r < 0.15);
+    /// ```
+    pub fn mix(&self, other: &Self, t: T) -> Self {
+        Oklab::from(*self).lerp(&Oklab::from(*other), t).into()
+    }
+}
+
+impl<T> Rgba<T> {
+    /// Creates a color from its linear components and alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Rgba;
+    ///
+    /// let smoke = Rgba::new(0.5, 0.5, 0.5, 0.25);
+    /// assert_eq!(smoke.a, 0.25);
+    /// ```
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns the color without its alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Rgb, Rgba};
+    ///
+    /// assert_eq!(Rgba::new(1.0, 0.5, 0.0, 0.75).rgb(), Rgb::new(1.0, 0.5, 0.0));
+    /// ```
+    pub fn rgb(self) -> Rgb<T> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+}
+
+impl<T> PremultipliedRgba<T> {
+    /// Creates a color from components already multiplied by `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::PremultipliedRgba;
+    ///
+    /// let half_red = PremultipliedRgba::new(0.5, 0.0, 0.0, 0.5);
+    /// assert_eq!(half_red.a, 0.5);
+    /// ```
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl<T: Real> PremultipliedRgba<T> {
+    /// Composites this color over `below` with the Porter–Duff "over"
+    /// operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{PremultipliedRgba, Rgba};
+    ///
+    /// let glass = PremultipliedRgba::from(Rgba::new(1.0, 0.0, 0.0, 0.25));
+    /// let wall = PremultipliedRgba::from(Rgba::new(0.0, 0.0, 1.0, 1.0));
+    /// let seen = Rgba::from(glass.over(&wall));
+    /// assert_eq!(seen, Rgba::new(0.25, 0.0, 0.75, 1.0));
+    /// ```
+    pub fn over(&self, below: &Self) -> Self {
+        let through = T::ONE - self.a;
+        Self::new(
+            self.r + below.r * through,
+            self.g + below.g * through,
+            self.b + below.b * through,
+            self.a + below.a * through,
+        )
+    }
+
+    /// Linearly interpolates between this color and another.
+    ///
+    /// Unlike interpolating straight alpha, a fully transparent end adds
+    /// nothing of its color to the blend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{PremultipliedRgba, Rgba};
+    ///
+    /// let red = PremultipliedRgba::from(Rgba::new(1.0, 0.0, 0.0, 1.0));
+    /// let clear_green = PremultipliedRgba::from(Rgba::new(0.0, 1.0, 0.0, 0.0));
+    /// let half = Rgba::from(red.lerp(&clear_green, 0.5));
+    /// assert_eq!(half, Rgba::new(1.0, 0.0, 0.0, 0.5));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t,
+        )
+    }
+}
+
+impl<T> Srgb<T> {
+    /// Creates a color from its encoded components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// let grey = Srgb::new(0.5, 0.5, 0.5);
+    /// assert_eq!(grey.g, 0.5);
+    /// ```
+    pub fn new(r: T, g: T, b: T) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Adds an alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Srgb, Srgba};
+    ///
+    /// let color = Srgb::new(1.0, 0.5, 0.0).with_alpha(0.75);
+    /// assert_eq!(color, Srgba::new(1.0, 0.5, 0.0, 0.75));
+    /// ```
+    pub fn with_alpha(self, a: T) -> Srgba<T> {
+        Srgba::new(self.r, self.g, self.b, a)
+    }
+}
+
+impl<T: Real> Srgb<T> {
+    /// Creates a color from 8-bit encoded components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Srgb::from_u8(255, 0, 51), Srgb::new(1.0, 0.0, 0.2));
+    /// ```
+    pub fn from_u8(r: u8, g: u8, b: u8) -> Self {
+        Self::new(from_byte(r), from_byte(g), from_byte(b))
+    }
+
+    /// Returns the components as 8-bit values, clamped to `[0, 1]` and
+    /// rounded to the nearest step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgb;
+    ///
+    /// assert_eq!(Srgb::new(1.5, 0.5, -0.25).to_u8(), [255, 128, 0]);
+    /// ```
+    pub fn to_u8(&self) -> [u8; 3] {
+        [to_byte(self.r), to_byte(self.g), to_byte(self.b)]
+    }
+}
+
+impl<T> Srgba<T> {
+    /// Creates a color from its encoded components and alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgba;
+    ///
+    /// let color = Srgba::new(0.5, 0.5, 0.5, 1.0);
+    /// assert_eq!(color.a, 1.0);
+    /// ```
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns the color without its alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Srgb, Srgba};
+    ///
+    /// assert_eq!(Srgba::new(1.0, 0.5, 0.0, 0.75).rgb(), Srgb::new(1.0, 0.5, 0.0));
+    /// ```
+    pub fn rgb(self) -> Srgb<T> {
+        Srgb::new(self.r, self.g, self.b)
+    }
+}
+
+impl<T: Real> Srgba<T> {
+    /// Creates a color from 8-bit encoded components and alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgba;
+    ///
+    /// assert_eq!(Srgba::from_u8(255, 0, 51, 255), Srgba::new(1.0, 0.0, 0.2, 1.0));
+    /// ```
+    pub fn from_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(from_byte(r), from_byte(g), from_byte(b), from_byte(a))
+    }
+
+    /// Returns the components and alpha as 8-bit values, clamped to `[0, 1]`
+    /// and rounded to the nearest step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::Srgba;
+    ///
+    /// assert_eq!(Srgba::new(1.0, 0.5, 0.0, 0.2).to_u8(), [255, 128, 0, 51]);
+    /// ```
+    pub fn to_u8(&self) -> [u8; 4] {
+        [
+            to_byte(self.r),
+            to_byte(self.g),
+            to_byte(self.b),
+            to_byte(self.a),
+        ]
+    }
+}
+
+impl<T: Real> From<Srgb<T>> for Rgb<T> {
+    /// Decodes the sRGB transfer function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Rgb, Srgb};
+    ///
+    /// assert_eq!(Rgb::from(Srgb::new(0.0, 1.0, 0.04045)), Rgb::new(0.0, 1.0, 0.04045 / 12.92));
+    /// ```
+    fn from(c: Srgb<T>) -> Self {
+        Self::new(decode(c.r), decode(c.g), decode(c.b))
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Srgb<T> {
+    /// Applies the sRGB transfer function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::{Rgb, Srgb};
+    ///
+    /// let encoded = Srgb::from(Rgb::new(0.0, 1.0, 0.214_041_140_482_232_52));
+    /// assert_eq!((encoded.r, encoded.g), (0.0, 1.0));
+    /// assert_approx_eq!(encoded.b, 0.5, epsilon = 1e-12);
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        Self::new(encode(c.r), encode(c.g), encode(c.b))
+    }
+}
+
+impl<T: Real> From<Srgba<T>> for Rgba<T> {
+    /// Decodes the sRGB transfer function, keeping alpha as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Rgba, Srgba};
+    ///
+    /// assert_eq!(Rgba::from(Srgba::new(1.0, 0.0, 0.0, 0.5)), Rgba::new(1.0, 0.0, 0.0, 0.5));
+    /// ```
+    fn from(c: Srgba<T>) -> Self {
+        Rgb::from(c.rgb()).with_alpha(c.a)
+    }
+}
+
+impl<T: Real> From<Rgba<T>> for Srgba<T> {
+    /// Applies the sRGB transfer function, keeping alpha as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{Rgba, Srgba};
+    ///
+    /// assert_eq!(Srgba::from(Rgba::new(1.0, 0.0, 0.0, 0.5)), Srgba::new(1.0, 0.0, 0.0, 0.5));
+    /// ```
+    fn from(c: Rgba<T>) -> Self {
+        Srgb::from(c.rgb()).with_alpha(c.a)
+    }
+}
+
+impl<T: Real> From<Rgba<T>> for PremultipliedRgba<T> {
+    /// Multiplies the color components by alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{PremultipliedRgba, Rgba};
+    ///
+    /// let p = PremultipliedRgba::from(Rgba::new(1.0, 0.5, 0.0, 0.5));
+    /// assert_eq!(p, PremultipliedRgba::new(0.5, 0.25, 0.0, 0.5));
+    /// ```
+    fn from(c: Rgba<T>) -> Self {
+        Self::new(c.r * c.a, c.g * c.a, c.b * c.a, c.a)
+    }
+}
+
+impl<T: Real> From<PremultipliedRgba<T>> for Rgba<T> {
+    /// Divides the color components by alpha. A fully transparent color
+    /// has no color left to recover and becomes transparent black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::rgb::{PremultipliedRgba, Rgba};
+    ///
+    /// let c = Rgba::from(PremultipliedRgba::new(0.5, 0.25, 0.0, 0.5));
+    /// assert_eq!(c, Rgba::new(1.0, 0.5, 0.0, 0.5));
+    /// assert_eq!(Rgba::from(PremultipliedRgba::new(0.0, 0.0, 0.0, 0.0)), Rgba::new(0.0, 0.0, 0.0, 0.0));
+    /// ```
+    fn from(c: PremultipliedRgba<T>) -> Self {
+        if c.a == T::ZERO {
+            return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+        }
+        let inverse = c.a.recip();
+        Self::new(c.r * inverse, c.g * inverse, c.b * inverse, c.a)
+    }
+}
+
+impl_vector3_conversions!(Rgb, r, g, b);
+impl_vector3_conversions!(Srgb, r, g, b);
+impl_vector4_conversions!(Rgba);
+impl_vector4_conversions!(Srgba);
+impl_vector4_conversions!(PremultipliedRgba);
+
+/// Applies the sRGB transfer function to a linear component. Negative values
+/// are mirrored, as in extended sRGB.
+fn encode<T: Real>(c: T) -> T {
+    let x = c.abs();
+    let encoded = if x <= T::from_f64(0.003_130_8) {
+        x * T::from_f64(12.92)
+    } else {
+        // 1.055 p - 0.055, arranged so that white encodes to exactly 1.
+        let p = x.powf(T::from_f64(1.0 / 2.4));
+        p + T::from_f64(0.055) * (p - T::ONE)
+    };
+    encoded.copysign(c)
+}
+
+/// Decodes the sRGB transfer function back to a linear component.
+fn decode<T: Real>(c: T) -> T {
+    let x = c.abs();
+    let decoded = if x <= T::from_f64(0.040_45) {
+        x / T::from_f64(12.92)
+    } else {
+        ((x + T::from_f64(0.055)) / T::from_f64(1.055)).powf(T::from_f64(2.4))
+    };
+    decoded.copysign(c)
+}
+
+fn from_byte<T: Real>(byte: u8) -> T {
+    T::from_f64(byte as f64 / 255.0)
+}
+
+fn to_byte<T: Real>(c: T) -> u8 {
+    (c.clamp(T::ZERO, T::ONE).to_f64() * 255.0).round() as u8
+}
@@ -0,0 +1,275 @@
+//! CIE 1931 XYZ and CIE L\*a\*b\*.
+//!
+//! XYZ is the device-independent space that other color spaces are defined
+//! against; `Y` is the luminance. L\*a\*b\* reshapes it so that distances
+//! roughly match perceived differences. Both use the D65 white point of sRGB
+//! here, so sRGB white is `Xyz { y: 1, .. }` and `Lab { l: 100, a: 0, b: 0 }`.
+
+use crate::scalar::Real;
+
+use super::rgb::Rgb;
+use super::{cbrt, impl_vector3_conversions, transform};
+
+/// The matrix from linear sRGB to XYZ, derived from the sRGB primaries and
+/// the D65 white point.
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.412_390_799_265_959_5,
+        0.357_584_339_383_877_96,
+        0.180_480_788_401_834_3,
+    ],
+    [
+        0.212_639_005_871_510_36,
+        0.715_168_678_767_755_9,
+        0.072_192_315_360_733_71,
+    ],
+    [
+        0.019_330_818_715_591_85,
+        0.119_194_779_794_625_99,
+        0.950_532_152_249_660_6,
+    ],
+];
+
+/// The inverse of [`SRGB_TO_XYZ`].
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [
+        3.240_969_941_904_521_3,
+        -1.537_383_177_570_093_5,
+        -0.498_610_760_293_003_3,
+    ],
+    [
+        -0.969_243_636_280_879_8,
+        1.875_967_501_507_720_6,
+        0.041_555_057_407_175_61,
+    ],
+    [
+        0.055_630_079_696_993_61,
+        -0.203_976_958_888_976_57,
+        1.056_971_514_242_878_6,
+    ],
+];
+
+/// The D65 white point, the XYZ of linear sRGB white.
+const WHITE: [f64; 3] = [0.950_455_927_051_671_7, 1.0, 1.089_057_750_759_878_4];
+
+/// A color in CIE 1931 XYZ.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::color::rgb::Rgb;
+/// use vexel::color::xyz::Xyz;
+///
+/// let white = Xyz::from(Rgb::new(1.0, 1.0, 1.0));
+/// assert_approx_eq!(white.y, 1.0, epsilon = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Xyz<T> {
+    /// The `X` tristimulus value.
+    pub x: T,
+    /// The `Y` tristimulus value, the luminance.
+    pub y: T,
+    /// The `Z` tristimulus value.
+    pub z: T,
+}
+
+/// A color in CIE L\*a\*b\*.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::color::rgb::Rgb;
+/// use vexel::color::xyz::Lab;
+///
+/// let grey = Lab::from(Rgb::new(0.18, 0.18, 0.18));
+/// assert_approx_eq!(grey.l, 49.5, epsilon = 0.1);
+/// assert_approx_eq!(grey.a, 0.0, epsilon = 1e-9);
+/// assert_approx_eq!(grey.b, 0.0, epsilon = 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab<T> {
+    /// The lightness, from 0 for black to 100 for white.
+    pub l: T,
+    /// The position between green, negative, and red, positive.
+    pub a: T,
+    /// The position between blue, negative, and yellow, positive.
+    pub b: T,
+}
+
+impl<T> Xyz<T> {
+    /// Creates a color from its tristimulus values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::xyz::Xyz;
+    ///
+    /// let c = Xyz::new(0.5, 0.4, 0.3);
+    /// assert_eq!(c.y, 0.4);
+    /// ```
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> Lab<T> {
+    /// Creates a color from its lightness and `a` and `b` positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::xyz::Lab;
+    ///
+    /// let c = Lab::new(50.0, 20.0, -10.0);
+    /// assert_eq!(c.l, 50.0);
+    /// ```
+    pub fn new(l: T, a: T, b: T) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Xyz<T> {
+    /// Converts a linear sRGB color to XYZ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::Rgb;
+    /// use vexel::color::xyz::Xyz;
+    ///
+    /// let green = Xyz::from(Rgb::new(0.0, 1.0, 0.0));
+    /// assert_approx_eq!(green.y, 0.7152, epsilon = 1e-4);
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        let [x, y, z] = transform(&SRGB_TO_XYZ, [c.r, c.g, c.b]);
+        Self::new(x, y, z)
+    }
+}
+
+impl<T: Real> From<Xyz<T>> for Rgb<T> {
+    /// Converts an XYZ color to linear sRGB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::Rgb;
+    /// use vexel::color::xyz::Xyz;
+    ///
+    /// let back = Rgb::from(Xyz::from(Rgb::new(0.2, 0.4, 0.6)));
+    /// assert_approx_eq!(back.b, 0.6, epsilon = 1e-12);
+    /// ```
+    fn from(c: Xyz<T>) -> Self {
+        let [r, g, b] = transform(&XYZ_TO_SRGB, [c.x, c.y, c.z]);
+        Self::new(r, g, b)
+    }
+}
+
+impl<T: Real> From<Xyz<T>> for Lab<T> {
+    /// Converts an XYZ color to L\*a\*b\* relative to D65 white.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::color::xyz::{Lab, Xyz};
+    ///
+    /// let black = Lab::from(Xyz::new(0.0, 0.0, 0.0));
+    /// assert_eq!(black, Lab::new(0.0, 0.0, 0.0));
+    /// ```
+    fn from(c: Xyz<T>) -> Self {
+        let v = [c.x, c.y, c.z];
+        let [fx, fy, fz]: [T; 3] = core::array::from_fn(|i| lab_f(v[i] / T::from_f64(WHITE[i])));
+        Self::new(
+            T::from_f64(116.0) * fy - T::from_f64(16.0),
+            T::from_f64(500.0) * (fx - fy),
+            T::from_f64(200.0) * (fy - fz),
+        )
+    }
+}
+
+impl<T: Real> From<Lab<T>> for Xyz<T> {
+    /// Converts an L\*a\*b\* color relative to D65 white to XYZ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::xyz::{Lab, Xyz};
+    ///
+    /// let white = Xyz::from(Lab::new(100.0, 0.0, 0.0));
+    /// assert_approx_eq!(white.y, 1.0, epsilon = 1e-12);
+    /// ```
+    fn from(c: Lab<T>) -> Self {
+        let fy = (c.l + T::from_f64(16.0)) / T::from_f64(116.0);
+        let fx = fy + c.a / T::from_f64(500.0);
+        let fz = fy - c.b / T::from_f64(200.0);
+        Self::new(
+            lab_f_inverse(fx) * T::from_f64(WHITE[0]),
+            lab_f_inverse(fy) * T::from_f64(WHITE[1]),
+            lab_f_inverse(fz) * T::from_f64(WHITE[2]),
+        )
+    }
+}
+
+impl<T: Real> From<Rgb<T>> for Lab<T> {
+    /// Converts a linear sRGB color to L\*a\*b\* through XYZ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::Rgb;
+    /// use vexel::color::xyz::Lab;
+    ///
+    /// let white = Lab::from(Rgb::new(1.0, 1.0, 1.0));
+    /// assert_approx_eq!(white.l, 100.0, epsilon = 1e-9);
+    /// ```
+    fn from(c: Rgb<T>) -> Self {
+        Xyz::from(c).into()
+    }
+}
+
+impl<T: Real> From<Lab<T>> for Rgb<T> {
+    /// Converts an L\*a\*b\* color to linear sRGB through XYZ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::color::rgb::Rgb;
+    /// use vexel::color::xyz::Lab;
+    ///
+    /// let white = Rgb::from(Lab::new(100.0, 0.0, 0.0));
+    /// assert_approx_eq!(white.g, 1.0, epsilon = 1e-9);
+    /// ```
+    fn from(c: Lab<T>) -> Self {
+        Xyz::from(c).into()
+    }
+}
+
+impl_vector3_conversions!(Xyz, x, y, z);
+impl_vector3_conversions!(Lab, l, a, b);
+
+/// The L\*a\*b\* compression of a white-relative tristimulus value: a cube
+/// root, with a linear segment near black.
+fn lab_f<T: Real>(t: T) -> T {
+    let delta = T::from_f64(6.0 / 29.0);
+    if t > delta * delta * delta {
+        cbrt(t)
+    } else {
+        t / (T::from_f64(3.0) * delta * delta) + T::from_f64(4.0 / 29.0)
+    }
+}
+
+/// The inverse of [`lab_f`].
+fn lab_f_inverse<T: Real>(t: T) -> T {
+    let delta = T::from_f64(6.0 / 29.0);
+    if t > delta {
+        t * t * t
+    } else {
+        T::from_f64(3.0) * delta * delta * (t - T::from_f64(4.0 / 29.0))
+    }
+}
@@ -0,0 +1,239 @@
+//! Moving along a curve by distance instead of by parameter.
+//!
+//! Equal steps in the parameter of a curve cover unequal distances: a point
+//! speeds up where control points are far apart and slows down where they
+//! bunch together. [`ArcLength`] measures the curve once and then maps any
+//! distance along it back to the parameter that reaches it.
+
+use alloc::vec::Vec;
+
+use crate::scalar::Real;
+
+use super::{length, Curve, CurvePoint};
+
+/// Nodes and weights of five-point Gauss–Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// The most Newton steps taken when inverting a distance.
+const MAX_ITERATIONS: usize = 16;
+
+/// A curve measured so it can be traversed by distance.
+///
+/// The domain is cut into equal pieces whose lengths are integrated
+/// numerically. More pieces give more accurate lengths on curves that bend
+/// sharply; a few dozen are plenty for smooth camera paths.
+#[derive(Debug, Clone)]
+pub struct ArcLength<C, T> {
+    curve: C,
+    /// The parameter at the start of each piece, and the end of the domain.
+    parameters: Vec<T>,
+    /// The distance along the curve to each entry of `parameters`.
+    distances: Vec<T>,
+}
+
+impl<C, T> ArcLength<C, T>
+where
+    T: Real,
+    C: Curve<T>,
+    C::Point: CurvePoint<Scalar = T>,
+{
+    /// Measures `curve` by splitting its domain into `pieces` equal parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pieces` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::arc_length::ArcLength;
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// // The usual cubic approximation of a quarter of the unit circle.
+    /// let k = 0.5519150244935105;
+    /// let quarter = CubicBezier::new(
+    ///     Vector2::new(1.0, 0.0),
+    ///     Vector2::new(1.0, k),
+    ///     Vector2::new(k, 1.0),
+    ///     Vector2::new(0.0, 1.0),
+    /// );
+    /// let measured = ArcLength::new(quarter, 16);
+    /// assert_approx_eq!(measured.length(), std::f64::consts::FRAC_PI_2, epsilon = 1e-3);
+    /// ```
+    pub fn new(curve: C, pieces: usize) -> Self {
+        assert!(pieces > 0, "a curve must be measured in at least one piece");
+        let (start, end) = curve.domain();
+        let step = (end - start) / T::from_f64(pieces as f64);
+        let parameters: Vec<_> = (0..=pieces)
+            .map(|i| {
+                if i == pieces {
+                    end
+                } else {
+                    start + step * T::from_f64(i as f64)
+                }
+            })
+            .collect();
+        let mut distances = Vec::with_capacity(parameters.len());
+        distances.push(T::ZERO);
+        for pair in parameters.windows(2) {
+            let previous = distances[distances.len() - 1];
+            distances.push(previous + integrate(&curve, pair[0], pair[1]));
+        }
+        Self {
+            curve,
+            parameters,
+            distances,
+        }
+    }
+
+    /// Returns the curve that was measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::arc_length::ArcLength;
+    /// use vexel::curves::bezier::QuadraticBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = QuadraticBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(2.0, 0.0),
+    /// );
+    /// assert_eq!(ArcLength::new(curve, 8).curve(), &curve);
+    /// ```
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    /// Returns the length of the whole curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::arc_length::ArcLength;
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // A straight line with unevenly spaced control points.
+    /// let line = CubicBezier::new(
+    ///     Vector3::new(0.0_f64, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 0.5),
+    ///     Vector3::new(0.0, 0.0, 0.75),
+    ///     Vector3::new(0.0, 0.0, 6.0),
+    /// );
+    /// assert_approx_eq!(ArcLength::new(line, 4).length(), 6.0, epsilon = 1e-12);
+    /// ```
+    pub fn length(&self) -> T {
+        self.distances[self.distances.len() - 1]
+    }
+
+    /// Returns the parameter of the point `distance` along the curve from its
+    /// start. Distances outside `[0, length]` are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::arc_length::ArcLength;
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = CubicBezier::new(
+    ///     Vector3::new(0.0_f64, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 0.5),
+    ///     Vector3::new(0.0, 0.0, 0.75),
+    ///     Vector3::new(0.0, 0.0, 6.0),
+    /// );
+    /// let measured = ArcLength::new(line, 4);
+    /// let t = measured.parameter_at(2.0);
+    /// assert_approx_eq!(line.position(t).z, 2.0, epsilon = 1e-9);
+    /// ```
+    pub fn parameter_at(&self, distance: T) -> T {
+        let distance = distance.clamp(T::ZERO, self.length());
+        let piece = self
+            .distances
+            .partition_point(|&d| d <= distance)
+            .clamp(1, self.parameters.len() - 1)
+            - 1;
+        let (low, high) = (self.parameters[piece], self.parameters[piece + 1]);
+        let (start, end) = (self.distances[piece], self.distances[piece + 1]);
+        if end <= start {
+            return low;
+        }
+
+        // Newton's method on the distance from the start of the piece, kept
+        // inside the piece so a stationary point cannot throw it out.
+        let target = distance - start;
+        let mut t = low + (high - low) * (target / (end - start));
+        for _ in 0..MAX_ITERATIONS {
+            let error = integrate(&self.curve, low, t) - target;
+            let speed = length(self.curve.derivative(t));
+            if speed <= T::ZERO {
+                break;
+            }
+            let next = (t - error / speed).clamp(low, high);
+            let converged = (next - t).abs() <= T::EPSILON * (T::ONE + t.abs());
+            t = next;
+            if converged {
+                break;
+            }
+        }
+        t
+    }
+
+    /// Returns the point `distance` along the curve from its start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::arc_length::ArcLength;
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = CubicBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(3.5, 0.0),
+    ///     Vector2::new(4.0, 0.0),
+    /// );
+    /// let measured = ArcLength::new(line, 8);
+    /// for step in 0..=8 {
+    ///     let d = step as f64 * 0.5;
+    ///     assert_approx_eq!(measured.position_at(d).x, d, epsilon = 1e-9);
+    /// }
+    /// ```
+    pub fn position_at(&self, distance: T) -> C::Point {
+        self.curve.position(self.parameter_at(distance))
+    }
+}
+
+/// Returns the length of `curve` between the parameters `a` and `b`.
+fn integrate<T, C>(curve: &C, a: T, b: T) -> T
+where
+    T: Real,
+    C: Curve<T>,
+    C::Point: CurvePoint<Scalar = T>,
+{
+    let half = (b - a) * T::HALF;
+    let middle = (a + b) * T::HALF;
+    GAUSS_LEGENDRE
+        .iter()
+        .map(|&(x, w)| {
+            let t = middle + half * T::from_f64(x);
+            T::from_f64(w) * length(curve.derivative(t))
+        })
+        .fold(T::ZERO, |sum, term| sum + term)
+        * half
+}
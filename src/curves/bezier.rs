@@ -0,0 +1,349 @@
+//! Quadratic and cubic Bézier curves.
+//!
+//! A Bézier curve starts at its first control point, ends at its last and is
+//! pulled towards the ones in between without passing through them. The
+//! curve always stays inside the convex hull of its control points. The
+//! domain of both curves is `[0, 1]`.
+
+use crate::scalar::{Real, Scalar};
+
+use super::hermite::CubicHermite;
+use super::{extent, lerp, Curve, CurvePoint};
+
+/// A Bézier curve with three control points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<P> {
+    /// The start of the curve.
+    pub p0: P,
+    /// The control point the curve is pulled towards.
+    pub p1: P,
+    /// The end of the curve.
+    pub p2: P,
+}
+
+impl<P> QuadraticBezier<P> {
+    /// Creates a curve from its control points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::QuadraticBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = QuadraticBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, 0.0),
+    /// );
+    /// assert_eq!(curve.position(0.5), Vector2::new(1.0, 1.0));
+    /// ```
+    pub fn new(p0: P, p1: P, p2: P) -> Self {
+        Self { p0, p1, p2 }
+    }
+}
+
+impl<P: CurvePoint> QuadraticBezier<P> {
+    /// Splits the curve at `t` into the part before and the part after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::QuadraticBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = QuadraticBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, 0.0),
+    /// );
+    /// let (before, after) = curve.split(0.5);
+    /// assert_eq!(before.p2, curve.position(0.5));
+    /// assert_eq!(after.p0, curve.position(0.5));
+    /// assert_eq!(before.position(0.5), curve.position(0.25));
+    /// ```
+    pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+        let a = lerp(self.p0, self.p1, t);
+        let b = lerp(self.p1, self.p2, t);
+        let middle = lerp(a, b, t);
+        (Self::new(self.p0, a, middle), Self::new(middle, b, self.p2))
+    }
+
+    /// Returns the tightest box enclosing the curve as its minimum and
+    /// maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::QuadraticBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = QuadraticBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, 0.0),
+    /// );
+    /// let (min, max) = curve.bounds();
+    /// assert_eq!(min, Vector2::new(0.0, 0.0));
+    /// assert_eq!(max, Vector2::new(2.0, 1.0));
+    /// ```
+    pub fn bounds(&self) -> (P, P) {
+        // Each component is extreme at an end or where its derivative,
+        // which is linear in t, crosses zero.
+        let turns = (0..P::DIMENSIONS).filter_map(|axis| {
+            let a = self.p1.component(axis) - self.p0.component(axis);
+            let b = self.p2.component(axis) - self.p1.component(axis);
+            let denominator = a - b;
+            if denominator == P::Scalar::ZERO {
+                return None;
+            }
+            let t = a / denominator;
+            (t > P::Scalar::ZERO && t < P::Scalar::ONE).then(|| self.position(t))
+        });
+        extent(self.p0, turns.chain([self.p2]))
+    }
+
+    /// Returns the same curve as a cubic Bézier curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::bezier::QuadraticBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = QuadraticBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 3.0),
+    ///     Vector2::new(6.0, 0.0),
+    /// );
+    /// let cubic = curve.to_cubic();
+    /// assert_eq!(cubic.p1, Vector2::new(2.0, 2.0));
+    /// assert_approx_eq!(cubic.position(0.3), curve.position(0.3), epsilon = 1e-12);
+    /// ```
+    pub fn to_cubic(&self) -> CubicBezier<P> {
+        let two_thirds = P::Scalar::TWO / P::Scalar::from_f64(3.0);
+        CubicBezier::new(
+            self.p0,
+            lerp(self.p0, self.p1, two_thirds),
+            lerp(self.p2, self.p1, two_thirds),
+            self.p2,
+        )
+    }
+}
+
+impl<P: CurvePoint> Curve<P::Scalar> for QuadraticBezier<P> {
+    type Point = P;
+
+    fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (P::Scalar::ZERO, P::Scalar::ONE)
+    }
+
+    fn position(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        lerp(lerp(self.p0, self.p1, t), lerp(self.p1, self.p2, t), t)
+    }
+
+    fn derivative(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        lerp(self.p1 - self.p0, self.p2 - self.p1, t).scale(P::Scalar::TWO)
+    }
+
+    fn second_derivative(&self, _t: P::Scalar) -> P {
+        (self.p2 - self.p1 - (self.p1 - self.p0)).scale(P::Scalar::TWO)
+    }
+}
+
+/// A Bézier curve with four control points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<P> {
+    /// The start of the curve.
+    pub p0: P,
+    /// The control point that sets the direction the curve leaves `p0` in.
+    pub p1: P,
+    /// The control point that sets the direction the curve arrives at `p3`
+    /// from.
+    pub p2: P,
+    /// The end of the curve.
+    pub p3: P,
+}
+
+impl<P> CubicBezier<P> {
+    /// Creates a curve from its control points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     Vector3::new(1.0, 1.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    /// );
+    /// assert_eq!(curve.position(0.0), curve.p0);
+    /// assert_eq!(curve.position(0.5), Vector3::new(0.5, 0.75, 0.0));
+    /// assert_eq!(curve.position(1.0), curve.p3);
+    /// ```
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+}
+
+impl<P: CurvePoint> CubicBezier<P> {
+    /// Splits the curve at `t` into the part before and the part after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(0.0, 1.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(1.0, 0.0),
+    /// );
+    /// let (before, after) = curve.split(0.25);
+    /// assert_eq!(before.p3, curve.position(0.25));
+    /// assert_eq!(after.p0, curve.position(0.25));
+    /// assert_approx_eq!(after.position(0.5), curve.position(0.625), epsilon = 1e-12);
+    /// ```
+    pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+        let a = lerp(self.p0, self.p1, t);
+        let b = lerp(self.p1, self.p2, t);
+        let c = lerp(self.p2, self.p3, t);
+        let ab = lerp(a, b, t);
+        let bc = lerp(b, c, t);
+        let middle = lerp(ab, bc, t);
+        (
+            Self::new(self.p0, a, ab, middle),
+            Self::new(middle, bc, c, self.p3),
+        )
+    }
+
+    /// Returns the tightest box enclosing the curve as its minimum and
+    /// maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(0.0, 1.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(1.0, 0.0),
+    /// );
+    /// let (min, max) = curve.bounds();
+    /// assert_eq!(min, Vector2::new(0.0, 0.0));
+    /// assert_eq!(max, Vector2::new(1.0, 0.75));
+    /// ```
+    pub fn bounds(&self) -> (P, P) {
+        // Each component is extreme at an end or where its derivative,
+        // which is quadratic in t, crosses zero.
+        let turns = (0..P::DIMENSIONS).flat_map(|axis| {
+            let [p0, p1, p2, p3] = [self.p0, self.p1, self.p2, self.p3].map(|p| p.component(axis));
+            let three = P::Scalar::from_f64(3.0);
+            let a = p3 - p0 + three * (p1 - p2);
+            let b = P::Scalar::TWO * (p0 - P::Scalar::TWO * p1 + p2);
+            let c = p1 - p0;
+            unit_roots(a, b, c)
+                .into_iter()
+                .flatten()
+                .map(|t| self.position(t))
+        });
+        extent(self.p0, turns.chain([self.p3]))
+    }
+
+    /// Returns the same curve in Hermite form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bezier::CubicBezier;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(0.0, 1.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(1.0, 0.0),
+    /// );
+    /// let hermite = curve.to_hermite();
+    /// assert_eq!(hermite.m0, Vector2::new(0.0, 3.0));
+    /// assert_eq!(hermite.m1, Vector2::new(0.0, -3.0));
+    /// ```
+    pub fn to_hermite(&self) -> CubicHermite<P> {
+        let three = P::Scalar::from_f64(3.0);
+        CubicHermite::new(
+            self.p0,
+            (self.p1 - self.p0).scale(three),
+            self.p3,
+            (self.p3 - self.p2).scale(three),
+        )
+    }
+}
+
+impl<P: CurvePoint> Curve<P::Scalar> for CubicBezier<P> {
+    type Point = P;
+
+    fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (P::Scalar::ZERO, P::Scalar::ONE)
+    }
+
+    fn position(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let a = lerp(self.p0, self.p1, t);
+        let b = lerp(self.p1, self.p2, t);
+        let c = lerp(self.p2, self.p3, t);
+        lerp(lerp(a, b, t), lerp(b, c, t), t)
+    }
+
+    fn derivative(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let hodograph =
+            QuadraticBezier::new(self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        hodograph.position(t).scale(P::Scalar::from_f64(3.0))
+    }
+
+    fn second_derivative(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let a = self.p2 - self.p1 - (self.p1 - self.p0);
+        let b = self.p3 - self.p2 - (self.p2 - self.p1);
+        lerp(a, b, t).scale(P::Scalar::from_f64(6.0))
+    }
+}
+
+/// Returns the roots of `a t² + b t + c` that lie strictly between zero and
+/// one.
+fn unit_roots<T: Real>(a: T, b: T, c: T) -> [Option<T>; 2] {
+    let inside = |t: T| (t > T::ZERO && t < T::ONE).then_some(t);
+    if a == T::ZERO {
+        if b == T::ZERO {
+            return [None, None];
+        }
+        return [inside(-c / b), None];
+    }
+    let discriminant = b * b - T::from_f64(4.0) * a * c;
+    if discriminant < T::ZERO {
+        return [None, None];
+    }
+    // Avoids cancelling two nearly equal numbers when b dominates.
+    let q = -T::HALF * (b + discriminant.sqrt().copysign(b));
+    if q == T::ZERO {
+        // Only happens when b and c are both zero: a double root at zero.
+        return [None, None];
+    }
+    [inside(q / a), inside(c / q)]
+}
@@ -0,0 +1,387 @@
+//! B-splines of any degree.
+//!
+//! A B-spline blends its control points with piecewise polynomials that
+//! meet at parameters called knots. Moving one control point only changes
+//! the curve near it, and a spline of degree `p` has `p - 1` continuous
+//! derivatives wherever its knots are distinct. Like a Bézier curve it stays
+//! inside the convex hull of its control points and does not, in general,
+//! pass through them.
+//!
+//! A spline with `n` control points of degree `p` has `n + p + 1` knots and
+//! is defined between knot `p` and knot `n`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::scalar::{Real, Scalar};
+
+use super::{extent, lerp, Curve, CurvePoint};
+
+/// A B-spline with any knot vector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<P: CurvePoint> {
+    degree: usize,
+    control_points: Vec<P>,
+    knots: Vec<P::Scalar>,
+}
+
+impl<P: CurvePoint> BSpline<P> {
+    /// Creates a spline from its degree, control points and knots.
+    ///
+    /// Returns `None` unless there are more control points than the degree,
+    /// exactly `control_points.len() + degree + 1` knots, the knots never
+    /// decrease and the domain is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// // Repeating the end knots makes the spline start and end on its
+    /// // first and last control points.
+    /// let spline = BSpline::new(
+    ///     2,
+    ///     vec![
+    ///         Vector2::new(0.0, 0.0),
+    ///         Vector2::new(1.0, 2.0),
+    ///         Vector2::new(3.0, 2.0),
+    ///         Vector2::new(4.0, 0.0),
+    ///     ],
+    ///     vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(spline.domain(), (0.0, 1.0));
+    /// assert_eq!(spline.position(0.0), Vector2::new(0.0, 0.0));
+    /// assert_eq!(spline.position(1.0), Vector2::new(4.0, 0.0));
+    ///
+    /// assert!(BSpline::new(2, vec![Vector2::new(0.0, 0.0); 4], vec![0.0; 7]).is_none());
+    /// ```
+    pub fn new(degree: usize, control_points: Vec<P>, knots: Vec<P::Scalar>) -> Option<Self> {
+        let n = control_points.len();
+        let valid = n > degree
+            && knots.len() == n + degree + 1
+            && knots.windows(2).all(|pair| pair[0] <= pair[1])
+            && knots[degree] < knots[n];
+        valid.then_some(Self {
+            degree,
+            control_points,
+            knots,
+        })
+    }
+
+    /// Creates a spline whose knots are the whole numbers `0, 1, 2, …`.
+    ///
+    /// Every piece of a uniform spline is shaped the same way. Its domain is
+    /// `[degree, control_points.len()]`. Returns `None` unless there are more
+    /// control points than the degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(
+    ///     3,
+    ///     vec![
+    ///         Vector2::new(0.0, 0.0),
+    ///         Vector2::new(1.0, 3.0),
+    ///         Vector2::new(2.0, 0.0),
+    ///         Vector2::new(3.0, 3.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(spline.domain(), (3.0, 4.0));
+    /// // A uniform cubic starts at a weighted average of its first three points.
+    /// assert_eq!(spline.position(3.0), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn uniform(degree: usize, control_points: Vec<P>) -> Option<Self> {
+        let knots = (0..control_points.len() + degree + 1)
+            .map(|i| P::Scalar::from_f64(i as f64))
+            .collect();
+        Self::new(degree, control_points, knots)
+    }
+
+    /// Returns the degree of the pieces of the spline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(2, vec![Vector2::new(0.0, 0.0); 5]).unwrap();
+    /// assert_eq!(spline.degree(), 2);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the control points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(1, vec![Vector2::new(1.0, 2.0); 3]).unwrap();
+    /// assert_eq!(spline.control_points().len(), 3);
+    /// ```
+    pub fn control_points(&self) -> &[P] {
+        &self.control_points
+    }
+
+    /// Returns the knots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(1, vec![Vector2::new(1.0, 2.0); 3]).unwrap();
+    /// assert_eq!(spline.knots(), [0.0, 1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn knots(&self) -> &[P::Scalar] {
+        &self.knots
+    }
+
+    /// Returns the spline traced by the derivative of this one, which has one
+    /// degree less, or `None` if this spline has degree zero.
+    ///
+    /// The result is a spline in its own right, so it can be split or bounded
+    /// like any other. [`Curve::derivative`] evaluates the same curve without
+    /// building it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(
+    ///     2,
+    ///     vec![
+    ///         Vector2::new(0.0, 0.0),
+    ///         Vector2::new(1.0, 1.0),
+    ///         Vector2::new(2.0, 0.0),
+    ///         Vector2::new(3.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let velocity = spline.derivative_spline().unwrap();
+    /// assert_eq!(velocity.degree(), 1);
+    /// assert_eq!(velocity.position(2.5), spline.derivative(2.5));
+    /// ```
+    pub fn derivative_spline(&self) -> Option<Self> {
+        let p = self.degree;
+        if p == 0 {
+            return None;
+        }
+        let degree = P::Scalar::from_f64(p as f64);
+        let control_points = self
+            .control_points
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let span = self.knots[i + p + 1] - self.knots[i + 1];
+                if span > P::Scalar::ZERO {
+                    (pair[1] - pair[0]).scale(degree / span)
+                } else {
+                    pair[0].scale(P::Scalar::ZERO)
+                }
+            })
+            .collect();
+        let knots = self.knots[1..self.knots.len() - 1].to_vec();
+        Some(Self {
+            degree: p - 1,
+            control_points,
+            knots,
+        })
+    }
+
+    /// Splits the spline at `t` into the part before and the part after it,
+    /// or returns `None` unless `t` lies strictly inside the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(
+    ///     3,
+    ///     vec![
+    ///         Vector2::new(0.0, 0.0),
+    ///         Vector2::new(1.0, 3.0),
+    ///         Vector2::new(2.0, 0.0),
+    ///         Vector2::new(3.0, 3.0),
+    ///         Vector2::new(4.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let (before, after) = spline.split(4.25).unwrap();
+    /// assert_eq!(before.domain(), (3.0, 4.25));
+    /// assert_eq!(after.domain(), (4.25, 5.0));
+    /// assert_approx_eq!(before.position(4.0), spline.position(4.0), epsilon = 1e-12);
+    /// assert_approx_eq!(after.position(4.5), spline.position(4.5), epsilon = 1e-12);
+    ///
+    /// assert!(spline.split(3.0).is_none());
+    /// ```
+    pub fn split(&self, t: P::Scalar) -> Option<(Self, Self)> {
+        let (start, end) = self.domain();
+        if t <= start || t >= end {
+            return None;
+        }
+        let p = self.degree;
+        let mut spline = self.clone();
+        let multiplicity = self.knots.iter().filter(|&&knot| knot == t).count();
+        for _ in multiplicity..p + 1 {
+            spline.insert_knot(t);
+        }
+
+        // With t repeated p + 1 times the pieces on either side no longer
+        // share any control points.
+        let before = spline.knots.iter().filter(|&&knot| knot < t).count();
+        let after = spline.knots.iter().filter(|&&knot| knot > t).count();
+        let copies = vec![t; p + 1];
+        let first = Self {
+            degree: p,
+            control_points: spline.control_points[..before].to_vec(),
+            knots: [&spline.knots[..before], &copies].concat(),
+        };
+        let second = Self {
+            degree: p,
+            control_points: spline.control_points[before..].to_vec(),
+            knots: [&copies, &spline.knots[spline.knots.len() - after..]].concat(),
+        };
+        Some((first, second))
+    }
+
+    /// Returns a box enclosing the spline as its minimum and maximum corners.
+    ///
+    /// The box encloses the control points, which the curve never leaves, so
+    /// it may be larger than the curve itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::bspline::BSpline;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = BSpline::uniform(
+    ///     2,
+    ///     vec![
+    ///         Vector2::new(0.0, 0.0),
+    ///         Vector2::new(1.0, 2.0),
+    ///         Vector2::new(2.0, -1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let (min, max) = spline.bounds();
+    /// assert_eq!(min, Vector2::new(0.0, -1.0));
+    /// assert_eq!(max, Vector2::new(2.0, 2.0));
+    /// ```
+    pub fn bounds(&self) -> (P, P) {
+        let (first, rest) = self
+            .control_points
+            .split_first()
+            .expect("a spline always has control points");
+        extent(*first, rest.iter().copied())
+    }
+
+    /// Returns the index `k` of the knot span `[knots[k], knots[k + 1])`
+    /// holding `t`, skipping empty spans.
+    fn span(&self, t: P::Scalar) -> usize {
+        let n = self.control_points.len();
+        (self.degree..n)
+            .rev()
+            .find(|&k| self.knots[k] <= t && self.knots[k] < self.knots[k + 1])
+            .unwrap_or(self.degree)
+    }
+
+    /// Inserts `t` into the knots without changing the shape of the spline,
+    /// adding one control point (Boehm's algorithm).
+    fn insert_knot(&mut self, t: P::Scalar) {
+        let p = self.degree;
+        let k = self.span(t);
+        let points = &self.control_points;
+        let mut inserted = Vec::with_capacity(points.len() + 1);
+        inserted.extend_from_slice(&points[..=k - p]);
+        for i in k - p + 1..=k {
+            let alpha = (t - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+            inserted.push(lerp(points[i - 1], points[i], alpha));
+        }
+        inserted.extend_from_slice(&points[k..]);
+        self.control_points = inserted;
+        self.knots.insert(k + 1, t);
+    }
+
+    /// Returns the derivative of the given order at `t`.
+    ///
+    /// Only the `degree + 1` control points of the span holding `t` matter.
+    /// Differencing them `order` times gives the matching control points of
+    /// the derivative spline, whose knots are these without the first and
+    /// last `order`, and de Boor's algorithm evaluates those.
+    fn evaluate(&self, t: P::Scalar, order: usize) -> P {
+        let p = self.degree;
+        if order > p {
+            return self.control_points[0].scale(P::Scalar::ZERO);
+        }
+        let (start, end) = self.domain();
+        let t = t.clamp(start, end);
+        let k = self.span(t);
+        let mut d = self.control_points[k - p..=k].to_vec();
+        for r in 1..=order {
+            let degree = P::Scalar::from_f64((p - r + 1) as f64);
+            for i in 0..=p - r {
+                let span = self.knots[k + i + 1] - self.knots[k - p + i + r];
+                d[i] = if span > P::Scalar::ZERO {
+                    (d[i + 1] - d[i]).scale(degree / span)
+                } else {
+                    d[i].scale(P::Scalar::ZERO)
+                };
+            }
+        }
+        let q = p - order;
+        for r in 1..=q {
+            for j in (r..=q).rev() {
+                let low = self.knots[j + k - p + order];
+                let high = self.knots[j + 1 + k - r];
+                d[j] = lerp(d[j - 1], d[j], (t - low) / (high - low));
+            }
+        }
+        d[q]
+    }
+}
+
+impl<P: CurvePoint> Curve<P::Scalar> for BSpline<P> {
+    type Point = P;
+
+    fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        )
+    }
+
+    /// Evaluates the spline with de Boor's algorithm.
+    fn position(&self, t: P::Scalar) -> P {
+        self.evaluate(t, 0)
+    }
+
+    fn derivative(&self, t: P::Scalar) -> P {
+        self.evaluate(t, 1)
+    }
+
+    fn second_derivative(&self, t: P::Scalar) -> P {
+        self.evaluate(t, 2)
+    }
+}
@@ -0,0 +1,248 @@
+//! Catmull–Rom splines.
+//!
+//! A Catmull–Rom spline passes through every one of its points except the
+//! first and the last, which only shape how it leaves the second point and
+//! arrives at the second to last. The tangent at each point is taken from
+//! its neighbours, so the spline needs nothing but the points themselves,
+//! which suits paths placed by hand such as camera rails.
+//!
+//! How far apart the points are assumed to be in time is set by the
+//! [`Parameterization`]. Centripetal spacing never forms cusps or loops
+//! inside a segment and is the usual choice.
+
+use alloc::vec::Vec;
+
+use crate::scalar::{Real, Scalar};
+
+use super::hermite::CubicHermite;
+use super::{length, Curve, CurvePoint};
+
+/// How the spacing between neighbouring points of a [`CatmullRom`] spline is
+/// measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parameterization {
+    /// Every pair of points is the same time apart. Can overshoot and form
+    /// loops where points are unevenly spaced.
+    Uniform,
+    /// Points are the square root of their distance apart in time. Never
+    /// forms cusps or self-intersections within a segment.
+    Centripetal,
+    /// Points are their distance apart in time. Gives the smoothest curve
+    /// but can swing wide around sharp corners.
+    Chordal,
+}
+
+impl Parameterization {
+    /// Returns the time between two points `distance` apart.
+    fn interval<T: Real>(self, distance: T) -> T {
+        let interval = match self {
+            Self::Uniform => T::ONE,
+            Self::Centripetal => distance.sqrt(),
+            Self::Chordal => distance,
+        };
+        // Repeated points would otherwise divide by zero.
+        if interval > T::ZERO {
+            interval
+        } else {
+            T::ONE
+        }
+    }
+}
+
+/// A spline through a sequence of points.
+///
+/// A spline with `n` points has `n - 3` segments. Its domain is `[0, n - 3]`
+/// and segment `i` covers parameters `i` to `i + 1`, running from point
+/// `i + 1` to point `i + 2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom<P> {
+    points: Vec<P>,
+    parameterization: Parameterization,
+}
+
+impl<P> CatmullRom<P> {
+    /// Creates a spline through `points`, or returns `None` if there are
+    /// fewer than four.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let points = vec![
+    ///     Vector2::new(-1.0, 0.0),
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(2.0, 1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let spline = CatmullRom::new(points, Parameterization::Centripetal).unwrap();
+    /// assert_eq!(spline.domain(), (0.0, 2.0));
+    /// assert_eq!(spline.position(0.0), Vector2::new(0.0, 0.0));
+    /// assert_eq!(spline.position(1.0), Vector2::new(1.0, 1.0));
+    /// assert_eq!(spline.position(2.0), Vector2::new(2.0, 1.0));
+    ///
+    /// assert!(CatmullRom::new(vec![Vector2::new(0.0, 0.0); 3], Parameterization::Uniform).is_none());
+    /// ```
+    pub fn new(points: Vec<P>, parameterization: Parameterization) -> Option<Self> {
+        (points.len() >= 4).then_some(Self {
+            points,
+            parameterization,
+        })
+    }
+
+    /// Returns the points the spline was built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let points = vec![Vector2::new(0.0, 0.0); 4];
+    /// let spline = CatmullRom::new(points.clone(), Parameterization::Chordal).unwrap();
+    /// assert_eq!(spline.points(), points.as_slice());
+    /// ```
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    /// Returns how the spacing between points is measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = CatmullRom::new(vec![Vector2::new(0.0, 0.0); 4], Parameterization::Chordal).unwrap();
+    /// assert_eq!(spline.parameterization(), Parameterization::Chordal);
+    /// ```
+    pub fn parameterization(&self) -> Parameterization {
+        self.parameterization
+    }
+
+    /// Returns the number of segments, three fewer than the number of points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let spline = CatmullRom::new(vec![Vector2::new(0.0, 0.0); 6], Parameterization::Uniform).unwrap();
+    /// assert_eq!(spline.segment_count(), 3);
+    /// ```
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 3
+    }
+}
+
+impl<P: CurvePoint> CatmullRom<P> {
+    /// Returns segment `i` as a Hermite curve over `[0, 1]`.
+    ///
+    /// The tangents follow the Barry–Goldman construction for the spacing of
+    /// the four points around the segment, rescaled to the unit domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than [`segment_count`](Self::segment_count).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let points = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 0.0),
+    ///     Vector2::new(2.0, 1.0),
+    ///     Vector2::new(3.0, 1.0),
+    /// ];
+    /// let spline = CatmullRom::new(points, Parameterization::Uniform).unwrap();
+    /// let segment = spline.segment(0);
+    /// assert_eq!(segment.p0, Vector2::new(1.0, 0.0));
+    /// assert_eq!(segment.p1, Vector2::new(2.0, 1.0));
+    /// // A uniform spline's tangent is half the gap between the neighbours.
+    /// assert_eq!(segment.m0, Vector2::new(1.0, 0.5));
+    /// ```
+    pub fn segment(&self, i: usize) -> CubicHermite<P> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|k| self.points[i + k]);
+        let interval = |a: P, b: P| self.parameterization.interval(length(b - a));
+        let (d0, d1, d2) = (interval(p0, p1), interval(p1, p2), interval(p2, p3));
+
+        let m1 = (p1 - p0).scale(d0.recip()) - (p2 - p0).scale((d0 + d1).recip())
+            + (p2 - p1).scale(d1.recip());
+        let m2 = (p2 - p1).scale(d1.recip()) - (p3 - p1).scale((d1 + d2).recip())
+            + (p3 - p2).scale(d2.recip());
+        CubicHermite::new(p1, m1.scale(d1), p2, m2.scale(d1))
+    }
+
+    /// Returns the tightest box enclosing the spline as its minimum and
+    /// maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let points = vec![
+    ///     Vector2::new(-10.0, 0.0),
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 0.0),
+    ///     Vector2::new(11.0, 0.0),
+    /// ];
+    /// let spline = CatmullRom::new(points, Parameterization::Centripetal).unwrap();
+    /// // The outer points only steer the spline, which runs from 0 to 1.
+    /// assert_eq!(spline.bounds(), (Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)));
+    /// ```
+    pub fn bounds(&self) -> (P, P) {
+        let (low, high) = self.segment(0).bounds();
+        (1..self.segment_count())
+            .map(|i| self.segment(i).bounds())
+            .fold((low, high), |(low, high), (l, h)| {
+                (low.component_min(l), high.component_max(h))
+            })
+    }
+
+    /// Returns the segment containing `t` and where `t` falls within it.
+    fn locate(&self, t: P::Scalar) -> (CubicHermite<P>, P::Scalar) {
+        let last = self.segment_count() - 1;
+        let t = t.clamp(
+            P::Scalar::ZERO,
+            P::Scalar::from_f64(self.segment_count() as f64),
+        );
+        let i = (t.floor().to_f64() as usize).min(last);
+        (self.segment(i), t - P::Scalar::from_f64(i as f64))
+    }
+}
+
+impl<P: CurvePoint> Curve<P::Scalar> for CatmullRom<P> {
+    type Point = P;
+
+    fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (
+            P::Scalar::ZERO,
+            P::Scalar::from_f64(self.segment_count() as f64),
+        )
+    }
+
+    fn position(&self, t: P::Scalar) -> P {
+        let (segment, t) = self.locate(t);
+        segment.position(t)
+    }
+
+    fn derivative(&self, t: P::Scalar) -> P {
+        let (segment, t) = self.locate(t);
+        segment.derivative(t)
+    }
+
+    fn second_derivative(&self, t: P::Scalar) -> P {
+        let (segment, t) = self.locate(t);
+        segment.second_derivative(t)
+    }
+}
@@ -0,0 +1,173 @@
+//! Cubic Hermite curves.
+//!
+//! A Hermite curve is given by where it starts and ends and by its velocity
+//! at both ends, which makes it easy to chain pieces that join smoothly: give
+//! the end of one the same tangent as the start of the next. It is the same
+//! family of curves as the cubic Bézier and converts to one losslessly. The
+//! domain is `[0, 1]`.
+
+use crate::scalar::{Real, Scalar};
+
+use super::bezier::CubicBezier;
+use super::{Curve, CurvePoint};
+
+/// A cubic curve given by its end points and the tangents at them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicHermite<P> {
+    /// The start of the curve.
+    pub p0: P,
+    /// The derivative of the curve at its start.
+    pub m0: P,
+    /// The end of the curve.
+    pub p1: P,
+    /// The derivative of the curve at its end.
+    pub m1: P,
+}
+
+impl<P> CubicHermite<P> {
+    /// Creates a curve from its end points and the tangents at them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::hermite::CubicHermite;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicHermite::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(2.0, 2.0),
+    ///     Vector2::new(0.0, 3.0),
+    /// );
+    /// assert_eq!(curve.position(1.0), Vector2::new(2.0, 2.0));
+    /// assert_eq!(curve.derivative(0.0), Vector2::new(3.0, 0.0));
+    /// assert_eq!(curve.derivative(1.0), Vector2::new(0.0, 3.0));
+    /// ```
+    pub fn new(p0: P, m0: P, p1: P, m1: P) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+}
+
+impl<P: CurvePoint> CubicHermite<P> {
+    /// Returns the same curve as a cubic Bézier curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::hermite::CubicHermite;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicHermite::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(2.0, 2.0),
+    ///     Vector2::new(0.0, 3.0),
+    /// );
+    /// let bezier = curve.to_bezier();
+    /// assert_eq!(bezier.p1, Vector2::new(1.0, 0.0));
+    /// assert_eq!(bezier.p2, Vector2::new(2.0, 1.0));
+    /// ```
+    pub fn to_bezier(&self) -> CubicBezier<P> {
+        let third = P::Scalar::ONE / P::Scalar::from_f64(3.0);
+        CubicBezier::new(
+            self.p0,
+            self.p0 + self.m0.scale(third),
+            self.p1 - self.m1.scale(third),
+            self.p1,
+        )
+    }
+
+    /// Splits the curve at `t` into the part before and the part after it.
+    ///
+    /// Each part keeps the domain `[0, 1]`, so its tangents are shortened in
+    /// proportion to its share of the original curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::hermite::CubicHermite;
+    /// use vexel::curves::Curve;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let curve = CubicHermite::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(2.0, 2.0),
+    ///     Vector2::new(0.0, 3.0),
+    /// );
+    /// let (before, after) = curve.split(0.5);
+    /// assert_approx_eq!(before.p1, curve.position(0.5), epsilon = 1e-12);
+    /// assert_approx_eq!(after.m0, curve.derivative(0.5) * Vector2::new(0.5, 0.5), epsilon = 1e-12);
+    /// ```
+    pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+        let (before, after) = self.to_bezier().split(t);
+        (before.to_hermite(), after.to_hermite())
+    }
+
+    /// Returns the tightest box enclosing the curve as its minimum and
+    /// maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::curves::hermite::CubicHermite;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// // Leaves upwards and comes back down, peaking halfway.
+    /// let curve = CubicHermite::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(0.0, 4.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(0.0, -4.0),
+    /// );
+    /// let (min, max) = curve.bounds();
+    /// assert_eq!(min, Vector2::new(0.0, 0.0));
+    /// assert_approx_eq!(max, Vector2::new(2.0, 1.0), epsilon = 1e-12);
+    /// ```
+    pub fn bounds(&self) -> (P, P) {
+        self.to_bezier().bounds()
+    }
+}
+
+impl<P: CurvePoint> Curve<P::Scalar> for CubicHermite<P> {
+    type Point = P;
+
+    fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (P::Scalar::ZERO, P::Scalar::ONE)
+    }
+
+    fn position(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let (t2, t3) = (t * t, t * t * t);
+        let two = P::Scalar::TWO;
+        let three = P::Scalar::from_f64(3.0);
+        let h00 = two * t3 - three * t2 + P::Scalar::ONE;
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        self.p0.scale(h00) + self.m0.scale(h10) + self.p1.scale(h01) + self.m1.scale(h11)
+    }
+
+    fn derivative(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let t2 = t * t;
+        let (two, three) = (P::Scalar::TWO, P::Scalar::from_f64(3.0));
+        let six = two * three;
+        let h00 = six * (t2 - t);
+        let h10 = three * t2 - two * two * t + P::Scalar::ONE;
+        let h11 = three * t2 - two * t;
+        (self.p0 - self.p1).scale(h00) + self.m0.scale(h10) + self.m1.scale(h11)
+    }
+
+    fn second_derivative(&self, t: P::Scalar) -> P {
+        let t = t.clamp(P::Scalar::ZERO, P::Scalar::ONE);
+        let (two, six) = (P::Scalar::TWO, P::Scalar::from_f64(6.0));
+        let h00 = six * (two * t - P::Scalar::ONE);
+        let h10 = six * t - two * two;
+        let h11 = six * t - two;
+        (self.p0 - self.p1).scale(h00) + self.m0.scale(h10) + self.m1.scale(h11)
+    }
+}
@@ -0,0 +1,285 @@
+//! Parametric curves through vector space.
+//!
+//! Every curve here works with [`Vector2`], [`Vector3`] and [`Vector4`]
+//! points alike, through the [`CurvePoint`] trait. Evaluation goes through
+//! the [`Curve`] trait, which gives the position and the first two
+//! derivatives at a parameter inside the curve's [`domain`](Curve::domain).
+//!
+//! The parameter of a curve does not advance at a steady speed along it. Wrap
+//! a curve in an [`ArcLength`] to move along it by distance instead, for
+//! example to drive a camera along a rail at constant speed.
+//!
+//! [`ArcLength`]: arc_length::ArcLength
+
+#[cfg(feature = "alloc")]
+pub mod arc_length;
+pub mod bezier;
+#[cfg(feature = "alloc")]
+pub mod bspline;
+#[cfg(feature = "alloc")]
+pub mod catmull_rom;
+pub mod hermite;
+
+use core::ops::{Add, Sub};
+
+use crate::scalar::Real;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// A point or vector that curves can be built from.
+pub trait CurvePoint: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// The type of each component.
+    type Scalar: Real;
+
+    /// The number of components.
+    const DIMENSIONS: usize;
+
+    /// Multiplies every component by `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::CurvePoint;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, -2.0, 0.5);
+    /// assert_eq!(v.scale(2.0), Vector3::new(2.0, -4.0, 1.0));
+    /// ```
+    fn scale(self, s: Self::Scalar) -> Self;
+
+    /// Returns the dot product with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::CurvePoint;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vector4::new(1.0, 0.0, -1.0, 0.5);
+    /// assert_eq!(CurvePoint::dot(&a, &b), 0.0);
+    /// ```
+    fn dot(&self, other: &Self) -> Self::Scalar;
+
+    /// Returns the component along `axis`, counting from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not less than [`DIMENSIONS`](Self::DIMENSIONS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::CurvePoint;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.component(2), 3.0);
+    /// ```
+    fn component(&self, axis: usize) -> Self::Scalar;
+
+    /// Returns the smaller of each pair of components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::CurvePoint;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Vector2::new(1.0, 5.0);
+    /// let b = Vector2::new(3.0, -1.0);
+    /// assert_eq!(a.component_min(b), Vector2::new(1.0, -1.0));
+    /// ```
+    fn component_min(self, other: Self) -> Self;
+
+    /// Returns the larger of each pair of components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::curves::CurvePoint;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Vector2::new(1.0, 5.0);
+    /// let b = Vector2::new(3.0, -1.0);
+    /// assert_eq!(a.component_max(b), Vector2::new(3.0, 5.0));
+    /// ```
+    fn component_max(self, other: Self) -> Self;
+}
+
+impl<T: Real> CurvePoint for Vector2<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 2;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        Vector2::dot(self, other)
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn component_min(self, other: Self) -> Self {
+        Vector2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn component_max(self, other: Self) -> Self {
+        Vector2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T: Real> CurvePoint for Vector3<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 3;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        Vector3::dot(self, other)
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn component_min(self, other: Self) -> Self {
+        Vector3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    fn component_max(self, other: Self) -> Self {
+        Vector3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl<T: Real> CurvePoint for Vector4<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 4;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        Vector4::dot(self, other)
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn component_min(self, other: Self) -> Self {
+        Vector4::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+            self.w.min(other.w),
+        )
+    }
+
+    fn component_max(self, other: Self) -> Self {
+        Vector4::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+            self.w.max(other.w),
+        )
+    }
+}
+
+/// A curve that can be evaluated at any parameter `t` of its domain.
+///
+/// Parameters outside the domain are clamped to it.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::curves::bezier::QuadraticBezier;
+/// use vexel::curves::Curve;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// fn speed_at_start<C: Curve<f64, Point = Vector2<f64>>>(curve: &C) -> f64 {
+///     let (start, _) = curve.domain();
+///     curve.derivative(start).length()
+/// }
+///
+/// let curve = QuadraticBezier::new(
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(1.5, 2.0),
+///     Vector2::new(4.0, 0.0),
+/// );
+/// assert_eq!(speed_at_start(&curve), 5.0);
+/// assert_eq!(curve.position(-1.0), curve.position(0.0));
+/// ```
+pub trait Curve<T> {
+    /// The type of the points on the curve.
+    type Point;
+
+    /// Returns the first and last parameter of the curve.
+    fn domain(&self) -> (T, T);
+
+    /// Returns the point of the curve at `t`.
+    fn position(&self, t: T) -> Self::Point;
+
+    /// Returns the first derivative of the curve with respect to `t`, the
+    /// velocity of a point moving along it.
+    fn derivative(&self, t: T) -> Self::Point;
+
+    /// Returns the second derivative of the curve with respect to `t`.
+    fn second_derivative(&self, t: T) -> Self::Point;
+}
+
+impl<T, C: Curve<T> + ?Sized> Curve<T> for &C {
+    type Point = C::Point;
+
+    fn domain(&self) -> (T, T) {
+        (**self).domain()
+    }
+
+    fn position(&self, t: T) -> Self::Point {
+        (**self).position(t)
+    }
+
+    fn derivative(&self, t: T) -> Self::Point {
+        (**self).derivative(t)
+    }
+
+    fn second_derivative(&self, t: T) -> Self::Point {
+        (**self).second_derivative(t)
+    }
+}
+
+/// Returns the point a fraction `t` of the way from `a` to `b`.
+fn lerp<P: CurvePoint>(a: P, b: P, t: P::Scalar) -> P {
+    a + (b - a).scale(t)
+}
+
+/// Returns the length of `p`.
+#[cfg(feature = "alloc")]
+fn length<P: CurvePoint>(p: P) -> P::Scalar {
+    p.dot(&p).sqrt()
+}
+
+/// Returns the box enclosing `points` as its minimum and maximum corners.
+fn extent<P: CurvePoint>(first: P, rest: impl IntoIterator<Item = P>) -> (P, P) {
+    rest.into_iter().fold((first, first), |(low, high), p| {
+        (low.component_min(p), high.component_max(p))
+    })
+}
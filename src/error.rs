@@ -0,0 +1,46 @@
+//! The error type for operations that fail on degenerate input.
+//!
+//! Most operations in this crate are total: `normalize` returns a zero
+//! vector unchanged and `angle_between` of a zero vector is NaN. The `try_`
+//! variants, such as
+//! [`Vector3::try_normalize`](crate::vectors::vector3::Vector3::try_normalize)
+//! and [`Mat4::try_inverse`](crate::matrices::mat4::Mat4::try_inverse),
+//! report these cases as a [`VexelError`] instead, so they can be caught
+//! where they happen rather than as NaNs further down the line.
+
+use core::fmt;
+
+/// The ways an operation can fail on degenerate input.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::error::VexelError;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let v = Vector2::new(0.0, 0.0);
+/// assert_eq!(v.try_normalize(), Err(VexelError::ZeroLength));
+/// assert_eq!(VexelError::ZeroLength.to_string(), "vector has zero length");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VexelError {
+    /// A vector had zero length where a direction was needed.
+    ZeroLength,
+    /// A length or result was infinite or NaN, usually because the input
+    /// was.
+    NonFinite,
+    /// A matrix had a zero determinant, so it has no inverse.
+    SingularMatrix,
+}
+
+impl fmt::Display for VexelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VexelError::ZeroLength => "vector has zero length",
+            VexelError::NonFinite => "value is infinite or NaN",
+            VexelError::SingularMatrix => "matrix is singular",
+        })
+    }
+}
+
+impl core::error::Error for VexelError {}
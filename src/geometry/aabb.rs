@@ -0,0 +1,573 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+/// A solid axis-aligned box in 3D space spanning `min` to `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<T> {
+    /// The corner with the smallest coordinates.
+    pub min: Vector3<T>,
+    /// The corner with the largest coordinates.
+    pub max: Vector3<T>,
+}
+
+impl<T> Aabb<T> {
+    /// Creates a box from its minimum and maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(aabb.volume(), 6.0);
+    /// ```
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Real> Aabb<T> {
+    /// Creates a box from its centre and half of its size along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::from_center_half_extents(
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    /// );
+    /// assert_eq!(aabb.min, Vector3::new(0.0, -1.0, -2.0));
+    /// assert_eq!(aabb.max, Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_center_half_extents(center: Vector3<T>, half_extents: Vector3<T>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Creates the smallest box containing every point, or `None` if `points`
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::from_points(&[
+    ///     Vector3::new(1.0, -2.0, 0.0),
+    ///     Vector3::new(-1.0, 3.0, 2.0),
+    ///     Vector3::new(0.0, 0.0, -4.0),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(aabb.min, Vector3::new(-1.0, -2.0, -4.0));
+    /// assert_eq!(aabb.max, Vector3::new(1.0, 3.0, 2.0));
+    ///
+    /// assert!(Aabb::<f64>::from_points(&[]).is_none());
+    /// ```
+    pub fn from_points(points: &[Vector3<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut aabb = Self::new(*first, *first);
+        for p in rest {
+            aabb.min = Vector3::new(
+                aabb.min.x.min(p.x),
+                aabb.min.y.min(p.y),
+                aabb.min.z.min(p.z),
+            );
+            aabb.max = Vector3::new(
+                aabb.max.x.max(p.x),
+                aabb.max.y.max(p.y),
+                aabb.max.z.max(p.z),
+            );
+        }
+        Some(aabb)
+    }
+
+    /// Returns the centre of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.center(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn center(&self) -> Vector3<T> {
+        self.min.lerp(&self.max, T::HALF)
+    }
+
+    /// Returns half of the size of the box along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.half_extents(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn half_extents(&self) -> Vector3<T> {
+        let size = self.size();
+        Vector3::new(size.x * T::HALF, size.y * T::HALF, size.z * T::HALF)
+    }
+
+    /// Returns the size of the box along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.size(), Vector3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn size(&self) -> Vector3<T> {
+        self.max - self.min
+    }
+
+    /// Returns `true` if `point` lies inside or on the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert!(aabb.contains_point(Vector3::new(1.0, 0.5, 0.0)));
+    /// assert!(!aabb.contains_point(Vector3::new(1.5, 0.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Returns the point of the box closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(
+    ///     aabb.closest_point(Vector3::new(2.0, 0.5, -1.0)),
+    ///     Vector3::new(1.0, 0.5, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            point.x.clamp(self.min.x, self.max.x),
+            point.y.clamp(self.min.y, self.max.y),
+            point.z.clamp(self.min.z, self.max.z),
+        )
+    }
+
+    /// Returns the distance from the box to `point`, or zero if the point is
+    /// inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(aabb.distance_to_point(Vector3::new(4.0, 5.0, 0.5)), 5.0);
+    /// assert_eq!(aabb.distance_to_point(Vector3::new(0.5, 0.5, 0.5)), 0.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns the total area of the six faces of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(aabb.surface_area(), 22.0);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        let s = self.size();
+        T::TWO * (s.x * s.y + s.y * s.z + s.z * s.x)
+    }
+
+    /// Returns the volume enclosed by the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(aabb.volume(), 8.0);
+    /// ```
+    pub fn volume(&self) -> T {
+        let s = self.size();
+        s.x * s.y * s.z
+    }
+
+    /// Returns the smallest box enclosing both boxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb::new(Vector3::new(2.0, -1.0, 0.5), Vector3::new(3.0, 0.5, 0.75));
+    /// let union = a.union(&b);
+    /// assert_eq!(union.min, Vector3::new(0.0, -1.0, 0.0));
+    /// assert_eq!(union.max, Vector3::new(3.0, 1.0, 1.0));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            Vector3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            Vector3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        )
+    }
+
+    /// Returns `true` if the boxes share at least one point.
+    ///
+    /// Unlike [`intersect_aabb`](Self::intersect_aabb) this only answers yes
+    /// or no, which is all broad-phase culling needs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let touching = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+    /// let apart = Aabb::new(Vector3::new(1.5, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+    /// assert!(a.overlaps(&touching));
+    /// assert!(!a.overlaps(&apart));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Returns the smallest axis-aligned box enclosing the transformed box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let rotation = Quaternion::from_axis_angle(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     std::f64::consts::FRAC_PI_4,
+    /// );
+    /// let rotated = aabb.transform(&Affine3::from_rotation(rotation));
+    /// assert_approx_eq!(rotated.max.x, 2.0_f64.sqrt(), epsilon = 1e-9);
+    /// assert_approx_eq!(rotated.max.z, 1.0, epsilon = 1e-9);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        let [c0, c1, c2] = transform.matrix.cols;
+        let h = self.half_extents();
+        let half_extents = Vector3::new(
+            c0.x.abs() * h.x + c1.x.abs() * h.y + c2.x.abs() * h.z,
+            c0.y.abs() * h.x + c1.y.abs() * h.y + c2.y.abs() * h.z,
+            c0.z.abs() * h.x + c1.z.abs() * h.y + c2.z.abs() * h.z,
+        );
+        Self::from_center_half_extents(transform.transform_point(self.center()), half_extents)
+    }
+}
+
+/// A solid axis-aligned rectangle in 2D space spanning `min` to `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb2<T> {
+    /// The corner with the smallest coordinates.
+    pub min: Vector2<T>,
+    /// The corner with the largest coordinates.
+    pub max: Vector2<T>,
+}
+
+impl<T> Aabb2<T> {
+    /// Creates a rectangle from its minimum and maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 3.0));
+    /// assert_eq!(aabb.area(), 6.0);
+    /// ```
+    pub fn new(min: Vector2<T>, max: Vector2<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Real> Aabb2<T> {
+    /// Creates a rectangle from its centre and half of its size along each
+    /// axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::from_center_half_extents(Vector2::new(1.0, 1.0), Vector2::new(1.0, 2.0));
+    /// assert_eq!(aabb, Aabb2::new(Vector2::new(0.0, -1.0), Vector2::new(2.0, 3.0)));
+    /// ```
+    pub fn from_center_half_extents(center: Vector2<T>, half_extents: Vector2<T>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Creates the smallest rectangle containing every point, or `None` if
+    /// `points` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::from_points(&[Vector2::new(1.0, -2.0), Vector2::new(-1.0, 3.0)]).unwrap();
+    /// assert_eq!(aabb, Aabb2::new(Vector2::new(-1.0, -2.0), Vector2::new(1.0, 3.0)));
+    /// ```
+    pub fn from_points(points: &[Vector2<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut aabb = Self::new(*first, *first);
+        for p in rest {
+            aabb.min = Vector2::new(aabb.min.x.min(p.x), aabb.min.y.min(p.y));
+            aabb.max = Vector2::new(aabb.max.x.max(p.x), aabb.max.y.max(p.y));
+        }
+        Some(aabb)
+    }
+
+    /// Returns the centre of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0));
+    /// assert_eq!(aabb.center(), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn center(&self) -> Vector2<T> {
+        self.min.lerp(&self.max, T::HALF)
+    }
+
+    /// Returns half of the size of the rectangle along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0));
+    /// assert_eq!(aabb.half_extents(), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn half_extents(&self) -> Vector2<T> {
+        let size = self.size();
+        Vector2::new(size.x * T::HALF, size.y * T::HALF)
+    }
+
+    /// Returns the size of the rectangle along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(-1.0, 0.0), Vector2::new(2.0, 4.0));
+    /// assert_eq!(aabb.size(), Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn size(&self) -> Vector2<T> {
+        self.max - self.min
+    }
+
+    /// Returns `true` if `point` lies inside or on the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// assert!(aabb.contains_point(Vector2::new(1.0, 0.5)));
+    /// assert!(!aabb.contains_point(Vector2::new(1.5, 0.5)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Returns the point of the rectangle closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// assert_eq!(aabb.closest_point(Vector2::new(2.0, 0.5)), Vector2::new(1.0, 0.5));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        Vector2::new(
+            point.x.clamp(self.min.x, self.max.x),
+            point.y.clamp(self.min.y, self.max.y),
+        )
+    }
+
+    /// Returns the distance from the rectangle to `point`, or zero if the
+    /// point is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// assert_eq!(aabb.distance_to_point(Vector2::new(4.0, 5.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns the area enclosed by the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(-1.0, -1.0), Vector2::new(1.0, 2.0));
+    /// assert_eq!(aabb.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> T {
+        let s = self.size();
+        s.x * s.y
+    }
+
+    /// Returns the length of the outline of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(-1.0, -1.0), Vector2::new(1.0, 2.0));
+    /// assert_eq!(aabb.perimeter(), 10.0);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        let s = self.size();
+        T::TWO * (s.x + s.y)
+    }
+
+    /// Returns the smallest rectangle enclosing both rectangles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// let b = Aabb2::new(Vector2::new(2.0, -1.0), Vector2::new(3.0, 0.5));
+    /// assert_eq!(a.union(&b), Aabb2::new(Vector2::new(0.0, -1.0), Vector2::new(3.0, 1.0)));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            Vector2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            Vector2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        )
+    }
+
+    /// Returns `true` if the rectangles share at least one point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// assert!(a.overlaps(&Aabb2::new(Vector2::new(0.5, 1.0), Vector2::new(2.0, 2.0))));
+    /// assert!(!a.overlaps(&Aabb2::new(Vector2::new(0.5, 1.5), Vector2::new(2.0, 2.0))));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// Returns the smallest axis-aligned rectangle enclosing the transformed
+    /// rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let aabb = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 1.0));
+    /// let moved = aabb.transform(&Affine2::from_translation(Vector2::new(1.0, 1.0)));
+    /// assert_eq!(moved, Aabb2::new(Vector2::new(1.0, 1.0), Vector2::new(3.0, 2.0)));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        let [c0, c1] = transform.matrix.cols;
+        let h = self.half_extents();
+        let half_extents = Vector2::new(
+            c0.x.abs() * h.x + c1.x.abs() * h.y,
+            c0.y.abs() * h.x + c1.y.abs() * h.y,
+        );
+        Self::from_center_half_extents(transform.transform_point(self.center()), half_extents)
+    }
+}
@@ -0,0 +1,827 @@
+//! A bounding volume hierarchy over axis-aligned boxes.
+//!
+//! The tree only stores boxes. Each box bounds an item that the caller keeps
+//! elsewhere, such as a triangle of a mesh or a body in the world, and
+//! queries hand the item's index to a callback that tests the real shape.
+//! Most of the tree is skipped whenever a box is missed, which is what makes
+//! raycasting a mesh of tens of thousands of triangles cheap.
+//!
+//! A tree built from a slice of boxes numbers its items by their position in
+//! the slice. Items added later with [`Bvh::insert`] get the index it
+//! returns.
+//!
+//! # Examples
+//!
+//! ```
+//! use vexel::geometry::aabb::Aabb;
+//! use vexel::geometry::bvh::{BuildStrategy, Bvh};
+//! use vexel::geometry::ray::Ray;
+//! use vexel::geometry::triangle::Triangle;
+//! use vexel::vectors::vector3::Vector3;
+//!
+//! let triangles: Vec<_> = (0..100)
+//!     .map(|i| {
+//!         let x = i as f64;
+//!         Triangle::new(
+//!             Vector3::new(x, 0.0, 0.0),
+//!             Vector3::new(x + 1.0, 0.0, 0.0),
+//!             Vector3::new(x, 1.0, 0.0),
+//!         )
+//!     })
+//!     .collect();
+//! let boxes: Vec<_> = triangles
+//!     .iter()
+//!     .map(|t| Aabb::from_points(&[t.a, t.b, t.c]).unwrap())
+//!     .collect();
+//! let bvh = Bvh::build(&boxes, BuildStrategy::SurfaceArea);
+//!
+//! let ray = Ray::new(Vector3::new(42.25, 0.25, 5.0), Vector3::new(0.0, 0.0, -1.0));
+//! let (item, distance) = bvh
+//!     .cast_ray(&ray, |i| ray.intersect_triangle(&triangles[i]).map(|hit| hit.distance))
+//!     .unwrap();
+//! assert_eq!(item, 42);
+//! assert_eq!(distance, 5.0);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+
+use super::aabb::Aabb;
+use super::ray::Ray;
+
+/// The number of buckets centroids are sorted into when searching for the
+/// cheapest surface area split.
+const BINS: usize = 16;
+
+/// How [`Bvh::build`] divides the items of each node between its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildStrategy {
+    /// Splits where the surface area heuristic predicts the cheapest
+    /// traversal. Slower to build than [`Median`](Self::Median) but gives
+    /// faster queries, so it suits static geometry.
+    SurfaceArea,
+    /// Splits at the median centroid along the longest axis of the node,
+    /// which is quick to build and always gives a balanced tree.
+    Median,
+}
+
+/// A binary tree of axis-aligned boxes with one item in each leaf.
+#[derive(Debug, Clone)]
+pub struct Bvh<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+    free_nodes: Vec<usize>,
+    /// The leaf holding each item, or `None` once the item is removed.
+    leaves: Vec<Option<usize>>,
+    free_items: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    aabb: Aabb<T>,
+    parent: Option<usize>,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Leaf(usize),
+    Branch(usize, usize),
+}
+
+impl<T> Bvh<T> {
+    /// Creates an empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::bvh::Bvh;
+    ///
+    /// let bvh = Bvh::<f64>::new();
+    /// assert!(bvh.is_empty());
+    /// assert!(bvh.bounds().is_none());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            free_nodes: Vec::new(),
+            leaves: Vec::new(),
+            free_items: Vec::new(),
+        }
+    }
+
+    /// Returns the number of items in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let unit = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::build(&[unit, unit, unit], BuildStrategy::Median);
+    /// assert_eq!(bvh.len(), 3);
+    /// bvh.remove(1);
+    /// assert_eq!(bvh.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.leaves.len() - self.free_items.len()
+    }
+
+    /// Returns `true` if the tree holds no items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::Bvh;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut bvh = Bvh::new();
+    /// let item = bvh.insert(Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)));
+    /// assert!(!bvh.is_empty());
+    /// bvh.remove(item);
+    /// assert!(bvh.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the leaf node holding `item`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is not in the tree.
+    fn leaf(&self, item: usize) -> usize {
+        match self.leaves.get(item) {
+            Some(Some(leaf)) => *leaf,
+            _ => panic!("item {} is not in the tree", item),
+        }
+    }
+
+    /// Adds a node, reusing the slot of a freed one when possible.
+    fn allocate(&mut self, node: Node<T>) -> usize {
+        match self.free_nodes.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Points `parent` at `new` where it used to point at `old`, or makes
+    /// `new` the root if `parent` is `None`.
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: usize) {
+        let Some(parent) = parent else {
+            self.root = Some(new);
+            return;
+        };
+        if let Kind::Branch(left, right) = self.nodes[parent].kind {
+            self.nodes[parent].kind = if left == old {
+                Kind::Branch(new, right)
+            } else {
+                Kind::Branch(left, new)
+            };
+        }
+    }
+}
+
+impl<T> Default for Bvh<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Real> Bvh<T> {
+    /// Builds a tree over `aabbs`, where item `i` is bounded by `aabbs[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let boxes = [
+    ///     Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)),
+    ///     Aabb::new(Vector3::new(4.0, -1.0, 0.0), Vector3::new(5.0, 0.0, 2.0)),
+    /// ];
+    /// for strategy in [BuildStrategy::SurfaceArea, BuildStrategy::Median] {
+    ///     let bvh = Bvh::build(&boxes, strategy);
+    ///     assert_eq!(bvh.len(), 2);
+    ///     assert_eq!(bvh.aabb(1), Some(boxes[1]));
+    ///     assert_eq!(bvh.bounds(), Some(boxes[0].union(&boxes[1])));
+    /// }
+    /// ```
+    pub fn build(aabbs: &[Aabb<T>], strategy: BuildStrategy) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::with_capacity((2 * aabbs.len()).saturating_sub(1)),
+            root: None,
+            free_nodes: Vec::new(),
+            leaves: vec![None; aabbs.len()],
+            free_items: Vec::new(),
+        };
+        if !aabbs.is_empty() {
+            let centroids: Vec<_> = aabbs.iter().map(|aabb| aabb.center()).collect();
+            let mut items: Vec<_> = (0..aabbs.len()).collect();
+            let root = bvh.build_node(aabbs, &centroids, &mut items, None, strategy);
+            bvh.root = Some(root);
+        }
+        bvh
+    }
+
+    /// Builds the subtree over `items` and returns the index of its root.
+    fn build_node(
+        &mut self,
+        aabbs: &[Aabb<T>],
+        centroids: &[Vector3<T>],
+        items: &mut [usize],
+        parent: Option<usize>,
+        strategy: BuildStrategy,
+    ) -> usize {
+        let index = self.nodes.len();
+        let aabb = items[1..]
+            .iter()
+            .fold(aabbs[items[0]], |bounds, &i| bounds.union(&aabbs[i]));
+        if let [item] = *items {
+            self.nodes.push(Node {
+                aabb,
+                parent,
+                kind: Kind::Leaf(item),
+            });
+            self.leaves[item] = Some(index);
+            return index;
+        }
+
+        // The children are filled in once they have been built.
+        self.nodes.push(Node {
+            aabb,
+            parent,
+            kind: Kind::Branch(index, index),
+        });
+        let split = match strategy {
+            BuildStrategy::SurfaceArea => surface_area_split(aabbs, centroids, items),
+            BuildStrategy::Median => median_split(centroids, items),
+        };
+        let (left_items, right_items) = items.split_at_mut(split);
+        let left = self.build_node(aabbs, centroids, left_items, Some(index), strategy);
+        let right = self.build_node(aabbs, centroids, right_items, Some(index), strategy);
+        self.nodes[index].kind = Kind::Branch(left, right);
+        index
+    }
+
+    /// Returns the box enclosing every item, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::Bvh;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut bvh = Bvh::new();
+    /// bvh.insert(Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)));
+    /// bvh.insert(Aabb::new(Vector3::new(-2.0, 0.5, 0.0), Vector3::new(-1.0, 3.0, 1.0)));
+    /// let bounds = bvh.bounds().unwrap();
+    /// assert_eq!(bounds.min, Vector3::new(-2.0, 0.0, 0.0));
+    /// assert_eq!(bounds.max, Vector3::new(1.0, 3.0, 1.0));
+    /// ```
+    pub fn bounds(&self) -> Option<Aabb<T>> {
+        self.root.map(|root| self.nodes[root].aabb)
+    }
+
+    /// Returns the box stored for `item`, or `None` if it is not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::Bvh;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::new();
+    /// let item = bvh.insert(aabb);
+    /// assert_eq!(bvh.aabb(item), Some(aabb));
+    /// assert_eq!(bvh.aabb(item + 1), None);
+    /// ```
+    pub fn aabb(&self, item: usize) -> Option<Aabb<T>> {
+        let leaf = (*self.leaves.get(item)?)?;
+        Some(self.nodes[leaf].aabb)
+    }
+
+    /// Adds an item bounded by `aabb` and returns its index.
+    ///
+    /// The leaf goes next to the node whose box grows the least, so a tree
+    /// grown one item at a time stays close in quality to one built in bulk.
+    /// Indices freed by [`remove`](Self::remove) are handed out again, most
+    /// recently removed first, so removing an item and inserting its new box
+    /// keeps its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::Bvh;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let unit = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::new();
+    /// assert_eq!(bvh.insert(unit), 0);
+    /// assert_eq!(bvh.insert(unit), 1);
+    /// bvh.remove(0);
+    /// assert_eq!(bvh.insert(unit), 0);
+    /// ```
+    pub fn insert(&mut self, aabb: Aabb<T>) -> usize {
+        let item = match self.free_items.pop() {
+            Some(item) => item,
+            None => {
+                self.leaves.push(None);
+                self.leaves.len() - 1
+            }
+        };
+        let leaf = self.allocate(Node {
+            aabb,
+            parent: None,
+            kind: Kind::Leaf(item),
+        });
+        self.leaves[item] = Some(leaf);
+
+        let Some(mut sibling) = self.root else {
+            self.root = Some(leaf);
+            return item;
+        };
+        // Walk down while pairing with a child is cheaper than pairing here,
+        // charging each level for how much its box has to grow.
+        while let Kind::Branch(left, right) = self.nodes[sibling].kind {
+            let area = self.nodes[sibling].aabb.surface_area();
+            let combined = self.nodes[sibling].aabb.union(&aabb).surface_area();
+            let here = T::TWO * combined;
+            let inherited = T::TWO * (combined - area);
+            let descend = |child: usize| {
+                let node = &self.nodes[child];
+                let grown = node.aabb.union(&aabb).surface_area();
+                match node.kind {
+                    Kind::Leaf(_) => grown + inherited,
+                    Kind::Branch(..) => grown - node.aabb.surface_area() + inherited,
+                }
+            };
+            let (left_cost, right_cost) = (descend(left), descend(right));
+            if here < left_cost && here < right_cost {
+                break;
+            }
+            sibling = if left_cost < right_cost { left } else { right };
+        }
+
+        let grandparent = self.nodes[sibling].parent;
+        let parent = self.allocate(Node {
+            aabb: self.nodes[sibling].aabb.union(&aabb),
+            parent: grandparent,
+            kind: Kind::Branch(sibling, leaf),
+        });
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+        self.replace_child(grandparent, sibling, parent);
+        self.refit_ancestors(grandparent);
+        item
+    }
+
+    /// Removes `item` from the tree and returns its box, or `None` if it was
+    /// not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb::new(Vector3::new(5.0, 0.0, 0.0), Vector3::new(6.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::build(&[a, b], BuildStrategy::SurfaceArea);
+    /// assert_eq!(bvh.remove(1), Some(b));
+    /// assert_eq!(bvh.remove(1), None);
+    /// assert_eq!(bvh.bounds(), Some(a));
+    /// ```
+    pub fn remove(&mut self, item: usize) -> Option<Aabb<T>> {
+        let leaf = self.leaves.get_mut(item)?.take()?;
+        self.free_items.push(item);
+        self.free_nodes.push(leaf);
+
+        if let Some(parent) = self.nodes[leaf].parent {
+            let Kind::Branch(left, right) = self.nodes[parent].kind else {
+                unreachable!("the parent of a node is always a branch");
+            };
+            let sibling = if left == leaf { right } else { left };
+            let grandparent = self.nodes[parent].parent;
+            self.nodes[sibling].parent = grandparent;
+            self.replace_child(grandparent, parent, sibling);
+            self.free_nodes.push(parent);
+            self.refit_ancestors(grandparent);
+        } else {
+            self.root = None;
+        }
+        Some(self.nodes[leaf].aabb)
+    }
+
+    /// Replaces the box of `item` without touching the rest of the tree.
+    ///
+    /// Call [`refit`](Self::refit) after moving items and before the next
+    /// query. Refitting keeps the shape of the tree, which is what you want
+    /// when a mesh deforms but its triangles stay close to their neighbours.
+    /// Objects that travel far should be removed and inserted again instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let unit = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::build(&[unit, unit], BuildStrategy::Median);
+    /// let moved = Aabb::new(Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0));
+    /// bvh.set_aabb(1, moved);
+    /// bvh.refit();
+    /// assert_eq!(bvh.bounds(), Some(unit.union(&moved)));
+    /// ```
+    pub fn set_aabb(&mut self, item: usize, aabb: Aabb<T>) {
+        let leaf = self.leaf(item);
+        self.nodes[leaf].aabb = aabb;
+    }
+
+    /// Recomputes the box of every branch from its children after items have
+    /// moved with [`set_aabb`](Self::set_aabb).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let boxes: Vec<_> = (0..8)
+    ///     .map(|i| {
+    ///         let min = Vector3::new(i as f64, 0.0, 0.0);
+    ///         Aabb::new(min, min + Vector3::new(1.0, 1.0, 1.0))
+    ///     })
+    ///     .collect();
+    /// let mut bvh = Bvh::build(&boxes, BuildStrategy::SurfaceArea);
+    /// for (i, aabb) in boxes.iter().enumerate() {
+    ///     let lifted = Vector3::new(0.0, 10.0, 0.0);
+    ///     bvh.set_aabb(i, Aabb::new(aabb.min + lifted, aabb.max + lifted));
+    /// }
+    /// bvh.refit();
+    /// assert_eq!(bvh.bounds().unwrap().min.y, 10.0);
+    /// ```
+    pub fn refit(&mut self) {
+        let Some(root) = self.root else {
+            return;
+        };
+        // A node is always listed before its children, so walking the list
+        // backwards reaches the children first.
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = vec![root];
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            if let Kind::Branch(left, right) = self.nodes[index].kind {
+                stack.push(left);
+                stack.push(right);
+            }
+        }
+        for &index in order.iter().rev() {
+            if let Kind::Branch(left, right) = self.nodes[index].kind {
+                self.nodes[index].aabb = self.nodes[left].aabb.union(&self.nodes[right].aabb);
+            }
+        }
+    }
+
+    /// Recomputes the boxes of `node` and every branch above it.
+    fn refit_ancestors(&mut self, mut node: Option<usize>) {
+        while let Some(index) = node {
+            if let Kind::Branch(left, right) = self.nodes[index].kind {
+                self.nodes[index].aabb = self.nodes[left].aabb.union(&self.nodes[right].aabb);
+            }
+            node = self.nodes[index].parent;
+        }
+    }
+
+    /// Finds the item closest along `ray`.
+    ///
+    /// `hit` is called with each item whose box the ray passes through and
+    /// returns the distance along the ray to the item itself, or `None` if
+    /// the ray misses it. Nearer boxes are visited first and boxes further
+    /// than the best hit so far are skipped. Returns the index of the nearest
+    /// item that was hit and its distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let spheres = [
+    ///     Sphere::new(Vector3::new(0.0, 0.0, -5.0), 1.0),
+    ///     Sphere::new(Vector3::new(0.0, 0.0, -2.0), 0.5),
+    ///     Sphere::new(Vector3::new(3.0, 0.0, -2.0), 0.5),
+    /// ];
+    /// let boxes: Vec<_> = spheres
+    ///     .iter()
+    ///     .map(|s| {
+    ///         let r = Vector3::new(s.radius, s.radius, s.radius);
+    ///         Aabb::from_center_half_extents(s.center, r)
+    ///     })
+    ///     .collect();
+    /// let bvh = Bvh::build(&boxes, BuildStrategy::SurfaceArea);
+    ///
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    /// let hit = bvh.cast_ray(&ray, |i| ray.intersect_sphere(&spheres[i]).map(|h| h.distance));
+    /// assert_eq!(hit, Some((1, 1.5)));
+    /// ```
+    pub fn cast_ray<F>(&self, ray: &Ray<T>, mut hit: F) -> Option<(usize, T)>
+    where
+        F: FnMut(usize) -> Option<T>,
+    {
+        let entry = |index: usize| {
+            ray.intersect_aabb(&self.nodes[index].aabb)
+                .map(|hit| hit.distance)
+        };
+        let mut best: Option<(usize, T)> = None;
+        let mut stack: Vec<_> = self
+            .root
+            .and_then(|root| Some((root, entry(root)?)))
+            .into_iter()
+            .collect();
+        while let Some((index, distance)) = stack.pop() {
+            if best.is_some_and(|(_, nearest)| distance >= nearest) {
+                continue;
+            }
+            match self.nodes[index].kind {
+                Kind::Leaf(item) => {
+                    if let Some(distance) = hit(item) {
+                        if best.is_none_or(|(_, nearest)| distance < nearest) {
+                            best = Some((item, distance));
+                        }
+                    }
+                }
+                Kind::Branch(left, right) => {
+                    push_nearest_last(&mut stack, (left, entry(left)), (right, entry(right)))
+                }
+            }
+        }
+        best
+    }
+
+    /// Calls `visit` with every item whose box overlaps `aabb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let boxes: Vec<_> = (0..10)
+    ///     .map(|i| {
+    ///         let min = Vector3::new(2.0 * i as f64, 0.0, 0.0);
+    ///         Aabb::new(min, min + Vector3::new(1.0, 1.0, 1.0))
+    ///     })
+    ///     .collect();
+    /// let bvh = Bvh::build(&boxes, BuildStrategy::Median);
+    ///
+    /// let query = Aabb::new(Vector3::new(3.5, 0.5, 0.5), Vector3::new(8.5, 2.0, 2.0));
+    /// let mut found = Vec::new();
+    /// bvh.query_aabb(&query, |i| found.push(i));
+    /// found.sort();
+    /// assert_eq!(found, [2, 3, 4]);
+    /// ```
+    pub fn query_aabb<F>(&self, aabb: &Aabb<T>, mut visit: F)
+    where
+        F: FnMut(usize),
+    {
+        let mut stack: Vec<_> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.aabb.overlaps(aabb) {
+                continue;
+            }
+            match node.kind {
+                Kind::Leaf(item) => visit(item),
+                Kind::Branch(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+    }
+
+    /// Finds the item closest to `point`.
+    ///
+    /// `distance` is called with candidate items and returns the distance
+    /// from `point` to the item itself, which must be at least the distance
+    /// to its box. Boxes further away than the best item so far are skipped.
+    /// Returns the index of the nearest item and its distance, or `None` if
+    /// the tree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::bvh::{BuildStrategy, Bvh};
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segments = [
+    ///     Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0)),
+    ///     Segment::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(4.0, 5.0, 0.0)),
+    /// ];
+    /// let boxes: Vec<_> = segments
+    ///     .iter()
+    ///     .map(|s| Aabb::from_points(&[s.start, s.end]).unwrap())
+    ///     .collect();
+    /// let bvh = Bvh::build(&boxes, BuildStrategy::SurfaceArea);
+    ///
+    /// let point = Vector3::new(1.0, 2.0, 0.0);
+    /// let (item, distance) = bvh
+    ///     .nearest(point, |i| segments[i].distance_to_point(point))
+    ///     .unwrap();
+    /// assert_eq!(item, 0);
+    /// assert_eq!(distance, 2.0);
+    /// ```
+    pub fn nearest<F>(&self, point: Vector3<T>, mut distance: F) -> Option<(usize, T)>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let bound = |index: usize| Some(self.nodes[index].aabb.distance_to_point(point));
+        let mut best: Option<(usize, T)> = None;
+        let mut stack: Vec<_> = self.root.map(|root| (root, T::ZERO)).into_iter().collect();
+        while let Some((index, lower)) = stack.pop() {
+            if best.is_some_and(|(_, nearest)| lower >= nearest) {
+                continue;
+            }
+            match self.nodes[index].kind {
+                Kind::Leaf(item) => {
+                    let distance = distance(item);
+                    if best.is_none_or(|(_, nearest)| distance < nearest) {
+                        best = Some((item, distance));
+                    }
+                }
+                Kind::Branch(left, right) => {
+                    push_nearest_last(&mut stack, (left, bound(left)), (right, bound(right)))
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Pushes the children that were reached so the nearer one is popped first.
+fn push_nearest_last<T: Real>(
+    stack: &mut Vec<(usize, T)>,
+    a: (usize, Option<T>),
+    b: (usize, Option<T>),
+) {
+    let (near, far) = match (a.1, b.1) {
+        (Some(da), Some(db)) if db < da => (b, a),
+        _ => (a, b),
+    };
+    for (index, distance) in [far, near] {
+        if let Some(distance) = distance {
+            stack.push((index, distance));
+        }
+    }
+}
+
+/// Returns the `axis` component of `v`.
+fn component<T: Copy>(v: Vector3<T>, axis: usize) -> T {
+    [v.x, v.y, v.z][axis]
+}
+
+/// Returns the box enclosing the centroids of `items`.
+fn centroid_bounds<T: Real>(centroids: &[Vector3<T>], items: &[usize]) -> Aabb<T> {
+    items[1..].iter().fold(
+        Aabb::new(centroids[items[0]], centroids[items[0]]),
+        |bounds, &i| bounds.union(&Aabb::new(centroids[i], centroids[i])),
+    )
+}
+
+/// Splits `items` in half at the median centroid along the longest axis of
+/// their centroids and returns the size of the first half.
+fn median_split<T: Real>(centroids: &[Vector3<T>], items: &mut [usize]) -> usize {
+    let size = centroid_bounds(centroids, items).size();
+    let axis = if size.x >= size.y && size.x >= size.z {
+        0
+    } else if size.y >= size.z {
+        1
+    } else {
+        2
+    };
+    let middle = items.len() / 2;
+    items.select_nth_unstable_by(middle, |&a, &b| {
+        component(centroids[a], axis)
+            .partial_cmp(&component(centroids[b], axis))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    middle
+}
+
+/// Splits `items` where the surface area heuristic is lowest and returns the
+/// size of the first part.
+///
+/// Centroids are sorted into [`BINS`] equal buckets along each axis and every
+/// boundary between buckets is scored by the area of the boxes on either side
+/// weighted by how many items each holds.
+fn surface_area_split<T: Real>(
+    aabbs: &[Aabb<T>],
+    centroids: &[Vector3<T>],
+    items: &mut [usize],
+) -> usize {
+    let bounds = centroid_bounds(centroids, items);
+    let bin = |item: usize, axis: usize| {
+        let low = component(bounds.min, axis);
+        let extent = component(bounds.max, axis) - low;
+        let t = (component(centroids[item], axis) - low) / extent;
+        ((t.to_f64() * BINS as f64) as usize).min(BINS - 1)
+    };
+
+    let mut best: Option<(T, usize, usize)> = None;
+    for axis in 0..3 {
+        if component(bounds.max, axis) <= component(bounds.min, axis) {
+            continue;
+        }
+        let mut counts = [0usize; BINS];
+        let mut boxes: [Option<Aabb<T>>; BINS] = [None; BINS];
+        for &item in items.iter() {
+            let b = bin(item, axis);
+            counts[b] += 1;
+            boxes[b] = Some(boxes[b].map_or(aabbs[item], |aabb| aabb.union(&aabbs[item])));
+        }
+
+        // right_costs[i] scores the buckets from i + 1 to the end.
+        let mut right_costs = [T::ZERO; BINS];
+        let mut right: Option<Aabb<T>> = None;
+        let mut count = 0;
+        for i in (1..BINS).rev() {
+            right = union_of(right, boxes[i]);
+            count += counts[i];
+            right_costs[i - 1] = cost(right, count);
+        }
+        let mut left: Option<Aabb<T>> = None;
+        let mut count = 0;
+        for i in 0..BINS - 1 {
+            left = union_of(left, boxes[i]);
+            count += counts[i];
+            if count == 0 || count == items.len() {
+                continue;
+            }
+            let total = cost(left, count) + right_costs[i];
+            if best.is_none_or(|(lowest, ..)| total < lowest) {
+                best = Some((total, axis, i));
+            }
+        }
+    }
+
+    // Every centroid is in the same place, so any split is as good as another.
+    let Some((_, axis, last)) = best else {
+        return items.len() / 2;
+    };
+    let mut split = 0;
+    for i in 0..items.len() {
+        if bin(items[i], axis) <= last {
+            items.swap(i, split);
+            split += 1;
+        }
+    }
+    split
+}
+
+/// Returns the box enclosing both boxes, either of which may be missing.
+fn union_of<T: Real>(a: Option<Aabb<T>>, b: Option<Aabb<T>>) -> Option<Aabb<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.union(&b)),
+        _ => a.or(b),
+    }
+}
+
+/// Scores `count` items inside `aabb` for the surface area heuristic.
+fn cost<T: Real>(aabb: Option<Aabb<T>>, count: usize) -> T {
+    aabb.map_or(T::ZERO, |aabb| {
+        aabb.surface_area() * T::from_f64(count as f64)
+    })
+}
@@ -0,0 +1,330 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::segment::{Segment, Segment2};
+use super::{max_scale, max_scale2};
+
+/// A solid capsule in 3D space: every point within `radius` of the segment
+/// from `start` to `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule<T> {
+    /// The centre of the first hemispherical cap.
+    pub start: Vector3<T>,
+    /// The centre of the second hemispherical cap.
+    pub end: Vector3<T>,
+    /// The radius of the capsule.
+    pub radius: T,
+}
+
+impl<T> Capsule<T> {
+    /// Creates a capsule around the segment from `start` to `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.radius, 0.5);
+    /// ```
+    pub fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl<T: Real> Capsule<T> {
+    /// Returns the segment running through the middle of the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.segment().length(), 2.0);
+    /// ```
+    pub fn segment(&self) -> Segment<T> {
+        Segment::new(self.start, self.end)
+    }
+
+    /// Returns `true` if `point` lies inside or on the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert!(capsule.contains_point(Vector3::new(0.5, 1.0, 0.0)));
+    /// assert!(capsule.contains_point(Vector3::new(0.0, 2.5, 0.0)));
+    /// assert!(!capsule.contains_point(Vector3::new(0.5, 2.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let closest = self.segment().closest_point(point);
+        (point - closest).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns the point of the capsule closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(
+    ///     capsule.closest_point(Vector3::new(3.0, 1.0, 0.0)),
+    ///     Vector3::new(0.5, 1.0, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     capsule.closest_point(Vector3::new(0.0, 5.0, 0.0)),
+    ///     Vector3::new(0.0, 2.5, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let axis_point = self.segment().closest_point(point);
+        let offset = point - axis_point;
+        let distance = offset.length();
+        if distance <= self.radius {
+            return point;
+        }
+        axis_point + offset * (self.radius / distance)
+    }
+
+    /// Returns the distance from the capsule to `point`, or zero if the point
+    /// is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.distance_to_point(Vector3::new(0.0, -3.0, 0.0)), 2.5);
+    /// assert_eq!(capsule.distance_to_point(Vector3::new(0.0, 1.0, 0.0)), 0.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.segment().distance_to_point(point) - self.radius).max(T::ZERO)
+    }
+
+    /// Returns the area of the surface of the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0_f64);
+    /// assert_eq!(capsule.surface_area(), 8.0 * std::f64::consts::PI);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        let r = self.radius;
+        T::TAU * r * (T::TWO * r + self.segment().length())
+    }
+
+    /// Returns the volume enclosed by the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 0.0), 3.0_f64);
+    /// assert_approx_eq!(capsule.volume(), 63.0 * std::f64::consts::PI, epsilon = 1e-9);
+    /// ```
+    pub fn volume(&self) -> T {
+        let r = self.radius;
+        let sphere = T::from_f64(4.0) / T::from_f64(3.0) * r;
+        T::PI * r * r * (sphere + self.segment().length())
+    }
+
+    /// Applies `transform` to the capsule.
+    ///
+    /// The radius grows by the largest stretch of the transform, so under
+    /// non-uniform scale the result encloses the transformed shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// let moved = capsule.transform(&Affine3::from_translation(Vector3::new(1.0, 0.0, 0.0)));
+    /// assert_eq!(moved.start, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_approx_eq!(moved.radius, 0.5, epsilon = 1e-12);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+            self.radius * max_scale(&transform.matrix),
+        )
+    }
+}
+
+/// A solid capsule in 2D space, also known as a stadium: every point within
+/// `radius` of the segment from `start` to `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule2<T> {
+    /// The centre of the first semicircular cap.
+    pub start: Vector2<T>,
+    /// The centre of the second semicircular cap.
+    pub end: Vector2<T>,
+    /// The radius of the capsule.
+    pub radius: T,
+}
+
+impl<T> Capsule2<T> {
+    /// Creates a capsule around the segment from `start` to `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.radius, 0.5);
+    /// ```
+    pub fn new(start: Vector2<T>, end: Vector2<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl<T: Real> Capsule2<T> {
+    /// Returns the segment running through the middle of the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.segment().length(), 2.0);
+    /// ```
+    pub fn segment(&self) -> Segment2<T> {
+        Segment2::new(self.start, self.end)
+    }
+
+    /// Returns `true` if `point` lies inside or on the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// assert!(capsule.contains_point(Vector2::new(2.5, 0.0)));
+    /// assert!(!capsule.contains_point(Vector2::new(2.5, 0.5)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        let closest = self.segment().closest_point(point);
+        (point - closest).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns the point of the capsule closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.closest_point(Vector2::new(1.0, 3.0)), Vector2::new(1.0, 0.5));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let axis_point = self.segment().closest_point(point);
+        let offset = point - axis_point;
+        let distance = offset.length();
+        if distance <= self.radius {
+            return point;
+        }
+        axis_point + offset * (self.radius / distance)
+    }
+
+    /// Returns the distance from the capsule to `point`, or zero if the point
+    /// is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.distance_to_point(Vector2::new(1.0, 3.0)), 2.5);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (self.segment().distance_to_point(point) - self.radius).max(T::ZERO)
+    }
+
+    /// Returns the area enclosed by the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 1.0_f64);
+    /// assert_eq!(capsule.area(), std::f64::consts::PI + 4.0);
+    /// ```
+    pub fn area(&self) -> T {
+        let r = self.radius;
+        T::PI * r * r + T::TWO * r * self.segment().length()
+    }
+
+    /// Returns the length of the outline of the capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 1.0_f64);
+    /// assert_eq!(capsule.perimeter(), 2.0 * std::f64::consts::PI + 4.0);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        T::TAU * self.radius + T::TWO * self.segment().length()
+    }
+
+    /// Applies `transform` to the capsule, enclosing the result if the scale
+    /// is not uniform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let capsule = Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 0.5);
+    /// let scaled = capsule.transform(&Affine2::from_scale(Vector2::new(2.0, 2.0)));
+    /// assert_eq!(scaled, Capsule2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), 1.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+            self.radius * max_scale2(&transform.matrix),
+        )
+    }
+}
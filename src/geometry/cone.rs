@@ -0,0 +1,138 @@
+use crate::scalar::Real;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector3::Vector3;
+
+use super::max_scale;
+
+/// A solid, capped cone in 3D space with its tip at `apex` and a circular
+/// base of `radius` centred on `base`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cone<T> {
+    /// The tip of the cone.
+    pub apex: Vector3<T>,
+    /// The centre of the base disc.
+    pub base: Vector3<T>,
+    /// The radius of the base disc.
+    pub radius: T,
+}
+
+impl<T> Cone<T> {
+    /// Creates a cone from its apex, the centre of its base and the base
+    /// radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert_eq!(cone.height(), 2.0);
+    /// ```
+    pub fn new(apex: Vector3<T>, base: Vector3<T>, radius: T) -> Self {
+        Self { apex, base, radius }
+    }
+}
+
+impl<T: Real> Cone<T> {
+    /// Returns the distance from the apex to the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(3.0, 4.0, 0.0), 1.0);
+    /// assert_eq!(cone.height(), 5.0);
+    /// ```
+    pub fn height(&self) -> T {
+        (self.base - self.apex).length()
+    }
+
+    /// Returns `true` if `point` lies inside or on the cone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert!(cone.contains_point(Vector3::new(0.5, 1.0, 0.0)));
+    /// assert!(!cone.contains_point(Vector3::new(0.6, 1.0, 0.0)));
+    /// assert!(!cone.contains_point(Vector3::new(0.0, -0.1, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let axis = self.base - self.apex;
+        let offset = point - self.apex;
+        let height_squared = axis.length_squared();
+        let along = offset.dot(&axis);
+        if along < T::ZERO || along > height_squared {
+            return false;
+        }
+        // Compare squared radial distance against the radius of the cross
+        // section, which grows linearly from the apex.
+        let radial_squared = offset.length_squared() - along * along / height_squared;
+        let section = self.radius * along / height_squared;
+        radial_squared <= section * section
+    }
+
+    /// Returns the area of the surface of the cone, including its base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 4.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 3.0_f64);
+    /// assert_eq!(cone.surface_area(), 24.0 * std::f64::consts::PI);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        let r = self.radius;
+        let h = self.height();
+        let slant = (r * r + h * h).sqrt();
+        T::PI * r * (r + slant)
+    }
+
+    /// Returns the volume enclosed by the cone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 1.0_f64);
+    /// assert_approx_eq!(cone.volume(), std::f64::consts::PI, epsilon = 1e-12);
+    /// ```
+    pub fn volume(&self) -> T {
+        T::PI * self.radius * self.radius * self.height() / T::from_f64(3.0)
+    }
+
+    /// Applies `transform` to the cone.
+    ///
+    /// The radius grows by the largest stretch of the transform, so under
+    /// non-uniform scale the result encloses the transformed shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// let moved = cone.transform(&Affine3::from_translation(Vector3::new(0.0, 1.0, 0.0)));
+    /// assert_eq!(moved.apex, Vector3::new(0.0, 3.0, 0.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.apex),
+            transform.transform_point(self.base),
+            self.radius * max_scale(&transform.matrix),
+        )
+    }
+}
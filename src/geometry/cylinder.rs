@@ -0,0 +1,130 @@
+use crate::scalar::Real;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector3::Vector3;
+
+use super::max_scale;
+
+/// A solid, capped cylinder in 3D space around the segment from `start` to
+/// `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylinder<T> {
+    /// The centre of the first cap.
+    pub start: Vector3<T>,
+    /// The centre of the second cap.
+    pub end: Vector3<T>,
+    /// The radius of the cylinder.
+    pub radius: T,
+}
+
+impl<T> Cylinder<T> {
+    /// Creates a cylinder whose caps are centred on `start` and `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(cylinder.radius, 0.5);
+    /// ```
+    pub fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl<T: Real> Cylinder<T> {
+    /// Returns the distance between the two caps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 4.0), 1.0);
+    /// assert_eq!(cylinder.height(), 5.0);
+    /// ```
+    pub fn height(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Returns `true` if `point` lies inside or on the cylinder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// assert!(cylinder.contains_point(Vector3::new(0.5, 2.0, 0.0)));
+    /// assert!(!cylinder.contains_point(Vector3::new(0.0, 2.1, 0.0)));
+    /// assert!(!cylinder.contains_point(Vector3::new(0.4, 1.0, 0.4)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let axis = self.end - self.start;
+        let offset = point - self.start;
+        let length_squared = axis.length_squared();
+        let along = offset.dot(&axis);
+        if along < T::ZERO || along > length_squared {
+            return false;
+        }
+        let radial_squared = offset.length_squared() - along * along / length_squared;
+        radial_squared <= self.radius * self.radius
+    }
+
+    /// Returns the area of the surface of the cylinder, including both caps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0_f64);
+    /// assert_eq!(cylinder.surface_area(), 6.0 * std::f64::consts::PI);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        T::TAU * self.radius * (self.radius + self.height())
+    }
+
+    /// Returns the volume enclosed by the cylinder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0_f64);
+    /// assert_eq!(cylinder.volume(), 2.0 * std::f64::consts::PI);
+    /// ```
+    pub fn volume(&self) -> T {
+        T::PI * self.radius * self.radius * self.height()
+    }
+
+    /// Applies `transform` to the cylinder.
+    ///
+    /// The radius grows by the largest stretch of the transform, so under
+    /// non-uniform scale the result encloses the transformed shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.5);
+    /// let moved = cylinder.transform(&Affine3::from_translation(Vector3::new(1.0, 0.0, 0.0)));
+    /// assert_eq!(moved.end, Vector3::new(1.0, 2.0, 0.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+            self.radius * max_scale(&transform.matrix),
+        )
+    }
+}
@@ -0,0 +1,374 @@
+use crate::matrices::mat4::Mat4;
+use crate::matrices::projection::DepthRange;
+use crate::scalar::Real;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+use super::aabb::Aabb;
+use super::plane::Plane;
+use super::sphere::Sphere;
+use super::support::SupportMap;
+
+/// How a shape lies relative to a volume such as a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// Entirely outside the volume.
+    Outside,
+    /// Partly inside and partly outside the volume.
+    Intersecting,
+    /// Entirely inside the volume.
+    Inside,
+}
+
+/// The volume a camera can see, bounded by six planes whose normals point
+/// inwards.
+///
+/// The planes are stored in the order left, right, bottom, top, near, far.
+/// A point is inside when it lies in front of or on every plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum<T> {
+    /// The bounding planes, facing into the frustum.
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T> Frustum<T> {
+    /// Creates a frustum from its left, right, bottom, top, near and far
+    /// planes, each facing inwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // An axis-aligned cube from -1 to 1 expressed as six planes.
+    /// let face = |x: f64, y: f64, z: f64| Plane::new(Vector3::new(x, y, z), 1.0);
+    /// let cube = Frustum::new([
+    ///     face(1.0, 0.0, 0.0),
+    ///     face(-1.0, 0.0, 0.0),
+    ///     face(0.0, 1.0, 0.0),
+    ///     face(0.0, -1.0, 0.0),
+    ///     face(0.0, 0.0, 1.0),
+    ///     face(0.0, 0.0, -1.0),
+    /// ]);
+    /// assert!(cube.contains_point(Vector3::new(0.5, -0.5, 1.0)));
+    /// ```
+    pub fn new(planes: [Plane<T>; 6]) -> Self {
+        Self { planes }
+    }
+}
+
+impl<T: Real> Frustum<T> {
+    /// Extracts the frustum from a view-projection matrix using the method
+    /// of Gribb and Hartmann.
+    ///
+    /// `depth_range` must match the one the projection was built with. The
+    /// planes come out in world space for a view-projection matrix, or in
+    /// view space for a projection alone. For reverse-Z projections the near
+    /// and far planes trade places, and an infinite far plane comes out with
+    /// a zero normal that every point lies in front of.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let depth = DepthRange::ZeroToOne;
+    /// let projection = Mat4::perspective_rh(std::f64::consts::FRAC_PI_2, 1.0, 0.1, 100.0, depth);
+    /// let view = Mat4::look_at_rh(
+    ///     Vector3::new(0.0, 0.0, 5.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let frustum = Frustum::from_view_projection(&(projection * view), depth);
+    /// assert!(frustum.contains_point(Vector3::new(0.0, 0.0, 0.0)));
+    /// assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, 6.0)));
+    /// assert!(!frustum.contains_point(Vector3::new(6.0, 0.0, 0.0)));
+    /// ```
+    pub fn from_view_projection(view_projection: &Mat4<T>, depth_range: DepthRange) -> Self {
+        let [x, y, z, w] = [0, 1, 2, 3].map(|i| view_projection.row(i));
+        let near = match depth_range {
+            DepthRange::NegativeOneToOne => w + z,
+            DepthRange::ZeroToOne => z,
+        };
+        Self::new([w + x, w - x, w + y, w - y, near, w - z].map(extracted_plane))
+    }
+
+    /// Creates the frustum of a right-handed perspective camera at `eye`
+    /// looking along `direction`.
+    ///
+    /// The arguments follow [`Mat4::look_to_rh`] and [`Mat4::perspective_rh`],
+    /// and the result matches extracting the frustum from their product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     std::f64::consts::FRAC_PI_2,
+    ///     2.0,
+    ///     1.0,
+    ///     10.0,
+    /// );
+    /// assert!(frustum.contains_point(Vector3::new(5.0, 4.0, 0.0)));
+    /// assert!(!frustum.contains_point(Vector3::new(5.0, 6.0, 0.0)));
+    /// assert!(frustum.contains_point(Vector3::new(5.0, 0.0, 9.0)));
+    /// assert!(!frustum.contains_point(Vector3::new(0.5, 0.0, 0.0)));
+    /// ```
+    pub fn from_perspective(
+        eye: Vector3<T>,
+        direction: Vector3<T>,
+        up: Vector3<T>,
+        fov_y: T,
+        aspect: T,
+        near: T,
+        far: T,
+    ) -> Self {
+        let forward = direction.normalize();
+        let right = forward.cross(&up).normalize();
+        let up = right.cross(&forward);
+        let vertical = (fov_y * T::HALF).tan();
+        let horizontal = vertical * aspect;
+
+        let side = |axis: Vector3<T>, sign: T, slope: T| {
+            Plane::from_point_normal(eye, axis * sign + forward * slope)
+        };
+        Self::new([
+            side(right, T::ONE, horizontal),
+            side(right, -T::ONE, horizontal),
+            side(up, T::ONE, vertical),
+            side(up, -T::ONE, vertical),
+            Plane::from_point_normal(eye + forward * near, forward),
+            Plane::from_point_normal(eye + forward * far, -forward),
+        ])
+    }
+
+    /// Returns `true` if `point` lies inside or on the frustum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     1.0,
+    ///     1.0,
+    ///     0.1,
+    ///     50.0,
+    /// );
+    /// assert!(frustum.contains_point(Vector3::new(0.0, 0.0, -10.0)));
+    /// assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, 10.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= T::ZERO)
+    }
+
+    /// Returns whether `sphere` is outside, inside or crossing the frustum.
+    ///
+    /// Like most culling tests this is conservative: a sphere near a corner
+    /// of the frustum may be reported as intersecting while lying just
+    /// outside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::{Containment, Frustum};
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     1.0,
+    ///     1.0,
+    ///     0.1,
+    ///     50.0,
+    /// );
+    /// let inside = Sphere::new(Vector3::new(0.0, 0.0, -10.0), 1.0);
+    /// let crossing_far = Sphere::new(Vector3::new(0.0, 0.0, -50.0), 1.0);
+    /// let behind = Sphere::new(Vector3::new(0.0, 0.0, 5.0), 1.0);
+    /// assert_eq!(frustum.intersects_sphere(&inside), Containment::Inside);
+    /// assert_eq!(frustum.intersects_sphere(&crossing_far), Containment::Intersecting);
+    /// assert_eq!(frustum.intersects_sphere(&behind), Containment::Outside);
+    /// ```
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance < sphere.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns whether `aabb` is outside, inside or crossing the frustum.
+    ///
+    /// Each plane is tested against the corner of the box furthest along
+    /// its normal and the one furthest against it. Like most culling tests
+    /// this is conservative: a box near a corner of the frustum may be
+    /// reported as intersecting while lying just outside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::frustum::{Containment, Frustum};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     1.0,
+    ///     1.0,
+    ///     0.1,
+    ///     50.0,
+    /// );
+    /// let inside = Aabb::new(Vector3::new(-1.0, -1.0, -12.0), Vector3::new(1.0, 1.0, -10.0));
+    /// let straddling = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let beside = Aabb::new(Vector3::new(20.0, -1.0, -12.0), Vector3::new(22.0, 1.0, -10.0));
+    /// assert_eq!(frustum.intersects_aabb(&inside), Containment::Inside);
+    /// assert_eq!(frustum.intersects_aabb(&straddling), Containment::Intersecting);
+    /// assert_eq!(frustum.intersects_aabb(&beside), Containment::Outside);
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            if plane.signed_distance(aabb.support(plane.normal)) < T::ZERO {
+                return Containment::Outside;
+            }
+            let away = -plane.normal;
+            if plane.signed_distance(aabb.support(away)) < T::ZERO {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns the eight corners of the frustum, or `None` if it has no
+    /// finite corners.
+    ///
+    /// The near corners come first, then the far ones, each in the order
+    /// bottom-left, bottom-right, top-right, top-left as seen by the camera.
+    /// This is the usual input for fitting a shadow cascade around a slice
+    /// of the view.
+    ///
+    /// An infinite projection extracts to a far plane with a zero normal,
+    /// which meets no other plane, so its frustum has no corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     std::f64::consts::FRAC_PI_2,
+    ///     2.0,
+    ///     1.0,
+    ///     10.0,
+    /// );
+    /// let corners = frustum.corners().unwrap();
+    /// let expected_near = Vector3::new(-2.0, -1.0, -1.0);
+    /// let expected_far = Vector3::new(20.0, 10.0, -10.0);
+    /// assert_approx_eq!(corners[0], expected_near, epsilon = 1e-12);
+    /// assert_approx_eq!(corners[6], expected_far, epsilon = 1e-12);
+    /// ```
+    pub fn corners(&self) -> Option<[Vector3<T>; 8]> {
+        let [left, right, bottom, top, near, far] = self.planes;
+        let corners = [
+            (near, bottom, left),
+            (near, bottom, right),
+            (near, top, right),
+            (near, top, left),
+            (far, bottom, left),
+            (far, bottom, right),
+            (far, top, right),
+            (far, top, left),
+        ]
+        .map(|(a, b, c)| meeting_point(&a, &b, &c));
+        if corners.contains(&None) {
+            return None;
+        }
+        Some(corners.map(Option::unwrap))
+    }
+
+    /// Applies `transform` to every plane of the frustum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::frustum::Frustum;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let frustum = Frustum::from_perspective(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     1.0,
+    ///     1.0,
+    ///     0.1,
+    ///     50.0,
+    /// );
+    /// let moved = frustum.transform(&Affine3::from_translation(Vector3::new(100.0, 0.0, 0.0)));
+    /// assert!(moved.contains_point(Vector3::new(100.0, 0.0, -10.0)));
+    /// assert!(!moved.contains_point(Vector3::new(0.0, 0.0, -10.0)));
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(self.planes.map(|plane| plane.transform(transform)))
+    }
+}
+
+/// Turns a combination of matrix rows into a plane with a unit normal,
+/// leaving planes pushed out to infinity with their zero normal.
+fn extracted_plane<T: Real>(row: Vector4<T>) -> Plane<T> {
+    let plane = Plane::new(Vector3::new(row.x, row.y, row.z), row.w);
+    if plane.normal.length_squared() == T::ZERO {
+        return plane;
+    }
+    plane.normalize()
+}
+
+/// Returns the point shared by three planes, or `None` if they do not meet
+/// in a single finite point.
+fn meeting_point<T: Real>(a: &Plane<T>, b: &Plane<T>, c: &Plane<T>) -> Option<Vector3<T>> {
+    let bc = b.normal.cross(&c.normal);
+    let denominator = a.normal.dot(&bc);
+    if denominator == T::ZERO {
+        return None;
+    }
+    let ca = c.normal.cross(&a.normal);
+    let ab = a.normal.cross(&b.normal);
+    let sum = bc * a.d + ca * b.d + ab * c.d;
+    let point = sum * -denominator.recip();
+    [point.x, point.y, point.z]
+        .iter()
+        .all(|x| x.is_finite())
+        .then_some(point)
+}
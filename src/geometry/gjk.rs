@@ -0,0 +1,590 @@
+//! Distance and penetration queries between any two convex shapes.
+//!
+//! The Gilbert–Johnson–Keerthi (GJK) algorithm finds the closest points of
+//! two convex shapes, or reports that they overlap, by searching their
+//! Minkowski difference for the point nearest the origin. When the shapes
+//! overlap, the expanding polytope algorithm (EPA) grows the last GJK simplex
+//! until it finds the shortest way to pull them apart.
+//!
+//! Both only look at the shapes through their [`SupportMap`], so any pair of
+//! convex shapes can be tested. Contacts follow the conventions of
+//! [`overlap`](super::overlap): the normal points from the first shape
+//! towards the second.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+
+use super::overlap::Contact;
+use super::support::SupportMap;
+use super::triangle::Triangle;
+
+/// The most refinement steps GJK takes before settling for the best answer
+/// found so far.
+const MAX_ITERATIONS: usize = 64;
+
+/// The most points EPA adds to its polytope. Curved shapes need more than
+/// GJK does, since each point only flattens the polytope where it lands.
+const MAX_EPA_ITERATIONS: usize = 128;
+
+/// Returns the closest pair of points of two convex shapes, the first on `a`
+/// and the second on `b`, or `None` if they overlap.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::geometry::aabb::Aabb;
+/// use vexel::geometry::gjk;
+/// use vexel::geometry::sphere::Sphere;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+/// let sphere = Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0);
+/// let (on_box, on_sphere) = gjk::closest_points(&aabb, &sphere).unwrap();
+/// assert_approx_eq!(on_box, Vector3::new(1.0, 0.0, 0.0), epsilon = 1e-6);
+/// assert_approx_eq!(on_sphere, Vector3::new(3.0, 0.0, 0.0), epsilon = 1e-6);
+///
+/// let overlapping = Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0);
+/// assert!(gjk::closest_points(&aabb, &overlapping).is_none());
+/// ```
+pub fn closest_points<T, A, B>(a: &A, b: &B) -> Option<(Vector3<T>, Vector3<T>)>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(on_a, on_b) => Some((on_a, on_b)),
+        Gjk::Overlapping(_) => None,
+    }
+}
+
+/// Returns the distance between two convex shapes, or zero if they overlap.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::geometry::capsule::Capsule;
+/// use vexel::geometry::gjk;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let a = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0), 0.5);
+/// let b = Capsule::new(Vector3::new(3.0, 2.0, -1.0), Vector3::new(3.0, 2.0, 1.0), 0.5);
+/// assert_approx_eq!(gjk::distance(&a, &b), 2.0, epsilon = 1e-6);
+/// ```
+pub fn distance<T, A, B>(a: &A, b: &B) -> T
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    closest_points(a, b).map_or(T::ZERO, |(on_a, on_b)| (on_b - on_a).length())
+}
+
+/// Returns `true` if two convex shapes touch or overlap.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::geometry::cylinder::Cylinder;
+/// use vexel::geometry::gjk;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let hull = [
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(1.0, 0.0, 0.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+///     Vector3::new(0.0, 0.0, 1.0),
+/// ];
+/// let near = Cylinder::new(Vector3::new(0.5, -1.0, 0.2), Vector3::new(0.5, 1.0, 0.2), 0.25);
+/// let far = Cylinder::new(Vector3::new(3.0, -1.0, 0.0), Vector3::new(3.0, 1.0, 0.0), 0.25);
+/// assert!(gjk::intersects(&hull, &near));
+/// assert!(!gjk::intersects(&hull, &far));
+/// ```
+pub fn intersects<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    closest_points(a, b).is_none()
+}
+
+/// Returns how two overlapping convex shapes touch, or `None` if they are
+/// apart.
+///
+/// Moving `b` by `normal * depth` separates the shapes. Shapes whose overlap
+/// has no volume, such as two coplanar triangles, report a depth of zero.
+/// On curved shapes the depth is found to a relative `1e-5`, or to the square
+/// root of the machine epsilon for `f32`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::geometry::gjk;
+/// use vexel::geometry::obb::Obb;
+/// use vexel::geometry::sphere::Sphere;
+/// use vexel::matrices::mat3::Mat3;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+/// let sphere = Sphere::new(Vector3::new(0.0, 1.5, 0.0), 1.0);
+/// let contact = gjk::penetration(&obb, &sphere).unwrap();
+/// assert_approx_eq!(contact.normal, Vector3::new(0.0, 1.0, 0.0), epsilon = 1e-6);
+/// assert_approx_eq!(contact.depth, 0.5, epsilon = 1e-6);
+///
+/// let apart = Sphere::new(Vector3::new(0.0, 3.0, 0.0), 1.0);
+/// assert!(gjk::penetration(&obb, &apart).is_none());
+/// ```
+pub fn penetration<T, A, B>(a: &A, b: &B) -> Option<Contact<T>>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(..) => None,
+        Gjk::Overlapping(simplex) => Some(epa(a, b, simplex)),
+    }
+}
+
+/// A point of the Minkowski difference `a - b`, together with the points of
+/// each shape it was made from.
+#[derive(Clone, Copy)]
+struct Vertex<T> {
+    w: Vector3<T>,
+    a: Vector3<T>,
+    b: Vector3<T>,
+}
+
+/// Returns the point of the Minkowski difference furthest along `direction`.
+fn vertex<T, A, B>(a: &A, b: &B, direction: Vector3<T>) -> Vertex<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let on_a = a.support(direction);
+    let on_b = b.support(-direction);
+    Vertex {
+        w: on_a - on_b,
+        a: on_a,
+        b: on_b,
+    }
+}
+
+/// The outcome of running GJK on a pair of shapes.
+enum Gjk<T> {
+    /// The closest points on each shape.
+    Separated(Vector3<T>, Vector3<T>),
+    /// The final simplex, which contains or touches the origin.
+    Overlapping(Vec<Vertex<T>>),
+}
+
+fn gjk<T, A, B>(a: &A, b: &B) -> Gjk<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let first = vertex(a, b, Vector3::new(T::ONE, T::ZERO, T::ZERO));
+    let mut simplex = vec![first];
+    let mut weights = [T::ONE, T::ZERO, T::ZERO, T::ZERO];
+    let mut v = first.w;
+    let relative = T::EPSILON.sqrt();
+
+    for _ in 0..MAX_ITERATIONS {
+        let vv = v.length_squared();
+        let size = simplex
+            .iter()
+            .fold(T::ZERO, |size, s| size.max(s.w.length_squared()));
+        if vv <= T::EPSILON * size {
+            return Gjk::Overlapping(simplex);
+        }
+
+        let next = vertex(a, b, -v);
+        // Stop once the new point brings us no meaningfully closer.
+        if vv - v.dot(&next.w) <= relative * vv || simplex.iter().any(|s| s.w == next.w) {
+            break;
+        }
+        simplex.push(next);
+
+        let points: Vec<_> = simplex.iter().map(|s| s.w).collect();
+        match closest_on_simplex(&points) {
+            Some(found) => weights = found,
+            None => return Gjk::Overlapping(simplex),
+        }
+        let mut kept = 0;
+        for i in 0..simplex.len() {
+            if weights[i] > T::ZERO {
+                simplex[kept] = simplex[i];
+                weights[kept] = weights[i];
+                kept += 1;
+            }
+        }
+        simplex.truncate(kept);
+        v = combine(&simplex, &weights, |s| s.w);
+    }
+
+    Gjk::Separated(
+        combine(&simplex, &weights, |s| s.a),
+        combine(&simplex, &weights, |s| s.b),
+    )
+}
+
+/// Returns the weighted sum of one of the points of each vertex.
+fn combine<T: Real>(
+    simplex: &[Vertex<T>],
+    weights: &[T; 4],
+    point: impl Fn(&Vertex<T>) -> Vector3<T>,
+) -> Vector3<T> {
+    simplex
+        .iter()
+        .zip(weights)
+        .fold(Vector3::new(T::ZERO, T::ZERO, T::ZERO), |sum, (s, &w)| {
+            sum + point(s) * w
+        })
+}
+
+/// Returns the barycentric weights of the point of the simplex closest to
+/// the origin, or `None` if the simplex is a tetrahedron containing it.
+///
+/// Vertices that do not contribute to the closest point get a weight of
+/// zero.
+fn closest_on_simplex<T: Real>(points: &[Vector3<T>]) -> Option<[T; 4]> {
+    let zero = T::ZERO;
+    match *points {
+        [_] => Some([T::ONE, zero, zero, zero]),
+        [p, q] => {
+            let [s, t] = closest_on_segment(p, q);
+            Some([s, t, zero, zero])
+        }
+        [p, q, r] => {
+            let [s, t, u] = closest_on_triangle(p, q, r);
+            Some([s, t, u, zero])
+        }
+        [p, q, r, s] => closest_on_tetrahedron([p, q, r, s]),
+        _ => unreachable!("a simplex has between one and four points"),
+    }
+}
+
+fn closest_on_segment<T: Real>(p: Vector3<T>, q: Vector3<T>) -> [T; 2] {
+    let pq = q - p;
+    let length_squared = pq.length_squared();
+    if length_squared == T::ZERO {
+        return [T::ONE, T::ZERO];
+    }
+    let t = (-p.dot(&pq) / length_squared).clamp(T::ZERO, T::ONE);
+    [T::ONE - t, t]
+}
+
+/// Finds the closest point of a triangle to the origin by checking which
+/// of its vertex, edge or face regions the origin falls in.
+fn closest_on_triangle<T: Real>(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> [T; 3] {
+    let zero = T::ZERO;
+    let one = T::ONE;
+    let ab = b - a;
+    let ac = c - a;
+
+    let d1 = -ab.dot(&a);
+    let d2 = -ac.dot(&a);
+    if d1 <= zero && d2 <= zero {
+        return [one, zero, zero];
+    }
+
+    let d3 = -ab.dot(&b);
+    let d4 = -ac.dot(&b);
+    if d3 >= zero && d4 <= d3 {
+        return [zero, one, zero];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        let t = d1 / (d1 - d3);
+        return [one - t, t, zero];
+    }
+
+    let d5 = -ab.dot(&c);
+    let d6 = -ac.dot(&c);
+    if d6 >= zero && d5 <= d6 {
+        return [zero, zero, one];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        let t = d2 / (d2 - d6);
+        return [one - t, zero, t];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+        let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [zero, one - t, t];
+    }
+
+    let total = va + vb + vc;
+    if total <= zero {
+        // A degenerate triangle: the closest point lies on one of its edges.
+        let edges = [(0, 1), (1, 2), (0, 2)];
+        let points = [a, b, c];
+        let mut best = ([zero; 3], T::INFINITY);
+        for (i, j) in edges {
+            let [s, t] = closest_on_segment(points[i], points[j]);
+            let distance = (points[i] * s + points[j] * t).length_squared();
+            if distance < best.1 {
+                let mut weights = [zero; 3];
+                weights[i] = s;
+                weights[j] = t;
+                best = (weights, distance);
+            }
+        }
+        return best.0;
+    }
+    let v = vb / total;
+    let w = vc / total;
+    [one - v - w, v, w]
+}
+
+fn closest_on_tetrahedron<T: Real>(p: [Vector3<T>; 4]) -> Option<[T; 4]> {
+    // Each face with the vertex left out of it.
+    let faces = [(0, 1, 2, 3), (0, 3, 1, 2), (0, 2, 3, 1), (1, 3, 2, 0)];
+    let mut best: Option<([T; 4], T)> = None;
+    for (i, j, k, opposite) in faces {
+        let normal = (p[j] - p[i]).cross(&(p[k] - p[i]));
+        let origin_side = -normal.dot(&p[i]);
+        let vertex_side = normal.dot(&(p[opposite] - p[i]));
+        // Only faces with the origin on their far side can hold the closest
+        // point; a flat tetrahedron checks all of them.
+        if vertex_side != T::ZERO && origin_side * vertex_side >= T::ZERO {
+            continue;
+        }
+        let [s, t, u] = closest_on_triangle(p[i], p[j], p[k]);
+        let distance = (p[i] * s + p[j] * t + p[k] * u).length_squared();
+        if best.is_none_or(|(_, d)| distance < d) {
+            let mut weights = [T::ZERO; 4];
+            weights[i] = s;
+            weights[j] = t;
+            weights[k] = u;
+            best = Some((weights, distance));
+        }
+    }
+    best.map(|(weights, _)| weights)
+}
+
+/// A triangle of the expanding polytope, wound so its normal points out.
+struct Face<T> {
+    corners: [usize; 3],
+    normal: Vector3<T>,
+    distance: T,
+}
+
+impl<T: Real> Face<T> {
+    fn new(vertices: &[Vertex<T>], corners: [usize; 3]) -> Self {
+        let [a, b, c] = corners.map(|i| vertices[i].w);
+        let normal = (b - a).cross(&(c - a));
+        let length = normal.length();
+        if length <= T::EPSILON {
+            // A sliver with no usable normal; never pick it as the closest.
+            return Self {
+                corners,
+                normal,
+                distance: T::INFINITY,
+            };
+        }
+        let normal = normal * length.recip();
+        Self {
+            corners,
+            normal,
+            distance: normal.dot(&a),
+        }
+    }
+}
+
+fn epa<T, A, B>(a: &A, b: &B, mut vertices: Vec<Vertex<T>>) -> Contact<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    if let Err(normal) = blow_up(a, b, &mut vertices) {
+        let touching = vertices[0];
+        return Contact {
+            normal,
+            depth: T::ZERO,
+            point: touching.a.lerp(&touching.b, T::HALF),
+        };
+    }
+
+    let mut faces: Vec<Face<T>> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .map(|[i, j, k]| {
+            let face = Face::new(&vertices, [i, j, k]);
+            let opposite = 6 - i - j - k;
+            if face.normal.dot(&(vertices[opposite].w - vertices[i].w)) > T::ZERO {
+                Face::new(&vertices, [i, k, j])
+            } else {
+                face
+            }
+        })
+        .collect();
+
+    // The nearest face bounds the depth from below and every support distance
+    // bounds it from above. On curved shapes the lower bound only closes in
+    // linearly with the number of points, so `f64` settles for five digits.
+    let tolerance = T::EPSILON.sqrt().max(T::from_f64(1e-5));
+    let mut best = (T::INFINITY, faces[0].normal);
+    for _ in 0..MAX_EPA_ITERATIONS {
+        let face = &faces[nearest_face(&faces)];
+        let next = vertex(a, b, face.normal);
+        let reach = next.w.dot(&face.normal);
+        if reach < best.0 {
+            best = (reach, face.normal);
+        }
+        if best.0 - face.distance <= tolerance * T::ONE.max(best.0.abs()) {
+            break;
+        }
+
+        // Replace every face the new point can see with a fan of faces from
+        // the point to the edge of the hole they leave.
+        let index = vertices.len();
+        vertices.push(next);
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            let visible = face.normal.dot(&(next.w - vertices[face.corners[0]].w)) > T::ZERO;
+            if visible {
+                let [i, j, k] = face.corners;
+                for (from, to) in [(i, j), (j, k), (k, i)] {
+                    match horizon.iter().position(|&edge| edge == (to, from)) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push((from, to)),
+                    }
+                }
+            }
+            !visible
+        });
+        if horizon.is_empty() {
+            break;
+        }
+        faces.extend(
+            horizon
+                .into_iter()
+                .map(|(from, to)| Face::new(&vertices, [from, to, index])),
+        );
+    }
+
+    let face = &faces[nearest_face(&faces)];
+    let [p, q, r] = face.corners.map(|i| vertices[i]);
+    let closest = face.normal * face.distance.max(T::ZERO);
+    let weights = Triangle::new(p.w, q.w, r.w).barycentric(closest);
+    let on_a = p.a * weights.x + q.a * weights.y + r.a * weights.z;
+    let on_b = p.b * weights.x + q.b * weights.y + r.b * weights.z;
+    // The direction with the smallest support distance is the better normal:
+    // moving `b` that far along it just separates the shapes.
+    let (depth, normal) = if best.0.is_finite() {
+        best
+    } else {
+        (face.distance, face.normal)
+    };
+    Contact {
+        normal,
+        depth: depth.max(T::ZERO),
+        point: on_a.lerp(&on_b, T::HALF),
+    }
+}
+
+/// Returns the index of the face nearest the origin.
+fn nearest_face<T: Real>(faces: &[Face<T>]) -> usize {
+    (1..faces.len()).fold(0, |best, i| {
+        if faces[i].distance < faces[best].distance {
+            i
+        } else {
+            best
+        }
+    })
+}
+
+/// Grows a simplex that touches the origin into a tetrahedron around it.
+///
+/// Fails with the best available normal when the Minkowski difference is
+/// flat, which happens when the shapes only touch.
+fn blow_up<T, A, B>(a: &A, b: &B, vertices: &mut Vec<Vertex<T>>) -> Result<(), Vector3<T>>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let zero = T::ZERO;
+    let one = T::ONE;
+    let up = Vector3::new(zero, one, zero);
+    let size = vertices
+        .iter()
+        .fold(one, |size, v| size.max(v.w.length_squared()));
+    let tolerance = T::EPSILON * size;
+
+    if vertices.len() == 1 {
+        let axes = [
+            Vector3::new(one, zero, zero),
+            Vector3::new(zero, one, zero),
+            Vector3::new(zero, zero, one),
+        ];
+        let directions = axes.into_iter().flat_map(|axis| [axis, -axis]);
+        let origin = vertices[0].w;
+        let found = directions
+            .map(|direction| vertex(a, b, direction))
+            .find(|v| (v.w - origin).length_squared() > tolerance);
+        vertices.extend(found);
+        if vertices.len() == 1 {
+            return Err(up);
+        }
+    }
+
+    if vertices.len() == 2 {
+        let line = vertices[1].w - vertices[0].w;
+        let (x, y, z) = (line.x.abs(), line.y.abs(), line.z.abs());
+        let least = if x <= y && x <= z {
+            Vector3::new(one, zero, zero)
+        } else if y <= z {
+            Vector3::new(zero, one, zero)
+        } else {
+            Vector3::new(zero, zero, one)
+        };
+        let side = line.cross(&least).normalize();
+        let other_side = line.normalize().cross(&side);
+        let start = vertices[0].w;
+        // Sweep around the line in sixths of a turn.
+        let found = (0..6)
+            .map(|step| {
+                let (sin, cos) = (T::TAU * T::from_f64(step as f64 / 6.0)).sin_cos();
+                vertex(a, b, side * cos + other_side * sin)
+            })
+            .find(|v| (v.w - start).cross(&line).length_squared() > tolerance * size);
+        vertices.extend(found);
+        if vertices.len() == 2 {
+            return Err(side);
+        }
+    }
+
+    if vertices.len() == 3 {
+        let [p, q, r] = [vertices[0].w, vertices[1].w, vertices[2].w];
+        let normal = (q - p).cross(&(r - p));
+        let unit = normal.normalize();
+        let off_plane = |v: &Vertex<T>| unit.dot(&(v.w - p)).abs() > tolerance.sqrt();
+        let found = [normal, -normal]
+            .into_iter()
+            .map(|direction| vertex(a, b, direction))
+            .find(off_plane);
+        vertices.extend(found);
+        if vertices.len() == 3 {
+            return Err(unit);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::tolerance;
+
+/// An infinite line in 3D space through `point` along `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line<T> {
+    /// A point on the line.
+    pub point: Vector3<T>,
+    /// The unit direction of the line.
+    pub direction: Vector3<T>,
+}
+
+impl<T: Real> Line<T> {
+    /// Creates a line through `point`, normalizing `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(3.0, 0.0, 0.0));
+    /// assert_eq!(line.direction, Vector3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn new(point: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self {
+            point,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Creates the line through two distinct points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::from_points(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 4.0));
+    /// assert_eq!(line.direction, Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Returns the point on the line closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     line.closest_point(Vector3::new(-4.0, 2.0, 1.0)),
+    ///     Vector3::new(-4.0, 0.0, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let t = (point - self.point).dot(&self.direction);
+        self.point + self.direction * t
+    }
+
+    /// Returns the distance from the line to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(line.distance_to_point(Vector3::new(-4.0, 3.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// assert!(line.contains_point(Vector3::new(0.0, 0.0, -7.0)));
+    /// assert!(!line.contains_point(Vector3::new(0.1, 0.0, -7.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let line = Line::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let moved = line.transform(&Affine3::from_translation(Vector3::new(0.0, 2.0, 0.0)));
+    /// assert!(moved.contains_point(Vector3::new(5.0, 2.0, 0.0)));
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.point),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
+
+/// An infinite line in 2D space through `point` along `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line2<T> {
+    /// A point on the line.
+    pub point: Vector2<T>,
+    /// The unit direction of the line.
+    pub direction: Vector2<T>,
+}
+
+impl<T: Real> Line2<T> {
+    /// Creates a line through `point`, normalizing `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 1.0), Vector2::new(3.0, 0.0));
+    /// assert_eq!(line.direction, Vector2::new(1.0, 0.0));
+    /// ```
+    pub fn new(point: Vector2<T>, direction: Vector2<T>) -> Self {
+        Self {
+            point,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Creates the line through two distinct points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::from_points(Vector2::new(1.0, 1.0), Vector2::new(1.0, 4.0));
+    /// assert_eq!(line.direction, Vector2::new(0.0, 1.0));
+    /// ```
+    pub fn from_points(a: Vector2<T>, b: Vector2<T>) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Returns the distance from the line to `point`, positive on the left of
+    /// the direction and negative on the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(line.signed_distance(Vector2::new(5.0, 2.0)), 2.0);
+    /// assert_eq!(line.signed_distance(Vector2::new(5.0, -2.0)), -2.0);
+    /// ```
+    pub fn signed_distance(&self, point: Vector2<T>) -> T {
+        self.direction.cross(&(point - self.point))
+    }
+
+    /// Returns the point on the line closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(line.closest_point(Vector2::new(-4.0, 2.0)), Vector2::new(-4.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let t = (point - self.point).dot(&self.direction);
+        self.point + self.direction * t
+    }
+
+    /// Returns the distance from the line to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(line.distance_to_point(Vector2::new(-4.0, -3.0)), 3.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+
+    /// Returns `true` if `point` lies on the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+    /// assert!(line.contains_point(Vector2::new(-3.0, -3.0)));
+    /// assert!(!line.contains_point(Vector2::new(-3.0, 3.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::line::Line2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// let moved = line.transform(&Affine2::from_translation(Vector2::new(0.0, 2.0)));
+    /// assert_eq!(moved.signed_distance(Vector2::new(0.0, 0.0)), -2.0);
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.point),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
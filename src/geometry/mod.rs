@@ -0,0 +1,79 @@
+//! Geometric primitives built from vectors.
+//!
+//! Solid shapes such as spheres, boxes and capsules include their interior: a
+//! point inside one is its own closest point and has a distance of zero.
+//! Shapes without volume such as planes, lines and triangles treat a point as
+//! contained when it lies within a small tolerance of the shape.
+//!
+//! 3D shapes are transformed by an [`Affine3`], 2D shapes by an [`Affine2`].
+//! A `Mat4` can be turned into an `Affine3` with [`Affine3::from_mat4`] and a
+//! `Transform` converts with `into()`.
+//!
+//! [`Affine2`]: crate::transforms::affine2::Affine2
+//! [`Affine3`]: crate::transforms::affine3::Affine3
+//! [`Affine3::from_mat4`]: crate::transforms::affine3::Affine3::from_mat4
+
+pub mod aabb;
+#[cfg(feature = "alloc")]
+pub mod bvh;
+pub mod capsule;
+pub mod cone;
+pub mod cylinder;
+pub mod frustum;
+#[cfg(feature = "alloc")]
+pub mod gjk;
+pub mod line;
+pub mod obb;
+pub mod overlap;
+pub mod plane;
+pub mod ray;
+pub mod raycast;
+pub mod segment;
+pub mod sphere;
+pub mod support;
+pub mod triangle;
+
+use crate::matrices::mat2::Mat2;
+use crate::matrices::mat3::Mat3;
+use crate::scalar::Real;
+
+/// Returns how far a point may be from a shape without volume and still be
+/// considered on it.
+fn tolerance<T: Real>() -> T {
+    T::EPSILON.sqrt()
+}
+
+/// Returns the largest factor by which `m` stretches any vector, which bounds
+/// how much a radius grows under the transform.
+///
+/// This is the largest singular value of `m`, found as the square root of the
+/// largest eigenvalue of the symmetric matrix `mᵀm`.
+fn max_scale<T: Real>(m: &Mat3<T>) -> T {
+    let [c0, c1, c2] = m.cols;
+    let (a00, a11, a22) = (c0.dot(&c0), c1.dot(&c1), c2.dot(&c2));
+    let (a01, a02, a12) = (c0.dot(&c1), c0.dot(&c2), c1.dot(&c2));
+
+    let off_diagonal = a01 * a01 + a02 * a02 + a12 * a12;
+    let three = T::from_f64(3.0);
+    let q = (a00 + a11 + a22) / three;
+    let (b00, b11, b22) = (a00 - q, a11 - q, a22 - q);
+    let p = ((b00 * b00 + b11 * b11 + b22 * b22 + T::TWO * off_diagonal) / T::from_f64(6.0)).sqrt();
+    if p == T::ZERO {
+        return q.sqrt();
+    }
+
+    let det = b00 * (b11 * b22 - a12 * a12) - a01 * (a01 * b22 - a12 * a02)
+        + a02 * (a01 * a12 - b11 * a02);
+    let r = det / (T::TWO * p * p * p);
+    let phi = r.clamp(-T::ONE, T::ONE).acos() / three;
+    (q + T::TWO * p * phi.cos()).sqrt()
+}
+
+/// Returns the largest factor by which `m` stretches any vector.
+fn max_scale2<T: Real>(m: &Mat2<T>) -> T {
+    let [c0, c1] = m.cols;
+    let (a00, a11, a01) = (c0.dot(&c0), c1.dot(&c1), c0.dot(&c1));
+    let half_trace = (a00 + a11) * T::HALF;
+    let half_gap = (a00 - a11) * T::HALF;
+    (half_trace + (half_gap * half_gap + a01 * a01).sqrt()).sqrt()
+}
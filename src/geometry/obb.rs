@@ -0,0 +1,410 @@
+use crate::matrices::mat2::Mat2;
+use crate::matrices::mat3::Mat3;
+use crate::quaternion::Quaternion;
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+/// A solid box in 3D space with an arbitrary orientation.
+///
+/// The columns of `axes` are the unit directions of the local `x`, `y` and
+/// `z` axes of the box and must be orthonormal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb<T> {
+    /// The centre of the box.
+    pub center: Vector3<T>,
+    /// Half of the size of the box along each of its local axes.
+    pub half_extents: Vector3<T>,
+    /// The local axes of the box.
+    pub axes: Mat3<T>,
+}
+
+impl<T> Obb<T> {
+    /// Creates a box from its centre, half extents and local axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     Mat3::identity(),
+    /// );
+    /// assert_eq!(obb.volume(), 48.0);
+    /// ```
+    pub fn new(center: Vector3<T>, half_extents: Vector3<T>, axes: Mat3<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            axes,
+        }
+    }
+}
+
+impl<T: Real> Obb<T> {
+    /// Creates a box whose local axes are rotated by a unit quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::from_rotation(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.5, 0.5),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_2),
+    /// );
+    /// assert!(obb.contains_point(Vector3::new(0.0, 1.9, 0.0)));
+    /// assert!(!obb.contains_point(Vector3::new(1.9, 0.0, 0.0)));
+    /// ```
+    pub fn from_rotation(
+        center: Vector3<T>,
+        half_extents: Vector3<T>,
+        rotation: Quaternion<T>,
+    ) -> Self {
+        Self::new(center, half_extents, rotation.to_mat3())
+    }
+
+    /// Expresses `point` in the local frame of the box, relative to its
+    /// centre.
+    fn local_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let d = point - self.center;
+        let [x, y, z] = self.axes.cols;
+        Vector3::new(d.dot(&x), d.dot(&y), d.dot(&z))
+    }
+
+    /// Returns `true` if `point` lies inside or on the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+    /// assert!(obb.contains_point(Vector3::new(2.0, 1.0, -1.0)));
+    /// assert!(!obb.contains_point(Vector3::new(-0.5, 0.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let local = self.local_point(point);
+        let h = self.half_extents;
+        local.x.abs() <= h.x && local.y.abs() <= h.y && local.z.abs() <= h.z
+    }
+
+    /// Returns the point of the box closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+    /// assert_eq!(
+    ///     obb.closest_point(Vector3::new(3.0, 0.5, -2.0)),
+    ///     Vector3::new(1.0, 0.5, -1.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let local = self.local_point(point);
+        let h = self.half_extents;
+        let [x, y, z] = self.axes.cols;
+        self.center
+            + x * local.x.clamp(-h.x, h.x)
+            + y * local.y.clamp(-h.y, h.y)
+            + z * local.z.clamp(-h.z, h.z)
+    }
+
+    /// Returns the distance from the box to `point`, or zero if the point is
+    /// inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+    /// assert_eq!(obb.distance_to_point(Vector3::new(4.0, 5.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns the total area of the six faces of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.5, 1.0, 1.5), Mat3::identity());
+    /// assert_eq!(obb.surface_area(), 22.0);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        let h = self.half_extents;
+        T::from_f64(8.0) * (h.x * h.y + h.y * h.z + h.z * h.x)
+    }
+
+    /// Returns the volume enclosed by the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.5, 1.0, 1.5), Mat3::identity());
+    /// assert_eq!(obb.volume(), 6.0);
+    /// ```
+    pub fn volume(&self) -> T {
+        let h = self.half_extents;
+        T::from_f64(8.0) * h.x * h.y * h.z
+    }
+
+    /// Applies `transform` to the box.
+    ///
+    /// Rotation, translation and scale along the local axes of the box are
+    /// represented exactly. Shear relative to those axes cannot be, and the
+    /// resulting axes are then no longer orthogonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+    /// let scaled = obb.transform(&Affine3::from_scale(Vector3::new(2.0, 1.0, 3.0)));
+    /// assert_eq!(scaled.center, Vector3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(scaled.half_extents, Vector3::new(2.0, 1.0, 3.0));
+    /// assert_eq!(scaled.axes, Mat3::identity());
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        let [x, y, z] = self.axes.cols;
+        let (x, sx) = normalize_scaled(transform.transform_vector(x));
+        let (y, sy) = normalize_scaled(transform.transform_vector(y));
+        let (z, sz) = normalize_scaled(transform.transform_vector(z));
+        let h = self.half_extents;
+        Self::new(
+            transform.transform_point(self.center),
+            Vector3::new(h.x * sx, h.y * sy, h.z * sz),
+            Mat3::from_cols(x, y, z),
+        )
+    }
+}
+
+/// Splits `v` into its direction and length.
+fn normalize_scaled<T: Real>(v: Vector3<T>) -> (Vector3<T>, T) {
+    let length = v.length();
+    (
+        Vector3::new(v.x / length, v.y / length, v.z / length),
+        length,
+    )
+}
+
+/// A solid rectangle in 2D space with an arbitrary orientation.
+///
+/// The columns of `axes` are the unit directions of the local `x` and `y`
+/// axes of the rectangle and must be orthonormal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb2<T> {
+    /// The centre of the rectangle.
+    pub center: Vector2<T>,
+    /// Half of the size of the rectangle along each of its local axes.
+    pub half_extents: Vector2<T>,
+    /// The local axes of the rectangle.
+    pub axes: Mat2<T>,
+}
+
+impl<T> Obb2<T> {
+    /// Creates a rectangle from its centre, half extents and local axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Mat2::identity());
+    /// assert_eq!(obb.area(), 8.0);
+    /// ```
+    pub fn new(center: Vector2<T>, half_extents: Vector2<T>, axes: Mat2<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            axes,
+        }
+    }
+}
+
+impl<T: Real> Obb2<T> {
+    /// Creates a rectangle rotated counter-clockwise by `angle` radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::from_angle(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(2.0, 0.5),
+    ///     std::f64::consts::FRAC_PI_2,
+    /// );
+    /// assert!(obb.contains_point(Vector2::new(0.0, 1.9)));
+    /// assert!(!obb.contains_point(Vector2::new(1.9, 0.0)));
+    /// ```
+    pub fn from_angle(center: Vector2<T>, half_extents: Vector2<T>, angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new(
+            center,
+            half_extents,
+            Mat2::from_cols(Vector2::new(c, s), Vector2::new(-s, c)),
+        )
+    }
+
+    /// Expresses `point` in the local frame of the rectangle, relative to its
+    /// centre.
+    fn local_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let d = point - self.center;
+        let [x, y] = self.axes.cols;
+        Vector2::new(d.dot(&x), d.dot(&y))
+    }
+
+    /// Returns `true` if `point` lies inside or on the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0), Mat2::identity());
+    /// assert!(obb.contains_point(Vector2::new(2.0, 1.0)));
+    /// assert!(!obb.contains_point(Vector2::new(-0.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        let local = self.local_point(point);
+        local.x.abs() <= self.half_extents.x && local.y.abs() <= self.half_extents.y
+    }
+
+    /// Returns the point of the rectangle closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Mat2::identity());
+    /// assert_eq!(obb.closest_point(Vector2::new(3.0, 0.5)), Vector2::new(1.0, 0.5));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let local = self.local_point(point);
+        let h = self.half_extents;
+        let [x, y] = self.axes.cols;
+        self.center + x * local.x.clamp(-h.x, h.x) + y * local.y.clamp(-h.y, h.y)
+    }
+
+    /// Returns the distance from the rectangle to `point`, or zero if the
+    /// point is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Mat2::identity());
+    /// assert_eq!(obb.distance_to_point(Vector2::new(4.0, 5.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns the area enclosed by the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.5, 1.0), Mat2::identity());
+    /// assert_eq!(obb.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> T {
+        T::from_f64(4.0) * self.half_extents.x * self.half_extents.y
+    }
+
+    /// Returns the length of the outline of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(0.0, 0.0), Vector2::new(1.5, 1.0), Mat2::identity());
+    /// assert_eq!(obb.perimeter(), 10.0);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        T::from_f64(4.0) * (self.half_extents.x + self.half_extents.y)
+    }
+
+    /// Applies `transform` to the rectangle.
+    ///
+    /// Shear relative to the local axes cannot be represented, and the
+    /// resulting axes are then no longer orthogonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb2;
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let obb = Obb2::new(Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0), Mat2::identity());
+    /// let scaled = obb.transform(&Affine2::from_scale(Vector2::new(2.0, 3.0)));
+    /// assert_eq!(scaled.center, Vector2::new(2.0, 0.0));
+    /// assert_eq!(scaled.half_extents, Vector2::new(2.0, 3.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        let [x, y] = self.axes.cols;
+        let x = transform.transform_vector(x);
+        let y = transform.transform_vector(y);
+        let (sx, sy) = (x.length(), y.length());
+        Self::new(
+            transform.transform_point(self.center),
+            Vector2::new(self.half_extents.x * sx, self.half_extents.y * sy),
+            Mat2::from_cols(
+                Vector2::new(x.x / sx, x.y / sx),
+                Vector2::new(y.x / sy, y.y / sy),
+            ),
+        )
+    }
+}
@@ -0,0 +1,699 @@
+//! Overlap tests between pairs of 3D primitives.
+//!
+//! Each test returns a [`Contact`] when the shapes touch or overlap and
+//! `None` when they are apart. The contact normal points from the shape the
+//! method is called on towards the shape passed in, so moving the second
+//! shape by `normal * depth` separates the two.
+//!
+//! Planes can also classify a shape as lying in front of, behind or across
+//! them. For contacts a plane is treated as the boundary of the solid
+//! half-space behind it.
+
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+
+use super::aabb::Aabb;
+use super::capsule::Capsule;
+use super::obb::Obb;
+use super::plane::Plane;
+use super::sphere::Sphere;
+use super::support::SupportMap;
+use super::triangle::Triangle;
+
+/// How two overlapping shapes touch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact<T> {
+    /// The unit direction in which the second shape must move to separate
+    /// from the first.
+    pub normal: Vector3<T>,
+    /// How far the second shape must move along `normal` to separate.
+    pub depth: T,
+    /// A point midway through the overlap.
+    pub point: Vector3<T>,
+}
+
+/// Where a shape lies relative to a plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaneSide {
+    /// Entirely on the side the plane's normal points to.
+    Front,
+    /// Entirely on the side opposite the plane's normal.
+    Back,
+    /// Touching or crossing the plane.
+    Intersecting,
+}
+
+impl<T: Real> Sphere<T> {
+    /// Tests the sphere against another sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// let b = Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0);
+    /// let contact = a.intersect_sphere(&b).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert_eq!(contact.point, Vector3::new(0.75, 0.0, 0.0));
+    ///
+    /// let far = Sphere::new(Vector3::new(3.0, 0.0, 0.0), 1.0);
+    /// assert!(a.intersect_sphere(&far).is_none());
+    /// ```
+    pub fn intersect_sphere(&self, other: &Sphere<T>) -> Option<Contact<T>> {
+        spheres_contact(
+            self.center,
+            self.radius,
+            other.center,
+            other.radius,
+            Vector3::new(T::ZERO, T::ONE, T::ZERO),
+        )
+    }
+
+    /// Tests the sphere against an axis-aligned box.
+    ///
+    /// When the centre of the sphere is inside the box the normal points
+    /// away from the nearest face.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// let sphere = Sphere::new(Vector3::new(-0.5, 1.0, 1.0), 1.0);
+    /// let contact = sphere.intersect_aabb(&aabb).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(contact.depth, 0.5);
+    ///
+    /// let apart = Sphere::new(Vector3::new(-2.0, 1.0, 1.0), 1.0);
+    /// assert!(apart.intersect_aabb(&aabb).is_none());
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<Contact<T>> {
+        let closest = aabb.closest_point(self.center);
+        if closest != self.center {
+            return sphere_point_contact(self, closest);
+        }
+
+        // The centre is inside the box: push the box out through the face
+        // nearest to the centre.
+        let c = [self.center.x, self.center.y, self.center.z];
+        let min = [aabb.min.x, aabb.min.y, aabb.min.z];
+        let max = [aabb.max.x, aabb.max.y, aabb.max.z];
+        let mut nearest = (T::INFINITY, Vector3::new(T::ZERO, T::ZERO, T::ZERO));
+        for (i, axis) in unit_axes().into_iter().enumerate() {
+            for (gap, normal) in [(c[i] - min[i], axis), (max[i] - c[i], -axis)] {
+                if gap < nearest.0 {
+                    nearest = (gap, normal);
+                }
+            }
+        }
+        let (gap, normal) = nearest;
+        Some(sphere_contact(self, normal, self.radius + gap))
+    }
+
+    /// Tests the sphere against a triangle.
+    ///
+    /// When the centre of the sphere lies on the triangle the normal points
+    /// against the triangle's normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(-1.0, 0.0, -1.0),
+    ///     Vector3::new(1.0, 0.0, -1.0),
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    /// );
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.75, 0.0), 1.0);
+    /// let contact = sphere.intersect_triangle(&triangle).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(0.0, -1.0, 0.0));
+    /// assert_eq!(contact.depth, 0.25);
+    /// ```
+    pub fn intersect_triangle(&self, triangle: &Triangle<T>) -> Option<Contact<T>> {
+        let closest = triangle.closest_point(self.center);
+        if (closest - self.center).length_squared() > T::EPSILON * T::EPSILON {
+            return sphere_point_contact(self, closest);
+        }
+        let normal = -triangle.normal();
+        Some(sphere_contact(self, normal, self.radius))
+    }
+}
+
+impl<T: Real> Aabb<T> {
+    /// Tests the box against another axis-aligned box.
+    ///
+    /// The normal is the axis along which the second box has the shortest
+    /// way out of the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// let b = Aabb::new(Vector3::new(1.5, 0.5, 0.5), Vector3::new(3.0, 1.5, 1.5));
+    /// let contact = a.intersect_aabb(&b).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert_eq!(contact.point, Vector3::new(1.75, 1.0, 1.0));
+    ///
+    /// let c = Aabb::new(Vector3::new(3.0, 0.0, 0.0), Vector3::new(4.0, 1.0, 1.0));
+    /// assert!(a.intersect_aabb(&c).is_none());
+    /// ```
+    pub fn intersect_aabb(&self, other: &Aabb<T>) -> Option<Contact<T>> {
+        let low = Vector3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let high = Vector3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        let overlap = high - low;
+        if overlap.x < T::ZERO || overlap.y < T::ZERO || overlap.z < T::ZERO {
+            return None;
+        }
+
+        // Along each axis the second box can leave through either side of
+        // the first; keep the shortest way out.
+        let exits = [
+            (self.max.x - other.min.x, other.max.x - self.min.x),
+            (self.max.y - other.min.y, other.max.y - self.min.y),
+            (self.max.z - other.min.z, other.max.z - self.min.z),
+        ];
+        let mut best = (T::INFINITY, Vector3::new(T::ZERO, T::ZERO, T::ZERO));
+        for ((forward, backward), axis) in exits.into_iter().zip(unit_axes()) {
+            let (depth, normal) = if forward <= backward {
+                (forward, axis)
+            } else {
+                (backward, -axis)
+            };
+            if depth < best.0 {
+                best = (depth, normal);
+            }
+        }
+        let (depth, normal) = best;
+        Some(Contact {
+            normal,
+            depth,
+            point: low.lerp(&high, T::HALF),
+        })
+    }
+}
+
+impl<T: Real> Obb<T> {
+    /// Tests the box against another oriented box using the separating axis
+    /// theorem.
+    ///
+    /// The normal is the candidate axis along which the boxes overlap the
+    /// least: a face normal of either box or the cross product of an edge
+    /// from each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let half = Vector3::new(1.0, 1.0, 1.0);
+    /// let a = Obb::from_rotation(Vector3::new(0.0, 0.0, 0.0), half, Quaternion::identity());
+    /// let turned = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    ///
+    /// let b = Obb::from_rotation(Vector3::new(2.2, 0.0, 0.0), half, turned);
+    /// let contact = a.intersect_obb(&b).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_approx_eq!(contact.depth, 2.0_f64.sqrt() - 1.2, epsilon = 1e-12);
+    ///
+    /// let c = Obb::from_rotation(Vector3::new(2.5, 0.0, 0.0), half, turned);
+    /// assert!(a.intersect_obb(&c).is_none());
+    /// ```
+    pub fn intersect_obb(&self, other: &Obb<T>) -> Option<Contact<T>> {
+        let a_axes = self.axes.cols;
+        let b_axes = other.axes.cols;
+        let a = |axis| box_interval(self.center, a_axes, self.half_extents, axis);
+        let b = |axis| box_interval(other.center, b_axes, other.half_extents, axis);
+
+        let mut search = AxisSearch::new();
+        for axis in a_axes.into_iter().chain(b_axes) {
+            if !search.test(axis, a, b) {
+                return None;
+            }
+        }
+        for edge_a in a_axes {
+            for edge_b in b_axes {
+                if !search.test_cross(edge_a, edge_b, a, b) {
+                    return None;
+                }
+            }
+        }
+
+        let normal = search.normal;
+        let on_a = self.closest_point(other.support(-normal));
+        let on_b = other.closest_point(self.support(normal));
+        Some(search.contact(on_a.lerp(&on_b, T::HALF)))
+    }
+}
+
+impl<T: Real> Triangle<T> {
+    /// Tests the triangle against an axis-aligned box using the separating
+    /// axis theorem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(-2.0, -0.75, -2.0),
+    ///     Vector3::new(2.0, -0.75, -2.0),
+    ///     Vector3::new(0.0, -0.75, 2.0),
+    /// );
+    /// let contact = triangle.intersect_aabb(&aabb).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(contact.depth, 0.25);
+    ///
+    /// let below = Triangle::new(
+    ///     Vector3::new(-2.0, -1.5, -2.0),
+    ///     Vector3::new(2.0, -1.5, -2.0),
+    ///     Vector3::new(0.0, -1.5, 2.0),
+    /// );
+    /// assert!(below.intersect_aabb(&aabb).is_none());
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<Contact<T>> {
+        let corners = [self.a, self.b, self.c];
+        let t = |axis| points_interval(&corners, axis);
+        let b = |axis| box_interval(aabb.center(), unit_axes(), aabb.half_extents(), axis);
+
+        let mut search = AxisSearch::new();
+        for axis in unit_axes() {
+            if !search.test(axis, t, b) {
+                return None;
+            }
+        }
+        if !search.test_cross(self.b - self.a, self.c - self.a, t, b) {
+            return None;
+        }
+        for edge in [self.b - self.a, self.c - self.b, self.a - self.c] {
+            for axis in unit_axes() {
+                if !search.test_cross(edge, axis, t, b) {
+                    return None;
+                }
+            }
+        }
+
+        let normal = search.normal;
+        let on_triangle = self.closest_point(aabb.support(-normal));
+        let on_box = aabb.closest_point(self.support(normal));
+        Some(search.contact(on_triangle.lerp(&on_box, T::HALF)))
+    }
+}
+
+impl<T: Real> Capsule<T> {
+    /// Tests the capsule against another capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0), 0.5);
+    /// let b = Capsule::new(Vector3::new(0.75, 2.0, -3.0), Vector3::new(0.75, 2.0, 3.0), 0.5);
+    /// let contact = a.intersect_capsule(&b).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(contact.depth, 0.25);
+    ///
+    /// let c = Capsule::new(Vector3::new(2.0, 2.0, -3.0), Vector3::new(2.0, 2.0, 3.0), 0.5);
+    /// assert!(a.intersect_capsule(&c).is_none());
+    /// ```
+    pub fn intersect_capsule(&self, other: &Capsule<T>) -> Option<Contact<T>> {
+        let (p, q) = self.segment().closest_points(&other.segment());
+        // Crossing axes leave no gap to take a direction from, so fall back
+        // to the direction perpendicular to both.
+        let fallback = (self.end - self.start)
+            .cross(&(other.end - other.start))
+            .normalize();
+        let fallback = if fallback.length_squared() > T::ZERO {
+            fallback
+        } else {
+            Vector3::new(T::ZERO, T::ONE, T::ZERO)
+        };
+        spheres_contact(p, self.radius, q, other.radius, fallback)
+    }
+}
+
+impl<T: Real> Plane<T> {
+    /// Returns the lowest and highest points of a shape along the normal,
+    /// classified against the plane.
+    fn classify(&self, low: Vector3<T>, high: Vector3<T>) -> PlaneSide {
+        if self.signed_distance(low) > T::ZERO {
+            PlaneSide::Front
+        } else if self.signed_distance(high) < T::ZERO {
+            PlaneSide::Back
+        } else {
+            PlaneSide::Intersecting
+        }
+    }
+
+    /// Builds the contact with the half-space behind the plane from the
+    /// point of a shape furthest behind it.
+    fn half_space_contact(&self, deepest: Vector3<T>) -> Option<Contact<T>> {
+        let distance = self.signed_distance(deepest);
+        if distance > T::ZERO {
+            return None;
+        }
+        let depth = -distance;
+        Some(Contact {
+            normal: self.normal,
+            depth,
+            point: deepest + self.normal * (depth * T::HALF),
+        })
+    }
+
+    /// Returns which side of the plane the sphere lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::overlap::PlaneSide;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let above = Sphere::new(Vector3::new(0.0, 2.0, 0.0), 1.0);
+    /// let across = Sphere::new(Vector3::new(0.0, 0.5, 0.0), 1.0);
+    /// assert_eq!(ground.classify_sphere(&above), PlaneSide::Front);
+    /// assert_eq!(ground.classify_sphere(&across), PlaneSide::Intersecting);
+    /// ```
+    pub fn classify_sphere(&self, sphere: &Sphere<T>) -> PlaneSide {
+        self.classify(sphere.support(-self.normal), sphere.support(self.normal))
+    }
+
+    /// Returns which side of the plane the box lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::overlap::PlaneSide;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let below = Aabb::new(Vector3::new(0.0, -3.0, 0.0), Vector3::new(1.0, -1.0, 1.0));
+    /// assert_eq!(ground.classify_aabb(&below), PlaneSide::Back);
+    /// ```
+    pub fn classify_aabb(&self, aabb: &Aabb<T>) -> PlaneSide {
+        self.classify(aabb.support(-self.normal), aabb.support(self.normal))
+    }
+
+    /// Returns which side of the plane the oriented box lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::geometry::overlap::PlaneSide;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let turned = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    /// let obb = Obb::from_rotation(Vector3::new(0.0, 1.2, 0.0), Vector3::new(1.0, 1.0, 1.0), turned);
+    /// assert_eq!(ground.classify_obb(&obb), PlaneSide::Intersecting);
+    /// ```
+    pub fn classify_obb(&self, obb: &Obb<T>) -> PlaneSide {
+        self.classify(obb.support(-self.normal), obb.support(self.normal))
+    }
+
+    /// Returns which side of the plane the capsule lies on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::geometry::overlap::PlaneSide;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let capsule = Capsule::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(3.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(ground.classify_capsule(&capsule), PlaneSide::Front);
+    /// ```
+    pub fn classify_capsule(&self, capsule: &Capsule<T>) -> PlaneSide {
+        self.classify(capsule.support(-self.normal), capsule.support(self.normal))
+    }
+
+    /// Tests the half-space behind the plane against a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.5, 0.0), 1.0);
+    /// let contact = ground.intersect_sphere(&sphere).unwrap();
+    /// assert_eq!(contact.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert_eq!(contact.point, Vector3::new(0.0, -0.25, 0.0));
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<Contact<T>> {
+        self.half_space_contact(sphere.support(-self.normal))
+    }
+
+    /// Tests the half-space behind the plane against an axis-aligned box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let aabb = Aabb::new(Vector3::new(0.0, -0.5, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(ground.intersect_aabb(&aabb).unwrap().depth, 0.5);
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<Contact<T>> {
+        self.half_space_contact(aabb.support(-self.normal))
+    }
+
+    /// Tests the half-space behind the plane against an oriented box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let obb = Obb::new(Vector3::new(0.0, 0.75, 0.0), Vector3::new(1.0, 1.0, 1.0), Mat3::identity());
+    /// assert_eq!(ground.intersect_obb(&obb).unwrap().depth, 0.25);
+    /// ```
+    pub fn intersect_obb(&self, obb: &Obb<T>) -> Option<Contact<T>> {
+        self.half_space_contact(obb.support(-self.normal))
+    }
+
+    /// Tests the half-space behind the plane against a capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.25, 0.0), Vector3::new(0.0, 3.0, 0.0), 0.5);
+    /// assert_eq!(ground.intersect_capsule(&capsule).unwrap().depth, 0.25);
+    ///
+    /// let lifted = Capsule::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 3.0, 0.0), 0.5);
+    /// assert!(ground.intersect_capsule(&lifted).is_none());
+    /// ```
+    pub fn intersect_capsule(&self, capsule: &Capsule<T>) -> Option<Contact<T>> {
+        self.half_space_contact(capsule.support(-self.normal))
+    }
+}
+
+/// Returns the x, y and z unit vectors.
+fn unit_axes<T: Real>() -> [Vector3<T>; 3] {
+    [
+        Vector3::new(T::ONE, T::ZERO, T::ZERO),
+        Vector3::new(T::ZERO, T::ONE, T::ZERO),
+        Vector3::new(T::ZERO, T::ZERO, T::ONE),
+    ]
+}
+
+/// Tests two spheres, or the spheres swept along two axes at their closest
+/// points, using `fallback` as the normal when the centres coincide.
+fn spheres_contact<T: Real>(
+    a: Vector3<T>,
+    a_radius: T,
+    b: Vector3<T>,
+    b_radius: T,
+    fallback: Vector3<T>,
+) -> Option<Contact<T>> {
+    let offset = b - a;
+    let reach = a_radius + b_radius;
+    let distance_squared = offset.length_squared();
+    if distance_squared > reach * reach {
+        return None;
+    }
+    let distance = distance_squared.sqrt();
+    let normal = if distance > T::EPSILON {
+        offset * distance.recip()
+    } else {
+        fallback
+    };
+    let depth = reach - distance;
+    Some(Contact {
+        normal,
+        depth,
+        point: a + normal * (a_radius - depth * T::HALF),
+    })
+}
+
+/// Tests a sphere whose centre lies outside a shape against the shape's
+/// point `closest` to that centre.
+fn sphere_point_contact<T: Real>(sphere: &Sphere<T>, closest: Vector3<T>) -> Option<Contact<T>> {
+    let offset = closest - sphere.center;
+    let distance_squared = offset.length_squared();
+    if distance_squared > sphere.radius * sphere.radius {
+        return None;
+    }
+    let distance = distance_squared.sqrt();
+    Some(sphere_contact(
+        sphere,
+        offset * distance.recip(),
+        sphere.radius - distance,
+    ))
+}
+
+/// Builds a contact for a sphere pushing along `normal` by `depth`.
+fn sphere_contact<T: Real>(sphere: &Sphere<T>, normal: Vector3<T>, depth: T) -> Contact<T> {
+    Contact {
+        normal,
+        depth,
+        point: sphere.center + normal * (sphere.radius - depth * T::HALF),
+    }
+}
+
+/// Returns the range covered by a box when projected onto `axis`.
+fn box_interval<T: Real>(
+    center: Vector3<T>,
+    axes: [Vector3<T>; 3],
+    half_extents: Vector3<T>,
+    axis: Vector3<T>,
+) -> (T, T) {
+    let c = center.dot(&axis);
+    let r = half_extents.x * axes[0].dot(&axis).abs()
+        + half_extents.y * axes[1].dot(&axis).abs()
+        + half_extents.z * axes[2].dot(&axis).abs();
+    (c - r, c + r)
+}
+
+/// Returns the range covered by `points` when projected onto `axis`.
+fn points_interval<T: Real>(points: &[Vector3<T>], axis: Vector3<T>) -> (T, T) {
+    points
+        .iter()
+        .fold((T::INFINITY, T::NEG_INFINITY), |(low, high), p| {
+            let d = p.dot(&axis);
+            (low.min(d), high.max(d))
+        })
+}
+
+/// Tracks the axis of least overlap during a separating axis test.
+struct AxisSearch<T> {
+    depth: T,
+    normal: Vector3<T>,
+}
+
+impl<T: Real> AxisSearch<T> {
+    fn new() -> Self {
+        Self {
+            depth: T::INFINITY,
+            normal: Vector3::new(T::ZERO, T::ONE, T::ZERO),
+        }
+    }
+
+    /// Projects both shapes onto `axis`, returning `false` if it separates
+    /// them.
+    fn test(
+        &mut self,
+        axis: Vector3<T>,
+        a: impl Fn(Vector3<T>) -> (T, T),
+        b: impl Fn(Vector3<T>) -> (T, T),
+    ) -> bool {
+        let axis = axis * axis.length().recip();
+        let (a_low, a_high) = a(axis);
+        let (b_low, b_high) = b(axis);
+        if a_high < b_low || b_high < a_low {
+            return false;
+        }
+
+        // Push the second shape whichever way along the axis is shorter.
+        let forward = a_high - b_low;
+        let backward = b_high - a_low;
+        let (depth, normal) = if forward <= backward {
+            (forward, axis)
+        } else {
+            (backward, -axis)
+        };
+        if depth < self.depth {
+            self.depth = depth;
+            self.normal = normal;
+        }
+        true
+    }
+
+    /// Tests the cross product of `u` and `v` as an axis, skipping it when
+    /// they are parallel or either is zero.
+    ///
+    /// The threshold is relative to the operands, since the cross product of
+    /// short edges is tiny even when they are perpendicular.
+    fn test_cross(
+        &mut self,
+        u: Vector3<T>,
+        v: Vector3<T>,
+        a: impl Fn(Vector3<T>) -> (T, T),
+        b: impl Fn(Vector3<T>) -> (T, T),
+    ) -> bool {
+        let axis = u.cross(&v);
+        if axis.length_squared() <= T::EPSILON * u.length_squared() * v.length_squared() {
+            return true;
+        }
+        self.test(axis, a, b)
+    }
+
+    fn contact(&self, point: Vector3<T>) -> Contact<T> {
+        Contact {
+            normal: self.normal,
+            depth: self.depth,
+            point,
+        }
+    }
+}
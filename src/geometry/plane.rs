@@ -0,0 +1,184 @@
+use crate::scalar::Real;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector3::Vector3;
+
+use super::tolerance;
+
+/// An infinite plane made of the points `p` satisfying `normal.dot(p) + d == 0`.
+///
+/// Distances are only measured in world units when `normal` has unit length,
+/// which every constructor except [`Plane::new`] guarantees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<T> {
+    /// The direction the front of the plane faces.
+    pub normal: Vector3<T>,
+    /// The negated distance from the origin along `normal`.
+    pub d: T,
+}
+
+impl<T> Plane<T> {
+    /// Creates a plane from the coefficients of its equation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), -2.0);
+    /// assert_eq!(plane.signed_distance(Vector3::new(5.0, 3.0, 1.0)), 1.0);
+    /// ```
+    pub fn new(normal: Vector3<T>, d: T) -> Self {
+        Self { normal, d }
+    }
+}
+
+impl<T: Real> Plane<T> {
+    /// Creates a plane through `point` facing along `normal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 0.0, 4.0), Vector3::new(0.0, 0.0, 2.0));
+    /// assert_eq!(plane.normal, Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(plane.d, -4.0);
+    /// ```
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let normal = normal.normalize();
+        Self::new(normal, -normal.dot(&point))
+    }
+
+    /// Creates the plane through three points.
+    ///
+    /// The front faces the side from which the points appear counter-clockwise.
+    /// The points must not be collinear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_points(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    /// );
+    /// assert_eq!(plane.normal, Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(plane.d, -1.0);
+    /// ```
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(&(c - a)))
+    }
+
+    /// Rescales the plane equation so that `normal` has unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::new(Vector3::new(0.0, 3.0, 4.0), 10.0).normalize();
+    /// assert_eq!(plane.normal, Vector3::new(0.0, 0.6, 0.8));
+    /// assert_eq!(plane.d, 2.0);
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.length();
+        let n = self.normal;
+        Self::new(
+            Vector3::new(n.x / length, n.y / length, n.z / length),
+            self.d / length,
+        )
+    }
+
+    /// Returns the distance from the plane to `point`, positive in front and
+    /// negative behind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(plane.signed_distance(Vector3::new(0.0, 3.0, 0.0)), 2.0);
+    /// assert_eq!(plane.signed_distance(Vector3::new(0.0, -1.0, 0.0)), -2.0);
+    /// ```
+    pub fn signed_distance(&self, point: Vector3<T>) -> T {
+        self.normal.dot(&point) + self.d
+    }
+
+    /// Returns the distance from the plane to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(plane.distance_to_point(Vector3::new(0.0, -1.0, 0.0)), 2.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+
+    /// Returns the point on the plane closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(
+    ///     plane.closest_point(Vector3::new(2.0, 5.0, 3.0)),
+    ///     Vector3::new(2.0, 1.0, 3.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Returns `true` if `point` lies on the plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert!(plane.contains_point(Vector3::new(7.0, 1.0, -3.0)));
+    /// assert!(!plane.contains_point(Vector3::new(7.0, 1.5, -3.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to the plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let moved = plane.transform(&Affine3::from_translation(Vector3::new(0.0, 2.0, 0.0)));
+    /// assert_eq!(moved.d, -3.0);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        let point = self.normal * (-self.d / self.normal.length_squared());
+        Self::from_point_normal(
+            transform.transform_point(point),
+            transform.transform_normal(self.normal),
+        )
+    }
+}
@@ -0,0 +1,251 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::tolerance;
+
+/// A half-line in 3D space starting at `origin` and extending along
+/// `direction`.
+///
+/// The direction has unit length, so the parameter `t` of a point on the ray
+/// is its distance from the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<T> {
+    /// The point the ray starts at.
+    pub origin: Vector3<T>,
+    /// The unit direction the ray extends in.
+    pub direction: Vector3<T>,
+}
+
+impl<T: Real> Ray<T> {
+    /// Creates a ray, normalizing `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 5.0));
+    /// assert_eq!(ray.direction, Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(ray.point_at(3.0), Vector3::new(1.0, 3.0, 0.0));
+    /// ```
+    pub fn point_at(&self, t: T) -> Vector3<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the point on the ray closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     ray.closest_point(Vector3::new(4.0, 2.0, 0.0)),
+    ///     Vector3::new(4.0, 0.0, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     ray.closest_point(Vector3::new(-4.0, 2.0, 0.0)),
+    ///     Vector3::new(0.0, 0.0, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let t = (point - self.origin).dot(&self.direction);
+        self.point_at(t.max(T::ZERO))
+    }
+
+    /// Returns the distance from the ray to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(ray.distance_to_point(Vector3::new(4.0, 2.0, 0.0)), 2.0);
+    /// assert_eq!(ray.distance_to_point(Vector3::new(-3.0, 4.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+    /// assert!(ray.contains_point(Vector3::new(2.0, 2.0, 0.0)));
+    /// assert!(!ray.contains_point(Vector3::new(-2.0, -2.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to the ray.
+    ///
+    /// The direction is renormalized, so distances along the transformed ray
+    /// are measured in the transformed space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ray = Ray::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let scaled = ray.transform(&Affine3::from_scale(Vector3::new(2.0, 2.0, 2.0)));
+    /// assert_eq!(scaled.origin, Vector3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(scaled.direction, Vector3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
+
+/// A half-line in 2D space starting at `origin` and extending along
+/// `direction`.
+///
+/// The direction has unit length, so the parameter `t` of a point on the ray
+/// is its distance from the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray2<T> {
+    /// The point the ray starts at.
+    pub origin: Vector2<T>,
+    /// The unit direction the ray extends in.
+    pub direction: Vector2<T>,
+}
+
+impl<T: Real> Ray2<T> {
+    /// Creates a ray, normalizing `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 5.0));
+    /// assert_eq!(ray.direction, Vector2::new(0.0, 1.0));
+    /// ```
+    pub fn new(origin: Vector2<T>, direction: Vector2<T>) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+    /// assert_eq!(ray.point_at(3.0), Vector2::new(1.0, 3.0));
+    /// ```
+    pub fn point_at(&self, t: T) -> Vector2<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the point on the ray closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(ray.closest_point(Vector2::new(4.0, 2.0)), Vector2::new(4.0, 0.0));
+    /// assert_eq!(ray.closest_point(Vector2::new(-4.0, 2.0)), Vector2::new(0.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let t = (point - self.origin).dot(&self.direction);
+        self.point_at(t.max(T::ZERO))
+    }
+
+    /// Returns the distance from the ray to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert_eq!(ray.distance_to_point(Vector2::new(-3.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// assert!(ray.contains_point(Vector2::new(2.0, 0.0)));
+    /// assert!(!ray.contains_point(Vector2::new(-2.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to the ray, renormalizing its direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let ray = Ray2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// let moved = ray.transform(&Affine2::from_translation(Vector2::new(0.0, 3.0)));
+    /// assert_eq!(moved.origin, Vector2::new(0.0, 3.0));
+    /// assert_eq!(moved.direction, Vector2::new(1.0, 0.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
@@ -0,0 +1,539 @@
+//! Intersections of rays with the 3D primitives.
+//!
+//! Every test returns the first point where the ray enters the shape. Solid
+//! shapes are treated as filled: a ray starting inside one hits it at
+//! distance zero, at its own origin, with a normal opposite to its direction.
+
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+
+use super::aabb::Aabb;
+use super::capsule::Capsule;
+use super::cone::Cone;
+use super::cylinder::Cylinder;
+use super::obb::Obb;
+use super::plane::Plane;
+use super::ray::Ray;
+use super::sphere::Sphere;
+use super::triangle::Triangle;
+
+/// Where a ray meets the surface of a shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T> {
+    /// The distance along the ray to the hit.
+    pub distance: T,
+    /// The point that was hit.
+    pub point: Vector3<T>,
+    /// The unit surface normal at the hit, facing back towards the ray.
+    pub normal: Vector3<T>,
+}
+
+/// Where a ray meets a triangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriangleHit<T> {
+    /// The distance along the ray to the hit.
+    pub distance: T,
+    /// The point that was hit.
+    pub point: Vector3<T>,
+    /// The unit normal of the triangle, facing back towards the ray.
+    pub normal: Vector3<T>,
+    /// The barycentric coordinates of the hit with respect to the corners
+    /// `a`, `b` and `c`.
+    pub barycentric: Vector3<T>,
+}
+
+impl<T: Real> Ray<T> {
+    /// Builds the hit reported when the ray starts inside a solid.
+    fn inside_hit(&self) -> RayHit<T> {
+        RayHit {
+            distance: T::ZERO,
+            point: self.origin,
+            normal: -self.direction,
+        }
+    }
+
+    /// Builds a hit at distance `t` with an outward `normal`.
+    fn hit_at(&self, t: T, normal: Vector3<T>) -> RayHit<T> {
+        RayHit {
+            distance: t,
+            point: self.point_at(t),
+            normal,
+        }
+    }
+
+    /// Intersects the ray with a plane, hitting either side.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or points away
+    /// from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::plane::Plane;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let ray = Ray::new(Vector3::new(1.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// let hit = ray.intersect_plane(&ground).unwrap();
+    /// assert_eq!(hit.distance, 5.0);
+    /// assert_eq!(hit.point, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+    ///
+    /// let parallel = Ray::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert!(parallel.intersect_plane(&ground).is_none());
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<RayHit<T>> {
+        let denom = plane.normal.dot(&self.direction);
+        if denom.abs() < T::EPSILON {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < T::ZERO {
+            return None;
+        }
+        let normal = if denom < T::ZERO {
+            plane.normal
+        } else {
+            -plane.normal
+        };
+        Some(self.hit_at(t, normal))
+    }
+
+    /// Intersects the ray with a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// let ray = Ray::new(Vector3::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let hit = ray.intersect_sphere(&sphere).unwrap();
+    /// assert_eq!(hit.distance, 4.0);
+    /// assert_eq!(hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+    ///
+    /// let miss = Ray::new(Vector3::new(-5.0, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert!(miss.intersect_sphere(&sphere).is_none());
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<RayHit<T>> {
+        let t = sphere_entry(self, sphere.center, sphere.radius)?;
+        if t == T::ZERO && sphere.contains_point(self.origin) {
+            return Some(self.inside_hit());
+        }
+        let point = self.point_at(t);
+        let normal = (point - sphere.center).normalize();
+        Some(RayHit {
+            distance: t,
+            point,
+            normal,
+        })
+    }
+
+    /// Intersects the ray with an axis-aligned box using the slab method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let ray = Ray::new(Vector3::new(0.5, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// let hit = ray.intersect_aabb(&aabb).unwrap();
+    /// assert_eq!(hit.distance, 4.0);
+    /// assert_eq!(hit.point, Vector3::new(0.5, 1.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<RayHit<T>> {
+        let o = self.origin;
+        let d = self.direction;
+        let (t, face) = slab(
+            [o.x, o.y, o.z],
+            [d.x, d.y, d.z],
+            [aabb.min.x, aabb.min.y, aabb.min.z],
+            [aabb.max.x, aabb.max.y, aabb.max.z],
+        )?;
+        Some(match face {
+            Some((axis, sign)) => {
+                let mut normal = [T::ZERO; 3];
+                normal[axis] = sign;
+                self.hit_at(t, Vector3::new(normal[0], normal[1], normal[2]))
+            }
+            None => self.inside_hit(),
+        })
+    }
+
+    /// Intersects the ray with an oriented box by running the slab method in
+    /// the local frame of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::obb::Obb;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let obb = Obb::from_rotation(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4),
+    /// );
+    /// let ray = Ray::new(Vector3::new(5.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+    /// let hit = ray.intersect_obb(&obb).unwrap();
+    /// assert_approx_eq!(hit.distance, 5.0 - 2.0_f64.sqrt(), epsilon = 1e-9);
+    /// ```
+    pub fn intersect_obb(&self, obb: &Obb<T>) -> Option<RayHit<T>> {
+        let [x, y, z] = obb.axes.cols;
+        let offset = self.origin - obb.center;
+        let d = self.direction;
+        let h = obb.half_extents;
+        let (t, face) = slab(
+            [offset.dot(&x), offset.dot(&y), offset.dot(&z)],
+            [d.dot(&x), d.dot(&y), d.dot(&z)],
+            [-h.x, -h.y, -h.z],
+            [h.x, h.y, h.z],
+        )?;
+        Some(match face {
+            Some((axis, sign)) => self.hit_at(t, obb.axes.cols[axis] * sign),
+            None => self.inside_hit(),
+        })
+    }
+
+    /// Intersects the ray with a triangle using the Möller–Trumbore
+    /// algorithm, hitting either side.
+    ///
+    /// Returns `None` if the ray is parallel to the plane of the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(4.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 4.0, 0.0),
+    /// );
+    /// let ray = Ray::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 0.0, -1.0));
+    /// let hit = ray.intersect_triangle(&triangle).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(hit.barycentric, Vector3::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn intersect_triangle(&self, triangle: &Triangle<T>) -> Option<TriangleHit<T>> {
+        let e1 = triangle.b - triangle.a;
+        let e2 = triangle.c - triangle.a;
+        let p = self.direction.cross(&e2);
+        let det = e1.dot(&p);
+        if det.abs() <= T::EPSILON * e1.length() * e2.length() {
+            return None;
+        }
+
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) / det;
+        if u < T::ZERO || u > T::ONE {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = self.direction.dot(&q) / det;
+        if v < T::ZERO || u + v > T::ONE {
+            return None;
+        }
+        let t = e2.dot(&q) / det;
+        if t < T::ZERO {
+            return None;
+        }
+
+        let normal = e1.cross(&e2).normalize();
+        let normal = if normal.dot(&self.direction) > T::ZERO {
+            -normal
+        } else {
+            normal
+        };
+        Some(TriangleHit {
+            distance: t,
+            point: self.point_at(t),
+            normal,
+            barycentric: Vector3::new(T::ONE - u - v, u, v),
+        })
+    }
+
+    /// Intersects the ray with a capsule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::capsule::Capsule;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0);
+    /// let side = Ray::new(Vector3::new(5.0, 1.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+    /// assert_eq!(side.intersect_capsule(&capsule).unwrap().distance, 4.0);
+    ///
+    /// let top = Ray::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// let hit = top.intersect_capsule(&capsule).unwrap();
+    /// assert_eq!(hit.point, Vector3::new(0.0, 3.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn intersect_capsule(&self, capsule: &Capsule<T>) -> Option<RayHit<T>> {
+        if capsule.contains_point(self.origin) {
+            return Some(self.inside_hit());
+        }
+
+        let mut best = cylinder_side(self, capsule.start, capsule.end, capsule.radius);
+        for center in [capsule.start, capsule.end] {
+            if let Some(t) = sphere_entry(self, center, capsule.radius) {
+                if best.is_none_or(|(b, _)| t < b) {
+                    best = Some((t, (self.point_at(t) - center).normalize()));
+                }
+            }
+        }
+        best.map(|(t, normal)| self.hit_at(t, normal))
+    }
+
+    /// Intersects the ray with a capped cylinder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cylinder::Cylinder;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0);
+    /// let side = Ray::new(Vector3::new(5.0, 1.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+    /// let hit = side.intersect_cylinder(&cylinder).unwrap();
+    /// assert_eq!(hit.distance, 4.0);
+    /// assert_eq!(hit.normal, Vector3::new(1.0, 0.0, 0.0));
+    ///
+    /// let top = Ray::new(Vector3::new(0.5, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// let hit = top.intersect_cylinder(&cylinder).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn intersect_cylinder(&self, cylinder: &Cylinder<T>) -> Option<RayHit<T>> {
+        if cylinder.contains_point(self.origin) {
+            return Some(self.inside_hit());
+        }
+
+        let axis = (cylinder.end - cylinder.start).normalize();
+        let mut best = cylinder_side(self, cylinder.start, cylinder.end, cylinder.radius);
+        let caps = [(cylinder.start, -axis), (cylinder.end, axis)];
+        for (center, normal) in caps {
+            if let Some(t) = disc_entry(self, center, normal, cylinder.radius) {
+                if best.is_none_or(|(b, _)| t < b) {
+                    best = Some((t, normal));
+                }
+            }
+        }
+        best.map(|(t, normal)| self.hit_at(t, normal))
+    }
+
+    /// Intersects the ray with a capped cone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::cone::Cone;
+    /// use vexel::geometry::ray::Ray;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let cone = Cone::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 0.0), 2.0);
+    /// let down = Ray::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// assert_eq!(down.intersect_cone(&cone).unwrap().distance, 3.0);
+    ///
+    /// let up = Ray::new(Vector3::new(1.0, -5.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let hit = up.intersect_cone(&cone).unwrap();
+    /// assert_eq!(hit.distance, 5.0);
+    /// assert_eq!(hit.normal, Vector3::new(0.0, -1.0, 0.0));
+    /// ```
+    pub fn intersect_cone(&self, cone: &Cone<T>) -> Option<RayHit<T>> {
+        if cone.contains_point(self.origin) {
+            return Some(self.inside_hit());
+        }
+
+        let height = cone.height();
+        let axis = (cone.base - cone.apex).normalize();
+        let cos_squared = height * height / (height * height + cone.radius * cone.radius);
+
+        // Points on the infinite double cone satisfy
+        // (v·axis)² = cos²θ |v|² with v measured from the apex.
+        let co = self.origin - cone.apex;
+        let d = self.direction;
+        let (da, ca) = (d.dot(&axis), co.dot(&axis));
+        let a = da * da - cos_squared;
+        let b = T::TWO * (da * ca - cos_squared * d.dot(&co));
+        let c = ca * ca - cos_squared * co.dot(&co);
+
+        let mut best = None;
+        for t in solve_quadratic(a, b, c).into_iter().flatten() {
+            let along = ca + t * da;
+            if t >= T::ZERO && along >= T::ZERO && along <= height && best.is_none_or(|b| t < b) {
+                best = Some(t);
+            }
+        }
+        let mut best = best.map(|t| {
+            let v = self.point_at(t) - cone.apex;
+            let n = v * cos_squared - axis * v.dot(&axis);
+            let normal = if n.length_squared() == T::ZERO {
+                -axis
+            } else {
+                n.normalize()
+            };
+            (t, normal)
+        });
+
+        if let Some(t) = disc_entry(self, cone.base, axis, cone.radius) {
+            if best.is_none_or(|(b, _)| t < b) {
+                best = Some((t, axis));
+            }
+        }
+        best.map(|(t, normal)| self.hit_at(t, normal))
+    }
+}
+
+/// Returns the distance at which `ray` enters the sphere, zero if it starts
+/// inside, or `None` if it misses.
+fn sphere_entry<T: Real>(ray: &Ray<T>, center: Vector3<T>, radius: T) -> Option<T> {
+    let oc = ray.origin - center;
+    let b = oc.dot(&ray.direction);
+    let c = oc.length_squared() - radius * radius;
+    if c <= T::ZERO {
+        return Some(T::ZERO);
+    }
+    let discriminant = b * b - c;
+    if discriminant < T::ZERO {
+        return None;
+    }
+    let t = -b - discriminant.sqrt();
+    (t >= T::ZERO).then_some(t)
+}
+
+/// Returns the first hit of a ray starting outside the shape with the side
+/// of the cylinder of `radius` around the segment from `start` to `end`,
+/// together with the outward normal there.
+fn cylinder_side<T: Real>(
+    ray: &Ray<T>,
+    start: Vector3<T>,
+    end: Vector3<T>,
+    radius: T,
+) -> Option<(T, Vector3<T>)> {
+    let axis = end - start;
+    let length = axis.length();
+    if length == T::ZERO {
+        return None;
+    }
+    let axis = axis * length.recip();
+
+    // Solve in the plane perpendicular to the axis.
+    let oc = ray.origin - start;
+    let d = ray.direction.reject_from(&axis);
+    let o = oc.reject_from(&axis);
+    let a = d.length_squared();
+    if a == T::ZERO {
+        return None;
+    }
+    let b = d.dot(&o);
+    let c = o.length_squared() - radius * radius;
+    let discriminant = b * b - a * c;
+    if discriminant < T::ZERO {
+        return None;
+    }
+
+    // The nearer root, written so that rays almost parallel to the axis
+    // don't lose it to cancellation.
+    let root = discriminant.sqrt();
+    let t = if b < T::ZERO {
+        c / (root - b)
+    } else {
+        (-b - root) / a
+    };
+    let along = oc.dot(&axis) + t * ray.direction.dot(&axis);
+    if t < T::ZERO || along < T::ZERO || along > length {
+        return None;
+    }
+    let radial = (ray.point_at(t) - start).reject_from(&axis);
+    Some((t, radial.normalize()))
+}
+
+/// Returns the distance at which `ray` crosses the disc of `radius` centred
+/// on `center` with the given unit `normal`.
+fn disc_entry<T: Real>(
+    ray: &Ray<T>,
+    center: Vector3<T>,
+    normal: Vector3<T>,
+    radius: T,
+) -> Option<T> {
+    let denom = normal.dot(&ray.direction);
+    if denom.abs() < T::EPSILON {
+        return None;
+    }
+    let t = (center - ray.origin).dot(&normal) / denom;
+    if t < T::ZERO || (ray.point_at(t) - center).length_squared() > radius * radius {
+        return None;
+    }
+    Some(t)
+}
+
+/// Returns the real roots of `a t² + b t + c = 0`, falling back to the linear
+/// equation when `a` vanishes.
+///
+/// The roots are computed without cancellation, so a tiny `a` gives the
+/// nearly linear root accurately instead of needing a threshold.
+fn solve_quadratic<T: Real>(a: T, b: T, c: T) -> [Option<T>; 2] {
+    if a == T::ZERO && b == T::ZERO {
+        return [None, None];
+    }
+    let discriminant = b * b - T::from_f64(4.0) * a * c;
+    if discriminant < T::ZERO {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    let q = -(b + if b < T::ZERO { -root } else { root }) * T::HALF;
+    [(a != T::ZERO).then(|| q / a), (q != T::ZERO).then(|| c / q)]
+}
+
+/// Runs the slab test of a ray against the box from `min` to `max`.
+///
+/// Returns the entry distance and the axis and sign of the face entered, or
+/// no face if the origin is inside the box.
+fn slab<T: Real>(
+    origin: [T; 3],
+    direction: [T; 3],
+    min: [T; 3],
+    max: [T; 3],
+) -> Option<(T, Option<(usize, T)>)> {
+    let mut t_near = T::ZERO;
+    let mut t_far = T::INFINITY;
+    let mut face = None;
+    for i in 0..3 {
+        if direction[i] == T::ZERO {
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return None;
+            }
+            continue;
+        }
+
+        let t0 = (min[i] - origin[i]) / direction[i];
+        let t1 = (max[i] - origin[i]) / direction[i];
+        let (t_enter, t_exit) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+        if t_enter >= t_near {
+            t_near = t_enter;
+            face = Some((i, -direction[i].signum()));
+        }
+        t_far = t_far.min(t_exit);
+        if t_near > t_far {
+            return None;
+        }
+    }
+    Some((t_near, face))
+}
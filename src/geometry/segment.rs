@@ -0,0 +1,329 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::tolerance;
+
+/// A line segment in 3D space between `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<T> {
+    /// The first endpoint.
+    pub start: Vector3<T>,
+    /// The second endpoint.
+    pub end: Vector3<T>,
+}
+
+impl<T> Segment<T> {
+    /// Creates a segment between two points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 4.0));
+    /// assert_eq!(segment.length(), 5.0);
+    /// ```
+    pub fn new(start: Vector3<T>, end: Vector3<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Real> Segment<T> {
+    /// Returns the length of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, -1.0));
+    /// assert_eq!(segment.length(), 2.0);
+    /// ```
+    pub fn length(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Returns the point halfway between the endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(segment.midpoint(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn midpoint(&self) -> Vector3<T> {
+        self.start.lerp(&self.end, T::HALF)
+    }
+
+    /// Returns the point on the segment closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     segment.closest_point(Vector3::new(1.0, 5.0, 0.0)),
+    ///     Vector3::new(1.0, 0.0, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     segment.closest_point(Vector3::new(9.0, 5.0, 0.0)),
+    ///     Vector3::new(4.0, 0.0, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let ab = self.end - self.start;
+        let length_squared = ab.length_squared();
+        if length_squared == T::ZERO {
+            return self.start;
+        }
+        let t = (point - self.start).dot(&ab) / length_squared;
+        self.start.lerp(&self.end, t.clamp(T::ZERO, T::ONE))
+    }
+
+    /// Returns the closest pair of points between this segment and `other`,
+    /// the first on this segment and the second on `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+    /// let b = Segment::new(Vector3::new(1.0, 1.0, -2.0), Vector3::new(1.0, 1.0, 2.0));
+    /// assert_eq!(
+    ///     a.closest_points(&b),
+    ///     (Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0))
+    /// );
+    /// ```
+    pub fn closest_points(&self, other: &Segment<T>) -> (Vector3<T>, Vector3<T>) {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(&r);
+
+        if a == T::ZERO {
+            return (self.start, other.closest_point(self.start));
+        }
+        if e == T::ZERO {
+            return (self.closest_point(other.start), other.start);
+        }
+
+        let c = d1.dot(&r);
+        let b = d1.dot(&d2);
+        let denom = a * e - b * b;
+        // Parallel segments have no unique pair, so start from `self.start`.
+        let mut s = if denom > T::ZERO {
+            ((b * f - c * e) / denom).clamp(T::ZERO, T::ONE)
+        } else {
+            T::ZERO
+        };
+        let mut t = (b * s + f) / e;
+        if t < T::ZERO {
+            t = T::ZERO;
+            s = (-c / a).clamp(T::ZERO, T::ONE);
+        } else if t > T::ONE {
+            t = T::ONE;
+            s = ((b - c) / a).clamp(T::ZERO, T::ONE);
+        }
+        (
+            self.start.lerp(&self.end, s),
+            other.start.lerp(&other.end, t),
+        )
+    }
+
+    /// Returns the distance from the segment to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+    /// assert_eq!(segment.distance_to_point(Vector3::new(7.0, 4.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+    /// assert!(segment.contains_point(Vector3::new(3.0, 0.0, 0.0)));
+    /// assert!(!segment.contains_point(Vector3::new(5.0, 0.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to both endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let scaled = segment.transform(&Affine3::from_scale(Vector3::new(3.0, 1.0, 1.0)));
+    /// assert_eq!(scaled.length(), 3.0);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+        )
+    }
+}
+
+/// A line segment in 2D space between `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment2<T> {
+    /// The first endpoint.
+    pub start: Vector2<T>,
+    /// The second endpoint.
+    pub end: Vector2<T>,
+}
+
+impl<T> Segment2<T> {
+    /// Creates a segment between two points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(segment.length(), 5.0);
+    /// ```
+    pub fn new(start: Vector2<T>, end: Vector2<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Real> Segment2<T> {
+    /// Returns the length of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(1.0, 1.0), Vector2::new(1.0, -1.0));
+    /// assert_eq!(segment.length(), 2.0);
+    /// ```
+    pub fn length(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Returns the point halfway between the endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0));
+    /// assert_eq!(segment.midpoint(), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn midpoint(&self) -> Vector2<T> {
+        self.start.lerp(&self.end, T::HALF)
+    }
+
+    /// Returns the point on the segment closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+    /// assert_eq!(segment.closest_point(Vector2::new(1.0, 5.0)), Vector2::new(1.0, 0.0));
+    /// assert_eq!(segment.closest_point(Vector2::new(-1.0, 5.0)), Vector2::new(0.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let ab = self.end - self.start;
+        let length_squared = ab.length_squared();
+        if length_squared == T::ZERO {
+            return self.start;
+        }
+        let t = (point - self.start).dot(&ab) / length_squared;
+        self.start.lerp(&self.end, t.clamp(T::ZERO, T::ONE))
+    }
+
+    /// Returns the distance from the segment to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+    /// assert_eq!(segment.distance_to_point(Vector2::new(7.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 4.0));
+    /// assert!(segment.contains_point(Vector2::new(1.0, 1.0)));
+    /// assert!(!segment.contains_point(Vector2::new(5.0, 5.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to both endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::segment::Segment2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    /// let scaled = segment.transform(&Affine2::from_scale(Vector2::new(3.0, 1.0)));
+    /// assert_eq!(scaled.end, Vector2::new(3.0, 0.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+        )
+    }
+}
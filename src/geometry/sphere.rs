@@ -0,0 +1,284 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::{max_scale, max_scale2};
+
+/// A solid ball in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere<T> {
+    /// The centre of the sphere.
+    pub center: Vector3<T>,
+    /// The radius of the sphere.
+    pub radius: T,
+}
+
+impl<T> Sphere<T> {
+    /// Creates a sphere from its centre and radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(sphere.radius, 2.0);
+    /// ```
+    pub fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: Real> Sphere<T> {
+    /// Returns `true` if `point` lies inside or on the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.0);
+    /// assert!(sphere.contains_point(Vector3::new(0.0, 2.0, 0.0)));
+    /// assert!(!sphere.contains_point(Vector3::new(1.5, 1.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns the point of the sphere closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(
+    ///     sphere.closest_point(Vector3::new(0.0, 0.0, 5.0)),
+    ///     Vector3::new(0.0, 0.0, 2.0)
+    /// );
+    /// assert_eq!(
+    ///     sphere.closest_point(Vector3::new(0.0, 1.0, 0.0)),
+    ///     Vector3::new(0.0, 1.0, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let offset = point - self.center;
+        let distance = offset.length();
+        if distance <= self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / distance)
+    }
+
+    /// Returns the distance from the sphere to `point`, or zero if the point
+    /// is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(sphere.distance_to_point(Vector3::new(1.0, 5.0, 0.0)), 3.0);
+    /// assert_eq!(sphere.distance_to_point(Vector3::new(1.0, 1.0, 0.0)), 0.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        ((point - self.center).length() - self.radius).max(T::ZERO)
+    }
+
+    /// Returns the area of the surface of the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0_f64);
+    /// assert_eq!(sphere.surface_area(), 4.0 * std::f64::consts::PI);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        T::from_f64(4.0) * T::PI * self.radius * self.radius
+    }
+
+    /// Returns the volume enclosed by the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 3.0_f64);
+    /// assert_approx_eq!(sphere.volume(), 36.0 * std::f64::consts::PI, epsilon = 1e-9);
+    /// ```
+    pub fn volume(&self) -> T {
+        T::from_f64(4.0) / T::from_f64(3.0) * T::PI * self.radius * self.radius * self.radius
+    }
+
+    /// Applies `transform` to the sphere.
+    ///
+    /// A sphere stays a sphere only under uniform scale. Otherwise the result
+    /// is the smallest sphere about the transformed centre that encloses the
+    /// transformed shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 1.0_f64);
+    /// let scaled = sphere.transform(&Affine3::from_scale(Vector3::new(2.0, 3.0, 1.0)));
+    /// assert_eq!(scaled.center, Vector3::new(2.0, 0.0, 0.0));
+    /// assert_approx_eq!(scaled.radius, 3.0, epsilon = 1e-9);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.center),
+            self.radius * max_scale(&transform.matrix),
+        )
+    }
+}
+
+/// A solid disc in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T> {
+    /// The centre of the circle.
+    pub center: Vector2<T>,
+    /// The radius of the circle.
+    pub radius: T,
+}
+
+impl<T> Circle<T> {
+    /// Creates a circle from its centre and radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+    /// assert_eq!(circle.radius, 2.0);
+    /// ```
+    pub fn new(center: Vector2<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: Real> Circle<T> {
+    /// Returns `true` if `point` lies inside or on the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+    /// assert!(circle.contains_point(Vector2::new(2.0, 0.0)));
+    /// assert!(!circle.contains_point(Vector2::new(1.5, 1.5)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns the point of the circle closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+    /// assert_eq!(circle.closest_point(Vector2::new(0.0, -5.0)), Vector2::new(0.0, -2.0));
+    /// assert_eq!(circle.closest_point(Vector2::new(1.0, 0.0)), Vector2::new(1.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let offset = point - self.center;
+        let distance = offset.length();
+        if distance <= self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / distance)
+    }
+
+    /// Returns the distance from the circle to `point`, or zero if the point
+    /// is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+    /// assert_eq!(circle.distance_to_point(Vector2::new(3.0, 4.0)), 3.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        ((point - self.center).length() - self.radius).max(T::ZERO)
+    }
+
+    /// Returns the area enclosed by the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0_f64);
+    /// assert_eq!(circle.area(), 4.0 * std::f64::consts::PI);
+    /// ```
+    pub fn area(&self) -> T {
+        T::PI * self.radius * self.radius
+    }
+
+    /// Returns the circumference of the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0_f64);
+    /// assert_eq!(circle.perimeter(), 4.0 * std::f64::consts::PI);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        T::TAU * self.radius
+    }
+
+    /// Applies `transform` to the circle, enclosing the result if the scale
+    /// is not uniform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Circle;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let circle = Circle::new(Vector2::new(1.0, 0.0), 1.0);
+    /// let scaled = circle.transform(&Affine2::from_scale(Vector2::new(2.0, 2.0)));
+    /// assert_eq!(scaled, Circle::new(Vector2::new(2.0, 0.0), 2.0));
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.center),
+            self.radius * max_scale2(&transform.matrix),
+        )
+    }
+}
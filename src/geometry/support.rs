@@ -0,0 +1,205 @@
+//! Support mappings for convex shapes.
+//!
+//! A convex shape is fully described by its support mapping: the function
+//! returning the point of the shape furthest along a direction. This is all
+//! the [`gjk`](super::gjk) queries need, so anything implementing
+//! [`SupportMap`] can be tested against anything else.
+
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+
+use super::aabb::Aabb;
+use super::capsule::Capsule;
+use super::cone::Cone;
+use super::cylinder::Cylinder;
+use super::obb::Obb;
+use super::segment::Segment;
+use super::sphere::Sphere;
+use super::triangle::Triangle;
+
+/// A convex shape that can report its furthest point in any direction.
+pub trait SupportMap<T> {
+    /// Returns a point of the shape furthest along `direction`.
+    ///
+    /// `direction` need not have unit length. When several points are
+    /// equally far, any of them may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::geometry::support::SupportMap;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(sphere.support(Vector3::new(0.0, 5.0, 0.0)), Vector3::new(1.0, 2.0, 0.0));
+    /// ```
+    fn support(&self, direction: Vector3<T>) -> Vector3<T>;
+}
+
+impl<T, S: SupportMap<T> + ?Sized> SupportMap<T> for &S {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        (**self).support(direction)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Sphere<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        self.center + direction.normalize() * self.radius
+    }
+}
+
+impl<T: Real> SupportMap<T> for Aabb<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let pick = |d: T, low: T, high: T| if d < T::ZERO { low } else { high };
+        Vector3::new(
+            pick(direction.x, self.min.x, self.max.x),
+            pick(direction.y, self.min.y, self.max.y),
+            pick(direction.z, self.min.z, self.max.z),
+        )
+    }
+}
+
+impl<T: Real> SupportMap<T> for Obb<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let h = [
+            self.half_extents.x,
+            self.half_extents.y,
+            self.half_extents.z,
+        ];
+        self.axes
+            .cols
+            .into_iter()
+            .zip(h)
+            .fold(self.center, |corner, (axis, half)| {
+                let sign = if axis.dot(&direction) < T::ZERO {
+                    -T::ONE
+                } else {
+                    T::ONE
+                };
+                corner + axis * (sign * half)
+            })
+    }
+}
+
+impl<T: Real> SupportMap<T> for Capsule<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        self.segment().support(direction) + direction.normalize() * self.radius
+    }
+}
+
+impl<T: Real> SupportMap<T> for Cylinder<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let axis = self.end - self.start;
+        let cap = if axis.dot(&direction) < T::ZERO {
+            self.start
+        } else {
+            self.end
+        };
+        if axis.length_squared() == T::ZERO {
+            return cap;
+        }
+        cap + direction.reject_from(&axis).normalize() * self.radius
+    }
+}
+
+impl<T: Real> SupportMap<T> for Cone<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let axis = self.base - self.apex;
+        if axis.length_squared() == T::ZERO {
+            return self.apex;
+        }
+        let rim = self.base + direction.reject_from(&axis).normalize() * self.radius;
+        if self.apex.dot(&direction) > rim.dot(&direction) {
+            self.apex
+        } else {
+            rim
+        }
+    }
+}
+
+impl<T: Real> SupportMap<T> for Segment<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        if self.start.dot(&direction) > self.end.dot(&direction) {
+            self.start
+        } else {
+            self.end
+        }
+    }
+}
+
+impl<T: Real> SupportMap<T> for Triangle<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        [self.a, self.b, self.c].support(direction)
+    }
+}
+
+/// The convex hull of a cloud of points.
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+impl<T: Real> SupportMap<T> for [Vector3<T>] {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        self[1..].iter().fold(self[0], |best, &p| {
+            if p.dot(&direction) > best.dot(&direction) {
+                p
+            } else {
+                best
+            }
+        })
+    }
+}
+
+/// The convex hull of a fixed number of points.
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+impl<T: Real, const N: usize> SupportMap<T> for [Vector3<T>; N] {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        self.as_slice().support(direction)
+    }
+}
+
+/// The Minkowski sum of two convex shapes: every point of `a` added to every
+/// point of `b`.
+///
+/// Sweeping a shape along a segment or rounding the corners of a box with a
+/// sphere are both Minkowski sums.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    /// The first shape.
+    pub a: A,
+    /// The second shape.
+    pub b: B,
+}
+
+impl<A, B> MinkowskiSum<A, B> {
+    /// Creates the Minkowski sum of `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::aabb::Aabb;
+    /// use vexel::geometry::sphere::Sphere;
+    /// use vexel::geometry::support::{MinkowskiSum, SupportMap};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // A box with its corners rounded off by a sphere.
+    /// let rounded = MinkowskiSum::new(
+    ///     Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0)),
+    ///     Sphere::new(Vector3::new(0.0, 0.0, 0.0), 0.5),
+    /// );
+    /// assert_eq!(rounded.support(Vector3::new(1.0, 0.0, 0.0)).x, 1.5);
+    /// ```
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<T: Real, A: SupportMap<T>, B: SupportMap<T>> SupportMap<T> for MinkowskiSum<A, B> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        self.a.support(direction) + self.b.support(direction)
+    }
+}
@@ -0,0 +1,547 @@
+use crate::scalar::Real;
+use crate::transforms::affine2::Affine2;
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::tolerance;
+
+/// A triangle in 3D space with corners `a`, `b` and `c`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle<T> {
+    /// The first corner.
+    pub a: Vector3<T>,
+    /// The second corner.
+    pub b: Vector3<T>,
+    /// The third corner.
+    pub c: Vector3<T>,
+}
+
+impl<T> Triangle<T> {
+    /// Creates a triangle from its corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.area(), 2.0);
+    /// ```
+    pub fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Real> Triangle<T> {
+    /// Returns the unit normal of the triangle, facing the side from which
+    /// the corners appear counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.normal(), Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn normal(&self) -> Vector3<T> {
+        (self.b - self.a).cross(&(self.c - self.a)).normalize()
+    }
+
+    /// Returns the area of the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     Vector3::new(3.0, 0.0, 1.0),
+    ///     Vector3::new(0.0, 4.0, 1.0),
+    /// );
+    /// assert_eq!(triangle.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(&(self.c - self.a)).length() * T::HALF
+    }
+
+    /// Returns the average of the three corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(3.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 3.0, 3.0),
+    /// );
+    /// assert_eq!(triangle.centroid(), Vector3::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn centroid(&self) -> Vector3<T> {
+        let sum = self.a + self.b + self.c;
+        let three = T::from_f64(3.0);
+        Vector3::new(sum.x / three, sum.y / three, sum.z / three)
+    }
+
+    /// Returns the barycentric coordinates `(u, v, w)` of `point` projected
+    /// onto the plane of the triangle, so that the projection equals
+    /// `a * u + b * v + c * w`.
+    ///
+    /// The coordinates are all in `[0, 1]` exactly when the projection lies
+    /// inside the triangle. The triangle must not be degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(4.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 4.0, 0.0),
+    /// );
+    /// assert_eq!(
+    ///     triangle.barycentric(Vector3::new(1.0, 2.0, 5.0)),
+    ///     Vector3::new(0.25, 0.25, 0.5)
+    /// );
+    /// ```
+    pub fn barycentric(&self, point: Vector3<T>) -> Vector3<T> {
+        let (v0, v1, v2) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (d00, d01, d11) = (v0.dot(&v0), v0.dot(&v1), v1.dot(&v1));
+        let (d20, d21) = (v2.dot(&v0), v2.dot(&v1));
+        let denom = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Vector3::new(T::ONE - v - w, v, w)
+    }
+
+    /// Returns the point of the triangle closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(
+    ///     triangle.closest_point(Vector3::new(0.5, 0.5, 3.0)),
+    ///     Vector3::new(0.5, 0.5, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     triangle.closest_point(Vector3::new(-1.0, -1.0, 0.0)),
+    ///     Vector3::new(0.0, 0.0, 0.0)
+    /// );
+    /// assert_eq!(
+    ///     triangle.closest_point(Vector3::new(2.0, 2.0, 0.0)),
+    ///     Vector3::new(1.0, 1.0, 0.0)
+    /// );
+    /// ```
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= T::ZERO && d2 <= T::ZERO {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= T::ZERO && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= T::ZERO && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::ZERO && d4 >= d3 && d5 >= d6 {
+            let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return b + (c - b) * t;
+        }
+
+        let denom = va + vb + vc;
+        a + ab * (vb / denom) + ac * (vc / denom)
+    }
+
+    /// Returns the distance from the triangle to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.distance_to_point(Vector3::new(0.5, 0.5, -3.0)), 3.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Returns `true` if `point` lies on the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert!(triangle.contains_point(Vector3::new(0.5, 0.5, 0.0)));
+    /// assert!(!triangle.contains_point(Vector3::new(0.5, 0.5, 0.1)));
+    /// assert!(!triangle.contains_point(Vector3::new(1.5, 1.5, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.distance_to_point(point) <= tolerance()
+    }
+
+    /// Applies `transform` to every corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let scaled = triangle.transform(&Affine3::from_scale(Vector3::new(2.0, 3.0, 1.0)));
+    /// assert_eq!(scaled.area(), 3.0);
+    /// ```
+    pub fn transform(&self, transform: &Affine3<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.a),
+            transform.transform_point(self.b),
+            transform.transform_point(self.c),
+        )
+    }
+}
+
+/// A solid triangle in 2D space with corners `a`, `b` and `c`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle2<T> {
+    /// The first corner.
+    pub a: Vector2<T>,
+    /// The second corner.
+    pub b: Vector2<T>,
+    /// The third corner.
+    pub c: Vector2<T>,
+}
+
+impl<T> Triangle2<T> {
+    /// Creates a triangle from its corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(0.0, 2.0),
+    /// );
+    /// assert_eq!(triangle.area(), 2.0);
+    /// ```
+    pub fn new(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Real> Triangle2<T> {
+    /// Returns the area of the triangle, positive if the corners are in
+    /// counter-clockwise order and negative otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Vector2::new(0.0, 0.0);
+    /// let b = Vector2::new(2.0, 0.0);
+    /// let c = Vector2::new(0.0, 2.0);
+    /// assert_eq!(Triangle2::new(a, b, c).signed_area(), 2.0);
+    /// assert_eq!(Triangle2::new(a, c, b).signed_area(), -2.0);
+    /// ```
+    pub fn signed_area(&self) -> T {
+        (self.b - self.a).cross(&(self.c - self.a)) * T::HALF
+    }
+
+    /// Returns the area of the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(0.0, 4.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// Returns the length of the outline of the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(0.0, 4.0),
+    /// );
+    /// assert_eq!(triangle.perimeter(), 12.0);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        (self.b - self.a).length() + (self.c - self.b).length() + (self.a - self.c).length()
+    }
+
+    /// Returns the average of the three corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(3.0, 0.0),
+    ///     Vector2::new(0.0, 3.0),
+    /// );
+    /// assert_eq!(triangle.centroid(), Vector2::new(1.0, 1.0));
+    /// ```
+    pub fn centroid(&self) -> Vector2<T> {
+        let sum = self.a + self.b + self.c;
+        let three = T::from_f64(3.0);
+        Vector2::new(sum.x / three, sum.y / three)
+    }
+
+    /// Returns the barycentric coordinates `(u, v, w)` of `point`, so that
+    /// `point` equals `a * u + b * v + c * w`.
+    ///
+    /// The triangle must not be degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(4.0, 0.0),
+    ///     Vector2::new(0.0, 4.0),
+    /// );
+    /// assert_eq!(
+    ///     triangle.barycentric(Vector2::new(1.0, 2.0)),
+    ///     Vector3::new(0.25, 0.25, 0.5)
+    /// );
+    /// ```
+    pub fn barycentric(&self, point: Vector2<T>) -> Vector3<T> {
+        let (v0, v1, v2) = (self.b - self.a, self.c - self.a, point - self.a);
+        let denom = v0.cross(&v1);
+        let v = v2.cross(&v1) / denom;
+        let w = v0.cross(&v2) / denom;
+        Vector3::new(T::ONE - v - w, v, w)
+    }
+
+    /// Returns `true` if `point` lies inside or on the triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(0.0, 2.0),
+    /// );
+    /// assert!(triangle.contains_point(Vector2::new(0.5, 0.5)));
+    /// assert!(triangle.contains_point(Vector2::new(1.0, 1.0)));
+    /// assert!(!triangle.contains_point(Vector2::new(1.5, 1.5)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        let d0 = (self.b - self.a).cross(&(point - self.a));
+        let d1 = (self.c - self.b).cross(&(point - self.b));
+        let d2 = (self.a - self.c).cross(&(point - self.c));
+        let has_negative = d0 < T::ZERO || d1 < T::ZERO || d2 < T::ZERO;
+        let has_positive = d0 > T::ZERO || d1 > T::ZERO || d2 > T::ZERO;
+        !(has_negative && has_positive)
+    }
+
+    /// Returns the point of the triangle closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(0.0, 2.0),
+    /// );
+    /// assert_eq!(triangle.closest_point(Vector2::new(0.5, 0.5)), Vector2::new(0.5, 0.5));
+    /// assert_eq!(triangle.closest_point(Vector2::new(3.0, -1.0)), Vector2::new(2.0, 0.0));
+    /// assert_eq!(triangle.closest_point(Vector2::new(1.0, -1.0)), Vector2::new(1.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= T::ZERO && d2 <= T::ZERO {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= T::ZERO && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= T::ZERO && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::ZERO && d4 >= d3 && d5 >= d6 {
+            let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return b + (c - b) * t;
+        }
+
+        point
+    }
+
+    /// Returns the distance from the triangle to `point`, or zero if the
+    /// point is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(0.0, 2.0),
+    /// );
+    /// assert_eq!(triangle.distance_to_point(Vector2::new(1.0, -3.0)), 3.0);
+    /// assert_eq!(triangle.distance_to_point(Vector2::new(0.5, 0.5)), 0.0);
+    /// ```
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// Applies `transform` to every corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::geometry::triangle::Triangle2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let triangle = Triangle2::new(
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 0.0),
+    ///     Vector2::new(0.0, 1.0),
+    /// );
+    /// let mirrored = triangle.transform(&Affine2::from_scale(Vector2::new(-1.0, 1.0)));
+    /// assert_eq!(mirrored.signed_area(), -0.5);
+    /// ```
+    pub fn transform(&self, transform: &Affine2<T>) -> Self {
+        Self::new(
+            transform.transform_point(self.a),
+            transform.transform_point(self.b),
+            transform.transform_point(self.c),
+        )
+    }
+}
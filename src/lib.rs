@@ -1 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod approx;
+pub mod color;
+pub mod curves;
+pub mod error;
+pub mod geometry;
+pub mod math;
+pub mod matrices;
+pub mod noise;
+pub mod quaternion;
+pub mod random;
+pub mod scalar;
+mod simd;
+pub mod transforms;
 pub mod vectors;
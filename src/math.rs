@@ -0,0 +1,1065 @@
+//! Software implementations of the elementary float functions.
+//!
+//! [`Real`](crate::scalar::Real) uses these in place of the standard
+//! library's float methods when the `std` feature is disabled, so the crate
+//! runs on targets without an operating system or a system math library.
+//! They follow fdlibm: the argument is reduced to a small interval and the
+//! function approximated there by a polynomial.
+//!
+//! `sqrt`, `floor`, `ceil`, `round` and `trunc` are exact. The other
+//! functions are within a few units in the last place of the exact result.
+//! `sin`, `cos` and `tan` keep that accuracy for arguments up to about
+//! 10⁶ radians. Beyond that they grow less accurate, though they stay
+//! bounded.
+//!
+//! Functions ending in `f` are the `f32` versions. They evaluate the `f64`
+//! version and round the result, which is accurate and cheap enough on the
+//! targets that need them.
+
+/// The fdlibm coefficients for sine on `[-π/4, π/4]`.
+const S1: f64 = -0.16666666666666632;
+const S2: f64 = 0.00833333333332249;
+const S3: f64 = -0.0001984126982985795;
+const S4: f64 = 2.7557313707070068e-6;
+const S5: f64 = -2.5050760253406863e-8;
+const S6: f64 = 1.58969099521155e-10;
+
+/// The fdlibm coefficients for cosine on `[-π/4, π/4]`.
+const C1: f64 = 0.0416666666666666;
+const C2: f64 = -0.001388888888887411;
+const C3: f64 = 2.480158728947673e-5;
+const C4: f64 = -2.7557314351390663e-7;
+const C5: f64 = 2.087572321298175e-9;
+const C6: f64 = -1.1359647557788195e-11;
+
+/// π/2 split into pieces of 33 bits, each followed by the rest of π/2
+/// beyond it. Products of the pieces with integers below 2²⁰ are exact.
+const PIO2_1: f64 = 1.5707963267341256;
+const PIO2_1T: f64 = 6.077100506506192e-11;
+const PIO2_2: f64 = 6.077100506303966e-11;
+const PIO2_2T: f64 = 2.0222662487959506e-21;
+const PIO2_3: f64 = 2.0222662487111665e-21;
+const PIO2_3T: f64 = 8.4784276603689e-32;
+
+/// 2¹⁹·π, below which a single reduction by the pieces of π/2 is exact.
+const MEDIUM: f64 = 1_647_099.0;
+
+/// The fdlibm arctangents of 0.5, 1, 1.5 and ∞, each split into a rounded
+/// value and its rounding error.
+const ATAN_HI: [f64; 4] = [
+    0.4636476090008061,
+    core::f64::consts::FRAC_PI_4,
+    0.982793723247329,
+    core::f64::consts::FRAC_PI_2,
+];
+const ATAN_LO: [f64; 4] = [
+    2.2698777452961687e-17,
+    3.061616997868383e-17,
+    1.3903311031230998e-17,
+    6.123233995736766e-17,
+];
+
+/// The fdlibm coefficients for arctangent on `[-7/16, 7/16]`.
+const AT: [f64; 11] = [
+    0.3333333333333293,
+    -0.19999999999876483,
+    0.14285714272503466,
+    -0.11111110405462356,
+    0.09090887133436507,
+    -0.0769187620504483,
+    0.06661073137387531,
+    -0.058335701337905735,
+    0.049768779946159324,
+    -0.036531572744216916,
+    0.016285820115365782,
+];
+
+/// The rounding error of `core::f64::consts::PI`.
+const PI_LO: f64 = 1.2246467991473532e-16;
+
+/// ln 2 split so that multiples of the first part by exponents are exact.
+const LN2_HI: f64 = 0.6931471803691238;
+const LN2_LO: f64 = 1.9082149292705877e-10;
+
+/// The fdlibm coefficients for exp on `[-ln 2 / 2, ln 2 / 2]`.
+const P1: f64 = 0.16666666666666602;
+const P2: f64 = -0.0027777777777015593;
+const P3: f64 = 6.613756321437934e-5;
+const P4: f64 = -1.6533902205465252e-6;
+const P5: f64 = 4.1381367970572385e-8;
+
+/// The fdlibm coefficients for the logarithm of `(1 + s) / (1 - s)`.
+const LG: [f64; 7] = [
+    0.6666666666666735,
+    0.3999999999940942,
+    0.2857142874366239,
+    0.22222198432149784,
+    0.1818357216161805,
+    0.15313837699209373,
+    0.14798198605116586,
+];
+
+/// 2⁵², beyond which every float is an integer.
+const TWO_POW_52: f64 = 4_503_599_627_370_496.0;
+
+/// Returns `2^n` for an exponent in the normal range.
+fn pow2(n: i32) -> f64 {
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+/// Multiplies `x` by `2^n`, overflowing to infinity and underflowing to zero
+/// as the exact product would.
+fn scale(x: f64, mut n: i32) -> f64 {
+    let mut y = x;
+    if n > 1023 {
+        y *= pow2(1023);
+        n -= 1023;
+        if n > 1023 {
+            y *= pow2(1023);
+            n = (n - 1023).min(1023);
+        }
+    } else if n < -1022 {
+        // Stepping by 2⁻⁹⁶⁹ rather than 2⁻¹⁰²² keeps the intermediate result
+        // normal, so it is only rounded once.
+        y *= pow2(-969);
+        n += 969;
+        if n < -1022 {
+            y *= pow2(-969);
+            n = (n + 969).max(-1022);
+        }
+    }
+    y * pow2(n)
+}
+
+/// Returns the square root of `x`, correctly rounded.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::sqrt(2.0), 2.0f64.sqrt());
+/// assert!(math::sqrt(-1.0).is_nan());
+/// ```
+pub fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    // Write x as mantissa · 2^exponent with an integer mantissa.
+    let bits = x.to_bits();
+    let mut mantissa = bits & ((1 << 52) - 1);
+    let mut exponent = (bits >> 52) as i32;
+    if exponent == 0 {
+        let shift = mantissa.leading_zeros() as i32 - 11;
+        mantissa <<= shift;
+        exponent = 1 - shift;
+    } else {
+        mantissa |= 1 << 52;
+    }
+    let mut exponent = exponent - 1075;
+    if exponent % 2 != 0 {
+        mantissa <<= 1;
+        exponent -= 1;
+    }
+
+    // The integer square root of the widened mantissa has the 53 bits of the
+    // result, and its remainder decides the rounding.
+    let wide = (mantissa as u128) << 52;
+    let mut root = wide.isqrt();
+    if wide - root * root > root {
+        root += 1;
+    }
+    root as f64 * pow2(exponent / 2 - 26)
+}
+
+/// Returns the square root of `x`, correctly rounded.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::sqrtf(2.0), 2.0f32.sqrt());
+/// ```
+pub fn sqrtf(x: f32) -> f32 {
+    // The square root rounded to f64 always rounds to the right f32.
+    sqrt(x as f64) as f32
+}
+
+/// Returns the integer part of `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::trunc(-2.7), -2.0);
+/// assert_eq!(math::trunc(2.7), 2.0);
+/// ```
+pub fn trunc(x: f64) -> f64 {
+    // Large values, infinities and NaN are their own integer part.
+    if x.is_nan() || x.abs() >= TWO_POW_52 {
+        return x;
+    }
+    (x as i64 as f64).copysign(x)
+}
+
+/// Returns the integer part of `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::truncf(-2.7), -2.0);
+/// ```
+pub fn truncf(x: f32) -> f32 {
+    trunc(x as f64) as f32
+}
+
+/// Returns the largest integer less than or equal to `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::floor(-2.5), -3.0);
+/// assert_eq!(math::floor(2.5), 2.0);
+/// ```
+pub fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Returns the largest integer less than or equal to `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::floorf(-2.5), -3.0);
+/// ```
+pub fn floorf(x: f32) -> f32 {
+    floor(x as f64) as f32
+}
+
+/// Returns the smallest integer greater than or equal to `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::ceil(-2.5), -2.0);
+/// assert_eq!(math::ceil(2.5), 3.0);
+/// ```
+pub fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if t < x {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+/// Returns the smallest integer greater than or equal to `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::ceilf(2.5), 3.0);
+/// ```
+pub fn ceilf(x: f32) -> f32 {
+    ceil(x as f64) as f32
+}
+
+/// Returns the nearest integer to `x`, rounding half-way cases away from
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::round(2.5), 3.0);
+/// assert_eq!(math::round(-2.5), -3.0);
+/// assert_eq!(math::round(2.49), 2.0);
+/// ```
+pub fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    // The fractional part is exact, so the comparison is too.
+    if (x - t).abs() >= 0.5 {
+        t + 1.0f64.copysign(x)
+    } else {
+        t
+    }
+}
+
+/// Returns the nearest integer to `x`, rounding half-way cases away from
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::roundf(-2.5), -3.0);
+/// ```
+pub fn roundf(x: f32) -> f32 {
+    round(x as f64) as f32
+}
+
+/// Returns `x` reduced to `[-π/4, π/4]` as a sum `hi + lo` of two floats,
+/// and the number of quarter turns removed, modulo 4.
+fn reduce_quarter_turns(x: f64) -> (f64, f64, u32) {
+    let (mut x, mut quarter_turns) = (x, 0);
+    // Huge arguments are first brought into range without exact products,
+    // which loses accuracy but keeps the result a sine or cosine.
+    while x.abs() >= MEDIUM {
+        let n = round(x * core::f64::consts::FRAC_2_PI);
+        x = ((x - n * PIO2_1) - n * PIO2_2) - n * PIO2_3;
+        quarter_turns += (n - 4.0 * floor(n * 0.25)) as u32;
+    }
+
+    // fdlibm's reduction: subtract further pieces of π/2 only while the
+    // remainder keeps cancelling, carrying the rounding error along in `w`.
+    let n = round(x * core::f64::consts::FRAC_2_PI);
+    let exponent = |v: f64| (v.to_bits() >> 52) as i32 & 0x7ff;
+    let mut r = x - n * PIO2_1;
+    let mut w = n * PIO2_1T;
+    let mut hi = r - w;
+    if exponent(x) - exponent(hi) > 16 {
+        let t = r;
+        w = n * PIO2_2;
+        r = t - w;
+        w = n * PIO2_2T - ((t - r) - w);
+        hi = r - w;
+        if exponent(x) - exponent(hi) > 49 {
+            let t = r;
+            w = n * PIO2_3;
+            r = t - w;
+            w = n * PIO2_3T - ((t - r) - w);
+            hi = r - w;
+        }
+    }
+    let lo = (r - hi) - w;
+    (hi, lo, (quarter_turns + (n as i32 & 3) as u32) % 4)
+}
+
+/// Sine of `x + y` on `[-π/4, π/4]`, where `y` is a tiny correction to `x`.
+fn sin_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let v = z * x;
+    let r = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+    x - ((z * (0.5 * y - v * r) - y) - v * S1)
+}
+
+/// Cosine of `x + y` on `[-π/4, π/4]`, where `y` is a tiny correction to
+/// `x`.
+fn cos_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let r = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    // Recovers the bits of 1 - hz lost to rounding.
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+/// Returns the sine of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::sin(1.0), 1.0f64.sin(), ulps = 1);
+/// assert_approx_eq!(math::sin(std::f64::consts::FRAC_PI_6), 0.5, ulps = 1);
+/// ```
+pub fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+/// Returns the sine of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::sinf(1.0), 1.0f32.sin());
+/// ```
+pub fn sinf(x: f32) -> f32 {
+    sin(x as f64) as f32
+}
+
+/// Returns the cosine of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::cos(1.0), 1.0f64.cos(), ulps = 1);
+/// assert_approx_eq!(math::cos(std::f64::consts::FRAC_PI_3), 0.5, ulps = 1);
+/// ```
+pub fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+/// Returns the cosine of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::cosf(1.0), 1.0f32.cos());
+/// ```
+pub fn cosf(x: f32) -> f32 {
+    cos(x as f64) as f32
+}
+
+/// Returns the sine and cosine of `x` (in radians) at once, sharing the
+/// argument reduction.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// let (sin, cos) = math::sin_cos(2.0);
+/// assert_approx_eq!(sin, 2.0f64.sin(), ulps = 1);
+/// assert_approx_eq!(cos, 2.0f64.cos(), ulps = 1);
+/// ```
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return (x, x);
+    }
+    if x.is_infinite() {
+        return (f64::NAN, f64::NAN);
+    }
+    // Tiny arguments are returned as they are, which keeps the sign of zero.
+    if x.abs() < 7.450_580_596_923_828e-9 {
+        return (x, 1.0);
+    }
+
+    let (hi, lo, quarter_turns) = reduce_quarter_turns(x);
+    let (sin, cos) = (sin_kernel(hi, lo), cos_kernel(hi, lo));
+    match quarter_turns {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Returns the sine and cosine of `x` (in radians) at once.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::sin_cosf(2.0), 2.0f32.sin_cos());
+/// ```
+pub fn sin_cosf(x: f32) -> (f32, f32) {
+    let (sin, cos) = sin_cos(x as f64);
+    (sin as f32, cos as f32)
+}
+
+/// Returns the tangent of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::tan(1.0), 1.0f64.tan(), ulps = 2);
+/// assert_approx_eq!(math::tan(std::f64::consts::FRAC_PI_4), 1.0, ulps = 2);
+/// ```
+pub fn tan(x: f64) -> f64 {
+    if x.is_nan() || x.abs() < 7.450_580_596_923_828e-9 {
+        return x;
+    }
+    if x.is_infinite() {
+        return f64::NAN;
+    }
+    let (hi, lo, quarter_turns) = reduce_quarter_turns(x);
+    if quarter_turns % 2 == 0 {
+        sin_kernel(hi, lo) / cos_kernel(hi, lo)
+    } else {
+        -cos_kernel(hi, lo) / sin_kernel(hi, lo)
+    }
+}
+
+/// Returns the tangent of `x` (in radians).
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::tanf(1.0), 1.0f32.tan());
+/// ```
+pub fn tanf(x: f32) -> f32 {
+    tan(x as f64) as f32
+}
+
+/// Returns the arctangent of `x` in the range `[-π/2, π/2]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::atan(1.0), std::f64::consts::FRAC_PI_4, ulps = 1);
+/// assert_approx_eq!(math::atan(-3.0), (-3.0f64).atan(), ulps = 1);
+/// ```
+pub fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    let a = x.abs();
+    // Beyond 2⁶⁶ the result rounds to ±π/2.
+    if a >= 7.378_697_629_483_821e19 {
+        return (ATAN_HI[3] + ATAN_LO[3]).copysign(x);
+    }
+    if a < 0.4375 {
+        if a < 7.450_580_596_923_828e-9 {
+            return x;
+        }
+        return x - x * atan_series(x);
+    }
+
+    // Shift the argument next to 0.5, 1, 1.5 or ∞ and add the arctangent of
+    // that point.
+    let (index, t) = if a < 0.6875 {
+        (0, (2.0 * a - 1.0) / (2.0 + a))
+    } else if a < 1.1875 {
+        (1, (a - 1.0) / (a + 1.0))
+    } else if a < 2.4375 {
+        (2, (a - 1.5) / (1.0 + 1.5 * a))
+    } else {
+        (3, -1.0 / a)
+    };
+    let z = ATAN_HI[index] - ((t * atan_series(t) - ATAN_LO[index]) - t);
+    z.copysign(x)
+}
+
+/// Returns the polynomial `p` for which `atan(x) ≈ x - x·p(x)` near zero.
+fn atan_series(x: f64) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    // The odd and even terms are summed separately to shorten the chain of
+    // dependent operations.
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    s1 + s2
+}
+
+/// Returns the arctangent of `x` in the range `[-π/2, π/2]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::atanf(1.0), std::f32::consts::FRAC_PI_4);
+/// ```
+pub fn atanf(x: f32) -> f32 {
+    atan(x as f64) as f32
+}
+
+/// Returns the four quadrant arctangent of `y` and `x`, the angle of the
+/// point `(x, y)` in the range `[-π, π]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::atan2(1.0, -1.0), 3.0 * std::f64::consts::FRAC_PI_4, ulps = 1);
+/// assert_eq!(math::atan2(-0.0, 1.0).to_bits(), (-0.0f64).to_bits());
+/// ```
+pub fn atan2(y: f64, x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    let x_is_negative = x.is_sign_negative();
+    if y == 0.0 {
+        return if x_is_negative { PI.copysign(y) } else { y };
+    }
+    if x == 0.0 {
+        return FRAC_PI_2.copysign(y);
+    }
+    if x.is_infinite() {
+        let angle = match (y.is_infinite(), x_is_negative) {
+            (true, false) => FRAC_PI_4,
+            (true, true) => 3.0 * FRAC_PI_4,
+            (false, false) => 0.0,
+            (false, true) => PI,
+        };
+        return angle.copysign(y);
+    }
+    if y.is_infinite() {
+        return FRAC_PI_2.copysign(y);
+    }
+
+    let a = atan((y / x).abs());
+    let angle = if x_is_negative { PI - (a - PI_LO) } else { a };
+    angle.copysign(y)
+}
+
+/// Returns the four quadrant arctangent of `y` and `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::atan2f(1.0, -1.0), 1.0f32.atan2(-1.0));
+/// ```
+pub fn atan2f(y: f32, x: f32) -> f32 {
+    atan2(y as f64, x as f64) as f32
+}
+
+/// Returns the arcsine of `x` in the range `[-π/2, π/2]`, or NaN outside
+/// `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::asin(0.5), std::f64::consts::FRAC_PI_6, ulps = 2);
+/// assert!(math::asin(1.5).is_nan());
+/// ```
+pub fn asin(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    // (1 - x)(1 + x) rather than 1 - x² keeps the precision near ±1.
+    atan2(x, sqrt((1.0 - x) * (1.0 + x)))
+}
+
+/// Returns the arcsine of `x` in the range `[-π/2, π/2]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::asinf(0.5), 0.5f32.asin());
+/// ```
+pub fn asinf(x: f32) -> f32 {
+    asin(x as f64) as f32
+}
+
+/// Returns the arccosine of `x` in the range `[0, π]`, or NaN outside
+/// `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::acos(0.5), std::f64::consts::FRAC_PI_3, ulps = 2);
+/// assert_eq!(math::acos(-1.0), std::f64::consts::PI);
+/// ```
+pub fn acos(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    atan2(sqrt((1.0 - x) * (1.0 + x)), x)
+}
+
+/// Returns the arccosine of `x` in the range `[0, π]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::acosf(0.5), 0.5f32.acos());
+/// ```
+pub fn acosf(x: f32) -> f32 {
+    acos(x as f64) as f32
+}
+
+/// Returns `e^x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::exp(1.0), std::f64::consts::E, ulps = 1);
+/// assert_eq!(math::exp(-1000.0), 0.0);
+/// ```
+pub fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782712893384 {
+        return f64::INFINITY;
+    }
+    if x < -745.1332191019411 {
+        return 0.0;
+    }
+    if x.abs() < 3.725_290_298_461_914e-9 {
+        return 1.0 + x;
+    }
+
+    // x = k·ln 2 + r with |r| ≤ ln 2 / 2, so e^x = 2^k · e^r.
+    let k = round(x * core::f64::consts::LOG2_E);
+    let hi = x - k * LN2_HI;
+    let lo = k * LN2_LO;
+    let r = hi - lo;
+    let t = r * r;
+    let c = r - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let y = 1.0 - ((lo - (r * c) / (2.0 - c)) - hi);
+    scale(y, k as i32)
+}
+
+/// Returns `e^x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::expf(1.0), std::f32::consts::E);
+/// ```
+pub fn expf(x: f32) -> f32 {
+    exp(x as f64) as f32
+}
+
+/// Returns the natural logarithm of `x`, or NaN for negative `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::ln(10.0), std::f64::consts::LN_10, ulps = 1);
+/// assert_eq!(math::ln(0.0), f64::NEG_INFINITY);
+/// ```
+pub fn ln(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    // x = 2^k · m with √2/2 ≤ m < √2.
+    let (mut bits, mut k) = (x.to_bits(), 0);
+    if bits >> 52 == 0 {
+        bits = (x * pow2(54)).to_bits();
+        k -= 54;
+    }
+    k += (bits >> 52) as i32 - 1023;
+    let mut m = f64::from_bits(bits & ((1 << 52) - 1) | 1023 << 52);
+    if m > core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        k += 1;
+    }
+
+    // ln(m) = ln((1 + s) / (1 - s)) = 2s + 2s³/3 + ..., with f = m - 1 and
+    // s = f / (2 + f).
+    let f = m - 1.0;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG[1] + w * (LG[3] + w * LG[5]));
+    let t2 = z * (LG[0] + w * (LG[2] + w * (LG[4] + w * LG[6])));
+    let half_f_squared = 0.5 * f * f;
+    let k = k as f64;
+    k * LN2_HI - ((half_f_squared - (s * (half_f_squared + t1 + t2) + k * LN2_LO)) - f)
+}
+
+/// Returns the natural logarithm of `x`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::lnf(10.0), std::f32::consts::LN_10);
+/// ```
+pub fn lnf(x: f32) -> f32 {
+    ln(x as f64) as f32
+}
+
+/// Raises `x` to the power `y`.
+///
+/// Small integer powers are computed by repeated multiplication, so results
+/// such as `pow(2.0, 10.0)` are exact. Other powers are computed as
+/// `e^(y·ln x)`, whose relative error grows with the size of `y·ln x`: it
+/// stays within a few units in the last place for moderate results, but
+/// reaches about 10⁻¹³ near the limits of `f64`. [`powf`] is accurate
+/// throughout.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::math;
+///
+/// assert_approx_eq!(math::pow(2.0, 0.5), std::f64::consts::SQRT_2, ulps = 2);
+/// assert_eq!(math::pow(-2.0, 3.0), -8.0);
+/// assert!(math::pow(-2.0, 0.5).is_nan());
+/// ```
+pub fn pow(x: f64, y: f64) -> f64 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+    if y.is_infinite() {
+        if x == -1.0 {
+            return 1.0;
+        }
+        return if (x.abs() > 1.0) == (y > 0.0) {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+    }
+
+    let y_is_integer = trunc(y) == y;
+    let y_is_odd = y_is_integer && trunc(y * 0.5) != y * 0.5;
+    if x == 0.0 || x.is_infinite() {
+        let magnitude = if (x == 0.0) == (y > 0.0) {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+        return if y_is_odd {
+            magnitude.copysign(x)
+        } else {
+            magnitude
+        };
+    }
+    if x < 0.0 && !y_is_integer {
+        return f64::NAN;
+    }
+    if y_is_integer && y.abs() <= 64.0 {
+        return powi(x, y as i32);
+    }
+
+    let magnitude = exp(y * ln(x.abs()));
+    if x < 0.0 && y_is_odd {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Raises `x` to the power `y`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::powf(2.0, 0.5), std::f32::consts::SQRT_2);
+/// ```
+pub fn powf(x: f32, y: f32) -> f32 {
+    // The f64 result is far more precise than an f32 needs, even for the
+    // largest exponents.
+    pow(x as f64, y as f64) as f32
+}
+
+/// Raises `x` to an integer power by repeated squaring.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::powi(3.0, 4), 81.0);
+/// assert_eq!(math::powi(2.0, -2), 0.25);
+/// ```
+pub fn powi(x: f64, n: i32) -> f64 {
+    let mut base = x;
+    let mut exponent = n.unsigned_abs();
+    let mut result = 1.0;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// Raises `x` to an integer power.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::math;
+///
+/// assert_eq!(math::powif(3.0, 4), 81.0);
+/// ```
+pub fn powif(x: f32, n: i32) -> f32 {
+    powi(x as f64, n) as f32
+}
+
+/// Provides the float methods that `core` lacks, backed by the functions
+/// above, so that the same method calls compile with and without `std`.
+#[cfg(not(feature = "std"))]
+pub(crate) trait Float: Sized {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! impl_float {
+    ($t:ty => $sqrt:ident, $sin:ident, $cos:ident, $sin_cos:ident, $tan:ident, $asin:ident,
+     $acos:ident, $atan:ident, $atan2:ident, $exp:ident, $ln:ident, $powf:ident,
+     $powi:ident, $floor:ident, $ceil:ident, $round:ident, $trunc:ident) => {
+        impl Float for $t {
+            #[inline]
+            fn sqrt(self) -> Self {
+                $sqrt(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                $sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $cos(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $sin_cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                $atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $atan2(self, other)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $powf(self, n)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                $powi(self, n)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                $floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                $ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                $round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                $trunc(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+impl_float!(f64 => sqrt, sin, cos, sin_cos, tan, asin, acos, atan, atan2, exp, ln, pow, powi,
+    floor, ceil, round, trunc);
+#[cfg(not(feature = "std"))]
+impl_float!(f32 => sqrtf, sinf, cosf, sin_cosf, tanf, asinf, acosf, atanf, atan2f, expf, lnf,
+    powf, powif, floorf, ceilf, roundf, truncf);
@@ -0,0 +1,237 @@
+use core::ops::Mul;
+
+use crate::matrices::mat4::Mat4;
+use crate::vectors::dvector4a::DVector4A;
+use crate::vectors::vector4::Vector4;
+
+/// A 4x4 `f64` matrix whose columns are stored in SIMD registers, the
+/// double-precision counterpart of [`Mat4A`](super::mat4a::Mat4A).
+///
+/// Products with `DMat4A` give bit-for-bit the same results as the matching
+/// [`Mat4<f64>`] products: each column is scaled and summed in the same order
+/// as the scalar code, just four rows at a time.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::matrices::mat4::Mat4;
+/// use vexel::matrices::dmat4a::DMat4A;
+///
+/// let a = Mat4::from_cols_array([
+///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+/// ]);
+/// let b = a.transpose();
+/// assert_eq!(Mat4::from(DMat4A::from(a) * DMat4A::from(b)), a * b);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DMat4A {
+    /// The columns of the matrix.
+    pub cols: [DVector4A; 4],
+}
+
+impl DMat4A {
+    /// Creates a matrix from its four columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let m = DMat4A::from_cols(
+    ///     DVector4A::new(1.0, 0.0, 0.0, 0.0),
+    ///     DVector4A::new(0.0, 1.0, 0.0, 0.0),
+    ///     DVector4A::new(0.0, 0.0, 1.0, 0.0),
+    ///     DVector4A::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// assert_eq!(m.cols[3], DVector4A::new(5.0, 6.0, 7.0, 1.0));
+    /// ```
+    pub fn from_cols(
+        x_axis: DVector4A,
+        y_axis: DVector4A,
+        z_axis: DVector4A,
+        w_axis: DVector4A,
+    ) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis, w_axis],
+        }
+    }
+
+    /// Creates a matrix from a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let m = DMat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.cols[1], DVector4A::new(5.0, 6.0, 7.0, 8.0));
+    /// ```
+    pub fn from_cols_array(m: [f64; 16]) -> Self {
+        Self::from_cols(
+            DVector4A::new(m[0], m[1], m[2], m[3]),
+            DVector4A::new(m[4], m[5], m[6], m[7]),
+            DVector4A::new(m[8], m[9], m[10], m[11]),
+            DVector4A::new(m[12], m[13], m[14], m[15]),
+        )
+    }
+
+    /// Returns the elements as a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    ///
+    /// let m = DMat4A::identity();
+    /// assert_eq!(m.to_cols_array()[5], 1.0);
+    /// assert_eq!(m.to_cols_array()[6], 0.0);
+    /// ```
+    pub fn to_cols_array(&self) -> [f64; 16] {
+        let mut m = [0.0; 16];
+        for (chunk, col) in m.chunks_exact_mut(4).zip(&self.cols) {
+            chunk.copy_from_slice(&col.to_array());
+        }
+        m
+    }
+
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(DMat4A::identity() * v, v);
+    /// ```
+    pub fn identity() -> Self {
+        Self::from_cols(
+            DVector4A::new(1.0, 0.0, 0.0, 0.0),
+            DVector4A::new(0.0, 1.0, 0.0, 0.0),
+            DVector4A::new(0.0, 0.0, 1.0, 0.0),
+            DVector4A::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let m = DMat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.transpose().cols[0], DVector4A::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        Mat4::from(*self).transpose().into()
+    }
+}
+
+impl From<Mat4<f64>> for DMat4A {
+    /// Loads a matrix into SIMD registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::dmat4a::DMat4A;
+    ///
+    /// assert_eq!(DMat4A::from(Mat4::identity()), DMat4A::identity());
+    /// ```
+    fn from(m: Mat4<f64>) -> Self {
+        Self {
+            cols: m.cols.map(DVector4A::from),
+        }
+    }
+}
+
+impl From<DMat4A> for Mat4<f64> {
+    /// Stores a SIMD matrix as a plain `Mat4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::dmat4a::DMat4A;
+    ///
+    /// assert_eq!(Mat4::from(DMat4A::identity()), Mat4::identity());
+    /// ```
+    fn from(m: DMat4A) -> Self {
+        Mat4 {
+            cols: m.cols.map(Vector4::from),
+        }
+    }
+}
+
+impl Mul<DVector4A> for DMat4A {
+    type Output = DVector4A;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let m = DMat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(
+    ///     m * DVector4A::new(1.0, 0.0, 0.0, 1.0),
+    ///     DVector4A::new(14.0, 16.0, 18.0, 20.0)
+    /// );
+    /// ```
+    #[inline]
+    fn mul(self, rhs: DVector4A) -> Self::Output {
+        // Summed column by column, which is the order `Mat4` adds each row's
+        // products in.
+        let [c0, c1, c2, c3] = self.cols.map(|col| col.0);
+        let v = rhs.0;
+        DVector4A(c0 * v.splat_x() + c1 * v.splat_y() + c2 * v.splat_z() + c3 * v.splat_w())
+    }
+}
+
+impl Mul for DMat4A {
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::dmat4a::DMat4A;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let scale = DMat4A::from_cols_array([
+    ///     2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// let translation = DMat4A::from_cols(
+    ///     DVector4A::new(1.0, 0.0, 0.0, 0.0),
+    ///     DVector4A::new(0.0, 1.0, 0.0, 0.0),
+    ///     DVector4A::new(0.0, 0.0, 1.0, 0.0),
+    ///     DVector4A::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// let m = translation * scale;
+    /// assert_eq!(
+    ///     m * DVector4A::new(1.0, 1.0, 1.0, 1.0),
+    ///     DVector4A::new(7.0, 8.0, 9.0, 1.0)
+    /// );
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            cols: rhs.cols.map(|col| self * col),
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use core::ops::Mul;
+
+use crate::error::VexelError;
+use crate::matrices::matrixmn::MatrixMN;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector2::Vector2;
+
+/// A 2x2 matrix stored in column-major order.
+///
+/// The memory layout matches what graphics APIs expect, so the matrix can be
+/// uploaded to a GPU buffer as-is.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat2<T> {
+    /// The columns of the matrix.
+    pub cols: [Vector2<T>; 2],
+}
+
+/// A `Mat2` with `f32` components.
+pub type Mat2f = Mat2<f32>;
+
+/// A `Mat2` with `f64` components.
+pub type Mat2d = Mat2<f64>;
+
+impl<T> Mat2<T> {
+    /// Creates a matrix from its two columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_cols(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(m.cols[1], Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn from_cols(x_axis: Vector2<T>, y_axis: Vector2<T>) -> Self {
+        Self {
+            cols: [x_axis, y_axis],
+        }
+    }
+
+    /// Creates a matrix from its two rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_rows(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    /// assert_eq!(m.cols[0], Vector2::new(1.0, 3.0));
+    /// ```
+    pub fn from_rows(r0: Vector2<T>, r1: Vector2<T>) -> Self {
+        Self::from_cols(Vector2::new(r0.x, r1.x), Vector2::new(r0.y, r1.y))
+    }
+
+    /// Creates a matrix from a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.col(1), Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn from_cols_array(m: [T; 4]) -> Self {
+        let [m00, m01, m10, m11] = m;
+        Self::from_cols(Vector2::new(m00, m01), Vector2::new(m10, m11))
+    }
+
+    /// Returns the matrix as a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.to_cols_array(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn to_cols_array(&self) -> [T; 4]
+    where
+        T: Copy,
+    {
+        let [c0, c1] = self.cols;
+        [c0.x, c0.y, c1.x, c1.y]
+    }
+
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::<f64>::identity();
+    /// assert_eq!(m.to_cols_array(), [1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector2::new(T::ONE, T::ONE))
+    }
+
+    /// Returns the matrix with every element set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::<f64>::zero();
+    /// assert_eq!(m.to_cols_array(), [0.0; 4]);
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector2::new(T::ZERO, T::ZERO))
+    }
+
+    /// Creates a matrix with the given diagonal and zeros elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_diagonal(Vector2::new(2.0, 3.0));
+    /// assert_eq!(m.to_cols_array(), [2.0, 0.0, 0.0, 3.0]);
+    /// ```
+    pub fn from_diagonal(diagonal: Vector2<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_cols(
+            Vector2::new(diagonal.x, T::ZERO),
+            Vector2::new(T::ZERO, diagonal.y),
+        )
+    }
+
+    /// Returns the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.col(0), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vector2<T>
+    where
+        T: Copy,
+    {
+        assert!(
+            index < 2,
+            "index out of bounds: Mat2 has 2 columns but the column index is {index}"
+        );
+        self.cols[index]
+    }
+
+    /// Returns the row at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.row(0), Vector2::new(1.0, 3.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vector2<T>
+    where
+        T: Copy,
+    {
+        let [c0, c1] = self.cols;
+        match index {
+            0 => Vector2::new(c0.x, c1.x),
+            1 => Vector2::new(c0.y, c1.y),
+            _ => panic!("index out of bounds: Mat2 has 2 rows but the row index is {index}"),
+        }
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.transpose().to_cols_array(), [1.0, 3.0, 2.0, 4.0]);
+    /// ```
+    pub fn transpose(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_cols(self.row(0), self.row(1))
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        self.cols[0].cross(&self.cols[1])
+    }
+
+    /// Computes the inverse of the matrix, or `None` if it is singular.
+    ///
+    /// The matrix counts as singular once it is within rounding of a
+    /// singular one, as judged by [`MatrixMN::inverse`], so float products
+    /// that only nearly cancel still give `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::from_cols_array([4.0, 3.0, 3.0, 2.0]);
+    /// let inv = m.inverse().unwrap();
+    /// assert_eq!(inv.to_cols_array(), [-2.0, 3.0, 3.0, -4.0]);
+    /// assert_eq!(m * inv, Mat2::identity());
+    ///
+    /// let singular = Mat2::from_cols_array([1.0, 2.0, 2.0, 4.0]);
+    /// assert!(singular.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let det = self.determinant();
+        if MatrixMN::from(*self).is_singular() {
+            return None;
+        }
+        let [c0, c1] = self.cols;
+        Some(Self::from_cols(
+            Vector2::new(c1.y / det, -c0.y / det),
+            Vector2::new(-c1.x / det, c0.x / det),
+        ))
+    }
+
+    /// Computes the inverse of the matrix, or returns an error if it is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let m = Mat2::from_cols_array([4.0, 3.0, 3.0, 2.0]);
+    /// assert_eq!(m * m.try_inverse()?, Mat2::identity());
+    ///
+    /// let singular = Mat2::from_cols_array([1.0, 2.0, 2.0, 4.0]);
+    /// assert_eq!(singular.try_inverse(), Err(VexelError::SingularMatrix));
+    /// # Ok::<(), VexelError>(())
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        self.inverse().ok_or(VexelError::SingularMatrix)
+    }
+}
+
+impl<T> Mul<Vector2<T>> for Mat2<T>
+where
+    T: Scalar,
+{
+    type Output = Vector2<T>;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let m = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m * Vector2::new(1.0, 1.0), Vector2::new(4.0, 6.0));
+    /// ```
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        let [c0, c1] = self.cols;
+        Vector2::new(c0.x * rhs.x + c1.x * rhs.y, c0.y * rhs.x + c1.y * rhs.y)
+    }
+}
+
+impl<T> Mul for Mat2<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    ///
+    /// let a = Mat2::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// let b = Mat2::from_cols_array([5.0, 6.0, 7.0, 8.0]);
+    /// assert_eq!((a * b).to_cols_array(), [23.0, 34.0, 31.0, 46.0]);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(self * rhs.cols[0], self * rhs.cols[1])
+    }
+}
@@ -0,0 +1,374 @@
+use core::ops::Mul;
+
+use crate::error::VexelError;
+use crate::matrices::matrixmn::MatrixMN;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector3::Vector3;
+
+/// A 3x3 matrix stored in column-major order.
+///
+/// The memory layout matches what graphics APIs expect, so the matrix can be
+/// uploaded to a GPU buffer as-is.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3<T> {
+    /// The columns of the matrix.
+    pub cols: [Vector3<T>; 3],
+}
+
+/// A `Mat3` with `f32` components.
+pub type Mat3f = Mat3<f32>;
+
+/// A `Mat3` with `f64` components.
+pub type Mat3d = Mat3<f64>;
+
+impl<T> Mat3<T> {
+    /// Creates a matrix from its three columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_cols(
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     Vector3::new(4.0, 5.0, 6.0),
+    ///     Vector3::new(7.0, 8.0, 9.0),
+    /// );
+    /// assert_eq!(m.cols[2], Vector3::new(7.0, 8.0, 9.0));
+    /// ```
+    pub fn from_cols(x_axis: Vector3<T>, y_axis: Vector3<T>, z_axis: Vector3<T>) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis],
+        }
+    }
+
+    /// Creates a matrix from its three rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_rows(
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     Vector3::new(4.0, 5.0, 6.0),
+    ///     Vector3::new(7.0, 8.0, 9.0),
+    /// );
+    /// assert_eq!(m.cols[0], Vector3::new(1.0, 4.0, 7.0));
+    /// ```
+    pub fn from_rows(r0: Vector3<T>, r1: Vector3<T>, r2: Vector3<T>) -> Self {
+        Self::from_cols(
+            Vector3::new(r0.x, r1.x, r2.x),
+            Vector3::new(r0.y, r1.y, r2.y),
+            Vector3::new(r0.z, r1.z, r2.z),
+        )
+    }
+
+    /// Creates a matrix from a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(m.col(1), Vector3::new(4.0, 5.0, 6.0));
+    /// ```
+    pub fn from_cols_array(m: [T; 9]) -> Self {
+        let [m00, m01, m02, m10, m11, m12, m20, m21, m22] = m;
+        Self::from_cols(
+            Vector3::new(m00, m01, m02),
+            Vector3::new(m10, m11, m12),
+            Vector3::new(m20, m21, m22),
+        )
+    }
+
+    /// Returns the matrix as a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    /// assert_eq!(Mat3::from_cols_array(a).to_cols_array(), a);
+    /// ```
+    pub fn to_cols_array(&self) -> [T; 9]
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2] = self.cols;
+        [c0.x, c0.y, c0.z, c1.x, c1.y, c1.z, c2.x, c2.y, c2.z]
+    }
+
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::<f64>::identity();
+    /// assert_eq!(m.row(1), Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector3::new(T::ONE, T::ONE, T::ONE))
+    }
+
+    /// Returns the matrix with every element set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let m = Mat3::<f64>::zero();
+    /// assert_eq!(m.to_cols_array(), [0.0; 9]);
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector3::new(T::ZERO, T::ZERO, T::ZERO))
+    }
+
+    /// Creates a matrix with the given diagonal and zeros elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_diagonal(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(m * Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_diagonal(diagonal: Vector3<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_cols(
+            Vector3::new(diagonal.x, T::ZERO, T::ZERO),
+            Vector3::new(T::ZERO, diagonal.y, T::ZERO),
+            Vector3::new(T::ZERO, T::ZERO, diagonal.z),
+        )
+    }
+
+    /// Returns the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(m.col(2), Vector3::new(7.0, 8.0, 9.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vector3<T>
+    where
+        T: Copy,
+    {
+        assert!(
+            index < 3,
+            "index out of bounds: Mat3 has 3 columns but the column index is {index}"
+        );
+        self.cols[index]
+    }
+
+    /// Returns the row at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(m.row(2), Vector3::new(3.0, 6.0, 9.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vector3<T>
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2] = self.cols;
+        match index {
+            0 => Vector3::new(c0.x, c1.x, c2.x),
+            1 => Vector3::new(c0.y, c1.y, c2.y),
+            2 => Vector3::new(c0.z, c1.z, c2.z),
+            _ => panic!("index out of bounds: Mat3 has 3 rows but the row index is {index}"),
+        }
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(
+    ///     m.transpose().to_cols_array(),
+    ///     [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+    /// );
+    /// ```
+    pub fn transpose(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let m = Mat3::from_cols_array([2.0, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0]);
+    /// assert_eq!(m.determinant(), 25.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        let [c0, c1, c2] = self.cols;
+        c2.dot(&c0.cross(&c1))
+    }
+
+    /// Computes the inverse of the matrix, or `None` if it is singular.
+    ///
+    /// The matrix counts as singular once it is within rounding of a
+    /// singular one, as judged by [`MatrixMN::inverse`], so float products
+    /// that only nearly cancel still give `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 0.0, 5.0, 2.0, 1.0, 6.0, 3.0, 4.0, 0.0]);
+    /// let inv = m.inverse().unwrap();
+    /// assert_eq!(
+    ///     inv.to_cols_array(),
+    ///     [-24.0, 20.0, -5.0, 18.0, -15.0, 4.0, 5.0, -4.0, 1.0]
+    /// );
+    /// assert_eq!(m * inv, Mat3::identity());
+    ///
+    /// let singular = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert!(singular.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let det = self.determinant();
+        if MatrixMN::from(*self).is_singular() {
+            return None;
+        }
+        let [c0, c1, c2] = self.cols;
+        let r0 = c1.cross(&c2);
+        let r1 = c2.cross(&c0);
+        let r2 = c0.cross(&c1);
+        Some(Self::from_rows(
+            Vector3::new(r0.x / det, r0.y / det, r0.z / det),
+            Vector3::new(r1.x / det, r1.y / det, r1.z / det),
+            Vector3::new(r2.x / det, r2.y / det, r2.z / det),
+        ))
+    }
+
+    /// Computes the inverse of the matrix, or returns an error if it is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::matrices::mat3::Mat3;
+    ///
+    /// let m = Mat3::from_cols_array([2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 8.0]);
+    /// assert_eq!(m * m.try_inverse()?, Mat3::identity());
+    ///
+    /// let singular = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(singular.try_inverse(), Err(VexelError::SingularMatrix));
+    /// # Ok::<(), VexelError>(())
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        self.inverse().ok_or(VexelError::SingularMatrix)
+    }
+}
+
+impl<T> Mul<Vector3<T>> for Mat3<T>
+where
+    T: Scalar,
+{
+    type Output = Vector3<T>;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(m * Vector3::new(1.0, 0.0, 1.0), Vector3::new(8.0, 10.0, 12.0));
+    /// ```
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        let [c0, c1, c2] = self.cols;
+        Vector3::new(
+            c0.x * rhs.x + c1.x * rhs.y + c2.x * rhs.z,
+            c0.y * rhs.x + c1.y * rhs.y + c2.y * rhs.z,
+            c0.z * rhs.x + c1.z * rhs.y + c2.z * rhs.z,
+        )
+    }
+}
+
+impl<T> Mul for Mat3<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let scale = Mat3::from_diagonal(Vector3::new(2.0, 2.0, 2.0));
+    /// let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// assert_eq!(
+    ///     (scale * m).to_cols_array(),
+    ///     [2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0]
+    /// );
+    /// assert_eq!(m * Mat3::identity(), m);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(self * rhs.cols[0], self * rhs.cols[1], self * rhs.cols[2])
+    }
+}
@@ -0,0 +1,473 @@
+use core::ops::Mul;
+
+use crate::error::VexelError;
+use crate::matrices::matrixmn::MatrixMN;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector4::Vector4;
+
+/// A 4x4 matrix stored in column-major order.
+///
+/// The memory layout matches what graphics APIs expect, so the matrix can be
+/// uploaded to a GPU buffer as-is.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4<T> {
+    /// The columns of the matrix.
+    pub cols: [Vector4<T>; 4],
+}
+
+/// A `Mat4` with `f32` components.
+pub type Mat4f = Mat4<f32>;
+
+/// A `Mat4` with `f64` components.
+pub type Mat4d = Mat4<f64>;
+
+impl<T> Mat4<T> {
+    /// Creates a matrix from its four columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_cols(
+    ///     Vector4::new(1.0, 0.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 1.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 0.0, 1.0, 0.0),
+    ///     Vector4::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// assert_eq!(m.cols[3], Vector4::new(5.0, 6.0, 7.0, 1.0));
+    /// ```
+    pub fn from_cols(
+        x_axis: Vector4<T>,
+        y_axis: Vector4<T>,
+        z_axis: Vector4<T>,
+        w_axis: Vector4<T>,
+    ) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis, w_axis],
+        }
+    }
+
+    /// Creates a matrix from its four rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_rows(
+    ///     Vector4::new(1.0, 0.0, 0.0, 5.0),
+    ///     Vector4::new(0.0, 1.0, 0.0, 6.0),
+    ///     Vector4::new(0.0, 0.0, 1.0, 7.0),
+    ///     Vector4::new(0.0, 0.0, 0.0, 1.0),
+    /// );
+    /// assert_eq!(m.cols[3], Vector4::new(5.0, 6.0, 7.0, 1.0));
+    /// ```
+    pub fn from_rows(r0: Vector4<T>, r1: Vector4<T>, r2: Vector4<T>, r3: Vector4<T>) -> Self {
+        Self::from_cols(
+            Vector4::new(r0.x, r1.x, r2.x, r3.x),
+            Vector4::new(r0.y, r1.y, r2.y, r3.y),
+            Vector4::new(r0.z, r1.z, r2.z, r3.z),
+            Vector4::new(r0.w, r1.w, r2.w, r3.w),
+        )
+    }
+
+    /// Creates a matrix from a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.col(1), Vector4::new(5.0, 6.0, 7.0, 8.0));
+    /// ```
+    pub fn from_cols_array(m: [T; 16]) -> Self {
+        let [m00, m01, m02, m03, m10, m11, m12, m13, m20, m21, m22, m23, m30, m31, m32, m33] = m;
+        Self::from_cols(
+            Vector4::new(m00, m01, m02, m03),
+            Vector4::new(m10, m11, m12, m13),
+            Vector4::new(m20, m21, m22, m23),
+            Vector4::new(m30, m31, m32, m33),
+        )
+    }
+
+    /// Returns the matrix as a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    ///
+    /// let a = [
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ];
+    /// assert_eq!(Mat4::from_cols_array(a).to_cols_array(), a);
+    /// ```
+    pub fn to_cols_array(&self) -> [T; 16]
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2, c3] = self.cols;
+        [
+            c0.x, c0.y, c0.z, c0.w, c1.x, c1.y, c1.z, c1.w, c2.x, c2.y, c2.z, c2.w, c3.x, c3.y,
+            c3.z, c3.w,
+        ]
+    }
+
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::<f64>::identity();
+    /// assert_eq!(m.row(3), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector4::new(T::ONE, T::ONE, T::ONE, T::ONE))
+    }
+
+    /// Returns the matrix with every element set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    ///
+    /// let m = Mat4::<f64>::zero();
+    /// assert_eq!(m.to_cols_array(), [0.0; 16]);
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(Vector4::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO))
+    }
+
+    /// Creates a matrix with the given diagonal and zeros elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_diagonal(Vector4::new(2.0, 3.0, 4.0, 1.0));
+    /// assert_eq!(
+    ///     m * Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ///     Vector4::new(2.0, 3.0, 4.0, 1.0)
+    /// );
+    /// ```
+    pub fn from_diagonal(diagonal: Vector4<T>) -> Self
+    where
+        T: Scalar,
+    {
+        let zero = T::ZERO;
+        Self::from_cols(
+            Vector4::new(diagonal.x, zero, zero, zero),
+            Vector4::new(zero, diagonal.y, zero, zero),
+            Vector4::new(zero, zero, diagonal.z, zero),
+            Vector4::new(zero, zero, zero, diagonal.w),
+        )
+    }
+
+    /// Returns the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::<f64>::identity();
+    /// assert_eq!(m.col(3), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vector4<T>
+    where
+        T: Copy,
+    {
+        assert!(
+            index < 4,
+            "index out of bounds: Mat4 has 4 columns but the column index is {index}"
+        );
+        self.cols[index]
+    }
+
+    /// Returns the row at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.row(0), Vector4::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vector4<T>
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2, c3] = self.cols;
+        match index {
+            0 => Vector4::new(c0.x, c1.x, c2.x, c3.x),
+            1 => Vector4::new(c0.y, c1.y, c2.y, c3.y),
+            2 => Vector4::new(c0.z, c1.z, c2.z, c3.z),
+            3 => Vector4::new(c0.w, c1.w, c2.w, c3.w),
+            _ => panic!("index out of bounds: Mat4 has 4 rows but the row index is {index}"),
+        }
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    ///
+    /// let m = Mat4::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.transpose().col(0), m.row(0));
+    /// assert_eq!(m.transpose().transpose(), m);
+    /// ```
+    pub fn transpose(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_diagonal(Vector4::new(2.0, 3.0, 4.0, 5.0));
+    /// assert_eq!(m.determinant(), 120.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        let (s, c) = self.sub_determinants();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Computes the inverse of the matrix, or `None` if it is singular.
+    ///
+    /// The matrix counts as singular once it is within rounding of a
+    /// singular one, as judged by [`MatrixMN::inverse`], so float products
+    /// that only nearly cancel still give `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let translation = Mat4::from_cols(
+    ///     Vector4::new(1.0, 0.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 1.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 0.0, 1.0, 0.0),
+    ///     Vector4::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// let inv = translation.inverse().unwrap();
+    /// assert_eq!(inv.col(3), Vector4::new(-5.0, -6.0, -7.0, 1.0));
+    /// assert_eq!(translation * inv, Mat4::identity());
+    ///
+    /// assert!(Mat4::<f64>::zero().inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let (s, c) = self.sub_determinants();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if MatrixMN::from(*self).is_singular() {
+            return None;
+        }
+
+        let [c0, c1, c2, c3] = self.cols;
+        let (a00, a10, a20, a30) = (c0.x, c0.y, c0.z, c0.w);
+        let (a01, a11, a21, a31) = (c1.x, c1.y, c1.z, c1.w);
+        let (a02, a12, a22, a32) = (c2.x, c2.y, c2.z, c2.w);
+        let (a03, a13, a23, a33) = (c3.x, c3.y, c3.z, c3.w);
+
+        Some(Self::from_rows(
+            Vector4::new(
+                (a11 * c[5] - a12 * c[4] + a13 * c[3]) / det,
+                (-a01 * c[5] + a02 * c[4] - a03 * c[3]) / det,
+                (a31 * s[5] - a32 * s[4] + a33 * s[3]) / det,
+                (-a21 * s[5] + a22 * s[4] - a23 * s[3]) / det,
+            ),
+            Vector4::new(
+                (-a10 * c[5] + a12 * c[2] - a13 * c[1]) / det,
+                (a00 * c[5] - a02 * c[2] + a03 * c[1]) / det,
+                (-a30 * s[5] + a32 * s[2] - a33 * s[1]) / det,
+                (a20 * s[5] - a22 * s[2] + a23 * s[1]) / det,
+            ),
+            Vector4::new(
+                (a10 * c[4] - a11 * c[2] + a13 * c[0]) / det,
+                (-a00 * c[4] + a01 * c[2] - a03 * c[0]) / det,
+                (a30 * s[4] - a31 * s[2] + a33 * s[0]) / det,
+                (-a20 * s[4] + a21 * s[2] - a23 * s[0]) / det,
+            ),
+            Vector4::new(
+                (-a10 * c[3] + a11 * c[1] - a12 * c[0]) / det,
+                (a00 * c[3] - a01 * c[1] + a02 * c[0]) / det,
+                (-a30 * s[3] + a31 * s[1] - a32 * s[0]) / det,
+                (a20 * s[3] - a21 * s[1] + a22 * s[0]) / det,
+            ),
+        ))
+    }
+
+    /// Computes the inverse of the matrix, or returns an error if it is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::matrices::mat4::Mat4;
+    ///
+    /// let m = Mat4::from_cols_array([
+    ///     2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// assert_eq!(m.try_inverse()? * m, Mat4::identity());
+    /// assert_eq!(Mat4::<f64>::zero().try_inverse(), Err(VexelError::SingularMatrix));
+    /// # Ok::<(), VexelError>(())
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        self.inverse().ok_or(VexelError::SingularMatrix)
+    }
+
+    /// Returns the 2x2 sub-determinants of the top two rows (`s`) and the
+    /// bottom two rows (`c`) used by the Laplace expansion of the determinant.
+    fn sub_determinants(&self) -> ([T; 6], [T; 6])
+    where
+        T: Signed,
+    {
+        let [c0, c1, c2, c3] = self.cols;
+        let (a00, a10, a20, a30) = (c0.x, c0.y, c0.z, c0.w);
+        let (a01, a11, a21, a31) = (c1.x, c1.y, c1.z, c1.w);
+        let (a02, a12, a22, a32) = (c2.x, c2.y, c2.z, c2.w);
+        let (a03, a13, a23, a33) = (c3.x, c3.y, c3.z, c3.w);
+
+        let s = [
+            a00 * a11 - a10 * a01,
+            a00 * a12 - a10 * a02,
+            a00 * a13 - a10 * a03,
+            a01 * a12 - a11 * a02,
+            a01 * a13 - a11 * a03,
+            a02 * a13 - a12 * a03,
+        ];
+        let c = [
+            a20 * a31 - a30 * a21,
+            a20 * a32 - a30 * a22,
+            a20 * a33 - a30 * a23,
+            a21 * a32 - a31 * a22,
+            a21 * a33 - a31 * a23,
+            a22 * a33 - a32 * a23,
+        ];
+        (s, c)
+    }
+}
+
+impl<T> Mul<Vector4<T>> for Mat4<T>
+where
+    T: Scalar,
+{
+    type Output = Vector4<T>;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(
+    ///     m * Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(14.0, 16.0, 18.0, 20.0)
+    /// );
+    /// ```
+    fn mul(self, rhs: Vector4<T>) -> Self::Output {
+        let [c0, c1, c2, c3] = self.cols;
+        Vector4::new(
+            c0.x * rhs.x + c1.x * rhs.y + c2.x * rhs.z + c3.x * rhs.w,
+            c0.y * rhs.x + c1.y * rhs.y + c2.y * rhs.z + c3.y * rhs.w,
+            c0.z * rhs.x + c1.z * rhs.y + c2.z * rhs.z + c3.z * rhs.w,
+            c0.w * rhs.x + c1.w * rhs.y + c2.w * rhs.z + c3.w * rhs.w,
+        )
+    }
+}
+
+impl<T> Mul for Mat4<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let scale = Mat4::from_diagonal(Vector4::new(2.0, 2.0, 2.0, 1.0));
+    /// let translation = Mat4::from_cols(
+    ///     Vector4::new(1.0, 0.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 1.0, 0.0, 0.0),
+    ///     Vector4::new(0.0, 0.0, 1.0, 0.0),
+    ///     Vector4::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// let m = translation * scale;
+    /// assert_eq!(
+    ///     m * Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ///     Vector4::new(7.0, 8.0, 9.0, 1.0)
+    /// );
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self * rhs.cols[0],
+            self * rhs.cols[1],
+            self * rhs.cols[2],
+            self * rhs.cols[3],
+        )
+    }
+}
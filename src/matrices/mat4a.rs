@@ -0,0 +1,236 @@
+use core::ops::Mul;
+
+use crate::matrices::mat4::Mat4;
+use crate::vectors::vector4::Vector4;
+use crate::vectors::vector4a::Vector4A;
+
+/// A 4x4 `f32` matrix whose columns are stored in SIMD registers.
+///
+/// Products with `Mat4A` give bit-for-bit the same results as the matching
+/// [`Mat4<f32>`] products: each column is scaled and summed in the same order
+/// as the scalar code, just four rows at a time.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::matrices::mat4::Mat4;
+/// use vexel::matrices::mat4a::Mat4A;
+///
+/// let a = Mat4::from_cols_array([
+///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+/// ]);
+/// let b = a.transpose();
+/// assert_eq!(Mat4::from(Mat4A::from(a) * Mat4A::from(b)), a * b);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4A {
+    /// The columns of the matrix.
+    pub cols: [Vector4A; 4],
+}
+
+impl Mat4A {
+    /// Creates a matrix from its four columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let m = Mat4A::from_cols(
+    ///     Vector4A::new(1.0, 0.0, 0.0, 0.0),
+    ///     Vector4A::new(0.0, 1.0, 0.0, 0.0),
+    ///     Vector4A::new(0.0, 0.0, 1.0, 0.0),
+    ///     Vector4A::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// assert_eq!(m.cols[3], Vector4A::new(5.0, 6.0, 7.0, 1.0));
+    /// ```
+    pub fn from_cols(
+        x_axis: Vector4A,
+        y_axis: Vector4A,
+        z_axis: Vector4A,
+        w_axis: Vector4A,
+    ) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis, w_axis],
+        }
+    }
+
+    /// Creates a matrix from a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let m = Mat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.cols[1], Vector4A::new(5.0, 6.0, 7.0, 8.0));
+    /// ```
+    pub fn from_cols_array(m: [f32; 16]) -> Self {
+        Self::from_cols(
+            Vector4A::new(m[0], m[1], m[2], m[3]),
+            Vector4A::new(m[4], m[5], m[6], m[7]),
+            Vector4A::new(m[8], m[9], m[10], m[11]),
+            Vector4A::new(m[12], m[13], m[14], m[15]),
+        )
+    }
+
+    /// Returns the elements as a column-major array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    ///
+    /// let m = Mat4A::identity();
+    /// assert_eq!(m.to_cols_array()[5], 1.0);
+    /// assert_eq!(m.to_cols_array()[6], 0.0);
+    /// ```
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let mut m = [0.0; 16];
+        for (chunk, col) in m.chunks_exact_mut(4).zip(&self.cols) {
+            chunk.copy_from_slice(&col.to_array());
+        }
+        m
+    }
+
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let v = Vector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(Mat4A::identity() * v, v);
+    /// ```
+    pub fn identity() -> Self {
+        Self::from_cols(
+            Vector4A::new(1.0, 0.0, 0.0, 0.0),
+            Vector4A::new(0.0, 1.0, 0.0, 0.0),
+            Vector4A::new(0.0, 0.0, 1.0, 0.0),
+            Vector4A::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let m = Mat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.transpose().cols[0], Vector4A::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        Mat4::from(*self).transpose().into()
+    }
+}
+
+impl From<Mat4<f32>> for Mat4A {
+    /// Loads a matrix into SIMD registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::mat4a::Mat4A;
+    ///
+    /// assert_eq!(Mat4A::from(Mat4::identity()), Mat4A::identity());
+    /// ```
+    fn from(m: Mat4<f32>) -> Self {
+        Self {
+            cols: m.cols.map(Vector4A::from),
+        }
+    }
+}
+
+impl From<Mat4A> for Mat4<f32> {
+    /// Stores a SIMD matrix as a plain `Mat4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::mat4a::Mat4A;
+    ///
+    /// assert_eq!(Mat4::from(Mat4A::identity()), Mat4::identity());
+    /// ```
+    fn from(m: Mat4A) -> Self {
+        Mat4 {
+            cols: m.cols.map(Vector4::from),
+        }
+    }
+}
+
+impl Mul<Vector4A> for Mat4A {
+    type Output = Vector4A;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let m = Mat4A::from_cols_array([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(
+    ///     m * Vector4A::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4A::new(14.0, 16.0, 18.0, 20.0)
+    /// );
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Vector4A) -> Self::Output {
+        // Summed column by column, which is the order `Mat4` adds each row's
+        // products in.
+        let [c0, c1, c2, c3] = self.cols.map(|col| col.0);
+        let v = rhs.0;
+        Vector4A(c0 * v.splat_x() + c1 * v.splat_y() + c2 * v.splat_z() + c3 * v.splat_w())
+    }
+}
+
+impl Mul for Mat4A {
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4a::Mat4A;
+    /// use vexel::vectors::vector4a::Vector4A;
+    ///
+    /// let scale = Mat4A::from_cols_array([
+    ///     2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// let translation = Mat4A::from_cols(
+    ///     Vector4A::new(1.0, 0.0, 0.0, 0.0),
+    ///     Vector4A::new(0.0, 1.0, 0.0, 0.0),
+    ///     Vector4A::new(0.0, 0.0, 1.0, 0.0),
+    ///     Vector4A::new(5.0, 6.0, 7.0, 1.0),
+    /// );
+    /// let m = translation * scale;
+    /// assert_eq!(
+    ///     m * Vector4A::new(1.0, 1.0, 1.0, 1.0),
+    ///     Vector4A::new(7.0, 8.0, 9.0, 1.0)
+    /// );
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            cols: rhs.cols.map(|col| self * col),
+        }
+    }
+}
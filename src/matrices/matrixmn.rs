@@ -0,0 +1,608 @@
+use core::ops::{Add, Mul};
+
+use crate::error::VexelError;
+use crate::matrices::mat2::Mat2;
+use crate::matrices::mat3::Mat3;
+use crate::matrices::mat4::Mat4;
+use crate::scalar::{Real, Scalar};
+use crate::vectors::vectorn::VectorN;
+
+/// An `R`x`C` matrix stored in column-major order, with its dimensions fixed
+/// at compile time.
+///
+/// `MatrixMN` is the [`VectorN`] counterpart of [`Mat2`], [`Mat3`] and
+/// [`Mat4`]: the covariances, Jacobians and gains of a 6- or 12-dimensional
+/// filter fit in it, and square ones of size two to four convert to and from
+/// the fixed-size matrices with `From`. Multiplying matrices or vectors whose
+/// dimensions don't line up is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::matrices::matrixmn::MatrixMN;
+/// use vexel::vectors::vectorn::VectorN;
+///
+/// // Maps a 3D position and velocity to the observed position.
+/// let observe: MatrixMN<f64, 3, 6> = MatrixMN::from_fn(|row, col| (row == col) as u8 as f64);
+/// let state = VectorN::new([1.0, 2.0, 3.0, 0.1, 0.2, 0.3]);
+/// assert_eq!(observe * state, VectorN::new([1.0, 2.0, 3.0]));
+/// ```
+///
+/// ```compile_fail
+/// use vexel::matrices::matrixmn::MatrixMN;
+/// use vexel::vectors::vectorn::VectorN;
+///
+/// let observe = MatrixMN::<f64, 3, 6>::zero();
+/// let _ = observe * VectorN::new([1.0, 2.0, 3.0]);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixMN<T, const R: usize, const C: usize> {
+    /// The columns of the matrix.
+    pub cols: [VectorN<T, R>; C],
+}
+
+impl<T, const R: usize, const C: usize> MatrixMN<T, R, C> {
+    /// Creates a matrix from its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_cols([
+    ///     VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]),
+    ///     VectorN::new([6.0, 7.0, 8.0, 9.0, 10.0]),
+    /// ]);
+    /// assert_eq!(m.cols[1], VectorN::new([6.0, 7.0, 8.0, 9.0, 10.0]));
+    /// ```
+    pub fn from_cols(cols: [VectorN<T, R>; C]) -> Self {
+        Self { cols }
+    }
+
+    /// Creates a matrix from its rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_rows([
+    ///     VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]),
+    ///     VectorN::new([6.0, 7.0, 8.0, 9.0, 10.0]),
+    /// ]);
+    /// assert_eq!(m.cols[0], VectorN::new([1.0, 6.0]));
+    /// ```
+    pub fn from_rows(rows: [VectorN<T, C>; R]) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_fn(|row, col| rows[row][col])
+    }
+
+    /// Creates a matrix whose element in row `row` and column `col` is
+    /// `f(row, col)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// assert_eq!(m.row(1), VectorN::new([10.0, 11.0, 12.0, 13.0, 14.0]));
+    /// ```
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self::from_cols(core::array::from_fn(|col| {
+            VectorN::from_fn(|row| f(row, col))
+        }))
+    }
+
+    /// Creates a matrix from an array of its columns.
+    ///
+    /// Stable Rust can't size a flat array of `R * C` elements, so unlike
+    /// [`Mat3::from_cols_array`] the array is nested, one inner array per
+    /// column. The memory layout is the same column-major one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_cols_array([[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]]);
+    /// assert_eq!(m.col(1), VectorN::new([6.0, 7.0, 8.0, 9.0, 10.0]));
+    /// ```
+    pub fn from_cols_array(m: [[T; R]; C]) -> Self {
+        Self::from_cols(m.map(VectorN::new))
+    }
+
+    /// Returns the matrix as an array of its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    ///
+    /// let a = [[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]];
+    /// assert_eq!(MatrixMN::from_cols_array(a).to_cols_array(), a);
+    /// ```
+    pub fn to_cols_array(&self) -> [[T; R]; C]
+    where
+        T: Copy,
+    {
+        self.cols.map(|col| col.components)
+    }
+
+    /// Returns a matrix with every element set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::<f64, 6, 2>::zero();
+    /// assert_eq!(m.cols, [VectorN::zero(); 2]);
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_cols([VectorN::zero(); C])
+    }
+
+    /// Returns the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `C` or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// assert_eq!(m.col(4), VectorN::new([4.0, 14.0]));
+    /// ```
+    pub fn col(&self, index: usize) -> VectorN<T, R>
+    where
+        T: Copy,
+    {
+        assert!(
+            index < C,
+            "index out of bounds: MatrixMN has {C} columns but the column index is {index}"
+        );
+        self.cols[index]
+    }
+
+    /// Returns the row at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `R` or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// assert_eq!(m.row(0), VectorN::new([0.0, 1.0, 2.0, 3.0, 4.0]));
+    /// ```
+    pub fn row(&self, index: usize) -> VectorN<T, C>
+    where
+        T: Copy,
+    {
+        assert!(
+            index < R,
+            "index out of bounds: MatrixMN has {R} rows but the row index is {index}"
+        );
+        VectorN::from_fn(|col| self.cols[col][index])
+    }
+
+    /// Returns the transpose of the matrix, swapping its rows and columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    ///
+    /// let m: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// let t: MatrixMN<f64, 5, 2> = m.transpose();
+    /// assert_eq!(t.cols[1], m.row(1));
+    /// assert_eq!(t.transpose(), m);
+    /// ```
+    pub fn transpose(&self) -> MatrixMN<T, C, R>
+    where
+        T: Copy,
+    {
+        MatrixMN::from_fn(|row, col| self.cols[row][col])
+    }
+}
+
+impl<T, const N: usize> MatrixMN<T, N, N> {
+    /// Returns the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::<f64, 6, 6>::identity();
+    /// let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(m * v, v);
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_diagonal(VectorN::splat(T::ONE))
+    }
+
+    /// Creates a matrix with `diagonal` on its main diagonal and zero
+    /// everywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_diagonal(VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// assert_eq!(m.row(4), VectorN::new([0.0, 0.0, 0.0, 0.0, 5.0, 0.0]));
+    /// ```
+    pub fn from_diagonal(diagonal: VectorN<T, N>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::from_fn(|row, col| if row == col { diagonal[row] } else { T::ZERO })
+    }
+
+    /// Computes the determinant of the matrix by Gaussian elimination with
+    /// partial pivoting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_diagonal(VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// assert_eq!(m.determinant(), 720.0);
+    ///
+    /// let swapped = MatrixMN::from_cols_array([[0.0, 1.0], [1.0, 0.0]]);
+    /// assert_eq!(swapped.determinant(), -1.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Real,
+    {
+        let mut a = self.transpose().to_cols_array();
+        let mut det = T::ONE;
+        for k in 0..N {
+            let pivot = pivot_row(&a, k);
+            if a[pivot][k] == T::ZERO {
+                return T::ZERO;
+            }
+            if pivot != k {
+                a.swap(pivot, k);
+                det = -det;
+            }
+            det *= a[k][k];
+
+            let (top, bottom) = a.split_at_mut(k + 1);
+            let pivot_row = &top[k];
+            for row in bottom {
+                let factor = row[k] / pivot_row[k];
+                for (x, &p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * p;
+                }
+            }
+        }
+        det
+    }
+
+    /// Computes the inverse of the matrix by Gaussian elimination with
+    /// partial pivoting, or `None` if it is singular.
+    ///
+    /// Rounding rarely leaves a pivot of a singular matrix at exactly zero,
+    /// so the rows and columns are first scaled so that each has a largest
+    /// element of one, and the matrix counts as singular once a pivot falls
+    /// within `N` epsilons. Scaling a row or column never makes a matrix
+    /// singular, so badly scaled but well-conditioned matrices still invert.
+    ///
+    /// [`Mat2`], [`Mat3`] and [`Mat4`] judge singularity the same way, so a
+    /// matrix and its conversion to or from those types always agree on
+    /// whether it has an inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m = MatrixMN::from_diagonal(VectorN::new([1.0, 2.0, 4.0, 8.0, 0.5, 0.25]));
+    /// let inv = m.inverse().unwrap();
+    /// assert_eq!(inv.cols[3], VectorN::new([0.0, 0.0, 0.0, 0.125, 0.0, 0.0]));
+    /// assert_eq!(m * inv, MatrixMN::identity());
+    ///
+    /// let singular = MatrixMN::from_cols_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert!(singular.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let inv = invert_rows(self.transpose().to_cols_array())?;
+        Some(Self::from_cols_array(inv).transpose())
+    }
+
+    /// Returns whether [`inverse`](Self::inverse) would find the matrix
+    /// singular, without computing the inverse.
+    ///
+    /// The fixed-size matrices keep their closed-form inverses but ask this
+    /// before dividing by their determinants, so they agree with
+    /// `MatrixMN` on which matrices have an inverse.
+    pub(crate) fn is_singular(&self) -> bool
+    where
+        T: Real,
+    {
+        let mut a = self.transpose().to_cols_array();
+        equilibrate(&mut a).is_none() || !eliminate(&mut a, &mut [[T::ZERO; 0]; N])
+    }
+
+    /// Computes the inverse of the matrix, or returns an error if it is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let covariance = MatrixMN::from_diagonal(VectorN::new([4.0, 4.0, 4.0, 0.25, 0.25, 0.25]));
+    /// assert_eq!(covariance * covariance.try_inverse()?, MatrixMN::identity());
+    ///
+    /// let singular = MatrixMN::<f64, 6, 6>::zero();
+    /// assert_eq!(singular.try_inverse(), Err(VexelError::SingularMatrix));
+    /// # Ok::<(), VexelError>(())
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        self.inverse().ok_or(VexelError::SingularMatrix)
+    }
+}
+
+/// Scales the rows of `a` and then its columns so that each has a largest
+/// element of one, returning the row and column scales, or `None` if a row
+/// or column of zeros leaves nothing to scale by.
+///
+/// Scaling a row or column never makes a matrix singular, so judging the
+/// scaled matrix keeps badly scaled but well-conditioned matrices invertible.
+fn equilibrate<T: Real, const N: usize>(a: &mut [[T; N]; N]) -> Option<([T; N], [T; N])> {
+    let row_scales: [T; N] = core::array::from_fn(|i| largest(a[i].iter()));
+    if row_scales.contains(&T::ZERO) {
+        return None;
+    }
+    for (row, &scale) in a.iter_mut().zip(&row_scales) {
+        for x in row {
+            *x /= scale;
+        }
+    }
+    let col_scales: [T; N] = core::array::from_fn(|j| largest(a.iter().map(|row| &row[j])));
+    if col_scales.contains(&T::ZERO) {
+        return None;
+    }
+    for row in a.iter_mut() {
+        for (x, &scale) in row.iter_mut().zip(&col_scales) {
+            *x /= scale;
+        }
+    }
+    Some((row_scales, col_scales))
+}
+
+/// Reduces the equilibrated rows `a` to upper triangular form by Gaussian
+/// elimination with partial pivoting, applying the same row operations to
+/// `b`. Returns `false` once a pivot falls within `N` epsilons, which is
+/// where every matrix type draws the line between invertible and singular.
+fn eliminate<T: Real, const N: usize, const M: usize>(
+    a: &mut [[T; N]; N],
+    b: &mut [[T; M]; N],
+) -> bool {
+    let tolerance = T::EPSILON * T::from_f64(N as f64);
+    for k in 0..N {
+        let pivot = pivot_row(a, k);
+        if a[pivot][k].abs() <= tolerance || a[pivot][k].is_nan() {
+            return false;
+        }
+        a.swap(pivot, k);
+        b.swap(pivot, k);
+
+        let (pivot_a, pivot_b) = (a[k], b[k]);
+        for (row, b_row) in a.iter_mut().zip(b.iter_mut()).skip(k + 1) {
+            let factor = row[k] / pivot_a[k];
+            if factor == T::ZERO {
+                continue;
+            }
+            for (x, &p) in row.iter_mut().zip(&pivot_a).skip(k) {
+                *x -= factor * p;
+            }
+            for (x, &p) in b_row.iter_mut().zip(&pivot_b) {
+                *x -= factor * p;
+            }
+        }
+    }
+    true
+}
+
+/// Inverts the square matrix whose rows are `a`, returning the rows of the
+/// inverse, or `None` if it is singular.
+fn invert_rows<T: Real, const N: usize>(mut a: [[T; N]; N]) -> Option<[[T; N]; N]> {
+    let (row_scales, col_scales) = equilibrate(&mut a)?;
+    let mut inv: [[T; N]; N] =
+        core::array::from_fn(|i| core::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }));
+    if !eliminate(&mut a, &mut inv) {
+        return None;
+    }
+    for k in (0..N).rev() {
+        let (top, solved) = inv.split_at_mut(k + 1);
+        let row = &mut top[k];
+        for (&factor, done) in a[k].iter().skip(k + 1).zip(solved.iter()) {
+            for (x, &p) in row.iter_mut().zip(done) {
+                *x -= factor * p;
+            }
+        }
+        let p = a[k][k];
+        for x in row {
+            *x /= p;
+        }
+    }
+    // `inv` holds the rows of the inverse of the scaled matrix. Undoing the
+    // scaling divides its rows by the column scales and its columns by the
+    // row scales.
+    for (row, &col_scale) in inv.iter_mut().zip(&col_scales) {
+        for (x, &row_scale) in row.iter_mut().zip(&row_scales) {
+            *x /= col_scale * row_scale;
+        }
+    }
+    Some(inv)
+}
+
+/// Returns the largest magnitude among `elements`, ignoring NaNs.
+fn largest<'a, T: Real + 'a>(elements: impl Iterator<Item = &'a T>) -> T {
+    elements.fold(T::ZERO, |m, x| m.max(x.abs()))
+}
+
+/// Returns the row at or below `k` with the largest element in column `k`,
+/// the pivot that keeps elimination stable.
+fn pivot_row<T: Real, const N: usize>(a: &[[T; N]; N], k: usize) -> usize {
+    let mut pivot = k;
+    for i in k + 1..N {
+        if a[i][k].abs() > a[pivot][k].abs() {
+            pivot = i;
+        }
+    }
+    pivot
+}
+
+impl<T, const R: usize, const C: usize> Mul<VectorN<T, C>> for MatrixMN<T, R, C>
+where
+    T: Scalar,
+{
+    type Output = VectorN<T, R>;
+
+    /// Transforms a vector by the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let m: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// let v = VectorN::new([1.0, 0.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(m * v, VectorN::new([4.0, 24.0]));
+    /// ```
+    fn mul(self, rhs: VectorN<T, C>) -> Self::Output {
+        VectorN::from_fn(|row| {
+            self.cols
+                .iter()
+                .zip(rhs.components)
+                .map(|(col, x)| col[row] * x)
+                .reduce(Add::add)
+                .unwrap_or(T::ZERO)
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize, const K: usize> Mul<MatrixMN<T, C, K>> for MatrixMN<T, R, C>
+where
+    T: Scalar,
+{
+    type Output = MatrixMN<T, R, K>;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::matrixmn::MatrixMN;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let a: MatrixMN<f64, 2, 5> = MatrixMN::from_fn(|row, col| (10 * row + col) as f64);
+    /// let b: MatrixMN<f64, 5, 1> = MatrixMN::from_cols([VectorN::splat(1.0)]);
+    /// assert_eq!((a * b).cols[0], VectorN::new([10.0, 60.0]));
+    /// assert_eq!(a * MatrixMN::identity(), a);
+    /// ```
+    fn mul(self, rhs: MatrixMN<T, C, K>) -> Self::Output {
+        MatrixMN::from_cols(rhs.cols.map(|col| self * col))
+    }
+}
+
+/// Implements conversions in both directions between a fixed-size matrix
+/// and the square `MatrixMN` of the same size.
+macro_rules! impl_fixed_size_conversions {
+    ($($matrix:ident, $module:ident, $n:literal, $array:literal;)+) => {
+        $(
+            impl<T> From<$matrix<T>> for MatrixMN<T, $n, $n> {
+                #[doc = concat!("Converts a `", stringify!($matrix), "` into a `MatrixMN`.")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                #[doc = concat!(
+                    "use vexel::matrices::", stringify!($module), "::", stringify!($matrix), ";"
+                )]
+                /// use vexel::matrices::matrixmn::MatrixMN;
+                ///
+                #[doc = concat!("let m = ", stringify!($matrix), "::from_cols_array(", $array, ");")]
+                /// let n = MatrixMN::from(m);
+                /// assert_eq!(n.col(1), m.col(1).into());
+                /// assert_eq!(n.row(0), m.row(0).into());
+                /// ```
+                fn from(m: $matrix<T>) -> Self {
+                    Self::from_cols(m.cols.map(VectorN::from))
+                }
+            }
+
+            impl<T> From<MatrixMN<T, $n, $n>> for $matrix<T> {
+                #[doc = concat!("Converts a `MatrixMN` into a `", stringify!($matrix), "`.")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                #[doc = concat!(
+                    "use vexel::matrices::", stringify!($module), "::", stringify!($matrix), ";"
+                )]
+                /// use vexel::matrices::matrixmn::MatrixMN;
+                ///
+                #[doc = concat!(
+                    "assert_eq!(", stringify!($matrix), "::from(MatrixMN::identity()), ",
+                    stringify!($matrix), "::<f64>::identity());"
+                )]
+                /// ```
+                fn from(m: MatrixMN<T, $n, $n>) -> Self {
+                    Self {
+                        cols: m.cols.map(Into::into),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_size_conversions! {
+    Mat2, mat2, 2, "[1.0, 2.0, 3.0, 4.0]";
+    Mat3, mat3, 3, "[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]";
+    Mat4, mat4, 4, "[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]";
+}
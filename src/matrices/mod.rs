@@ -0,0 +1,7 @@
+pub mod dmat4a;
+pub mod mat2;
+pub mod mat3;
+pub mod mat4;
+pub mod mat4a;
+pub mod matrixmn;
+pub mod projection;
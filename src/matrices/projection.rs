@@ -0,0 +1,569 @@
+//! Camera matrices and conversions between world and window coordinates.
+//!
+//! The view constructors come in right-handed (`_rh`, camera looks down `-z`)
+//! and left-handed (`_lh`, camera looks down `+z`) flavours. Projections take
+//! a [`DepthRange`] choosing the clip-space depth convention of the target
+//! graphics API.
+
+use crate::matrices::mat4::Mat4;
+use crate::scalar::Real;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// The range normalized device depth is mapped to by a projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// Depth in `-1..=1`, as used by OpenGL.
+    NegativeOneToOne,
+    /// Depth in `0..=1`, as used by Vulkan, Direct3D, Metal and WebGPU.
+    ZeroToOne,
+}
+
+impl DepthRange {
+    /// Maps a normalized device depth to window depth in `0..=1`.
+    fn ndc_to_window<T: Real>(self, z: T) -> T {
+        match self {
+            DepthRange::NegativeOneToOne => (z + T::ONE) * T::HALF,
+            DepthRange::ZeroToOne => z,
+        }
+    }
+
+    /// Maps a window depth in `0..=1` back to normalized device depth.
+    fn window_to_ndc<T: Real>(self, z: T) -> T {
+        match self {
+            DepthRange::NegativeOneToOne => z * T::TWO - T::ONE,
+            DepthRange::ZeroToOne => z,
+        }
+    }
+}
+
+/// A rectangle of the render target that normalized device coordinates are
+/// mapped onto.
+///
+/// Window coordinates follow the OpenGL convention: the origin is the
+/// bottom-left corner and `y` grows upwards. Flip `y` against the target
+/// height when working with top-left origins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport<T> {
+    /// The left edge of the viewport.
+    pub x: T,
+    /// The bottom edge of the viewport.
+    pub y: T,
+    /// The width of the viewport.
+    pub width: T,
+    /// The height of the viewport.
+    pub height: T,
+}
+
+impl<T> Viewport<T> {
+    /// Creates a viewport from its origin and size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::projection::Viewport;
+    ///
+    /// let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    /// assert_eq!(viewport.width, 800.0);
+    /// ```
+    pub fn new(x: T, y: T, width: T, height: T) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Projects a world-space point into window coordinates.
+    ///
+    /// The returned `z` is the window depth in `0..=1`. Returns `None` if the
+    /// point lies on the plane of the camera, where the projection is
+    /// undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::{DepthRange, Viewport};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let projection = Mat4::perspective_rh(1.0_f64, 4.0 / 3.0, 1.0, 100.0, DepthRange::ZeroToOne);
+    /// let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    ///
+    /// let centre = viewport
+    ///     .project(Vector3::new(0.0, 0.0, -1.0), &projection, DepthRange::ZeroToOne)
+    ///     .unwrap();
+    /// assert_approx_eq!(centre, Vector3::new(400.0, 300.0, 0.0), epsilon = 1e-9);
+    ///
+    /// assert!(viewport
+    ///     .project(Vector3::new(1.0, 0.0, 0.0), &projection, DepthRange::ZeroToOne)
+    ///     .is_none());
+    /// ```
+    pub fn project(
+        &self,
+        point: Vector3<T>,
+        view_projection: &Mat4<T>,
+        depth_range: DepthRange,
+    ) -> Option<Vector3<T>>
+    where
+        T: Real,
+    {
+        let clip = *view_projection * point.extend(T::ONE);
+        if clip.w == T::ZERO {
+            return None;
+        }
+
+        let ndc = clip.perspective_divide();
+        Some(Vector3::new(
+            self.x + (ndc.x + T::ONE) * T::HALF * self.width,
+            self.y + (ndc.y + T::ONE) * T::HALF * self.height,
+            depth_range.ndc_to_window(ndc.z),
+        ))
+    }
+
+    /// Converts window coordinates back into a world-space point.
+    ///
+    /// This is the inverse of [`Viewport::project`]; pass a window depth of
+    /// `0` and `1` to get the near and far ends of a picking ray. Returns
+    /// `None` if `view_projection` is not invertible or the point maps to
+    /// infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::{DepthRange, Viewport};
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let view = Mat4::look_at_rh(
+    ///     Vector3::new(0.0_f64, 2.0, 5.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let projection = Mat4::perspective_rh(1.0, 1.5, 0.1, 50.0, DepthRange::NegativeOneToOne);
+    /// let view_projection = projection * view;
+    /// let viewport = Viewport::new(0.0, 0.0, 1200.0, 800.0);
+    ///
+    /// let point = Vector3::new(0.5, -0.25, 1.0);
+    /// let window = viewport
+    ///     .project(point, &view_projection, DepthRange::NegativeOneToOne)
+    ///     .unwrap();
+    /// let back = viewport
+    ///     .unproject(window, &view_projection, DepthRange::NegativeOneToOne)
+    ///     .unwrap();
+    /// assert_approx_eq!(back, point, epsilon = 1e-9);
+    /// ```
+    pub fn unproject(
+        &self,
+        window: Vector3<T>,
+        view_projection: &Mat4<T>,
+        depth_range: DepthRange,
+    ) -> Option<Vector3<T>>
+    where
+        T: Real,
+    {
+        let inverse = view_projection.inverse()?;
+        let ndc = Vector4::point(
+            (window.x - self.x) / self.width * T::TWO - T::ONE,
+            (window.y - self.y) / self.height * T::TWO - T::ONE,
+            depth_range.window_to_ndc(window.z),
+        );
+
+        let world = inverse * ndc;
+        if world.w == T::ZERO {
+            return None;
+        }
+        Some(world.perspective_divide())
+    }
+}
+
+impl<T: Real> Mat4<T> {
+    /// Creates a right-handed view matrix for a camera at `eye` looking at
+    /// `target`.
+    ///
+    /// The camera looks down its local `-z` axis with `up` roughly along `+y`.
+    /// `up` must not be parallel to the viewing direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let view = Mat4::look_at_rh(
+    ///     Vector3::new(0.0, 0.0, 5.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert_eq!(
+    ///     view * Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, 0.0, -5.0, 1.0)
+    /// );
+    /// ```
+    pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to_rh(eye, target - eye, up)
+    }
+
+    /// Creates a left-handed view matrix for a camera at `eye` looking at
+    /// `target`.
+    ///
+    /// The camera looks down its local `+z` axis with `up` roughly along `+y`.
+    /// `up` must not be parallel to the viewing direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let view = Mat4::look_at_lh(
+    ///     Vector3::new(0.0, 0.0, -5.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert_eq!(
+    ///     view * Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, 0.0, 5.0, 1.0)
+    /// );
+    /// ```
+    pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = (target - eye).normalize();
+        let side = up.cross(&forward).normalize();
+        let up = forward.cross(&side);
+        Self::view(eye, side, up, forward)
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking along
+    /// `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let eye = Vector3::new(1.0, 2.0, 3.0);
+    /// let up = Vector3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(
+    ///     Mat4::look_to_rh(eye, Vector3::new(0.0, 0.0, -1.0), up),
+    ///     Mat4::look_at_rh(eye, Vector3::new(1.0, 2.0, 0.0), up)
+    /// );
+    /// ```
+    pub fn look_to_rh(eye: Vector3<T>, direction: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = direction.normalize();
+        let side = forward.cross(&up).normalize();
+        let up = side.cross(&forward);
+        let back = Vector3::new(-forward.x, -forward.y, -forward.z);
+        Self::view(eye, side, up, back)
+    }
+
+    /// Builds a view matrix from an orthonormal camera basis.
+    fn view(eye: Vector3<T>, x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self::from_rows(
+            Vector4::new(x.x, x.y, x.z, -x.dot(&eye)),
+            Vector4::new(y.x, y.y, y.z, -y.dot(&eye)),
+            Vector4::new(z.x, z.y, z.z, -z.dot(&eye)),
+            Vector4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+
+    /// Creates a right-handed perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians and `aspect` is
+    /// width divided by height. Points at `-near` and `-far` on the view axis
+    /// map to the minimum and maximum of `depth_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let gl = Mat4::perspective_rh(1.2_f64, 1.0, 0.5, 20.0, DepthRange::NegativeOneToOne);
+    /// let near = gl * Vector4::new(0.0, 0.0, -0.5, 1.0);
+    /// let far = gl * Vector4::new(0.0, 0.0, -20.0, 1.0);
+    /// assert_approx_eq!(near.z / near.w, -1.0, epsilon = 1e-12);
+    /// assert_approx_eq!(far.z / far.w, 1.0, epsilon = 1e-12);
+    ///
+    /// let vk = Mat4::perspective_rh(1.2_f64, 1.0, 0.5, 20.0, DepthRange::ZeroToOne);
+    /// let near = vk * Vector4::new(0.0, 0.0, -0.5, 1.0);
+    /// assert_approx_eq!(near.z / near.w, 0.0, epsilon = 1e-12);
+    /// ```
+    pub fn perspective_rh(fov_y: T, aspect: T, near: T, far: T, depth_range: DepthRange) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (
+                (far + near) / (near - far),
+                T::TWO * far * near / (near - far),
+            ),
+            DepthRange::ZeroToOne => (far / (near - far), far * near / (near - far)),
+        };
+        Self::perspective(fov_y, aspect, a, b, -T::ONE)
+    }
+
+    /// Creates a left-handed perspective projection.
+    ///
+    /// Identical to [`Mat4::perspective_rh`] except that the camera looks
+    /// down `+z`, so points at `near` and `far` map to the depth range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_lh(1.2_f64, 1.0, 0.5, 20.0, DepthRange::ZeroToOne);
+    /// let near = m * Vector4::new(0.0, 0.0, 0.5, 1.0);
+    /// let far = m * Vector4::new(0.0, 0.0, 20.0, 1.0);
+    /// assert_approx_eq!(near.z / near.w, 0.0, epsilon = 1e-12);
+    /// assert_approx_eq!(far.z / far.w, 1.0, epsilon = 1e-12);
+    /// ```
+    pub fn perspective_lh(fov_y: T, aspect: T, near: T, far: T, depth_range: DepthRange) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (
+                (far + near) / (far - near),
+                T::TWO * far * near / (near - far),
+            ),
+            DepthRange::ZeroToOne => (far / (far - near), far * near / (near - far)),
+        };
+        Self::perspective(fov_y, aspect, a, b, T::ONE)
+    }
+
+    /// Creates a right-handed perspective projection with the far plane at
+    /// infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_infinite_rh(1.2_f64, 1.0, 0.5, DepthRange::NegativeOneToOne);
+    /// let near = m * Vector4::new(0.0, 0.0, -0.5, 1.0);
+    /// let distant = m * Vector4::new(0.0, 0.0, -1e9, 1.0);
+    /// assert_approx_eq!(near.z / near.w, -1.0, epsilon = 1e-12);
+    /// assert_approx_eq!(distant.z / distant.w, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn perspective_infinite_rh(fov_y: T, aspect: T, near: T, depth_range: DepthRange) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (-T::ONE, -T::TWO * near),
+            DepthRange::ZeroToOne => (-T::ONE, -near),
+        };
+        Self::perspective(fov_y, aspect, a, b, -T::ONE)
+    }
+
+    /// Creates a left-handed perspective projection with the far plane at
+    /// infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_infinite_lh(1.2_f64, 1.0, 0.5, DepthRange::ZeroToOne);
+    /// let near = m * Vector4::new(0.0, 0.0, 0.5, 1.0);
+    /// let distant = m * Vector4::new(0.0, 0.0, 1e9, 1.0);
+    /// assert_approx_eq!(near.z / near.w, 0.0, epsilon = 1e-12);
+    /// assert_approx_eq!(distant.z / distant.w, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn perspective_infinite_lh(fov_y: T, aspect: T, near: T, depth_range: DepthRange) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (T::ONE, -T::TWO * near),
+            DepthRange::ZeroToOne => (T::ONE, -near),
+        };
+        Self::perspective(fov_y, aspect, a, b, T::ONE)
+    }
+
+    /// Creates a right-handed reverse-Z perspective projection.
+    ///
+    /// The near plane maps to depth `1` and the far plane to `0`, which
+    /// spreads floating-point depth precision evenly over the view distance.
+    /// Reverse-Z only pays off with a `0..=1` depth range, so no
+    /// [`DepthRange`] is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_reverse_z_rh(1.2_f64, 1.0, 0.5, 20.0);
+    /// let near = m * Vector4::new(0.0, 0.0, -0.5, 1.0);
+    /// let far = m * Vector4::new(0.0, 0.0, -20.0, 1.0);
+    /// assert_approx_eq!(near.z / near.w, 1.0, epsilon = 1e-12);
+    /// assert_approx_eq!(far.z / far.w, 0.0, epsilon = 1e-12);
+    /// ```
+    pub fn perspective_reverse_z_rh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let a = near / (far - near);
+        let b = far * near / (far - near);
+        Self::perspective(fov_y, aspect, a, b, -T::ONE)
+    }
+
+    /// Creates a left-handed reverse-Z perspective projection.
+    ///
+    /// See [`Mat4::perspective_reverse_z_rh`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_reverse_z_lh(1.2_f64, 1.0, 0.5, 20.0);
+    /// let near = m * Vector4::new(0.0, 0.0, 0.5, 1.0);
+    /// let far = m * Vector4::new(0.0, 0.0, 20.0, 1.0);
+    /// assert_approx_eq!(near.z / near.w, 1.0, epsilon = 1e-12);
+    /// assert_approx_eq!(far.z / far.w, 0.0, epsilon = 1e-12);
+    /// ```
+    pub fn perspective_reverse_z_lh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let a = near / (near - far);
+        let b = far * near / (far - near);
+        Self::perspective(fov_y, aspect, a, b, T::ONE)
+    }
+
+    /// Creates a right-handed reverse-Z perspective projection with the far
+    /// plane at infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_infinite_reverse_z_rh(1.2_f64, 1.0, 0.5);
+    /// let near = m * Vector4::new(0.0, 0.0, -0.5, 1.0);
+    /// let distant = m * Vector4::new(0.0, 0.0, -1e9, 1.0);
+    /// assert_eq!(near.z / near.w, 1.0);
+    /// assert!(distant.z / distant.w < 1e-9);
+    /// ```
+    pub fn perspective_infinite_reverse_z_rh(fov_y: T, aspect: T, near: T) -> Self {
+        Self::perspective(fov_y, aspect, T::ZERO, near, -T::ONE)
+    }
+
+    /// Creates a left-handed reverse-Z perspective projection with the far
+    /// plane at infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::perspective_infinite_reverse_z_lh(1.2_f64, 1.0, 0.5);
+    /// let near = m * Vector4::new(0.0, 0.0, 0.5, 1.0);
+    /// assert_eq!(near.z / near.w, 1.0);
+    /// ```
+    pub fn perspective_infinite_reverse_z_lh(fov_y: T, aspect: T, near: T) -> Self {
+        Self::perspective(fov_y, aspect, T::ZERO, near, T::ONE)
+    }
+
+    /// Builds a perspective matrix whose depth row is `(0, 0, a, b)` and whose
+    /// `w` row is `(0, 0, w, 0)`.
+    fn perspective(fov_y: T, aspect: T, a: T, b: T, w: T) -> Self {
+        let y_scale = (fov_y * T::HALF).tan().recip();
+        let x_scale = y_scale / aspect;
+        Self::from_rows(
+            Vector4::new(x_scale, T::ZERO, T::ZERO, T::ZERO),
+            Vector4::new(T::ZERO, y_scale, T::ZERO, T::ZERO),
+            Vector4::new(T::ZERO, T::ZERO, a, b),
+            Vector4::new(T::ZERO, T::ZERO, w, T::ZERO),
+        )
+    }
+
+    /// Creates a right-handed orthographic projection of the box bounded by
+    /// `left`, `right`, `bottom`, `top` and the planes at `-near` and `-far`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::orthographic_rh(0.0, 800.0, 0.0, 600.0, 0.0, 10.0, DepthRange::ZeroToOne);
+    /// assert_eq!(
+    ///     m * Vector4::new(800.0, 600.0, -10.0, 1.0),
+    ///     Vector4::new(1.0, 1.0, 1.0, 1.0)
+    /// );
+    ///
+    /// let gl = Mat4::orthographic_rh(0.0, 800.0, 0.0, 600.0, 0.0, 10.0, DepthRange::NegativeOneToOne);
+    /// assert_eq!(
+    ///     gl * Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(-1.0, -1.0, -1.0, 1.0)
+    /// );
+    /// ```
+    pub fn orthographic_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (-T::TWO / (far - near), -(far + near) / (far - near)),
+            DepthRange::ZeroToOne => (-T::ONE / (far - near), -near / (far - near)),
+        };
+        Self::orthographic(left, right, bottom, top, a, b)
+    }
+
+    /// Creates a left-handed orthographic projection of the box bounded by
+    /// `left`, `right`, `bottom`, `top` and the planes at `near` and `far`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::matrices::projection::DepthRange;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let m = Mat4::orthographic_lh(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0, DepthRange::ZeroToOne);
+    /// assert_eq!(
+    ///     m * Vector4::new(0.0, 0.0, 2.0, 1.0),
+    ///     Vector4::new(0.0, 0.0, 0.5, 1.0)
+    /// );
+    /// ```
+    pub fn orthographic_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (a, b) = match depth_range {
+            DepthRange::NegativeOneToOne => (T::TWO / (far - near), -(far + near) / (far - near)),
+            DepthRange::ZeroToOne => (T::ONE / (far - near), -near / (far - near)),
+        };
+        Self::orthographic(left, right, bottom, top, a, b)
+    }
+
+    /// Builds an orthographic matrix whose depth row is `(0, 0, a, b)`.
+    fn orthographic(left: T, right: T, bottom: T, top: T, a: T, b: T) -> Self {
+        let width = right - left;
+        let height = top - bottom;
+        Self::from_rows(
+            Vector4::new(T::TWO / width, T::ZERO, T::ZERO, -(right + left) / width),
+            Vector4::new(T::ZERO, T::TWO / height, T::ZERO, -(top + bottom) / height),
+            Vector4::new(T::ZERO, T::ZERO, a, b),
+            Vector4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+}
@@ -0,0 +1,268 @@
+//! Layering octaves of noise into fractal detail.
+//!
+//! A single layer of noise has features of one size. Terrain has hills,
+//! rocks and pebbles at once, so fractal noise adds up octaves: each one is
+//! sampled at `lacunarity` times the frequency of the last and weighted by
+//! `gain` times its amplitude. With the defaults of 2 and ½ every octave
+//! adds details half the size and half the height.
+//!
+//! The combinators differ in how each octave is shaped before it is added:
+//!
+//! * [`Fbm`] adds the octaves as they are, for rolling hills and clouds.
+//! * [`Turbulence`] adds their absolute values, which folds the noise into
+//!   billows with sharp creases, for smoke and fire.
+//! * [`Ridged`] adds `(1 - |n|)²`, which turns the creases into sharp
+//!   crests, for mountain ranges.
+//!
+//! The sum is divided by the total amplitude of the octaves, so `Fbm` keeps
+//! the range of its source and the others stay in `[0, 1]` for sources in
+//! `[-1, 1]`.
+
+use crate::scalar::{Real, Scalar, Signed};
+
+use super::{Noise, NoisePoint};
+
+/// Fractal Brownian motion: octaves of noise added as they are.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::fractal::Fbm;
+/// use vexel::noise::simplex::Simplex;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let terrain = Fbm::new(Simplex::new(1), 6);
+/// let height = terrain.sample(Vector2::new(12.5, 3.25));
+/// assert!((-1.0..=1.0).contains(&height));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fbm<N, T> {
+    /// The noise each octave samples.
+    pub source: N,
+    /// The number of octaves.
+    pub octaves: u32,
+    /// The factor by which the frequency grows from one octave to the next.
+    pub lacunarity: T,
+    /// The factor by which the amplitude shrinks from one octave to the next.
+    pub gain: T,
+}
+
+impl<N, T: Real> Fbm<N, T> {
+    /// Layers `octaves` octaves of `source` with a lacunarity of 2 and a
+    /// gain of ½.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::fractal::Fbm;
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Noise;
+    ///
+    /// let one = Fbm::new(Perlin::new(4), 1);
+    /// assert_eq!(one.sample(0.3_f64), Perlin::new(4).sample(0.3_f64));
+    /// ```
+    pub fn new(source: N, octaves: u32) -> Self {
+        Self {
+            source,
+            octaves,
+            lacunarity: T::TWO,
+            gain: T::HALF,
+        }
+    }
+}
+
+impl<P: NoisePoint, N: Noise<P>> Noise<P> for Fbm<N, P::Scalar> {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        layer(
+            &self.source,
+            self.octaves,
+            self.lacunarity,
+            self.gain,
+            point,
+            |n, slope| (n, slope),
+        )
+    }
+}
+
+/// Octaves of the absolute value of noise, in `[0, 1]` for sources in
+/// `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::fractal::Turbulence;
+/// use vexel::noise::perlin::Perlin;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let smoke = Turbulence::new(Perlin::new(1), 5);
+/// let density = smoke.sample(Vector3::new(0.4, 1.2, 7.9));
+/// assert!((0.0..=1.0).contains(&density));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turbulence<N, T> {
+    /// The noise each octave samples.
+    pub source: N,
+    /// The number of octaves.
+    pub octaves: u32,
+    /// The factor by which the frequency grows from one octave to the next.
+    pub lacunarity: T,
+    /// The factor by which the amplitude shrinks from one octave to the next.
+    pub gain: T,
+}
+
+impl<N, T: Real> Turbulence<N, T> {
+    /// Layers `octaves` octaves of `source` with a lacunarity of 2 and a
+    /// gain of ½.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::fractal::Turbulence;
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Noise;
+    ///
+    /// let one = Turbulence::new(Perlin::new(4), 1);
+    /// assert_eq!(one.sample(0.3_f64), Perlin::new(4).sample(0.3_f64).abs());
+    /// ```
+    pub fn new(source: N, octaves: u32) -> Self {
+        Self {
+            source,
+            octaves,
+            lacunarity: T::TWO,
+            gain: T::HALF,
+        }
+    }
+}
+
+impl<P: NoisePoint, N: Noise<P>> Noise<P> for Turbulence<N, P::Scalar> {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        layer(
+            &self.source,
+            self.octaves,
+            self.lacunarity,
+            self.gain,
+            point,
+            |n, slope| {
+                if n < P::Scalar::ZERO {
+                    (-n, slope.scale(-P::Scalar::ONE))
+                } else {
+                    (n, slope)
+                }
+            },
+        )
+    }
+}
+
+/// Octaves of `(1 - |n|)²` for noise `n`, in `[0, 1]` for sources in
+/// `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::fractal::Ridged;
+/// use vexel::noise::simplex::Simplex;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mountains = Ridged::new(Simplex::new(1), 6);
+/// let height = mountains.sample(Vector2::new(-8.5, 0.75));
+/// assert!((0.0..=1.0).contains(&height));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ridged<N, T> {
+    /// The noise each octave samples.
+    pub source: N,
+    /// The number of octaves.
+    pub octaves: u32,
+    /// The factor by which the frequency grows from one octave to the next.
+    pub lacunarity: T,
+    /// The factor by which the amplitude shrinks from one octave to the next.
+    pub gain: T,
+}
+
+impl<N, T: Real> Ridged<N, T> {
+    /// Layers `octaves` octaves of `source` with a lacunarity of 2 and a
+    /// gain of ½.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::fractal::Ridged;
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Noise;
+    ///
+    /// // Perlin noise is zero on the lattice, the top of a ridge.
+    /// let ridges = Ridged::new(Perlin::new(4), 3);
+    /// assert_eq!(ridges.sample(2.0_f64), 1.0);
+    /// ```
+    pub fn new(source: N, octaves: u32) -> Self {
+        Self {
+            source,
+            octaves,
+            lacunarity: T::TWO,
+            gain: T::HALF,
+        }
+    }
+}
+
+impl<P: NoisePoint, N: Noise<P>> Noise<P> for Ridged<N, P::Scalar> {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        layer(
+            &self.source,
+            self.octaves,
+            self.lacunarity,
+            self.gain,
+            point,
+            |n, slope| {
+                let ridge = P::Scalar::ONE - n.abs();
+                let sign = if n < P::Scalar::ZERO {
+                    P::Scalar::ONE
+                } else {
+                    -P::Scalar::ONE
+                };
+                (ridge * ridge, slope.scale(P::Scalar::TWO * ridge * sign))
+            },
+        )
+    }
+}
+
+/// Adds up `octaves` octaves of `source`, each passed through `shape`, and
+/// divides by the total amplitude.
+///
+/// `shape` maps a sample and its gradient to the shaped sample and the
+/// gradient of that.
+fn layer<P, N>(
+    source: &N,
+    octaves: u32,
+    lacunarity: P::Scalar,
+    gain: P::Scalar,
+    point: P,
+    shape: impl Fn(P::Scalar, P) -> (P::Scalar, P),
+) -> (P::Scalar, P)
+where
+    P: NoisePoint,
+    N: Noise<P>,
+{
+    let zero = P::Scalar::ZERO;
+    let mut value = zero;
+    let mut slope = point.scale(zero);
+    let mut amplitude = P::Scalar::ONE;
+    let mut frequency = P::Scalar::ONE;
+    let mut total = zero;
+    for _ in 0..octaves {
+        let (n, g) = source.sample_with_gradient(point.scale(frequency));
+        let (n, g) = shape(n, g);
+        value += amplitude * n;
+        slope = slope + g.scale(amplitude * frequency);
+        total += amplitude;
+        amplitude *= gain;
+        frequency *= lacunarity;
+    }
+    if total == zero {
+        return (zero, slope);
+    }
+    let inverse = total.recip();
+    (value * inverse, slope.scale(inverse))
+}
@@ -0,0 +1,453 @@
+//! Procedural noise for terrain, textures and other natural-looking detail.
+//!
+//! Every noise function implements [`Noise`] for 1D to 4D points: plain
+//! `f32` or `f64` values and [`Vector2`], [`Vector3`] and [`Vector4`]. Each
+//! sample comes with an analytic gradient through
+//! [`sample_with_gradient`](Noise::sample_with_gradient), which is exact and
+//! much cheaper than finite differences, for example when lighting terrain.
+//!
+//! The lattice-based functions are driven by a [`Permutation`] table built
+//! from a seed, so the same seed always gives the same noise. Only addition,
+//! multiplication, division, `floor` and `sqrt` are used, all of which IEEE
+//! 754 rounds exactly, so results are bit-for-bit identical on every
+//! platform.
+//!
+//! Octaves of any noise can be layered with the combinators in [`fractal`].
+
+pub mod fractal;
+pub mod perlin;
+pub mod simplex;
+pub mod value;
+pub mod worley;
+
+use core::ops::{Add, Sub};
+
+use crate::random::split_mix;
+use crate::scalar::{Real, Scalar};
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// A point that noise can be sampled at.
+///
+/// Implemented for `f32` and `f64` as 1D points and for [`Vector2`],
+/// [`Vector3`] and [`Vector4`].
+pub trait NoisePoint: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// The type of each component.
+    type Scalar: Real;
+
+    /// The number of components, from 1 to 4.
+    const DIMENSIONS: usize;
+
+    /// Multiplies every component by `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::NoisePoint;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(1.0, -2.0).scale(3.0), Vector2::new(3.0, -6.0));
+    /// assert_eq!(NoisePoint::scale(1.5, 2.0), 3.0);
+    /// ```
+    fn scale(self, s: Self::Scalar) -> Self;
+
+    /// Returns the component along `axis`, counting from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not less than [`DIMENSIONS`](Self::DIMENSIONS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::NoisePoint;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(1.0, 2.0, 3.0).component(1), 2.0);
+    /// ```
+    fn component(&self, axis: usize) -> Self::Scalar;
+
+    /// Creates a point whose component along each axis is `f(axis)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::NoisePoint;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let p = Vector4::from_components(|axis| axis as f64);
+    /// assert_eq!(p, Vector4::new(0.0, 1.0, 2.0, 3.0));
+    /// ```
+    fn from_components(f: impl FnMut(usize) -> Self::Scalar) -> Self;
+}
+
+macro_rules! impl_noise_point_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl NoisePoint for $t {
+                type Scalar = $t;
+
+                const DIMENSIONS: usize = 1;
+
+                fn scale(self, s: $t) -> $t {
+                    self * s
+                }
+
+                fn component(&self, axis: usize) -> $t {
+                    [*self][axis]
+                }
+
+                fn from_components(mut f: impl FnMut(usize) -> $t) -> $t {
+                    f(0)
+                }
+            }
+        )*
+    };
+}
+
+impl_noise_point_for_float!(f32, f64);
+
+impl<T: Real> NoisePoint for Vector2<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 2;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn from_components(mut f: impl FnMut(usize) -> T) -> Self {
+        Vector2::new(f(0), f(1))
+    }
+}
+
+impl<T: Real> NoisePoint for Vector3<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 3;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn from_components(mut f: impl FnMut(usize) -> T) -> Self {
+        Vector3::new(f(0), f(1), f(2))
+    }
+}
+
+impl<T: Real> NoisePoint for Vector4<T> {
+    type Scalar = T;
+
+    const DIMENSIONS: usize = 4;
+
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    fn component(&self, axis: usize) -> T {
+        self[axis]
+    }
+
+    fn from_components(mut f: impl FnMut(usize) -> T) -> Self {
+        Vector4::new(f(0), f(1), f(2), f(3))
+    }
+}
+
+/// A noise function that can be sampled at points of type `P`.
+pub trait Noise<P: NoisePoint> {
+    /// Returns the noise at `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Noise;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let perlin = Perlin::new(7);
+    /// let height = perlin.sample(Vector2::new(3.25, -1.5));
+    /// assert!((-1.0..=1.0).contains(&height));
+    /// ```
+    fn sample(&self, point: P) -> P::Scalar {
+        self.sample_with_gradient(point).0
+    }
+
+    /// Returns the noise at `point` together with its gradient, the
+    /// derivative of the noise along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::noise::simplex::Simplex;
+    /// use vexel::noise::Noise;
+    ///
+    /// let simplex = Simplex::new(7);
+    /// let (value, slope) = simplex.sample_with_gradient(0.4_f64);
+    /// let ahead = simplex.sample(0.4_f64 + 1e-6);
+    /// assert_approx_eq!(ahead, value + slope * 1e-6, epsilon = 1e-9);
+    /// ```
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P);
+}
+
+impl<P: NoisePoint, N: Noise<P> + ?Sized> Noise<P> for &N {
+    fn sample(&self, point: P) -> P::Scalar {
+        (**self).sample(point)
+    }
+
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        (**self).sample_with_gradient(point)
+    }
+}
+
+/// A shuffled table of the numbers 0 to 255 that hashes lattice cells.
+///
+/// Noise built from the same table is the same everywhere. The table repeats
+/// every 256 cells along each axis, which is far enough that the repetition
+/// is not noticeable at usual scales.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    /// The table twice over, so sums of an entry and a byte need no wrapping.
+    table: [u8; 512],
+}
+
+impl Permutation {
+    /// Shuffles a table with the given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Permutation::new(42), Permutation::new(42));
+    /// assert_ne!(Permutation::new(42), Permutation::new(43));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        let mut state = seed;
+        let mut entries = [0u8; 256];
+        for (i, entry) in entries.iter_mut().enumerate() {
+            *entry = i as u8;
+        }
+        for i in (1..entries.len()).rev() {
+            let j = (split_mix(&mut state) % (i as u64 + 1)) as usize;
+            entries.swap(i, j);
+        }
+        Self::from_entries(entries)
+    }
+
+    /// Uses `table` as the permutation, for example to reproduce the table
+    /// from Ken Perlin's reference implementation.
+    ///
+    /// Returns `None` if `table` does not hold each of the numbers 0 to 255
+    /// exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::Permutation;
+    ///
+    /// let mut table = [0u8; 256];
+    /// for (i, entry) in table.iter_mut().enumerate() {
+    ///     *entry = 255 - i as u8;
+    /// }
+    /// assert!(Permutation::from_table(table).is_some());
+    ///
+    /// table[0] = 0;
+    /// assert!(Permutation::from_table(table).is_none());
+    /// ```
+    pub fn from_table(table: [u8; 256]) -> Option<Self> {
+        let mut seen = [false; 256];
+        for &entry in &table {
+            if core::mem::replace(&mut seen[entry as usize], true) {
+                return None;
+            }
+        }
+        Some(Self::from_entries(table))
+    }
+
+    /// Returns the 256 entries of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::Permutation;
+    ///
+    /// let permutation = Permutation::new(1);
+    /// let mut sorted = *permutation.table();
+    /// sorted.sort_unstable();
+    /// assert!(sorted.iter().enumerate().all(|(i, &entry)| entry as usize == i));
+    /// ```
+    pub fn table(&self) -> &[u8; 256] {
+        self.table[..256]
+            .try_into()
+            .expect("the table holds 512 entries")
+    }
+
+    fn from_entries(entries: [u8; 256]) -> Self {
+        let mut table = [0u8; 512];
+        table[..256].copy_from_slice(&entries);
+        table[256..].copy_from_slice(&entries);
+        Self { table }
+    }
+
+    /// Hashes the lattice cell with the given coordinates to a byte.
+    fn hash(&self, coordinates: &[i64]) -> u8 {
+        coordinates
+            .iter()
+            .fold(0, |hash, &c| self.table[hash as usize + (c & 255) as usize])
+    }
+}
+
+impl Default for Permutation {
+    /// Returns the table for seed 0.
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Copies the components of `point` into an array, padding with zeros.
+fn to_array<P: NoisePoint>(point: P) -> [P::Scalar; 4] {
+    let mut array = [P::Scalar::ZERO; 4];
+    for (axis, component) in array.iter_mut().enumerate().take(P::DIMENSIONS) {
+        *component = point.component(axis);
+    }
+    array
+}
+
+/// Returns the lattice cell containing `x` and the offset of `x` inside it.
+fn cell<T: Real>(x: T) -> (i64, T) {
+    let floor = x.floor();
+    (floor.to_f64() as i64, x - floor)
+}
+
+/// Returns the dot product of the first `dimensions` components.
+fn dot<T: Real>(dimensions: usize, a: &[T; 4], b: &[T; 4]) -> T {
+    (0..dimensions).fold(T::ZERO, |sum, axis| sum + a[axis] * b[axis])
+}
+
+/// Returns the gradient that `hash` picks for a lattice point.
+///
+/// 1D gradients are spread over `(-1, 1)`. 2D gradients are the eight unit
+/// compass directions, 3D gradients point to the edges of a cube as in Ken
+/// Perlin's improved noise, and 4D gradients to the edges of a tesseract.
+fn gradient<T: Real>(dimensions: usize, hash: u8) -> [T; 4] {
+    const D: f64 = core::f64::consts::FRAC_1_SQRT_2;
+    const PLANE: [[f64; 2]; 8] = [
+        [1.0, 0.0],
+        [-1.0, 0.0],
+        [0.0, 1.0],
+        [0.0, -1.0],
+        [D, D],
+        [-D, D],
+        [D, -D],
+        [-D, -D],
+    ];
+    const CUBE: [[f64; 3]; 16] = [
+        [1.0, 1.0, 0.0],
+        [-1.0, 1.0, 0.0],
+        [1.0, -1.0, 0.0],
+        [-1.0, -1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [-1.0, 0.0, 1.0],
+        [1.0, 0.0, -1.0],
+        [-1.0, 0.0, -1.0],
+        [0.0, 1.0, 1.0],
+        [0.0, -1.0, 1.0],
+        [0.0, 1.0, -1.0],
+        [0.0, -1.0, -1.0],
+        [1.0, 1.0, 0.0],
+        [-1.0, 1.0, 0.0],
+        [0.0, -1.0, 1.0],
+        [0.0, -1.0, -1.0],
+    ];
+    let mut g = [0.0; 4];
+    match dimensions {
+        1 => g[0] = ((hash & 15) as f64 + 0.5) / 8.0 - 1.0,
+        2 => g[..2].copy_from_slice(&PLANE[(hash & 7) as usize]),
+        3 => g[..3].copy_from_slice(&CUBE[(hash & 15) as usize]),
+        _ => {
+            // The axis left at zero, then one sign bit for each other axis.
+            let zero = ((hash >> 3) & 3) as usize;
+            let mut bit = 0;
+            for (axis, component) in g.iter_mut().enumerate() {
+                if axis != zero {
+                    *component = if hash >> bit & 1 == 0 { 1.0 } else { -1.0 };
+                    bit += 1;
+                }
+            }
+        }
+    }
+    g.map(T::from_f64)
+}
+
+/// Blends values given at the corners of the lattice cell around `point`
+/// with the quintic fade curve `6t⁵ - 15t⁴ + 10t³`, which keeps the result
+/// smooth up to its second derivative.
+///
+/// `corner` receives the coordinates of each corner and the offset from the
+/// corner to `point`, and returns the value there and its gradient. The
+/// blended value is returned with its gradient.
+fn blend_lattice<T: Real>(
+    dimensions: usize,
+    point: [T; 4],
+    mut corner: impl FnMut(&[i64], [T; 4]) -> (T, [T; 4]),
+) -> (T, [T; 4]) {
+    let (six, ten, fifteen, thirty) = (
+        T::from_f64(6.0),
+        T::from_f64(10.0),
+        T::from_f64(15.0),
+        T::from_f64(30.0),
+    );
+    let mut base = [0i64; 4];
+    let mut offset = [T::ZERO; 4];
+    let mut fade = [T::ZERO; 4];
+    let mut slope = [T::ZERO; 4];
+    for axis in 0..dimensions {
+        let (c, t) = cell(point[axis]);
+        base[axis] = c;
+        offset[axis] = t;
+        fade[axis] = t * t * t * (t * (t * six - fifteen) + ten);
+        slope[axis] = thirty * t * t * (t - T::ONE) * (t - T::ONE);
+    }
+
+    let mut value = T::ZERO;
+    let mut gradient = [T::ZERO; 4];
+    for bits in 0..1usize << dimensions {
+        let mut coordinates = base;
+        let mut from_corner = offset;
+        let mut weights = [T::ONE; 4];
+        let mut weight_slopes = [T::ZERO; 4];
+        for axis in 0..dimensions {
+            if bits >> axis & 1 == 1 {
+                coordinates[axis] += 1;
+                from_corner[axis] -= T::ONE;
+                weights[axis] = fade[axis];
+                weight_slopes[axis] = slope[axis];
+            } else {
+                weights[axis] = T::ONE - fade[axis];
+                weight_slopes[axis] = -slope[axis];
+            }
+        }
+        let (v, g) = corner(&coordinates[..dimensions], from_corner);
+        let weight = weights[..dimensions]
+            .iter()
+            .fold(T::ONE, |product, &w| product * w);
+        value += weight * v;
+        for axis in 0..dimensions {
+            let others = (0..dimensions)
+                .filter(|&other| other != axis)
+                .fold(T::ONE, |product, other| product * weights[other]);
+            gradient[axis] += weight_slopes[axis] * others * v + weight * g[axis];
+        }
+    }
+    (value, gradient)
+}
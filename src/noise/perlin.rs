@@ -0,0 +1,99 @@
+//! Perlin gradient noise.
+//!
+//! Each lattice point gets a pseudo-random gradient, and the noise between
+//! them blends the ramps those gradients describe. The noise is zero at
+//! every lattice point, which makes its features line up with the axes a
+//! little; [`Simplex`](super::simplex::Simplex) noise avoids that.
+
+use crate::scalar::Real;
+
+use super::{blend_lattice, dot, gradient, to_array, Noise, NoisePoint, Permutation};
+
+/// Factors that stretch the noise in 1D to 4D to fill `[-1, 1]`.
+///
+/// With gradients of length `g`, noise in `n` dimensions peaks at
+/// `g * sqrt(n) / 2`.
+const SCALE: [f64; 4] = [
+    2.0,
+    core::f64::consts::SQRT_2,
+    0.816_496_580_927_726,
+    0.577_350_269_189_625_8,
+];
+
+/// Perlin noise in 1D to 4D, with values in `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::perlin::Perlin;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let perlin = Perlin::new(2024);
+/// let density = perlin.sample(Vector3::new(0.3, 1.7, -4.2));
+/// assert!((-1.0..=1.0).contains(&density));
+/// assert_eq!(perlin.sample(Vector3::new(1.0, 2.0, 3.0)), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Perlin {
+    permutation: Permutation,
+}
+
+impl Perlin {
+    /// Creates the noise for the given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Noise;
+    ///
+    /// assert_eq!(Perlin::new(5).sample(0.5_f64), Perlin::new(5).sample(0.5_f64));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self::from_permutation(Permutation::new(seed))
+    }
+
+    /// Creates the noise from a permutation table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Perlin::from_permutation(Permutation::new(5)), Perlin::new(5));
+    /// ```
+    pub fn from_permutation(permutation: Permutation) -> Self {
+        Self { permutation }
+    }
+
+    /// Returns the permutation table the noise is built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::perlin::Perlin;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Perlin::new(5).permutation(), &Permutation::new(5));
+    /// ```
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+}
+
+impl<P: NoisePoint> Noise<P> for Perlin {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        let dimensions = P::DIMENSIONS;
+        let (value, slope) = blend_lattice(dimensions, to_array(point), |corner, offset| {
+            let g = gradient(dimensions, self.permutation.hash(corner));
+            (dot(dimensions, &g, &offset), g)
+        });
+        let scale = P::Scalar::from_f64(SCALE[dimensions - 1]);
+        (
+            value * scale,
+            P::from_components(|axis| slope[axis] * scale),
+        )
+    }
+}
@@ -0,0 +1,156 @@
+//! Simplex noise.
+//!
+//! Simplex noise tiles space with simplices (triangles in 2D, tetrahedra in
+//! 3D) instead of squares or cubes. Each sample only visits the `n + 1`
+//! corners of one simplex instead of the `2ⁿ` corners of a cube, which makes
+//! it cheaper than [`Perlin`](super::perlin::Perlin) noise in 3D and 4D, and
+//! it has no visible alignment with the axes.
+//!
+//! Each corner contributes a radially fading ramp with a radius of
+//! `sqrt(0.5)`, small enough that the noise stays continuous across simplex
+//! boundaries in every dimension.
+
+use crate::scalar::{Real, Scalar};
+
+use super::{cell, dot, gradient, to_array, Noise, NoisePoint, Permutation};
+
+/// Factors that stretch the noise in 1D to 4D to fill `[-1, 1]`.
+///
+/// They are found by maximising the sum of the corner contributions with
+/// every gradient pointing the most favourable way, then rounding down.
+const SCALE: [f64; 4] = [70.0, 98.0, 75.0, 62.0];
+
+/// Simplex noise in 1D to 4D, with values in `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::simplex::Simplex;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector4::Vector4;
+///
+/// // Animated 3D noise, with time as the fourth axis.
+/// let simplex = Simplex::new(11);
+/// let value = simplex.sample(Vector4::new(0.3, 2.1, -0.8, 0.25));
+/// assert!((-1.0..=1.0).contains(&value));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Simplex {
+    permutation: Permutation,
+}
+
+impl Simplex {
+    /// Creates the noise for the given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::simplex::Simplex;
+    /// use vexel::noise::Noise;
+    ///
+    /// assert_eq!(Simplex::new(5).sample(0.5_f64), Simplex::new(5).sample(0.5_f64));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self::from_permutation(Permutation::new(seed))
+    }
+
+    /// Creates the noise from a permutation table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::simplex::Simplex;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Simplex::from_permutation(Permutation::new(5)), Simplex::new(5));
+    /// ```
+    pub fn from_permutation(permutation: Permutation) -> Self {
+        Self { permutation }
+    }
+
+    /// Returns the permutation table the noise is built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::simplex::Simplex;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Simplex::new(5).permutation(), &Permutation::new(5));
+    /// ```
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+}
+
+impl<P: NoisePoint> Noise<P> for Simplex {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        let n = P::DIMENSIONS;
+        let x = to_array(point);
+        let root = ((n + 1) as f64).sqrt();
+        let skew = P::Scalar::from_f64((root - 1.0) / n as f64);
+        let unskew = P::Scalar::from_f64((1.0 - 1.0 / root) / n as f64);
+        let radius = P::Scalar::HALF;
+        let eight = P::Scalar::from_f64(8.0);
+
+        // Find the skewed cell containing the point and the offset from its
+        // first corner, back in unskewed space.
+        let sum = (0..n).fold(P::Scalar::ZERO, |sum, axis| sum + x[axis]);
+        let mut base = [0i64; 4];
+        let mut corner_sum = P::Scalar::ZERO;
+        for axis in 0..n {
+            base[axis] = cell(x[axis] + sum * skew).0;
+            corner_sum += P::Scalar::from_f64(base[axis] as f64);
+        }
+        let origin = corner_sum * unskew;
+        let mut offset = [P::Scalar::ZERO; 4];
+        for axis in 0..n {
+            offset[axis] = x[axis] - (P::Scalar::from_f64(base[axis] as f64) - origin);
+        }
+
+        // The simplex is reached by stepping along the axes in order of
+        // decreasing offset, breaking ties by axis.
+        let mut order = [0, 1, 2, 3];
+        order[..n].sort_unstable_by(|&a, &b| {
+            offset[b]
+                .partial_cmp(&offset[a])
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then(a.cmp(&b))
+        });
+
+        let mut value = P::Scalar::ZERO;
+        let mut slope = [P::Scalar::ZERO; 4];
+        let mut corner = base;
+        let mut from_corner = offset;
+        for k in 0..=n {
+            if k > 0 {
+                let axis = order[k - 1];
+                corner[axis] += 1;
+                for (axis_offset, &start) in from_corner.iter_mut().zip(&offset).take(n) {
+                    *axis_offset = start + unskew * P::Scalar::from_f64(k as f64);
+                }
+                for &stepped in &order[..k] {
+                    from_corner[stepped] -= P::Scalar::ONE;
+                }
+            }
+            let t = radius - dot(n, &from_corner, &from_corner);
+            if t <= P::Scalar::ZERO {
+                continue;
+            }
+            let g = gradient(n, self.permutation.hash(&corner[..n]));
+            let ramp = dot(n, &g, &from_corner);
+            let t2 = t * t;
+            let t4 = t2 * t2;
+            value += t4 * ramp;
+            for axis in 0..n {
+                slope[axis] += t4 * g[axis] - eight * t2 * t * ramp * from_corner[axis];
+            }
+        }
+
+        let scale = P::Scalar::from_f64(SCALE[n - 1]);
+        (
+            value * scale,
+            P::from_components(|axis| slope[axis] * scale),
+        )
+    }
+}
@@ -0,0 +1,83 @@
+//! Value noise.
+//!
+//! Each lattice point gets a pseudo-random value, and the noise between them
+//! blends those values smoothly. It is the cheapest noise here but looks
+//! blockier than gradient noise, so it suits layering in a
+//! [fractal](super::fractal) more than being used alone.
+
+use crate::scalar::{Real, Scalar};
+
+use super::{blend_lattice, to_array, Noise, NoisePoint, Permutation};
+
+/// Value noise in 1D to 4D, with values in `[-1, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::value::Value;
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let noise = Value::new(3);
+/// let between = noise.sample(Vector2::new(0.5, 0.5));
+/// assert!((-1.0..=1.0).contains(&between));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Value {
+    permutation: Permutation,
+}
+
+impl Value {
+    /// Creates the noise for the given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::value::Value;
+    /// use vexel::noise::Noise;
+    ///
+    /// assert_ne!(Value::new(1).sample(0.5_f64), Value::new(2).sample(0.5_f64));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self::from_permutation(Permutation::new(seed))
+    }
+
+    /// Creates the noise from a permutation table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::value::Value;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Value::from_permutation(Permutation::new(5)), Value::new(5));
+    /// ```
+    pub fn from_permutation(permutation: Permutation) -> Self {
+        Self { permutation }
+    }
+
+    /// Returns the permutation table the noise is built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::value::Value;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Value::new(5).permutation(), &Permutation::new(5));
+    /// ```
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+}
+
+impl<P: NoisePoint> Noise<P> for Value {
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        let step = P::Scalar::from_f64(2.0 / 255.0);
+        let (value, slope) = blend_lattice(P::DIMENSIONS, to_array(point), |corner, _| {
+            let hash = P::Scalar::from_f64(self.permutation.hash(corner) as f64);
+            (hash * step - P::Scalar::ONE, [P::Scalar::ZERO; 4])
+        });
+        (value, P::from_components(|axis| slope[axis]))
+    }
+}
@@ -0,0 +1,288 @@
+//! Worley, or cellular, noise.
+//!
+//! Every lattice cell holds one pseudo-random feature point, and the noise
+//! at a point is its distance to the nearest feature points. The distance to
+//! the nearest, `F1`, looks like a field of cells; the distance to the
+//! second nearest, `F2`, and the difference `F2 - F1` bring out the walls
+//! between them, as in cracked mud or stone tiles.
+
+use crate::scalar::Real;
+
+use super::{cell, to_array, Noise, NoisePoint, Permutation};
+
+/// How distances to feature points are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Distance {
+    /// The straight-line distance, giving round cells.
+    #[default]
+    Euclidean,
+    /// The sum of the distances along each axis, giving diamond-shaped cells.
+    Manhattan,
+    /// The largest distance along any axis, giving square cells.
+    Chebyshev,
+}
+
+/// Which distance [`Worley`] noise returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Feature {
+    /// The distance to the nearest feature point.
+    #[default]
+    F1,
+    /// The distance to the second nearest feature point.
+    F2,
+    /// How much farther the second nearest feature point is than the
+    /// nearest, which is zero on the walls between cells.
+    F2MinusF1,
+}
+
+/// Worley noise in 1D to 4D.
+///
+/// Values are distances in lattice cells rather than values in `[-1, 1]`.
+/// With the default jitter of one, `F1` rarely exceeds one.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::noise::worley::{Feature, Worley};
+/// use vexel::noise::Noise;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mut cells = Worley::new(9);
+/// let p = Vector2::new(4.3, -2.6);
+/// let (f1, f2) = cells.distances(p);
+/// assert!(f1 <= f2);
+/// assert_eq!(cells.sample(p), f1);
+///
+/// cells.feature = Feature::F2MinusF1;
+/// assert_eq!(cells.sample(p), f2 - f1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Worley<T> {
+    permutation: Permutation,
+    /// How distances are measured.
+    pub distance: Distance,
+    /// Which distance [`sample`](Noise::sample) returns.
+    pub feature: Feature,
+    /// How far feature points may stray from the centres of their cells, from
+    /// zero for a regular grid to one for points anywhere in their cells.
+    pub jitter: T,
+}
+
+impl<T: Real> Worley<T> {
+    /// Creates `F1` noise with Euclidean distances and full jitter for the
+    /// given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::worley::{Distance, Feature, Worley};
+    ///
+    /// let cells = Worley::<f64>::new(1);
+    /// assert_eq!(cells.distance, Distance::Euclidean);
+    /// assert_eq!(cells.feature, Feature::F1);
+    /// assert_eq!(cells.jitter, 1.0);
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self::from_permutation(Permutation::new(seed))
+    }
+
+    /// Creates `F1` noise with Euclidean distances and full jitter from a
+    /// permutation table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::worley::Worley;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Worley::from_permutation(Permutation::new(5)), Worley::<f32>::new(5));
+    /// ```
+    pub fn from_permutation(permutation: Permutation) -> Self {
+        Self {
+            permutation,
+            distance: Distance::Euclidean,
+            feature: Feature::F1,
+            jitter: T::ONE,
+        }
+    }
+
+    /// Returns the permutation table the feature points are placed with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::worley::Worley;
+    /// use vexel::noise::Permutation;
+    ///
+    /// assert_eq!(Worley::<f64>::new(5).permutation(), &Permutation::new(5));
+    /// ```
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    /// Returns the distances `F1` and `F2` from `point` to the nearest and
+    /// second nearest feature points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::noise::worley::Worley;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // Without jitter the feature points sit at the centres of the cells.
+    /// let mut grid = Worley::new(0);
+    /// grid.jitter = 0.0;
+    /// let (f1, f2) = grid.distances(Vector3::new(0.5, 0.5, 0.75));
+    /// assert_eq!(f1, 0.25);
+    /// assert_eq!(f2, 0.75);
+    /// ```
+    pub fn distances<P: NoisePoint<Scalar = T>>(&self, point: P) -> (T, T) {
+        let [first, second] = self.nearest::<P>(point);
+        (first.0, second.0)
+    }
+
+    /// Returns the distance and the offset from the feature point to `point`
+    /// for the two nearest feature points.
+    fn nearest<P: NoisePoint<Scalar = T>>(&self, point: P) -> [(T, [T; 4]); 2] {
+        let n = P::DIMENSIONS;
+        let x = to_array(point);
+        let mut base = [0i64; 4];
+        let mut inside = [T::ZERO; 4];
+        for axis in 0..n {
+            (base[axis], inside[axis]) = cell(x[axis]);
+        }
+
+        let half = T::HALF;
+        let low = half - self.jitter * half;
+        let high = half + self.jitter * half;
+        let mut nearest = [(T::INFINITY, [T::ZERO; 4]); 2];
+
+        // Feature points stay inside their cells for jitter up to one, so
+        // two cells in every direction is far enough. The nearby cells
+        // almost always hold both nearest points, so visit them first and
+        // skip outer cells that cannot hold anything closer.
+        let cells = 5usize.pow(n as u32);
+        for ring in 0..=2 {
+            for index in 0..cells {
+                let mut step = [0i64; 4];
+                let mut rest = index;
+                for axis_step in step.iter_mut().take(n) {
+                    *axis_step = (rest % 5) as i64 - 2;
+                    rest /= 5;
+                }
+                if step[..n].iter().map(|s| s.abs()).max() != Some(ring) {
+                    continue;
+                }
+
+                let mut gaps = [T::ZERO; 4];
+                for axis in 0..n {
+                    let s = T::from_f64(step[axis] as f64);
+                    gaps[axis] = (s + low - inside[axis])
+                        .max(inside[axis] - s - high)
+                        .max(T::ZERO);
+                }
+                if self.measure(n, &gaps) >= nearest[1].0 {
+                    continue;
+                }
+
+                let mut salted = [0i64; 5];
+                for axis in 0..n {
+                    salted[axis] = base[axis] + step[axis];
+                }
+                let mut offset = [T::ZERO; 4];
+                for axis in 0..n {
+                    salted[n] = 2 * axis as i64;
+                    let coarse = self.permutation.hash(&salted[..=n]) as f64;
+                    salted[n] += 1;
+                    let fine = self.permutation.hash(&salted[..=n]) as f64;
+                    let r = T::from_f64((coarse * 256.0 + fine) / 65536.0);
+                    let feature = T::from_f64(step[axis] as f64) + low + self.jitter * r;
+                    offset[axis] = inside[axis] - feature;
+                }
+
+                let d = self.measure(n, &offset);
+                if d < nearest[0].0 {
+                    nearest = [(d, offset), nearest[0]];
+                } else if d < nearest[1].0 {
+                    nearest[1] = (d, offset);
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Returns the length of `offset` under the distance metric.
+    fn measure(&self, n: usize, offset: &[T; 4]) -> T {
+        let axes = offset[..n].iter();
+        match self.distance {
+            Distance::Euclidean => axes.fold(T::ZERO, |sum, &d| sum + d * d).sqrt(),
+            Distance::Manhattan => axes.fold(T::ZERO, |sum, &d| sum + d.abs()),
+            Distance::Chebyshev => axes.fold(T::ZERO, |most, &d| most.max(d.abs())),
+        }
+    }
+
+    /// Returns the gradient of the distance to a feature point with respect
+    /// to the sample point, given the offset from one to the other.
+    fn slope(&self, n: usize, (distance, offset): (T, [T; 4])) -> [T; 4] {
+        let mut slope = [T::ZERO; 4];
+        if distance <= T::ZERO {
+            return slope;
+        }
+        let sign = |d: T| {
+            if d > T::ZERO {
+                T::ONE
+            } else if d < T::ZERO {
+                -T::ONE
+            } else {
+                T::ZERO
+            }
+        };
+        match self.distance {
+            Distance::Euclidean => {
+                for axis in 0..n {
+                    slope[axis] = offset[axis] / distance;
+                }
+            }
+            Distance::Manhattan => {
+                for axis in 0..n {
+                    slope[axis] = sign(offset[axis]);
+                }
+            }
+            Distance::Chebyshev => {
+                let axis = (0..n)
+                    .find(|&axis| offset[axis].abs() == distance)
+                    .unwrap_or(0);
+                slope[axis] = sign(offset[axis]);
+            }
+        }
+        slope
+    }
+}
+
+impl<P: NoisePoint> Noise<P> for Worley<P::Scalar> {
+    fn sample(&self, point: P) -> P::Scalar {
+        let (f1, f2) = self.distances(point);
+        match self.feature {
+            Feature::F1 => f1,
+            Feature::F2 => f2,
+            Feature::F2MinusF1 => f2 - f1,
+        }
+    }
+
+    fn sample_with_gradient(&self, point: P) -> (P::Scalar, P) {
+        let n = P::DIMENSIONS;
+        let [first, second] = self.nearest::<P>(point);
+        let (value, slope) = match self.feature {
+            Feature::F1 => (first.0, self.slope(n, first)),
+            Feature::F2 => (second.0, self.slope(n, second)),
+            Feature::F2MinusF1 => {
+                let (near, far) = (self.slope(n, first), self.slope(n, second));
+                (
+                    second.0 - first.0,
+                    core::array::from_fn(|i| far[i] - near[i]),
+                )
+            }
+        };
+        (value, P::from_components(|axis| slope[axis]))
+    }
+}
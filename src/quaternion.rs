@@ -0,0 +1,619 @@
+use core::ops::Mul;
+
+use crate::error::VexelError;
+use crate::matrices::mat3::Mat3;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// A quaternion representing an orientation or rotation in 3D space.
+///
+/// The vector part is stored in `x`, `y` and `z` and the scalar part in `w`,
+/// matching the layout of [`Vector4`]. Rotation quaternions are expected to
+/// be normalized.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T> {
+    /// The x-component of the vector part.
+    pub x: T,
+    /// The y-component of the vector part.
+    pub y: T,
+    /// The z-component of the vector part.
+    pub z: T,
+    /// The scalar part.
+    pub w: T,
+}
+
+/// A `Quaternion` with `f32` components.
+pub type Quatf = Quaternion<f32>;
+
+/// A `Quaternion` with `f64` components.
+pub type Quatd = Quaternion<f64>;
+
+impl<T> Quaternion<T> {
+    /// Creates a new `Quaternion` from its vector part (`x`, `y`, `z`) and
+    /// scalar part (`w`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(q.w, 1.0);
+    /// ```
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Returns the identity rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::<f64>::identity();
+    /// assert_eq!(q, Quaternion::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE)
+    }
+
+    /// Creates a rotation of `angle` radians around a unit-length `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f64::consts::PI);
+    /// assert_approx_eq!(q.z, 1.0, epsilon = 1e-6);
+    /// assert_approx_eq!(q.w, 0.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Self
+    where
+        T: Real,
+    {
+        let (s, c) = (angle * T::HALF).sin_cos();
+        Self::new(axis.x * s, axis.y * s, axis.z * s, c)
+    }
+
+    /// Creates a rotation from a rotation vector, whose direction is the axis
+    /// and whose length is the angle in radians.
+    ///
+    /// This is the natural way to integrate an angular velocity `ω` over a
+    /// time step `dt`: `Quaternion::from_scaled_axis(ω * dt) * orientation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let angular_velocity = Vector3::new(0.0, 0.0, std::f64::consts::PI);
+    /// let dt = 0.5;
+    /// let step = Vector3::new(
+    ///     angular_velocity.x * dt,
+    ///     angular_velocity.y * dt,
+    ///     angular_velocity.z * dt,
+    /// );
+    /// let orientation = Quaternion::from_scaled_axis(step) * Quaternion::identity();
+    ///
+    /// let rotated = orientation.rotate(Vector3::new(1.0, 0.0, 0.0));
+    /// assert_approx_eq!(rotated.x, 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(rotated.y, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_scaled_axis(v: Vector3<T>) -> Self
+    where
+        T: Real,
+    {
+        let angle = v.length();
+        if angle == T::ZERO {
+            return Self::identity();
+        }
+        Self::from_axis_angle(Vector3::new(v.x / angle, v.y / angle, v.z / angle), angle)
+    }
+
+    /// Returns the unit axis and the angle in radians of this rotation.
+    ///
+    /// The identity rotation has no well-defined axis, so the x axis is
+    /// returned together with an angle of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.5_f64);
+    /// let (axis, angle) = q.to_axis_angle();
+    /// assert_approx_eq!(axis.y, 1.0, epsilon = 1e-6);
+    /// assert_approx_eq!(angle, 0.5, epsilon = 1e-6);
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vector3<T>, T)
+    where
+        T: Real,
+    {
+        let w = self.w.clamp(-T::ONE, T::ONE);
+        let s = (T::ONE - w * w).sqrt();
+        let angle = T::TWO * w.acos();
+        if s <= T::EPSILON {
+            return (Vector3::new(T::ONE, T::ZERO, T::ZERO), angle);
+        }
+        (Vector3::new(self.x / s, self.y / s, self.z / s), angle)
+    }
+
+    /// Creates a rotation from a pure rotation matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // A 90 degree rotation around z maps x to y.
+    /// let m = Mat3::from_cols(
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     Vector3::new(-1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    /// );
+    /// let q = Quaternion::from_mat3(&m);
+    /// let expected = Quaternion::from_axis_angle(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     std::f64::consts::FRAC_PI_2,
+    /// );
+    /// assert_approx_eq!(q.dot(&expected), 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_mat3(m: &Mat3<T>) -> Self
+    where
+        T: Real,
+    {
+        let [c0, c1, c2] = m.cols;
+        let (m00, m10, m20) = (c0.x, c0.y, c0.z);
+        let (m01, m11, m21) = (c1.x, c1.y, c1.z);
+        let (m02, m12, m22) = (c2.x, c2.y, c2.z);
+        let quarter = T::HALF * T::HALF;
+
+        let trace = m00 + m11 + m22;
+        if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * T::TWO;
+            Self::new(
+                (m21 - m12) / s,
+                (m02 - m20) / s,
+                (m10 - m01) / s,
+                quarter * s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (T::ONE + m00 - m11 - m22).sqrt() * T::TWO;
+            Self::new(
+                quarter * s,
+                (m01 + m10) / s,
+                (m02 + m20) / s,
+                (m21 - m12) / s,
+            )
+        } else if m11 > m22 {
+            let s = (T::ONE + m11 - m00 - m22).sqrt() * T::TWO;
+            Self::new(
+                (m01 + m10) / s,
+                quarter * s,
+                (m12 + m21) / s,
+                (m02 - m20) / s,
+            )
+        } else {
+            let s = (T::ONE + m22 - m00 - m11).sqrt() * T::TWO;
+            Self::new(
+                (m02 + m20) / s,
+                (m12 + m21) / s,
+                quarter * s,
+                (m10 - m01) / s,
+            )
+        }
+    }
+
+    /// Converts this rotation into a rotation matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 1.2_f64);
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// let by_matrix = q.to_mat3() * v;
+    /// let by_quaternion = q.rotate(v);
+    /// assert_approx_eq!(by_matrix, by_quaternion, epsilon = 1e-6);
+    /// ```
+    pub fn to_mat3(&self) -> Mat3<T>
+    where
+        T: Real,
+    {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let two = T::TWO;
+        Mat3::from_cols(
+            Vector3::new(
+                T::ONE - two * (y * y + z * z),
+                two * (x * y + w * z),
+                two * (x * z - w * y),
+            ),
+            Vector3::new(
+                two * (x * y - w * z),
+                T::ONE - two * (x * x + z * z),
+                two * (y * z + w * x),
+            ),
+            Vector3::new(
+                two * (x * z + w * y),
+                two * (y * z - w * x),
+                T::ONE - two * (x * x + y * y),
+            ),
+        )
+    }
+
+    /// Computes the dot product of this quaternion and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(a.dot(&b), 70.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T
+    where
+        T: Scalar,
+    {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Computes the length (norm) of the quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 2.0, 4.0);
+    /// assert_eq!(q.length(), 5.0);
+    /// ```
+    pub fn length(&self) -> T
+    where
+        T: Real,
+    {
+        self.dot(self).sqrt()
+    }
+
+    /// Normalizes the quaternion to unit length.
+    ///
+    /// A zero quaternion is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, 0.0, 3.0, 4.0).normalize();
+    /// assert_eq!(q, Quaternion::new(0.0, 0.0, 0.6, 0.8));
+    /// ```
+    pub fn normalize(&self) -> Self
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return *self;
+        }
+        Self::new(self.x / len, self.y / len, self.z / len, self.w / len)
+    }
+
+    /// Normalizes the quaternion to unit length, or returns an error if it
+    /// has zero length or its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, 0.0, 3.0, 4.0);
+    /// assert_eq!(q.try_normalize(), Ok(Quaternion::new(0.0, 0.0, 0.6, 0.8)));
+    /// let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    /// assert_eq!(zero.try_normalize(), Err(VexelError::ZeroLength));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !len.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        Ok(Self::new(
+            self.x / len,
+            self.y / len,
+            self.z / len,
+            self.w / len,
+        ))
+    }
+
+    /// Returns the conjugate, which negates the vector part.
+    ///
+    /// For a unit quaternion this is the inverse rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q.conjugate(), Quaternion::new(-1.0, -2.0, -3.0, 4.0));
+    /// ```
+    pub fn conjugate(&self) -> Self
+    where
+        T: Signed,
+    {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the multiplicative inverse, so that `q * q.inverse()` is the
+    /// identity.
+    ///
+    /// A zero quaternion is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let q = Quaternion::new(0.0, 0.0, 0.0, 2.0);
+    /// assert_eq!(q.inverse(), Quaternion::new(0.0, 0.0, 0.0, 0.5));
+    /// assert_eq!(q * q.inverse(), Quaternion::identity());
+    /// ```
+    pub fn inverse(&self) -> Self
+    where
+        T: Real,
+    {
+        let len_sq = self.dot(self);
+        if len_sq == T::ZERO {
+            return *self;
+        }
+        let c = self.conjugate();
+        Self::new(c.x / len_sq, c.y / len_sq, c.z / len_sq, c.w / len_sq)
+    }
+
+    /// Rotates a vector by this unit quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     std::f64::consts::FRAC_PI_2,
+    /// );
+    /// let v = q.rotate(Vector3::new(1.0, 0.0, 0.0));
+    /// assert_approx_eq!(v.x, 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(v.y, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn rotate(&self, v: Vector3<T>) -> Vector3<T>
+    where
+        T: Real,
+    {
+        let u = Vector3::new(self.x, self.y, self.z);
+        let c = u.cross(&v);
+        let t = Vector3::new(c.x * T::TWO, c.y * T::TWO, c.z * T::TWO);
+        v + Vector3::new(t.x * self.w, t.y * self.w, t.z * self.w) + u.cross(&t)
+    }
+
+    /// Computes the angle in radians between two unit rotations.
+    ///
+    /// This is the angle of the rotation taking one orientation to the other
+    /// and always lies in `[0, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let axis = Vector3::new(0.0_f64, 1.0, 0.0);
+    /// let a = Quaternion::from_axis_angle(axis, 0.25);
+    /// let b = Quaternion::from_axis_angle(axis, 1.0);
+    /// assert_approx_eq!(a.angle_between(&b), 0.75, epsilon = 1e-6);
+    /// ```
+    pub fn angle_between(&self, other: &Self) -> T
+    where
+        T: Real,
+    {
+        let d = self.dot(other).abs().min(T::ONE);
+        T::TWO * d.acos()
+    }
+
+    /// Normalized linear interpolation between two unit rotations.
+    ///
+    /// This is cheaper than [`Quaternion::slerp`] but does not move at a
+    /// constant angular velocity. It always takes the shortest path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let axis = Vector3::new(0.0_f64, 0.0, 1.0);
+    /// let a = Quaternion::from_axis_angle(axis, 0.0);
+    /// let b = Quaternion::from_axis_angle(axis, 1.0);
+    /// let mid = a.nlerp(&b, 0.5);
+    /// assert_approx_eq!(mid.angle_between(&a), 0.5, epsilon = 1e-6);
+    /// ```
+    pub fn nlerp(&self, other: &Self, t: T) -> Self
+    where
+        T: Real,
+    {
+        let end = if self.dot(other) < T::ZERO {
+            Self::new(-other.x, -other.y, -other.z, -other.w)
+        } else {
+            *other
+        };
+        Self::new(
+            self.x + (end.x - self.x) * t,
+            self.y + (end.y - self.y) * t,
+            self.z + (end.z - self.z) * t,
+            self.w + (end.w - self.w) * t,
+        )
+        .normalize()
+    }
+
+    /// Spherical linear interpolation between two unit rotations.
+    ///
+    /// The result rotates at a constant angular velocity as `t` goes from `0`
+    /// to `1` and always takes the shortest path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let axis = Vector3::new(0.0_f64, 0.0, 1.0);
+    /// let a = Quaternion::from_axis_angle(axis, 0.0);
+    /// let b = Quaternion::from_axis_angle(axis, 1.5);
+    /// let q = a.slerp(&b, 0.25);
+    /// let expected = Quaternion::from_axis_angle(axis, 0.375);
+    /// assert_approx_eq!(q.dot(&expected), 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn slerp(&self, other: &Self, t: T) -> Self
+    where
+        T: Real,
+    {
+        let mut cos_theta = self.dot(other);
+        let mut end = *other;
+        if cos_theta < T::ZERO {
+            cos_theta = -cos_theta;
+            end = Self::new(-other.x, -other.y, -other.z, -other.w);
+        }
+
+        // Fall back to nlerp when the rotations are nearly identical, where
+        // sin(theta) would be too small to divide by.
+        if cos_theta > T::ONE - T::from_f64(1e-6) {
+            return self.nlerp(&end, t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::ONE - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        Self::new(
+            self.x * a + end.x * b,
+            self.y * a + end.y * b,
+            self.z * a + end.z * b,
+            self.w * a + end.w * b,
+        )
+    }
+}
+
+impl<T> Mul for Quaternion<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Computes the Hamilton product, composing two rotations.
+    ///
+    /// `a * b` applies `b` first and then `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    ///
+    /// let i = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+    /// let j = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    /// assert_eq!(i * j, Quaternion::new(0.0, 0.0, 1.0, 0.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+impl<T> Mul<Vector3<T>> for Quaternion<T>
+where
+    T: Real,
+{
+    type Output = Vector3<T>;
+
+    /// Rotates a vector by this unit quaternion, see [`Quaternion::rotate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::<f64>::identity();
+    /// assert_eq!(q * Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+impl<T> From<Vector4<T>> for Quaternion<T> {
+    /// Creates a quaternion from a `Vector4`, mapping `w` to the scalar part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let q = Quaternion::from(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(q, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn from(v: Vector4<T>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<T> From<Quaternion<T>> for Vector4<T> {
+    /// Converts a quaternion into a `Vector4`, mapping the scalar part to `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v: Vector4<f64> = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    /// assert_eq!(v, Vector4::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn from(q: Quaternion<T>) -> Self {
+        Vector4::new(q.x, q.y, q.z, q.w)
+    }
+}
@@ -0,0 +1,178 @@
+//! Seedable pseudo-random numbers for simulations, sampling and tests.
+//!
+//! The generators are small and fast, and the same seed gives the same
+//! sequence of bits on every platform, which keeps simulations and tests
+//! reproducible. The transcendental functions that turn those bits into
+//! Gaussian numbers and points come from [`math`](crate::math) rather than
+//! the platform's math library, so the values agree everywhere too. The
+//! generators are not cryptographically secure. [`Pcg32`] is a good
+//! default; [`Xoshiro256`] is faster at producing 64-bit values and has a far
+//! longer period.
+//!
+//! Both implement [`Rng`], which turns raw bits into uniform and Gaussian
+//! numbers. The functions in [`sample`] build on it to produce points in
+//! boxes, discs and spheres, directions, and uniformly random rotations.
+//!
+//! [`Pcg32`]: pcg::Pcg32
+//! [`Xoshiro256`]: xoshiro::Xoshiro256
+
+pub mod pcg;
+pub mod sample;
+pub mod xoshiro;
+
+use core::f64::consts::TAU;
+
+use crate::math;
+use crate::scalar::Real;
+
+/// A source of uniformly distributed random bits.
+///
+/// Only [`next_u64`](Rng::next_u64) must be implemented; everything else is
+/// derived from it.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::Rng;
+///
+/// let mut rng = Pcg32::new(7);
+/// let roll = rng.below(6) + 1;
+/// assert!((1..=6).contains(&roll));
+///
+/// let x: f64 = rng.range(-1.0, 1.0);
+/// assert!((-1.0..1.0).contains(&x));
+/// ```
+pub trait Rng {
+    /// Returns the next 64 random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns the next 32 random bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut a = Pcg32::new(1);
+    /// let mut b = Pcg32::new(1);
+    /// assert_eq!(a.next_u32(), b.next_u32());
+    /// ```
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a uniformly distributed value in `[0, 1)`.
+    ///
+    /// Values are multiples of 2⁻⁵³, so every `f64` draw is exact; `f32`
+    /// draws are rounded but never reach 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut rng = Pcg32::new(3);
+    /// let x: f32 = rng.uniform();
+    /// assert!((0.0..1.0).contains(&x));
+    /// ```
+    fn uniform<T: Real>(&mut self) -> T {
+        let x = T::from_f64((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64);
+        // Rounding to a narrower type can land on 1, so step back below it.
+        if x < T::ONE {
+            x
+        } else {
+            T::ONE - T::EPSILON * T::HALF
+        }
+    }
+
+    /// Returns a uniformly distributed value between `low` and `high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut rng = Pcg32::new(3);
+    /// let x = rng.range(10.0, 20.0);
+    /// assert!((10.0..20.0).contains(&x));
+    /// ```
+    fn range<T: Real>(&mut self, low: T, high: T) -> T {
+        low + (high - low) * self.uniform::<T>()
+    }
+
+    /// Returns a uniformly distributed integer in `[0, bound)`, without the
+    /// bias of taking a remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut rng = Pcg32::new(3);
+    /// let items = ["a", "b", "c"];
+    /// let pick = items[rng.below(items.len() as u64) as usize];
+    /// assert!(items.contains(&pick));
+    /// ```
+    fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "the bound must be positive");
+        // Lemire's method: the high half of a 128-bit product is uniform once
+        // the few low halves that would favor some results are rejected.
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(bound);
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Returns a normally distributed value with mean 0 and standard
+    /// deviation 1.
+    ///
+    /// Scale and offset the result for other distributions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut rng = Pcg32::new(3);
+    /// let height = 170.0 + 8.0 * rng.gaussian::<f64>();
+    /// assert!(height.is_finite());
+    /// ```
+    fn gaussian<T: Real>(&mut self) -> T {
+        // The Box-Muller transform. `1 - u` keeps the logarithm finite.
+        let radius = math::sqrt(-2.0 * math::ln(1.0 - self.uniform::<f64>()));
+        T::from_f64(radius * math::cos(TAU * self.uniform::<f64>()))
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+}
+
+/// Advances `state` and returns the next output of the SplitMix64 generator,
+/// which spreads a seed over a generator's full state.
+pub(crate) fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,108 @@
+//! The PCG32 generator.
+
+use super::Rng;
+
+/// The multiplier of the underlying 64-bit linear congruential generator.
+const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+/// The PCG-XSH-RR generator by Melissa O'Neill, with 64 bits of state and 32
+/// bits of output.
+///
+/// Each seed can be combined with one of 2⁶³ streams, which give unrelated
+/// sequences, for example one per thread or per simulated object. The period
+/// of each stream is 2⁶⁴.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::Rng;
+///
+/// let mut a = Pcg32::new(42);
+/// let mut b = Pcg32::new(42);
+/// let x: f64 = a.uniform();
+/// assert_eq!(x, b.uniform());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    /// Creates a generator from a seed on the first stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    ///
+    /// assert_eq!(Pcg32::new(5), Pcg32::with_stream(5, 0));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Creates a generator from a seed on the given stream, seeding it the
+    /// same way as the reference implementation.
+    ///
+    /// Only the low 63 bits of `stream` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut a = Pcg32::with_stream(5, 1);
+    /// let mut b = Pcg32::with_stream(5, 2);
+    /// assert_ne!(a.next_u64(), b.next_u64());
+    /// ```
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// Advances the underlying linear congruential generator.
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Default for Pcg32 {
+    /// Creates a generator from seed 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::pcg::Pcg32;
+    ///
+    /// assert_eq!(Pcg32::default(), Pcg32::new(0));
+    /// ```
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Rng for Pcg32 {
+    fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.next_u32());
+        (high << 32) | u64::from(self.next_u32())
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+}
@@ -0,0 +1,263 @@
+//! Random points, directions and rotations.
+//!
+//! Every sampler draws a fixed number of values from the generator, so a
+//! seeded sequence of calls is reproducible, and none of them reject
+//! samples, so they take the same time on every call.
+
+use core::f64::consts::TAU;
+
+use crate::math;
+use crate::quaternion::Quaternion;
+use crate::scalar::Real;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+use super::Rng;
+
+/// Returns a uniformly distributed point in the rectangle from `min` to
+/// `max`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mut rng = Pcg32::new(1);
+/// let p = sample::in_rect(&mut rng, Vector2::new(0.0, -1.0), Vector2::new(4.0, 1.0));
+/// assert!((0.0..4.0).contains(&p.x) && (-1.0..1.0).contains(&p.y));
+/// ```
+pub fn in_rect<T: Real, R: Rng + ?Sized>(
+    rng: &mut R,
+    min: Vector2<T>,
+    max: Vector2<T>,
+) -> Vector2<T> {
+    Vector2::new(rng.range(min.x, max.x), rng.range(min.y, max.y))
+}
+
+/// Returns a uniformly distributed point in the box from `min` to `max`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let mut rng = Pcg32::new(1);
+/// let p = sample::in_box(&mut rng, Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+/// assert!((0.0..3.0).contains(&p.z));
+/// ```
+pub fn in_box<T: Real, R: Rng + ?Sized>(
+    rng: &mut R,
+    min: Vector3<T>,
+    max: Vector3<T>,
+) -> Vector3<T> {
+    Vector3::new(
+        rng.range(min.x, max.x),
+        rng.range(min.y, max.y),
+        rng.range(min.z, max.z),
+    )
+}
+
+/// Returns a uniformly distributed direction in 2D: a point on the unit
+/// circle.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mut rng = Pcg32::new(1);
+/// let d: Vector2<f64> = sample::on_circle(&mut rng);
+/// assert_approx_eq!(d.length(), 1.0, epsilon = 1e-12);
+/// ```
+pub fn on_circle<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector2<T> {
+    let (sin, cos) = math::sin_cos(TAU * rng.uniform::<f64>());
+    Vector2::new(T::from_f64(cos), T::from_f64(sin))
+}
+
+/// Returns a uniformly distributed point inside the unit disc.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mut rng = Pcg32::new(1);
+/// let p: Vector2<f64> = sample::in_disc(&mut rng);
+/// assert!(p.length() <= 1.0);
+/// ```
+pub fn in_disc<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector2<T> {
+    // The area within radius r grows as r², so the square root spreads the
+    // points evenly rather than bunching them at the center.
+    let radius = rng.uniform::<T>().sqrt();
+    let direction = on_circle::<T, R>(rng);
+    Vector2::new(direction.x * radius, direction.y * radius)
+}
+
+/// Returns a uniformly distributed direction in 3D: a point on the unit
+/// sphere.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let mut rng = Pcg32::new(1);
+/// let d: Vector3<f64> = sample::on_sphere(&mut rng);
+/// assert_approx_eq!(d.length(), 1.0, epsilon = 1e-12);
+/// ```
+pub fn on_sphere<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector3<T> {
+    // Archimedes: the height of a uniform point on the sphere is uniform.
+    let z = T::TWO * rng.uniform::<T>() - T::ONE;
+    let ring = (T::ONE - z * z).max(T::ZERO).sqrt();
+    let around = on_circle::<T, R>(rng);
+    Vector3::new(around.x * ring, around.y * ring, z)
+}
+
+/// Returns a uniformly distributed point inside the unit sphere.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let mut rng = Pcg32::new(1);
+/// let p: Vector3<f64> = sample::in_sphere(&mut rng);
+/// assert!(p.length() <= 1.0);
+/// ```
+pub fn in_sphere<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector3<T> {
+    let radius = T::from_f64(math::pow(rng.uniform::<f64>(), 1.0 / 3.0));
+    let direction = on_sphere::<T, R>(rng);
+    Vector3::new(
+        direction.x * radius,
+        direction.y * radius,
+        direction.z * radius,
+    )
+}
+
+/// Returns a unit direction in the hemisphere around the unit vector
+/// `normal`, with a density proportional to the cosine of its angle from
+/// `normal`.
+///
+/// This is the distribution of light scattered by a matte surface, so it is
+/// the natural way to pick bounce directions and ambient occlusion rays.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let mut rng = Pcg32::new(1);
+/// let normal = Vector3::new(0.0, 1.0, 0.0);
+/// let d = sample::cosine_hemisphere(&mut rng, normal);
+/// assert!(d.dot(&normal) >= 0.0);
+/// assert_approx_eq!(d.length(), 1.0, epsilon = 1e-12);
+/// ```
+pub fn cosine_hemisphere<T: Real, R: Rng + ?Sized>(rng: &mut R, normal: Vector3<T>) -> Vector3<T> {
+    // Malley's method: points spread evenly over the disc and lifted onto the
+    // hemisphere above it are cosine distributed.
+    let p = in_disc::<T, R>(rng);
+    let up = (T::ONE - p.x * p.x - p.y * p.y).max(T::ZERO).sqrt();
+    let (tangent, bitangent) = basis(normal);
+    Vector3::new(
+        tangent.x * p.x + bitangent.x * p.y + normal.x * up,
+        tangent.y * p.x + bitangent.y * p.y + normal.y * up,
+        tangent.z * p.x + bitangent.z * p.y + normal.z * up,
+    )
+}
+
+/// Returns a uniformly distributed rotation.
+///
+/// Every orientation is equally likely, which is not the case when picking
+/// random Euler angles or a random axis and angle.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::assert_approx_eq;
+/// use vexel::quaternion::Quaternion;
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+///
+/// let mut rng = Pcg32::new(1);
+/// let q: Quaternion<f64> = sample::rotation(&mut rng);
+/// assert_approx_eq!(q.length(), 1.0, epsilon = 1e-12);
+/// ```
+pub fn rotation<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Quaternion<T> {
+    // Shoemake's method: two uniform points on circles, weighted so that
+    // together they are uniform on the unit 3-sphere.
+    let u = rng.uniform::<T>();
+    let (a, b) = ((T::ONE - u).sqrt(), u.sqrt());
+    let first = on_circle::<T, R>(rng);
+    let second = on_circle::<T, R>(rng);
+    Quaternion::new(a * first.y, a * first.x, b * second.y, b * second.x)
+}
+
+/// Returns a vector of independent, normally distributed components with
+/// mean 0 and standard deviation 1.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector2::Vector2;
+///
+/// let mut rng = Pcg32::new(1);
+/// let jitter: Vector2<f64> = sample::gaussian2(&mut rng);
+/// assert!(jitter.x.is_finite() && jitter.y.is_finite());
+/// ```
+pub fn gaussian2<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector2<T> {
+    Vector2::new(rng.gaussian(), rng.gaussian())
+}
+
+/// Returns a vector of independent, normally distributed components with
+/// mean 0 and standard deviation 1.
+///
+/// The direction of the result is uniformly distributed, so this also suits
+/// isotropic random walks and noise.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::pcg::Pcg32;
+/// use vexel::random::sample;
+/// use vexel::vectors::vector3::Vector3;
+///
+/// let mut rng = Pcg32::new(1);
+/// let kick: Vector3<f64> = sample::gaussian3(&mut rng);
+/// assert!(kick.length().is_finite());
+/// ```
+pub fn gaussian3<T: Real, R: Rng + ?Sized>(rng: &mut R) -> Vector3<T> {
+    Vector3::new(rng.gaussian(), rng.gaussian(), rng.gaussian())
+}
+
+/// Returns two unit vectors that form an orthonormal basis with the unit
+/// vector `n`, without branching on its direction.
+///
+/// From Duff et al., "Building an Orthonormal Basis, Revisited" (2017).
+fn basis<T: Real>(n: Vector3<T>) -> (Vector3<T>, Vector3<T>) {
+    let sign = T::ONE.copysign(n.z);
+    let a = -T::ONE / (sign + n.z);
+    let b = n.x * n.y * a;
+    (
+        Vector3::new(T::ONE + sign * n.x * n.x * a, sign * b, -sign * n.x),
+        Vector3::new(b, sign + n.y * n.y * a, -n.y),
+    )
+}
@@ -0,0 +1,113 @@
+//! The xoshiro256++ generator.
+
+use super::{split_mix, Rng};
+
+/// The xoshiro256++ generator by David Blackman and Sebastiano Vigna, with
+/// 256 bits of state and 64 bits of output.
+///
+/// Its period of 2²⁵⁶ − 1 is long enough that sequences from different
+/// seeds will never overlap in practice.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::random::xoshiro::Xoshiro256;
+/// use vexel::random::Rng;
+///
+/// let mut a = Xoshiro256::new(42);
+/// let mut b = Xoshiro256::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Xoshiro256 {
+    state: [u64; 4],
+}
+
+impl Xoshiro256 {
+    /// Creates a generator from a seed, spreading it over the state with
+    /// SplitMix64 as the authors recommend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::xoshiro::Xoshiro256;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut a = Xoshiro256::new(1);
+    /// let mut b = Xoshiro256::new(2);
+    /// assert_ne!(a.next_u64(), b.next_u64());
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        Self {
+            state: core::array::from_fn(|_| split_mix(&mut seed)),
+        }
+    }
+
+    /// Creates a generator with the given state, for example to resume a
+    /// sequence saved with [`state`](Self::state).
+    ///
+    /// Returns `None` if the state is all zeros, which the generator can
+    /// never leave.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::xoshiro::Xoshiro256;
+    /// use vexel::random::Rng;
+    ///
+    /// let mut rng = Xoshiro256::new(9);
+    /// rng.next_u64();
+    /// let mut resumed = Xoshiro256::from_state(rng.state()).unwrap();
+    /// assert_eq!(resumed.next_u64(), rng.next_u64());
+    ///
+    /// assert!(Xoshiro256::from_state([0; 4]).is_none());
+    /// ```
+    pub fn from_state(state: [u64; 4]) -> Option<Self> {
+        (state != [0; 4]).then_some(Self { state })
+    }
+
+    /// Returns the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::xoshiro::Xoshiro256;
+    ///
+    /// let state = [1, 2, 3, 4];
+    /// assert_eq!(Xoshiro256::from_state(state).unwrap().state(), state);
+    /// ```
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+}
+
+impl Default for Xoshiro256 {
+    /// Creates a generator from seed 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::random::xoshiro::Xoshiro256;
+    ///
+    /// assert_eq!(Xoshiro256::default(), Xoshiro256::new(0));
+    /// ```
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Rng for Xoshiro256 {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
@@ -0,0 +1,541 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// A numeric type that can be used as the component of a vector.
+///
+/// `Scalar` is implemented for `f32`, `f64` and all of the primitive integer
+/// types. Every operation on the vector types is computed natively in the
+/// component type, so a `Vector3<f32>` never widens to `f64` and a
+/// `Vector3<i32>` stays exact.
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+    /// The value two, handy for generic formulas such as `2 * a * b`.
+    const TWO: Self;
+}
+
+/// A [`Scalar`] with a distance from zero.
+///
+/// Implemented for `f32`, `f64` and all of the primitive integer types.
+/// Norms such as [`Vector3::manhattan_length`] only need this, so they work
+/// for unsigned components too.
+///
+/// [`Vector3::manhattan_length`]: crate::vectors::vector3::Vector3::manhattan_length
+pub trait Magnitude: Scalar {
+    /// Returns the absolute value, which for an unsigned integer is the
+    /// value itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::scalar::Magnitude;
+    ///
+    /// assert_eq!((-3).magnitude(), 3);
+    /// assert_eq!(3u32.magnitude(), 3);
+    /// assert_eq!((-1.5).magnitude(), 1.5);
+    /// ```
+    fn magnitude(self) -> Self;
+}
+
+/// A [`Scalar`] that can be negated.
+///
+/// Implemented for `f32`, `f64` and the signed integer types.
+pub trait Signed: Magnitude + Neg<Output = Self> {
+    /// Returns the absolute value.
+    ///
+    /// This is [`Magnitude::magnitude`] under its usual name for signed
+    /// types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::scalar::Signed;
+    ///
+    /// fn distance<T: Signed>(a: T, b: T) -> T {
+    ///     (a - b).abs()
+    /// }
+    ///
+    /// assert_eq!(distance(2, 5), 3);
+    /// assert_eq!(distance(-1.5, 0.0), 1.5);
+    /// ```
+    #[inline]
+    fn abs(self) -> Self {
+        self.magnitude()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the value.
+    ///
+    /// Floating-point values follow the standard library and return `1.0`
+    /// for `+0.0` and `-1.0` for `-0.0`.
+    fn signum(self) -> Self;
+}
+
+/// A [`Signed`] floating-point scalar.
+///
+/// Implemented for `f32` and `f64`. This is the bound used by operations that
+/// need square roots, trigonometry or fractional values, such as
+/// `length`, `normalize` and `angle_between`.
+pub trait Real: Signed {
+    /// The value `0.5`.
+    const HALF: Self;
+    /// Machine epsilon for the type.
+    const EPSILON: Self;
+    /// Archimedes' constant (π).
+    const PI: Self;
+    /// The full circle constant (τ = 2π).
+    const TAU: Self;
+    /// π/2.
+    const FRAC_PI_2: Self;
+    /// Positive infinity.
+    const INFINITY: Self;
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+    /// The largest finite value.
+    const MAX: Self;
+    /// The smallest finite value.
+    const MIN: Self;
+
+    /// Converts an `f64` into this type, rounding if necessary.
+    ///
+    /// This is intended for writing constants in generic code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::scalar::Real;
+    ///
+    /// assert_eq!(f32::from_f64(0.25), 0.25f32);
+    /// ```
+    fn from_f64(v: f64) -> Self;
+
+    /// Converts this value into an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Returns the sine (in radians).
+    fn sin(self) -> Self;
+    /// Returns the cosine (in radians).
+    fn cos(self) -> Self;
+    /// Returns the sine and cosine at once.
+    fn sin_cos(self) -> (Self, Self);
+    /// Returns the tangent (in radians).
+    fn tan(self) -> Self;
+    /// Returns the arcsine in the range `[-π/2, π/2]`.
+    fn asin(self) -> Self;
+    /// Returns the arccosine in the range `[0, π]`.
+    fn acos(self) -> Self;
+    /// Returns the arctangent in the range `[-π/2, π/2]`.
+    fn atan(self) -> Self;
+    /// Returns the four quadrant arctangent of `self` (y) and `other` (x).
+    fn atan2(self, other: Self) -> Self;
+    /// Returns `e^self`.
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm.
+    fn ln(self) -> Self;
+    /// Raises `self` to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+    /// Raises `self` to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Returns the largest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to `self`.
+    fn ceil(self) -> Self;
+    /// Returns the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Returns the integer part of `self`.
+    fn trunc(self) -> Self;
+    /// Returns `1 / self`.
+    fn recip(self) -> Self;
+    /// Returns the minimum of two values, ignoring NaN.
+    fn min(self, other: Self) -> Self;
+    /// Returns the maximum of two values, ignoring NaN.
+    fn max(self, other: Self) -> Self;
+    /// Restricts the value to the range `[min, max]`.
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Returns a value with the magnitude of `self` and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns `true` if the value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if the value is NaN.
+    fn is_nan(self) -> bool;
+}
+
+/// A primitive integer [`Scalar`].
+///
+/// Integer vectors are exact, totally ordered and hashable, which makes them
+/// suitable for grid, voxel and tile coordinates.
+pub trait Integer: Scalar + Eq + Ord + Hash {
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Euclidean division, rounding the quotient towards negative infinity
+    /// for positive divisors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::scalar::Integer;
+    ///
+    /// assert_eq!(Integer::div_euclid(-7, 4), -2);
+    /// ```
+    fn div_euclid(self, rhs: Self) -> Self;
+    /// The non-negative remainder of Euclidean division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::scalar::Integer;
+    ///
+    /// assert_eq!(Integer::rem_euclid(-7, 4), 1);
+    /// ```
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// Addition returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Subtraction returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Multiplication returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Division returning `None` on overflow or division by zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Addition wrapping around at the type's boundary.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Subtraction wrapping around at the type's boundary.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Multiplication wrapping around at the type's boundary.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Addition saturating at the type's boundary.
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Subtraction saturating at the type's boundary.
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Multiplication saturating at the type's boundary.
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty => $zero:expr, $one:expr, $two:expr);* $(;)?) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+                const TWO: Self = $two;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_magnitude {
+    (abs: $($t:ty),*) => {
+        $(
+            impl Magnitude for $t {
+                #[inline]
+                fn magnitude(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+    (identity: $($t:ty),*) => {
+        $(
+            impl Magnitude for $t {
+                #[inline]
+                fn magnitude(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                #[inline]
+                fn div_euclid(self, rhs: Self) -> Self {
+                    <$t>::div_euclid(self, rhs)
+                }
+
+                #[inline]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                #[inline]
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$t>::wrapping_mul(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$t>::saturating_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+/// Calls a float method from `std`, or its software version from
+/// [`math`](crate::math) when `std` is disabled.
+#[cfg(feature = "std")]
+macro_rules! float {
+    ($t:ident::$method:ident($($arg:expr),*)) => {
+        $t::$method($($arg),*)
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! float {
+    ($t:ident::$method:ident($($arg:expr),*)) => {
+        <$t as crate::math::Float>::$method($($arg),*)
+    };
+}
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                const HALF: Self = 0.5;
+                const EPSILON: Self = $t::EPSILON;
+                const PI: Self = core::$t::consts::PI;
+                const TAU: Self = core::$t::consts::TAU;
+                const FRAC_PI_2: Self = core::$t::consts::FRAC_PI_2;
+                const INFINITY: Self = $t::INFINITY;
+                const NEG_INFINITY: Self = $t::NEG_INFINITY;
+                const MAX: Self = $t::MAX;
+                const MIN: Self = $t::MIN;
+
+                #[inline]
+                fn from_f64(v: f64) -> Self {
+                    v as $t
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    float!($t::sqrt(self))
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    float!($t::sin(self))
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    float!($t::cos(self))
+                }
+
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    float!($t::sin_cos(self))
+                }
+
+                #[inline]
+                fn tan(self) -> Self {
+                    float!($t::tan(self))
+                }
+
+                #[inline]
+                fn asin(self) -> Self {
+                    float!($t::asin(self))
+                }
+
+                #[inline]
+                fn acos(self) -> Self {
+                    float!($t::acos(self))
+                }
+
+                #[inline]
+                fn atan(self) -> Self {
+                    float!($t::atan(self))
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    float!($t::atan2(self, other))
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    float!($t::exp(self))
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    float!($t::ln(self))
+                }
+
+                #[inline]
+                fn powf(self, n: Self) -> Self {
+                    float!($t::powf(self, n))
+                }
+
+                #[inline]
+                fn powi(self, n: i32) -> Self {
+                    float!($t::powi(self, n))
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    float!($t::floor(self))
+                }
+
+                #[inline]
+                fn ceil(self) -> Self {
+                    float!($t::ceil(self))
+                }
+
+                #[inline]
+                fn round(self) -> Self {
+                    float!($t::round(self))
+                }
+
+                #[inline]
+                fn trunc(self) -> Self {
+                    float!($t::trunc(self))
+                }
+
+                #[inline]
+                fn recip(self) -> Self {
+                    $t::recip(self)
+                }
+
+                #[inline]
+                fn min(self, other: Self) -> Self {
+                    $t::min(self, other)
+                }
+
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    $t::max(self, other)
+                }
+
+                #[inline]
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    $t::clamp(self, min, max)
+                }
+
+                #[inline]
+                fn copysign(self, sign: Self) -> Self {
+                    $t::copysign(self, sign)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    $t::is_nan(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(
+    f32 => 0.0, 1.0, 2.0;
+    f64 => 0.0, 1.0, 2.0;
+    i8 => 0, 1, 2;
+    i16 => 0, 1, 2;
+    i32 => 0, 1, 2;
+    i64 => 0, 1, 2;
+    i128 => 0, 1, 2;
+    isize => 0, 1, 2;
+    u8 => 0, 1, 2;
+    u16 => 0, 1, 2;
+    u32 => 0, 1, 2;
+    u64 => 0, 1, 2;
+    u128 => 0, 1, 2;
+    usize => 0, 1, 2;
+);
+
+impl_magnitude!(abs: f32, f64, i8, i16, i32, i64, i128, isize);
+impl_magnitude!(identity: u8, u16, u32, u64, u128, usize);
+impl_signed!(f32, f64, i8, i16, i32, i64, i128, isize);
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_real!(f32, f64);
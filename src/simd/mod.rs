@@ -0,0 +1,40 @@
+//! The four-lane `f32` and `f64` types behind the aligned vectors and
+//! matrices.
+//!
+//! On x86 and x86_64 targets with SSE2, which every x86_64 target has, the
+//! lanes live in SSE registers: one for four `f32`s, two for four `f64`s.
+//! Elsewhere, or with the `scalar-math` feature, they are a plain array.
+//! Both backends perform the same floating-point operations in the same
+//! order as the generic types, so results are bit-for-bit identical
+//! whichever path is compiled in.
+//!
+//! AVX is deliberately out of scope: `F64x4` stays a pair of SSE2 registers
+//! even when `target_feature = "avx"` is enabled. AVX is not part of the
+//! x86_64 baseline, so a third backend would only run in builds that opt into
+//! it, and it would be one more path to keep bit-for-bit identical.
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+))]
+mod sse2;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+))]
+pub(crate) use sse2::{F32x4, F64x4};
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+)))]
+mod scalar;
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+)))]
+pub(crate) use scalar::{F32x4, F64x4};
@@ -0,0 +1,205 @@
+//! The portable backend, used when SSE2 is unavailable or disabled.
+
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Four `f32` lanes, aligned like an SSE register.
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub(crate) struct F32x4([f32; 4]);
+
+impl F32x4 {
+    #[inline]
+    pub(crate) fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self([x, y, z, w])
+    }
+
+    #[inline]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self([value; 4])
+    }
+
+    #[inline]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+
+    #[inline]
+    pub(crate) fn x(self) -> f32 {
+        self.0[0]
+    }
+
+    #[inline]
+    pub(crate) fn y(self) -> f32 {
+        self.0[1]
+    }
+
+    #[inline]
+    pub(crate) fn z(self) -> f32 {
+        self.0[2]
+    }
+
+    #[inline]
+    pub(crate) fn w(self) -> f32 {
+        self.0[3]
+    }
+
+    #[inline]
+    pub(crate) fn splat_x(self) -> Self {
+        Self::splat(self.0[0])
+    }
+
+    #[inline]
+    pub(crate) fn splat_y(self) -> Self {
+        Self::splat(self.0[1])
+    }
+
+    #[inline]
+    pub(crate) fn splat_z(self) -> Self {
+        Self::splat(self.0[2])
+    }
+
+    #[inline]
+    pub(crate) fn splat_w(self) -> Self {
+        Self::splat(self.0[3])
+    }
+
+    /// Sums the products of the first three lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot3(self, other: Self) -> f32 {
+        let [a, b] = [self.0, other.0];
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    }
+
+    /// Sums the products of all four lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot4(self, other: Self) -> f32 {
+        let [a, b] = [self.0, other.0];
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+    }
+
+    /// Computes the cross product of the first three lanes. The last lane is
+    /// left unspecified.
+    #[inline]
+    pub(crate) fn cross3(self, other: Self) -> Self {
+        let [a, b] = [self.0, other.0];
+        Self([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+            0.0,
+        ])
+    }
+}
+
+/// Four `f64` lanes, aligned like a pair of SSE registers.
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub(crate) struct F64x4([f64; 4]);
+
+impl F64x4 {
+    #[inline]
+    pub(crate) fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self([x, y, z, w])
+    }
+
+    #[inline]
+    pub(crate) fn splat(value: f64) -> Self {
+        Self([value; 4])
+    }
+
+    #[inline]
+    pub(crate) fn to_array(self) -> [f64; 4] {
+        self.0
+    }
+
+    #[inline]
+    pub(crate) fn x(self) -> f64 {
+        self.0[0]
+    }
+
+    #[inline]
+    pub(crate) fn y(self) -> f64 {
+        self.0[1]
+    }
+
+    #[inline]
+    pub(crate) fn z(self) -> f64 {
+        self.0[2]
+    }
+
+    #[inline]
+    pub(crate) fn w(self) -> f64 {
+        self.0[3]
+    }
+
+    #[inline]
+    pub(crate) fn splat_x(self) -> Self {
+        Self::splat(self.0[0])
+    }
+
+    #[inline]
+    pub(crate) fn splat_y(self) -> Self {
+        Self::splat(self.0[1])
+    }
+
+    #[inline]
+    pub(crate) fn splat_z(self) -> Self {
+        Self::splat(self.0[2])
+    }
+
+    #[inline]
+    pub(crate) fn splat_w(self) -> Self {
+        Self::splat(self.0[3])
+    }
+
+    /// Sums the products of the first three lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot3(self, other: Self) -> f64 {
+        let [a, b] = [self.0, other.0];
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    }
+
+    /// Sums the products of all four lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot4(self, other: Self) -> f64 {
+        let [a, b] = [self.0, other.0];
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+    }
+
+    /// Computes the cross product of the first three lanes. The last lane is
+    /// left unspecified.
+    #[inline]
+    pub(crate) fn cross3(self, other: Self) -> Self {
+        let [a, b] = [self.0, other.0];
+        Self([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+            0.0,
+        ])
+    }
+}
+
+macro_rules! impl_lanewise {
+    ($lanes:ident: $($trait:ident::$method:ident),*) => {
+        $(
+            impl $trait for $lanes {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    let [a, b] = [self.0, rhs.0];
+                    Self([
+                        a[0].$method(b[0]),
+                        a[1].$method(b[1]),
+                        a[2].$method(b[2]),
+                        a[3].$method(b[3]),
+                    ])
+                }
+            }
+        )*
+    };
+}
+
+impl_lanewise!(F32x4: Add::add, Sub::sub, Mul::mul, Div::div);
+impl_lanewise!(F64x4: Add::add, Sub::sub, Mul::mul, Div::div);
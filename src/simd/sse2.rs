@@ -0,0 +1,273 @@
+//! The SSE2 backend.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Four `f32` lanes in an SSE register.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub(crate) struct F32x4(__m128);
+
+// SAFETY: every intrinsic below only requires SSE2, which the module is
+// compiled for.
+impl F32x4 {
+    #[inline]
+    pub(crate) fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        // `_mm_set_ps` takes the lanes from last to first.
+        Self(unsafe { _mm_set_ps(w, z, y, x) })
+    }
+
+    #[inline]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self(unsafe { _mm_set1_ps(value) })
+    }
+
+    #[inline]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+        out
+    }
+
+    #[inline]
+    pub(crate) fn x(self) -> f32 {
+        unsafe { _mm_cvtss_f32(self.0) }
+    }
+
+    #[inline]
+    pub(crate) fn y(self) -> f32 {
+        self.splat_y().x()
+    }
+
+    #[inline]
+    pub(crate) fn z(self) -> f32 {
+        self.splat_z().x()
+    }
+
+    #[inline]
+    pub(crate) fn w(self) -> f32 {
+        self.splat_w().x()
+    }
+
+    #[inline]
+    pub(crate) fn splat_x(self) -> Self {
+        Self(unsafe { _mm_shuffle_ps::<0b00_00_00_00>(self.0, self.0) })
+    }
+
+    #[inline]
+    pub(crate) fn splat_y(self) -> Self {
+        Self(unsafe { _mm_shuffle_ps::<0b01_01_01_01>(self.0, self.0) })
+    }
+
+    #[inline]
+    pub(crate) fn splat_z(self) -> Self {
+        Self(unsafe { _mm_shuffle_ps::<0b10_10_10_10>(self.0, self.0) })
+    }
+
+    #[inline]
+    pub(crate) fn splat_w(self) -> Self {
+        Self(unsafe { _mm_shuffle_ps::<0b11_11_11_11>(self.0, self.0) })
+    }
+
+    /// Sums the products of the first three lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot3(self, other: Self) -> f32 {
+        // The products are computed together, but the sum is accumulated one
+        // lane at a time so that it rounds exactly like the scalar code.
+        let products = self * other;
+        let sum = unsafe { _mm_add_ss(products.0, products.splat_y().0) };
+        unsafe { _mm_cvtss_f32(_mm_add_ss(sum, products.splat_z().0)) }
+    }
+
+    /// Sums the products of all four lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot4(self, other: Self) -> f32 {
+        let products = self * other;
+        let sum = unsafe { _mm_add_ss(products.0, products.splat_y().0) };
+        let sum = unsafe { _mm_add_ss(sum, products.splat_z().0) };
+        unsafe { _mm_cvtss_f32(_mm_add_ss(sum, products.splat_w().0)) }
+    }
+
+    /// Computes the cross product of the first three lanes. The last lane is
+    /// left unspecified.
+    #[inline]
+    pub(crate) fn cross3(self, other: Self) -> Self {
+        // self.yzx * other.zxy - self.zxy * other.yzx
+        let (a, b) = (self.0, other.0);
+        unsafe {
+            let a_yzx = _mm_shuffle_ps::<0b11_00_10_01>(a, a);
+            let a_zxy = _mm_shuffle_ps::<0b11_01_00_10>(a, a);
+            let b_yzx = _mm_shuffle_ps::<0b11_00_10_01>(b, b);
+            let b_zxy = _mm_shuffle_ps::<0b11_01_00_10>(b, b);
+            Self(_mm_sub_ps(
+                _mm_mul_ps(a_yzx, b_zxy),
+                _mm_mul_ps(a_zxy, b_yzx),
+            ))
+        }
+    }
+}
+
+macro_rules! impl_lanewise {
+    ($($trait:ident::$method:ident => $intrinsic:ident),*) => {
+        $(
+            impl $trait for F32x4 {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self(unsafe { $intrinsic(self.0, rhs.0) })
+                }
+            }
+        )*
+    };
+}
+
+impl_lanewise!(
+    Add::add => _mm_add_ps,
+    Sub::sub => _mm_sub_ps,
+    Mul::mul => _mm_mul_ps,
+    Div::div => _mm_div_ps
+);
+
+/// Four `f64` lanes in a pair of SSE registers, the first holding `x` and
+/// `y` and the second `z` and `w`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct F64x4(__m128d, __m128d);
+
+// SAFETY: as for `F32x4`, every intrinsic below only requires SSE2.
+impl F64x4 {
+    #[inline]
+    pub(crate) fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        // `_mm_set_pd` takes the lanes from last to first.
+        unsafe { Self(_mm_set_pd(y, x), _mm_set_pd(w, z)) }
+    }
+
+    #[inline]
+    pub(crate) fn splat(value: f64) -> Self {
+        let lanes = unsafe { _mm_set1_pd(value) };
+        Self(lanes, lanes)
+    }
+
+    #[inline]
+    pub(crate) fn to_array(self) -> [f64; 4] {
+        let mut out = [0.0; 4];
+        unsafe {
+            _mm_storeu_pd(out.as_mut_ptr(), self.0);
+            _mm_storeu_pd(out[2..].as_mut_ptr(), self.1);
+        }
+        out
+    }
+
+    #[inline]
+    pub(crate) fn x(self) -> f64 {
+        unsafe { _mm_cvtsd_f64(self.0) }
+    }
+
+    #[inline]
+    pub(crate) fn y(self) -> f64 {
+        unsafe { _mm_cvtsd_f64(_mm_unpackhi_pd(self.0, self.0)) }
+    }
+
+    #[inline]
+    pub(crate) fn z(self) -> f64 {
+        unsafe { _mm_cvtsd_f64(self.1) }
+    }
+
+    #[inline]
+    pub(crate) fn w(self) -> f64 {
+        unsafe { _mm_cvtsd_f64(_mm_unpackhi_pd(self.1, self.1)) }
+    }
+
+    #[inline]
+    pub(crate) fn splat_x(self) -> Self {
+        let lanes = unsafe { _mm_unpacklo_pd(self.0, self.0) };
+        Self(lanes, lanes)
+    }
+
+    #[inline]
+    pub(crate) fn splat_y(self) -> Self {
+        let lanes = unsafe { _mm_unpackhi_pd(self.0, self.0) };
+        Self(lanes, lanes)
+    }
+
+    #[inline]
+    pub(crate) fn splat_z(self) -> Self {
+        let lanes = unsafe { _mm_unpacklo_pd(self.1, self.1) };
+        Self(lanes, lanes)
+    }
+
+    #[inline]
+    pub(crate) fn splat_w(self) -> Self {
+        let lanes = unsafe { _mm_unpackhi_pd(self.1, self.1) };
+        Self(lanes, lanes)
+    }
+
+    /// Sums the products of the first three lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot3(self, other: Self) -> f64 {
+        let products = self * other;
+        unsafe {
+            let sum = _mm_add_sd(products.0, _mm_unpackhi_pd(products.0, products.0));
+            _mm_cvtsd_f64(_mm_add_sd(sum, products.1))
+        }
+    }
+
+    /// Sums the products of all four lanes, in lane order.
+    #[inline]
+    pub(crate) fn dot4(self, other: Self) -> f64 {
+        let products = self * other;
+        unsafe {
+            let sum = _mm_add_sd(products.0, _mm_unpackhi_pd(products.0, products.0));
+            let sum = _mm_add_sd(sum, products.1);
+            _mm_cvtsd_f64(_mm_add_sd(sum, _mm_unpackhi_pd(products.1, products.1)))
+        }
+    }
+
+    /// Computes the cross product of the first three lanes. The last lane is
+    /// left unspecified.
+    #[inline]
+    pub(crate) fn cross3(self, other: Self) -> Self {
+        // self.yzx * other.zxy - self.zxy * other.yzx, where `_mm_shuffle_pd`
+        // takes its low lane from the first register and its high lane from
+        // the second.
+        let yzx = |v: Self| unsafe {
+            Self(
+                _mm_shuffle_pd::<0b01>(v.0, v.1),
+                _mm_shuffle_pd::<0b10>(v.0, v.1),
+            )
+        };
+        let zxy = |v: Self| unsafe {
+            Self(
+                _mm_shuffle_pd::<0b00>(v.1, v.0),
+                _mm_shuffle_pd::<0b11>(v.0, v.1),
+            )
+        };
+        yzx(self) * zxy(other) - zxy(self) * yzx(other)
+    }
+}
+
+macro_rules! impl_lanewise_pairs {
+    ($($trait:ident::$method:ident => $intrinsic:ident),*) => {
+        $(
+            impl $trait for F64x4 {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    unsafe { Self($intrinsic(self.0, rhs.0), $intrinsic(self.1, rhs.1)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_lanewise_pairs!(
+    Add::add => _mm_add_pd,
+    Sub::sub => _mm_sub_pd,
+    Mul::mul => _mm_mul_pd,
+    Div::div => _mm_div_pd
+);
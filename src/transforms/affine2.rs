@@ -0,0 +1,366 @@
+use core::ops::Mul;
+
+use crate::matrices::mat2::Mat2;
+use crate::matrices::mat3::Mat3;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+
+/// A 2D affine transform made of a linear part and a translation.
+///
+/// A point `p` is transformed as `matrix * p + translation`. The linear part
+/// can hold any combination of rotation, scale and shear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2<T> {
+    /// The linear part of the transform.
+    pub matrix: Mat2<T>,
+    /// The translation applied after the linear part.
+    pub translation: Vector2<T>,
+}
+
+impl<T> Affine2<T> {
+    /// Creates a transform from a linear part and a translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat2::Mat2;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::new(Mat2::identity(), Vector2::new(1.0, 2.0));
+    /// assert_eq!(a.transform_point(Vector2::new(0.0, 0.0)), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn new(matrix: Mat2<T>, translation: Vector2<T>) -> Self {
+        Self {
+            matrix,
+            translation,
+        }
+    }
+
+    /// Returns the identity transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::<f64>::identity();
+    /// assert_eq!(a.transform_point(Vector2::new(3.0, 4.0)), Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(Mat2::identity(), Vector2::new(T::ZERO, T::ZERO))
+    }
+
+    /// Creates a pure translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(1.0, -1.0));
+    /// assert_eq!(a.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 0.0));
+    /// ```
+    pub fn from_translation(translation: Vector2<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(Mat2::identity(), translation)
+    }
+
+    /// Creates a counter-clockwise rotation by `angle` radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_angle(std::f64::consts::FRAC_PI_2);
+    /// let p = a.transform_point(Vector2::new(1.0, 0.0));
+    /// assert_approx_eq!(p.x, 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(p.y, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_angle(angle: T) -> Self
+    where
+        T: Real,
+    {
+        let (s, c) = angle.sin_cos();
+        Self::new(
+            Mat2::from_cols(Vector2::new(c, s), Vector2::new(-s, c)),
+            Vector2::new(T::ZERO, T::ZERO),
+        )
+    }
+
+    /// Creates a non-uniform scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_scale(Vector2::new(2.0, 3.0));
+    /// assert_eq!(a.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 3.0));
+    /// ```
+    pub fn from_scale(scale: Vector2<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(Mat2::from_diagonal(scale), Vector2::new(T::ZERO, T::ZERO))
+    }
+
+    /// Creates a transform that scales, then rotates by `angle` radians, then
+    /// translates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_scale_angle_translation(
+    ///     Vector2::new(2.0, 2.0),
+    ///     std::f64::consts::PI,
+    ///     Vector2::new(1.0, 0.0),
+    /// );
+    /// let p = a.transform_point(Vector2::new(1.0, 0.0));
+    /// assert_approx_eq!(p.x, -1.0, epsilon = 1e-6);
+    /// assert_approx_eq!(p.y, 0.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_scale_angle_translation(
+        scale: Vector2<T>,
+        angle: T,
+        translation: Vector2<T>,
+    ) -> Self
+    where
+        T: Real,
+    {
+        let rotation = Self::from_angle(angle).matrix;
+        Self::new(rotation * Mat2::from_diagonal(scale), translation)
+    }
+
+    /// Creates a transform from a homogeneous 3x3 matrix, ignoring its last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(3.0, 4.0));
+    /// assert_eq!(Affine2::from_mat3(&a.to_mat3()), a);
+    /// ```
+    pub fn from_mat3(m: &Mat3<T>) -> Self
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2] = m.cols;
+        Self::new(
+            Mat2::from_cols(Vector2::new(c0.x, c0.y), Vector2::new(c1.x, c1.y)),
+            Vector2::new(c2.x, c2.y),
+        )
+    }
+
+    /// Converts the transform into a homogeneous 3x3 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(3.0, 4.0));
+    /// let m = a.to_mat3();
+    /// assert_eq!(m * Vector3::new(1.0, 1.0, 1.0), Vector3::new(4.0, 5.0, 1.0));
+    /// ```
+    pub fn to_mat3(&self) -> Mat3<T>
+    where
+        T: Scalar,
+    {
+        let [c0, c1] = self.matrix.cols;
+        let t = self.translation;
+        Mat3::from_cols(
+            Vector3::new(c0.x, c0.y, T::ZERO),
+            Vector3::new(c1.x, c1.y, T::ZERO),
+            Vector3::new(t.x, t.y, T::ONE),
+        )
+    }
+
+    /// Composes two transforms so that `other` is applied first and `self`
+    /// second. This is equivalent to `self * other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let scale = Affine2::from_scale(Vector2::new(2.0, 2.0));
+    /// let translate = Affine2::from_translation(Vector2::new(1.0, 0.0));
+    /// let a = translate.compose(&scale);
+    /// assert_eq!(a.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(3.0, 2.0));
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(
+            self.matrix * other.matrix,
+            self.transform_point(other.translation),
+        )
+    }
+
+    /// Computes the inverse transform, or `None` if the linear part is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_scale_angle_translation(
+    ///     Vector2::new(2.0, 4.0),
+    ///     0.0,
+    ///     Vector2::new(1.0, 2.0),
+    /// );
+    /// let inv = a.inverse().unwrap();
+    /// let p = Vector2::new(5.0, -3.0);
+    /// assert_eq!(inv.transform_point(a.transform_point(p)), p);
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let matrix = self.matrix.inverse()?;
+        let t = matrix * self.translation;
+        Some(Self::new(matrix, Vector2::new(-t.x, -t.y)))
+    }
+
+    /// Returns the determinant of the linear part. A negative value means
+    /// the transform mirrors its input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mirror = Affine2::from_scale(Vector2::new(-1.0, 1.0));
+    /// assert_eq!(mirror.determinant(), -1.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        self.matrix.determinant()
+    }
+
+    /// Transforms a point, applying both the linear part and the translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(1.0, 1.0));
+    /// assert_eq!(a.transform_point(Vector2::new(2.0, 3.0)), Vector2::new(3.0, 4.0));
+    /// ```
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T>
+    where
+        T: Scalar,
+    {
+        self.matrix * point + self.translation
+    }
+
+    /// Transforms a direction, applying only the linear part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(1.0, 1.0));
+    /// assert_eq!(a.transform_vector(Vector2::new(2.0, 3.0)), Vector2::new(2.0, 3.0));
+    /// ```
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T>
+    where
+        T: Scalar,
+    {
+        self.matrix * vector
+    }
+
+    /// Transforms a surface normal by the inverse transpose of the linear part
+    /// and renormalizes it, so it stays perpendicular to transformed edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// // The edge (1, -1) with normal (1, 1), squashed vertically.
+    /// let a = Affine2::from_scale(Vector2::new(1.0_f64, 0.5));
+    /// let edge = a.transform_vector(Vector2::new(1.0, -1.0));
+    /// let normal = a.transform_normal(Vector2::new(1.0, 1.0));
+    /// assert_approx_eq!(edge.dot(&normal), 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(normal.length(), 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn transform_normal(&self, normal: Vector2<T>) -> Vector2<T>
+    where
+        T: Real,
+    {
+        // The cofactor matrix is the inverse transpose scaled by the
+        // determinant, which is well defined even for singular matrices.
+        let [c0, c1] = self.matrix.cols;
+        let cofactor = Mat2::from_cols(Vector2::new(c1.y, -c1.x), Vector2::new(-c0.y, c0.x));
+        let n = cofactor * normal;
+        let sign = self.matrix.determinant().signum();
+        Vector2::new(n.x * sign, n.y * sign).normalize()
+    }
+}
+
+impl<T> Mul for Affine2<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Composes two transforms, see [`Affine2::compose`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine2::Affine2;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let a = Affine2::from_translation(Vector2::new(1.0, 0.0));
+    /// let b = Affine2::from_translation(Vector2::new(0.0, 1.0));
+    /// assert_eq!((a * b).translation, Vector2::new(1.0, 1.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl<T> From<Affine2<T>> for Mat3<T>
+where
+    T: Scalar,
+{
+    /// Converts the transform into a homogeneous 3x3 matrix.
+    fn from(a: Affine2<T>) -> Self {
+        a.to_mat3()
+    }
+}
@@ -0,0 +1,435 @@
+use core::ops::Mul;
+
+use crate::matrices::mat3::Mat3;
+use crate::matrices::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::{Real, Scalar, Signed};
+use crate::vectors::vector3::Vector3;
+
+/// A 3D affine transform made of a linear part and a translation.
+///
+/// A point `p` is transformed as `matrix * p + translation`. The linear part
+/// can hold any combination of rotation, scale and shear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine3<T> {
+    /// The linear part of the transform.
+    pub matrix: Mat3<T>,
+    /// The translation applied after the linear part.
+    pub translation: Vector3<T>,
+}
+
+impl<T> Affine3<T> {
+    /// Creates a transform from a linear part and a translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat3::Mat3;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::new(Mat3::identity(), Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(0.0, 0.0, 0.0)),
+    ///     Vector3::new(1.0, 2.0, 3.0)
+    /// );
+    /// ```
+    pub fn new(matrix: Mat3<T>, translation: Vector3<T>) -> Self {
+        Self {
+            matrix,
+            translation,
+        }
+    }
+
+    /// Returns the identity transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::<f64>::identity();
+    /// let p = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(a.transform_point(p), p);
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(Mat3::identity(), Vector3::new(T::ZERO, T::ZERO, T::ZERO))
+    }
+
+    /// Creates a pure translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(2.0, 3.0, 4.0)
+    /// );
+    /// ```
+    pub fn from_translation(translation: Vector3<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(Mat3::identity(), translation)
+    }
+
+    /// Creates a pure rotation from a unit quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     std::f64::consts::FRAC_PI_2,
+    /// );
+    /// let p = Affine3::from_rotation(q).transform_point(Vector3::new(1.0, 0.0, 0.0));
+    /// assert_approx_eq!(p.x, 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(p.y, 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self
+    where
+        T: Real,
+    {
+        Self::new(rotation.to_mat3(), Vector3::new(T::ZERO, T::ZERO, T::ZERO))
+    }
+
+    /// Creates a non-uniform scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(2.0, 3.0, 4.0)
+    /// );
+    /// ```
+    pub fn from_scale(scale: Vector3<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(
+            Mat3::from_diagonal(scale),
+            Vector3::new(T::ZERO, T::ZERO, T::ZERO),
+        )
+    }
+
+    /// Creates a transform that scales, then rotates, then translates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_scale_rotation_translation(
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    ///     Quaternion::identity(),
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    /// );
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(2.0, 2.0, 3.0)
+    /// );
+    /// ```
+    pub fn from_scale_rotation_translation(
+        scale: Vector3<T>,
+        rotation: Quaternion<T>,
+        translation: Vector3<T>,
+    ) -> Self
+    where
+        T: Real,
+    {
+        Self::new(rotation.to_mat3() * Mat3::from_diagonal(scale), translation)
+    }
+
+    /// Decomposes the transform into scale, rotation and translation.
+    ///
+    /// The linear part must not contain shear. A mirroring transform is
+    /// reported as a negative `x` scale. The rotation is undefined if any
+    /// scale factor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.5_f64);
+    /// let a = Affine3::from_scale_rotation_translation(
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     rotation,
+    ///     Vector3::new(4.0, 5.0, 6.0),
+    /// );
+    /// let (scale, r, translation) = a.to_scale_rotation_translation();
+    /// assert_approx_eq!(scale.z, 3.0, epsilon = 1e-6);
+    /// assert_approx_eq!(r.dot(&rotation), 1.0, epsilon = 1e-6);
+    /// assert_eq!(translation, Vector3::new(4.0, 5.0, 6.0));
+    /// ```
+    pub fn to_scale_rotation_translation(&self) -> (Vector3<T>, Quaternion<T>, Vector3<T>)
+    where
+        T: Real,
+    {
+        let [c0, c1, c2] = self.matrix.cols;
+        let sign = self.matrix.determinant().signum();
+        let scale = Vector3::new(c0.length() * sign, c1.length(), c2.length());
+        let rotation = Mat3::from_cols(
+            Vector3::new(c0.x / scale.x, c0.y / scale.x, c0.z / scale.x),
+            Vector3::new(c1.x / scale.y, c1.y / scale.y, c1.z / scale.y),
+            Vector3::new(c2.x / scale.z, c2.y / scale.z, c2.z / scale.z),
+        );
+        (
+            scale,
+            Quaternion::from_mat3(&rotation).normalize(),
+            self.translation,
+        )
+    }
+
+    /// Creates a transform from a homogeneous 4x4 matrix, ignoring its last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(Affine3::from_mat4(&a.to_mat4()), a);
+    /// ```
+    pub fn from_mat4(m: &Mat4<T>) -> Self
+    where
+        T: Copy,
+    {
+        let [c0, c1, c2, c3] = m.cols;
+        Self::new(
+            Mat3::from_cols(
+                Vector3::new(c0.x, c0.y, c0.z),
+                Vector3::new(c1.x, c1.y, c1.z),
+                Vector3::new(c2.x, c2.y, c2.z),
+            ),
+            Vector3::new(c3.x, c3.y, c3.z),
+        )
+    }
+
+    /// Converts the transform into a homogeneous 4x4 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     a.to_mat4() * Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ///     Vector4::new(2.0, 3.0, 4.0, 1.0)
+    /// );
+    /// ```
+    pub fn to_mat4(&self) -> Mat4<T>
+    where
+        T: Scalar,
+    {
+        let [c0, c1, c2] = self.matrix.cols;
+        let t = self.translation;
+        Mat4::from_cols(
+            c0.extend(T::ZERO),
+            c1.extend(T::ZERO),
+            c2.extend(T::ZERO),
+            t.extend(T::ONE),
+        )
+    }
+
+    /// Composes two transforms so that `other` is applied first and `self`
+    /// second. This is equivalent to `self * other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let scale = Affine3::from_scale(Vector3::new(2.0, 2.0, 2.0));
+    /// let translate = Affine3::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    /// let a = translate.compose(&scale);
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(3.0, 2.0, 2.0)
+    /// );
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(
+            self.matrix * other.matrix,
+            self.transform_point(other.translation),
+        )
+    }
+
+    /// Computes the inverse transform, or `None` if the linear part is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0))
+    ///     .compose(&Affine3::from_scale(Vector3::new(2.0, 4.0, 8.0)));
+    /// let inv = a.inverse().unwrap();
+    /// let p = Vector3::new(5.0, -3.0, 0.5);
+    /// assert_eq!(inv.transform_point(a.transform_point(p)), p);
+    ///
+    /// assert!(Affine3::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let matrix = self.matrix.inverse()?;
+        let t = matrix * self.translation;
+        Some(Self::new(matrix, Vector3::new(-t.x, -t.y, -t.z)))
+    }
+
+    /// Returns the determinant of the linear part. A negative value means
+    /// the transform mirrors its input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_scale(Vector3::new(2.0, 3.0, -1.0));
+    /// assert_eq!(a.determinant(), -6.0);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: Signed,
+    {
+        self.matrix.determinant()
+    }
+
+    /// Transforms a point, applying both the linear part and the translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(
+    ///     a.transform_point(Vector3::new(1.0, 2.0, 3.0)),
+    ///     Vector3::new(2.0, 3.0, 4.0)
+    /// );
+    /// ```
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T>
+    where
+        T: Scalar,
+    {
+        self.matrix * point + self.translation
+    }
+
+    /// Transforms a direction, applying only the linear part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 1.0, 1.0));
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(a.transform_vector(v), v);
+    /// ```
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T>
+    where
+        T: Scalar,
+    {
+        self.matrix * vector
+    }
+
+    /// Transforms a surface normal by the inverse transpose of the linear part
+    /// and renormalizes it, so it stays perpendicular to transformed surfaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// // A plane containing (1, -1, 0) with normal (1, 1, 0), squashed along y.
+    /// let a = Affine3::from_scale(Vector3::new(1.0_f64, 0.5, 1.0));
+    /// let tangent = a.transform_vector(Vector3::new(1.0, -1.0, 0.0));
+    /// let normal = a.transform_normal(Vector3::new(1.0, 1.0, 0.0));
+    /// assert_approx_eq!(tangent.dot(&normal), 0.0, epsilon = 1e-6);
+    /// assert_approx_eq!(normal.length(), 1.0, epsilon = 1e-6);
+    /// ```
+    pub fn transform_normal(&self, normal: Vector3<T>) -> Vector3<T>
+    where
+        T: Real,
+    {
+        // The cofactor matrix is the inverse transpose scaled by the
+        // determinant, which is well defined even for singular matrices.
+        let [c0, c1, c2] = self.matrix.cols;
+        let cofactor = Mat3::from_cols(c1.cross(&c2), c2.cross(&c0), c0.cross(&c1));
+        let n = cofactor * normal;
+        let sign = self.matrix.determinant().signum();
+        Vector3::new(n.x * sign, n.y * sign, n.z * sign).normalize()
+    }
+}
+
+impl<T> Mul for Affine3<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    /// Composes two transforms, see [`Affine3::compose`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    /// let b = Affine3::from_translation(Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!((a * b).translation, Vector3::new(1.0, 1.0, 0.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl<T> From<Affine3<T>> for Mat4<T>
+where
+    T: Scalar,
+{
+    /// Converts the transform into a homogeneous 4x4 matrix.
+    fn from(a: Affine3<T>) -> Self {
+        a.to_mat4()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod affine2;
+pub mod affine3;
+pub mod transform;
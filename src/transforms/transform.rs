@@ -0,0 +1,432 @@
+use core::ops::Mul;
+
+use crate::matrices::mat3::Mat3;
+use crate::matrices::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::{Real, Scalar};
+use crate::transforms::affine3::Affine3;
+use crate::vectors::vector3::Vector3;
+
+/// A 3D transform stored as separate translation, rotation and scale (TRS).
+///
+/// A point `p` is transformed as `rotation * (scale * p) + translation`.
+/// Keeping the parts separate makes the transform cheap to edit and to
+/// interpolate, which suits scene hierarchies and animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform<T> {
+    /// The translation, applied last.
+    pub translation: Vector3<T>,
+    /// The rotation as a unit quaternion.
+    pub rotation: Quaternion<T>,
+    /// The per-axis scale, applied first.
+    pub scale: Vector3<T>,
+}
+
+impl<T> Transform<T> {
+    /// Creates a transform from its translation, rotation and scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::new(
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Quaternion::identity(),
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// assert_eq!(
+    ///     t.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(3.0, 2.0, 2.0)
+    /// );
+    /// ```
+    pub fn new(translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Returns the identity transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::<f64>::identity();
+    /// assert_eq!(t.scale, Vector3::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(
+            Vector3::new(T::ZERO, T::ZERO, T::ZERO),
+            Quaternion::identity(),
+            Vector3::new(T::ONE, T::ONE, T::ONE),
+        )
+    }
+
+    /// Creates a pure translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_translation(Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(
+    ///     t.transform_point(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(1.0, 2.0, 1.0)
+    /// );
+    /// ```
+    pub fn from_translation(translation: Vector3<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a pure rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.3_f64);
+    /// assert_eq!(Transform::from_rotation(q).rotation, q);
+    /// ```
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a pure scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_scale(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     t.transform_vector(Vector3::new(1.0, 1.0, 1.0)),
+    ///     Vector3::new(1.0, 2.0, 3.0)
+    /// );
+    /// ```
+    pub fn from_scale(scale: Vector3<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// Decomposes an affine transform into translation, rotation and scale.
+    ///
+    /// See [`Affine3::to_scale_rotation_translation`] for the restrictions on
+    /// the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::affine3::Affine3;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Affine3::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// let t = Transform::from_affine3(&a);
+    /// assert_eq!(t.scale, Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_affine3(affine: &Affine3<T>) -> Self
+    where
+        T: Real,
+    {
+        let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+        Self::new(translation, rotation, scale)
+    }
+
+    /// Converts the transform into an [`Affine3`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(t.to_affine3().translation, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_affine3(&self) -> Affine3<T>
+    where
+        T: Real,
+    {
+        Affine3::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Returns the linear part (rotation and scale) as a 3x3 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(t.to_mat3().row(2), Vector3::new(0.0, 0.0, 4.0));
+    /// ```
+    pub fn to_mat3(&self) -> Mat3<T>
+    where
+        T: Real,
+    {
+        self.to_affine3().matrix
+    }
+
+    /// Converts the transform into a homogeneous 4x4 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let t = Transform::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(t.to_mat4().col(3), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn to_mat4(&self) -> Mat4<T>
+    where
+        T: Real,
+    {
+        self.to_affine3().to_mat4()
+    }
+
+    /// Composes two transforms so that `other` is applied first and `self`
+    /// second, for example `parent.compose(&local)` gives a world transform.
+    ///
+    /// The result is exact when `self` has a uniform scale. A non-uniform
+    /// parent scale combined with a rotated child introduces shear, which a
+    /// TRS transform cannot represent; use [`Affine3`] if that matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let parent = Transform::from_scale(Vector3::new(2.0, 2.0, 2.0));
+    /// let child = Transform::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    /// let world = parent.compose(&child);
+    /// assert_eq!(world.translation, Vector3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(world.scale, Vector3::new(2.0, 2.0, 2.0));
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self
+    where
+        T: Real,
+    {
+        Self::new(
+            self.transform_point(other.translation),
+            self.rotation * other.rotation,
+            self.scale * other.scale,
+        )
+    }
+
+    /// Computes the inverse transform, or `None` if any scale factor is zero.
+    ///
+    /// Like [`Transform::compose`], the result is exact for uniform scales.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::new(
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.7),
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// let p = Vector3::new(-1.0, 0.5, 4.0);
+    /// let q = t.inverse().unwrap().transform_point(t.transform_point(p));
+    /// assert_approx_eq!(q, p, epsilon = 1e-6);
+    /// ```
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: Real,
+    {
+        let s = self.scale;
+        if s.x == T::ZERO || s.y == T::ZERO || s.z == T::ZERO {
+            return None;
+        }
+        let scale = Vector3::new(s.x.recip(), s.y.recip(), s.z.recip());
+        let rotation = self.rotation.conjugate();
+        let t = rotation.rotate(self.translation) * scale;
+        Some(Self::new(Vector3::new(-t.x, -t.y, -t.z), rotation, scale))
+    }
+
+    /// Transforms a point, applying scale, rotation and translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_translation(Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(
+    ///     t.transform_point(Vector3::new(1.0, 2.0, 3.0)),
+    ///     Vector3::new(2.0, 3.0, 4.0)
+    /// );
+    /// ```
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T>
+    where
+        T: Real,
+    {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms a direction, applying scale and rotation but not translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_translation(Vector3::new(1.0, 1.0, 1.0));
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(t.transform_vector(v), v);
+    /// ```
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T>
+    where
+        T: Real,
+    {
+        self.rotation.rotate(vector * self.scale)
+    }
+
+    /// Transforms a surface normal, dividing by the scale instead of
+    /// multiplying so that it stays perpendicular to transformed surfaces,
+    /// then renormalizes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let t = Transform::from_scale(Vector3::new(1.0_f64, 0.5, 1.0));
+    /// let tangent = t.transform_vector(Vector3::new(1.0, -1.0, 0.0));
+    /// let normal = t.transform_normal(Vector3::new(1.0, 1.0, 0.0));
+    /// assert_approx_eq!(tangent.dot(&normal), 0.0, epsilon = 1e-6);
+    /// ```
+    pub fn transform_normal(&self, normal: Vector3<T>) -> Vector3<T>
+    where
+        T: Real,
+    {
+        self.rotation.rotate(normal / self.scale).normalize()
+    }
+
+    /// Interpolates between two transforms, linearly for translation and
+    /// scale and spherically for rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::assert_approx_eq;
+    /// use vexel::quaternion::Quaternion;
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let axis = Vector3::new(0.0_f64, 1.0, 0.0);
+    /// let a = Transform::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Quaternion::from_axis_angle(axis, 0.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    /// );
+    /// let b = Transform::new(
+    ///     Vector3::new(10.0, 0.0, 0.0),
+    ///     Quaternion::from_axis_angle(axis, 1.0),
+    ///     Vector3::new(3.0, 3.0, 3.0),
+    /// );
+    /// let mid = a.lerp(&b, 0.5);
+    /// assert_eq!(mid.translation, Vector3::new(5.0, 0.0, 0.0));
+    /// assert_eq!(mid.scale, Vector3::new(2.0, 2.0, 2.0));
+    /// assert_approx_eq!(mid.rotation.angle_between(&a.rotation), 0.5, epsilon = 1e-6);
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self
+    where
+        T: Real,
+    {
+        Self::new(
+            self.translation.lerp(&other.translation, t),
+            self.rotation.slerp(&other.rotation, t),
+            self.scale.lerp(&other.scale, t),
+        )
+    }
+}
+
+impl<T> Mul for Transform<T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    /// Composes two transforms, see [`Transform::compose`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::transforms::transform::Transform;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let a = Transform::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    /// let b = Transform::from_translation(Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!((a * b).translation, Vector3::new(1.0, 1.0, 0.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl<T> From<Transform<T>> for Affine3<T>
+where
+    T: Real,
+{
+    /// Converts the transform into an [`Affine3`].
+    fn from(t: Transform<T>) -> Self {
+        t.to_affine3()
+    }
+}
+
+impl<T> From<Transform<T>> for Mat4<T>
+where
+    T: Real,
+{
+    /// Converts the transform into a homogeneous 4x4 matrix.
+    fn from(t: Transform<T>) -> Self {
+        t.to_mat4()
+    }
+}
@@ -0,0 +1,378 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::scalar::Real;
+use crate::simd::F64x4;
+use crate::vectors::vector3::Vector3;
+
+/// A three-component `f64` vector stored in a pair of SIMD registers.
+///
+/// `DVector3A` is the double-precision counterpart of
+/// [`Vector3A`](super::vector3a::Vector3A). It is laid out like a
+/// [`DVector4A`](super::dvector4a::DVector4A), 32 bytes and 16-byte aligned, with a fourth lane that is ignored by every
+/// operation and comparison. Like `DVector4A` it gives bit-for-bit the same
+/// results as the matching [`Vector3<f64>`] operations.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector3::Vector3;
+/// use vexel::vectors::dvector3a::DVector3A;
+///
+/// let a = Vector3::new(1.0, 2.0, 3.0);
+/// let b = Vector3::new(-2.0, 0.5, 4.0);
+/// let cross = DVector3A::from(a).cross(&DVector3A::from(b));
+/// assert_eq!(Vector3::from(cross), a.cross(&b));
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DVector3A(pub(crate) F64x4);
+
+impl DVector3A {
+    /// Creates a new `DVector3A` with the given `x`, `y`, and `z` components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v = DVector3A::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.to_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self(F64x4::new(x, y, z, 0.0))
+    }
+
+    /// Creates a vector with every component set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::splat(2.0), DVector3A::new(2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn splat(value: f64) -> Self {
+        Self::new(value, value, value)
+    }
+
+    /// Creates a vector from an array of its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v = DVector3A::from_array([1.0, 2.0, 3.0]);
+    /// assert_eq!(v, DVector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn from_array([x, y, z]: [f64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+
+    /// Returns the components as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(1.0, 2.0, 3.0).to_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [f64; 3] {
+        let [x, y, z, _] = self.0.to_array();
+        [x, y, z]
+    }
+
+    /// Returns the `x` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(1.0, 2.0, 3.0).x(), 1.0);
+    /// ```
+    #[inline]
+    pub fn x(&self) -> f64 {
+        self.0.x()
+    }
+
+    /// Returns the `y` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(1.0, 2.0, 3.0).y(), 2.0);
+    /// ```
+    #[inline]
+    pub fn y(&self) -> f64 {
+        self.0.y()
+    }
+
+    /// Returns the `z` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(1.0, 2.0, 3.0).z(), 3.0);
+    /// ```
+    #[inline]
+    pub fn z(&self) -> f64 {
+        self.0.z()
+    }
+
+    /// Computes the dot product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = DVector3A::new(4.0, 5.0, 6.0);
+    /// assert_eq!(v1.dot(&v2), 32.0);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.0.dot3(other.0)
+    }
+
+    /// Computes the cross product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let x = DVector3A::new(1.0, 0.0, 0.0);
+    /// let y = DVector3A::new(0.0, 1.0, 0.0);
+    /// assert_eq!(x.cross(&y), DVector3A::new(0.0, 0.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self(self.0.cross3(other.0))
+    }
+
+    /// Computes the length (magnitude) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(2.0, 3.0, 6.0).length(), 7.0);
+    /// ```
+    #[inline]
+    pub fn length(&self) -> f64 {
+        Real::sqrt(self.dot(self))
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::new(2.0, 3.0, 6.0).length_squared(), 49.0);
+    /// ```
+    #[inline]
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the vector scaled to unit length, or the vector itself if its
+    /// length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v = DVector3A::new(0.0, 3.0, 4.0);
+    /// assert_eq!(v.normalize(), DVector3A::new(0.0, 0.6, 0.8));
+    /// assert_eq!(DVector3A::splat(0.0).normalize(), DVector3A::splat(0.0));
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        if len == 0.0 {
+            return *self;
+        }
+        Self(self.0 / F64x4::splat(len))
+    }
+
+    /// Linearly interpolates between this vector and another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = DVector3A::new(5.0, 6.0, 7.0);
+    /// assert_eq!(v1.lerp(&v2, 0.5), DVector3A::new(3.0, 4.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        Self(self.0 + (other.0 - self.0) * F64x4::splat(t))
+    }
+}
+
+impl Default for DVector3A {
+    /// Returns the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// assert_eq!(DVector3A::default(), DVector3A::splat(0.0));
+    /// ```
+    fn default() -> Self {
+        Self::splat(0.0)
+    }
+}
+
+impl PartialEq for DVector3A {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl fmt::Debug for DVector3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = self.to_array();
+        f.debug_struct("DVector3A")
+            .field("x", &x)
+            .field("y", &y)
+            .field("z", &z)
+            .finish()
+    }
+}
+
+impl From<Vector3<f64>> for DVector3A {
+    /// Loads a vector into SIMD registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v = DVector3A::from(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v, DVector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn from(v: Vector3<f64>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<DVector3A> for Vector3<f64> {
+    /// Stores a SIMD vector as a plain `Vector3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v = Vector3::from(DVector3A::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn from(v: DVector3A) -> Self {
+        let [x, y, z] = v.to_array();
+        Vector3::new(x, y, z)
+    }
+}
+
+impl Add for DVector3A {
+    type Output = Self;
+
+    /// Adds two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = DVector3A::new(4.0, 5.0, 6.0);
+    /// assert_eq!(v1 + v2, DVector3A::new(5.0, 7.0, 9.0));
+    /// ```
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for DVector3A {
+    type Output = Self;
+
+    /// Subtracts one vector from another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(5.0, 6.0, 7.0);
+    /// let v2 = DVector3A::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v1 - v2, DVector3A::new(4.0, 4.0, 4.0));
+    /// ```
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for DVector3A {
+    type Output = Self;
+
+    /// Multiplies two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = DVector3A::new(2.0, 3.0, 4.0);
+    /// assert_eq!(v1 * v2, DVector3A::new(2.0, 6.0, 12.0));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for DVector3A {
+    type Output = Self;
+
+    /// Divides one vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector3a::DVector3A;
+    ///
+    /// let v1 = DVector3A::new(2.0, 6.0, 12.0);
+    /// let v2 = DVector3A::new(2.0, 3.0, 4.0);
+    /// assert_eq!(v1 / v2, DVector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,379 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::scalar::Real;
+use crate::simd::F64x4;
+use crate::vectors::vector4::Vector4;
+
+/// A four-component `f64` vector stored in a pair of SIMD registers.
+///
+/// `DVector4A` is the double-precision counterpart of
+/// [`Vector4A`](super::vector4a::Vector4A). It is 32 bytes and 16-byte
+/// aligned and uses SSE2 on x86 and x86_64, two components to a register,
+/// falling back to plain scalar code on other targets or when the
+/// `scalar-math` feature is enabled. Every operation gives bit-for-bit the same result as
+/// the matching [`Vector4<f64>`] operation, so the two can be mixed freely.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector4::Vector4;
+/// use vexel::vectors::dvector4a::DVector4A;
+///
+/// let v = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+/// let w = DVector4A::new(0.5, 0.25, 2.0, 1.0);
+/// assert_eq!(
+///     Vector4::from(v + w),
+///     Vector4::new(1.0, 2.0, 3.0, 4.0) + Vector4::new(0.5, 0.25, 2.0, 1.0)
+/// );
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DVector4A(pub(crate) F64x4);
+
+impl DVector4A {
+    /// Creates a new `DVector4A` with the given `x`, `y`, `z`, and `w` components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self(F64x4::new(x, y, z, w))
+    }
+
+    /// Creates a vector with every component set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::splat(2.0), DVector4A::new(2.0, 2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn splat(value: f64) -> Self {
+        Self(F64x4::splat(value))
+    }
+
+    /// Creates a vector from an array of its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = DVector4A::from_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(v, DVector4A::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn from_array([x, y, z, w]: [f64; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+
+    /// Returns the components as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 3.0, 4.0).to_array(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [f64; 4] {
+        self.0.to_array()
+    }
+
+    /// Returns the `x` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 3.0, 4.0).x(), 1.0);
+    /// ```
+    #[inline]
+    pub fn x(&self) -> f64 {
+        self.0.x()
+    }
+
+    /// Returns the `y` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 3.0, 4.0).y(), 2.0);
+    /// ```
+    #[inline]
+    pub fn y(&self) -> f64 {
+        self.0.y()
+    }
+
+    /// Returns the `z` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 3.0, 4.0).z(), 3.0);
+    /// ```
+    #[inline]
+    pub fn z(&self) -> f64 {
+        self.0.z()
+    }
+
+    /// Returns the `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 3.0, 4.0).w(), 4.0);
+    /// ```
+    #[inline]
+    pub fn w(&self) -> f64 {
+        self.0.w()
+    }
+
+    /// Computes the dot product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = DVector4A::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(v1.dot(&v2), 70.0);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.0.dot4(other.0)
+    }
+
+    /// Computes the length (magnitude) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 2.0, 4.0).length(), 5.0);
+    /// ```
+    #[inline]
+    pub fn length(&self) -> f64 {
+        Real::sqrt(self.dot(self))
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::new(1.0, 2.0, 2.0, 4.0).length_squared(), 25.0);
+    /// ```
+    #[inline]
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the vector scaled to unit length, or the vector itself if its
+    /// length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = DVector4A::new(0.0, 3.0, 0.0, 4.0);
+    /// assert_eq!(v.normalize(), DVector4A::new(0.0, 0.6, 0.0, 0.8));
+    /// assert_eq!(DVector4A::splat(0.0).normalize(), DVector4A::splat(0.0));
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        if len == 0.0 {
+            return *self;
+        }
+        Self(self.0 / F64x4::splat(len))
+    }
+
+    /// Linearly interpolates between this vector and another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = DVector4A::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(v1.lerp(&v2, 0.5), DVector4A::new(3.0, 4.0, 5.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        Self(self.0 + (other.0 - self.0) * F64x4::splat(t))
+    }
+}
+
+impl Default for DVector4A {
+    /// Returns the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// assert_eq!(DVector4A::default(), DVector4A::splat(0.0));
+    /// ```
+    fn default() -> Self {
+        Self::splat(0.0)
+    }
+}
+
+impl PartialEq for DVector4A {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl fmt::Debug for DVector4A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z, w] = self.to_array();
+        f.debug_struct("DVector4A")
+            .field("x", &x)
+            .field("y", &y)
+            .field("z", &z)
+            .field("w", &w)
+            .finish()
+    }
+}
+
+impl From<Vector4<f64>> for DVector4A {
+    /// Loads a vector into SIMD registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = DVector4A::from(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(v, DVector4A::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    fn from(v: Vector4<f64>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<DVector4A> for Vector4<f64> {
+    /// Stores a SIMD vector as a plain `Vector4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v = Vector4::from(DVector4A::new(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(v, Vector4::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    fn from(v: DVector4A) -> Self {
+        let [x, y, z, w] = v.to_array();
+        Vector4::new(x, y, z, w)
+    }
+}
+
+impl Add for DVector4A {
+    type Output = Self;
+
+    /// Adds two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = DVector4A::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(v1 + v2, DVector4A::new(6.0, 8.0, 10.0, 12.0));
+    /// ```
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for DVector4A {
+    type Output = Self;
+
+    /// Subtracts one vector from another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(5.0, 6.0, 7.0, 8.0);
+    /// let v2 = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v1 - v2, DVector4A::new(4.0, 4.0, 4.0, 4.0));
+    /// ```
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for DVector4A {
+    type Output = Self;
+
+    /// Multiplies two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = DVector4A::new(2.0, 3.0, 4.0, 5.0);
+    /// assert_eq!(v1 * v2, DVector4A::new(2.0, 6.0, 12.0, 20.0));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for DVector4A {
+    type Output = Self;
+
+    /// Divides one vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::dvector4a::DVector4A;
+    ///
+    /// let v1 = DVector4A::new(2.0, 6.0, 12.0, 20.0);
+    /// let v2 = DVector4A::new(2.0, 3.0, 4.0, 5.0);
+    /// assert_eq!(v1 / v2, DVector4A::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
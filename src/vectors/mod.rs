@@ -1,3 +1,10 @@
+pub mod dvector3a;
+pub mod dvector4a;
+mod ops;
+pub mod swizzles;
 pub mod vector2;
 pub mod vector3;
+pub mod vector3a;
 pub mod vector4;
+pub mod vector4a;
+pub mod vectorn;
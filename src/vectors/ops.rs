@@ -0,0 +1,204 @@
+//! Operator impls shared by `Vector2`, `Vector3` and `Vector4`.
+//!
+//! Each vector file implements the operators on owned values, with examples.
+//! This module forwards them to borrowed operands, so `&a + &b` and
+//! `v += &w` work without a copy at the call site, and adds the impls that
+//! can't be written generically: scalar on the left, as in `2.0 * v`, needs
+//! one impl per primitive type.
+
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::scalar::Scalar;
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// Implements `op` for every mix of owned and borrowed vector operands, in
+/// terms of the impl on two owned vectors.
+macro_rules! forward_ref_binop {
+    ($vector:ident, $($trait:ident::$method:ident),+) => {
+        $(
+            impl<'a, T: Copy> $trait<&'a $vector<T>> for $vector<T>
+            where
+                $vector<T>: $trait<Output = $vector<T>>,
+            {
+                type Output = $vector<T>;
+
+                #[inline]
+                fn $method(self, rhs: &'a $vector<T>) -> $vector<T> {
+                    $trait::$method(self, *rhs)
+                }
+            }
+
+            impl<T: Copy> $trait<$vector<T>> for &$vector<T>
+            where
+                $vector<T>: $trait<Output = $vector<T>>,
+            {
+                type Output = $vector<T>;
+
+                #[inline]
+                fn $method(self, rhs: $vector<T>) -> $vector<T> {
+                    $trait::$method(*self, rhs)
+                }
+            }
+
+            impl<'a, T: Copy> $trait<&'a $vector<T>> for &$vector<T>
+            where
+                $vector<T>: $trait<Output = $vector<T>>,
+            {
+                type Output = $vector<T>;
+
+                #[inline]
+                fn $method(self, rhs: &'a $vector<T>) -> $vector<T> {
+                    $trait::$method(*self, *rhs)
+                }
+            }
+        )+
+    };
+}
+
+/// Implements scalar `op` on a borrowed vector, in terms of the impl on an
+/// owned one.
+macro_rules! forward_ref_scalar_binop {
+    ($vector:ident, $($trait:ident::$method:ident),+) => {
+        $(
+            impl<T: Copy> $trait<T> for &$vector<T>
+            where
+                $vector<T>: $trait<T, Output = $vector<T>>,
+            {
+                type Output = $vector<T>;
+
+                #[inline]
+                fn $method(self, rhs: T) -> $vector<T> {
+                    $trait::$method(*self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+/// Implements compound assignment from a borrowed vector, in terms of the
+/// impl for an owned one.
+macro_rules! forward_ref_op_assign {
+    ($vector:ident, $($trait:ident::$method:ident),+) => {
+        $(
+            impl<'a, T: Copy> $trait<&'a $vector<T>> for $vector<T>
+            where
+                $vector<T>: $trait,
+            {
+                #[inline]
+                fn $method(&mut self, rhs: &'a $vector<T>) {
+                    $trait::$method(self, *rhs);
+                }
+            }
+        )+
+    };
+}
+
+/// Implements every forwarded operator for a vector type.
+macro_rules! forward_ref_ops {
+    ($($vector:ident),+) => {
+        $(
+            forward_ref_binop!(
+                $vector,
+                Add::add,
+                Sub::sub,
+                Mul::mul,
+                Div::div,
+                Rem::rem
+            );
+            forward_ref_scalar_binop!($vector, Mul::mul, Div::div);
+            forward_ref_op_assign!(
+                $vector,
+                AddAssign::add_assign,
+                SubAssign::sub_assign,
+                MulAssign::mul_assign,
+                DivAssign::div_assign,
+                RemAssign::rem_assign
+            );
+
+            impl<T: Copy> Neg for &$vector<T>
+            where
+                $vector<T>: Neg<Output = $vector<T>>,
+            {
+                type Output = $vector<T>;
+
+                #[inline]
+                fn neg(self) -> $vector<T> {
+                    -*self
+                }
+            }
+
+            impl<'a, T: Scalar> Sum<&'a $vector<T>> for $vector<T> {
+                fn sum<I: Iterator<Item = &'a $vector<T>>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+
+            impl<'a, T: Scalar> Product<&'a $vector<T>> for $vector<T> {
+                fn product<I: Iterator<Item = &'a $vector<T>>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+        )+
+    };
+}
+
+forward_ref_ops!(Vector2, Vector3, Vector4);
+
+/// Implements multiplication and division with the scalar on the left for
+/// each primitive type.
+///
+/// Division divides the scalar by every component, so `1.0 / v` gives the
+/// reciprocals.
+macro_rules! impl_scalar_lhs_ops {
+    ($($t:ty),+) => {
+        $(
+            impl_scalar_lhs_ops!(@vector $t, Vector2 { x, y });
+            impl_scalar_lhs_ops!(@vector $t, Vector3 { x, y, z });
+            impl_scalar_lhs_ops!(@vector $t, Vector4 { x, y, z, w });
+        )+
+    };
+    (@vector $t:ty, $vector:ident { $($field:ident),+ }) => {
+        impl Mul<$vector<$t>> for $t {
+            type Output = $vector<$t>;
+
+            #[inline]
+            fn mul(self, rhs: $vector<$t>) -> $vector<$t> {
+                $vector { $($field: self * rhs.$field),+ }
+            }
+        }
+
+        impl Mul<&$vector<$t>> for $t {
+            type Output = $vector<$t>;
+
+            #[inline]
+            fn mul(self, rhs: &$vector<$t>) -> $vector<$t> {
+                self * *rhs
+            }
+        }
+
+        impl Div<$vector<$t>> for $t {
+            type Output = $vector<$t>;
+
+            #[inline]
+            fn div(self, rhs: $vector<$t>) -> $vector<$t> {
+                $vector { $($field: self / rhs.$field),+ }
+            }
+        }
+
+        impl Div<&$vector<$t>> for $t {
+            type Output = $vector<$t>;
+
+            #[inline]
+            fn div(self, rhs: &$vector<$t>) -> $vector<$t> {
+                self / *rhs
+            }
+        }
+    };
+}
+
+impl_scalar_lhs_ops!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,647 @@
+//! Named swizzles: the components of a vector picked out in any order, as
+//! in shader code.
+//!
+//! Every vector has a method for each combination of two, three or four of
+//! its components, returning a vector of that size, so `v.zyx()` reverses a
+//! `Vector3` and `v.xy()` drops its `z`. Each combination of distinct
+//! components also has a setter such as `set_xz`. Since the names are
+//! methods, asking for a component a vector doesn't have is a compile
+//! error rather than a panic.
+//!
+//! # Examples
+//!
+//! ```
+//! use vexel::vectors::vector2::Vector2;
+//! use vexel::vectors::vector3::Vector3;
+//! use vexel::vectors::vector4::Vector4;
+//!
+//! let v = Vector3::new(1.0, 2.0, 3.0);
+//! assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+//! assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+//! assert_eq!(v.xxyy(), Vector4::new(1.0, 1.0, 2.0, 2.0));
+//!
+//! let mut p = Vector4::new(1, 2, 3, 4);
+//! p.set_wx(Vector2::new(0, 9));
+//! assert_eq!(p, Vector4::new(9, 2, 3, 0));
+//! ```
+//!
+//! A `Vector2` has no `z`:
+//!
+//! ```compile_fail
+//! use vexel::vectors::vector2::Vector2;
+//!
+//! let v = Vector2::new(1.0, 2.0);
+//! let _ = v.xz();
+//! ```
+
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// Implements swizzle getters and setters on a vector type.
+///
+/// Each getter is written `name => Output(components)` and each setter
+/// `name: Input(target = source, ...)`, assigning fields of the argument to
+/// fields of `self`.
+macro_rules! swizzles {
+    (
+        $vector:ident;
+        $($getter:ident => $output:ident($($component:ident),+);)*
+        ---
+        $($setter:ident: $input:ident($($target:ident = $source:ident),+);)*
+    ) => {
+        impl<T: Copy> $vector<T> {
+            $(
+                #[doc = concat!(
+                    "Returns the `", stringify!($($component),+), "` components as a `",
+                    stringify!($output), "`.",
+                )]
+                #[inline]
+                pub fn $getter(&self) -> $output<T> {
+                    $output::new($(self.$component),+)
+                }
+            )*
+
+            $(
+                #[doc = concat!(
+                    "Sets the `", stringify!($($target),+), "` components from a `",
+                    stringify!($input), "`.",
+                )]
+                #[inline]
+                pub fn $setter(&mut self, v: $input<T>) {
+                    $(self.$target = v.$source;)+
+                }
+            )*
+        }
+    };
+}
+
+swizzles! {
+    Vector2;
+    xx => Vector2(x, x);
+    xy => Vector2(x, y);
+    yx => Vector2(y, x);
+    yy => Vector2(y, y);
+    xxx => Vector3(x, x, x);
+    xxy => Vector3(x, x, y);
+    xyx => Vector3(x, y, x);
+    xyy => Vector3(x, y, y);
+    yxx => Vector3(y, x, x);
+    yxy => Vector3(y, x, y);
+    yyx => Vector3(y, y, x);
+    yyy => Vector3(y, y, y);
+    xxxx => Vector4(x, x, x, x);
+    xxxy => Vector4(x, x, x, y);
+    xxyx => Vector4(x, x, y, x);
+    xxyy => Vector4(x, x, y, y);
+    xyxx => Vector4(x, y, x, x);
+    xyxy => Vector4(x, y, x, y);
+    xyyx => Vector4(x, y, y, x);
+    xyyy => Vector4(x, y, y, y);
+    yxxx => Vector4(y, x, x, x);
+    yxxy => Vector4(y, x, x, y);
+    yxyx => Vector4(y, x, y, x);
+    yxyy => Vector4(y, x, y, y);
+    yyxx => Vector4(y, y, x, x);
+    yyxy => Vector4(y, y, x, y);
+    yyyx => Vector4(y, y, y, x);
+    yyyy => Vector4(y, y, y, y);
+    ---
+    set_xy: Vector2(x = x, y = y);
+    set_yx: Vector2(y = x, x = y);
+}
+
+swizzles! {
+    Vector3;
+    xx => Vector2(x, x);
+    xy => Vector2(x, y);
+    xz => Vector2(x, z);
+    yx => Vector2(y, x);
+    yy => Vector2(y, y);
+    yz => Vector2(y, z);
+    zx => Vector2(z, x);
+    zy => Vector2(z, y);
+    zz => Vector2(z, z);
+    xxx => Vector3(x, x, x);
+    xxy => Vector3(x, x, y);
+    xxz => Vector3(x, x, z);
+    xyx => Vector3(x, y, x);
+    xyy => Vector3(x, y, y);
+    xyz => Vector3(x, y, z);
+    xzx => Vector3(x, z, x);
+    xzy => Vector3(x, z, y);
+    xzz => Vector3(x, z, z);
+    yxx => Vector3(y, x, x);
+    yxy => Vector3(y, x, y);
+    yxz => Vector3(y, x, z);
+    yyx => Vector3(y, y, x);
+    yyy => Vector3(y, y, y);
+    yyz => Vector3(y, y, z);
+    yzx => Vector3(y, z, x);
+    yzy => Vector3(y, z, y);
+    yzz => Vector3(y, z, z);
+    zxx => Vector3(z, x, x);
+    zxy => Vector3(z, x, y);
+    zxz => Vector3(z, x, z);
+    zyx => Vector3(z, y, x);
+    zyy => Vector3(z, y, y);
+    zyz => Vector3(z, y, z);
+    zzx => Vector3(z, z, x);
+    zzy => Vector3(z, z, y);
+    zzz => Vector3(z, z, z);
+    xxxx => Vector4(x, x, x, x);
+    xxxy => Vector4(x, x, x, y);
+    xxxz => Vector4(x, x, x, z);
+    xxyx => Vector4(x, x, y, x);
+    xxyy => Vector4(x, x, y, y);
+    xxyz => Vector4(x, x, y, z);
+    xxzx => Vector4(x, x, z, x);
+    xxzy => Vector4(x, x, z, y);
+    xxzz => Vector4(x, x, z, z);
+    xyxx => Vector4(x, y, x, x);
+    xyxy => Vector4(x, y, x, y);
+    xyxz => Vector4(x, y, x, z);
+    xyyx => Vector4(x, y, y, x);
+    xyyy => Vector4(x, y, y, y);
+    xyyz => Vector4(x, y, y, z);
+    xyzx => Vector4(x, y, z, x);
+    xyzy => Vector4(x, y, z, y);
+    xyzz => Vector4(x, y, z, z);
+    xzxx => Vector4(x, z, x, x);
+    xzxy => Vector4(x, z, x, y);
+    xzxz => Vector4(x, z, x, z);
+    xzyx => Vector4(x, z, y, x);
+    xzyy => Vector4(x, z, y, y);
+    xzyz => Vector4(x, z, y, z);
+    xzzx => Vector4(x, z, z, x);
+    xzzy => Vector4(x, z, z, y);
+    xzzz => Vector4(x, z, z, z);
+    yxxx => Vector4(y, x, x, x);
+    yxxy => Vector4(y, x, x, y);
+    yxxz => Vector4(y, x, x, z);
+    yxyx => Vector4(y, x, y, x);
+    yxyy => Vector4(y, x, y, y);
+    yxyz => Vector4(y, x, y, z);
+    yxzx => Vector4(y, x, z, x);
+    yxzy => Vector4(y, x, z, y);
+    yxzz => Vector4(y, x, z, z);
+    yyxx => Vector4(y, y, x, x);
+    yyxy => Vector4(y, y, x, y);
+    yyxz => Vector4(y, y, x, z);
+    yyyx => Vector4(y, y, y, x);
+    yyyy => Vector4(y, y, y, y);
+    yyyz => Vector4(y, y, y, z);
+    yyzx => Vector4(y, y, z, x);
+    yyzy => Vector4(y, y, z, y);
+    yyzz => Vector4(y, y, z, z);
+    yzxx => Vector4(y, z, x, x);
+    yzxy => Vector4(y, z, x, y);
+    yzxz => Vector4(y, z, x, z);
+    yzyx => Vector4(y, z, y, x);
+    yzyy => Vector4(y, z, y, y);
+    yzyz => Vector4(y, z, y, z);
+    yzzx => Vector4(y, z, z, x);
+    yzzy => Vector4(y, z, z, y);
+    yzzz => Vector4(y, z, z, z);
+    zxxx => Vector4(z, x, x, x);
+    zxxy => Vector4(z, x, x, y);
+    zxxz => Vector4(z, x, x, z);
+    zxyx => Vector4(z, x, y, x);
+    zxyy => Vector4(z, x, y, y);
+    zxyz => Vector4(z, x, y, z);
+    zxzx => Vector4(z, x, z, x);
+    zxzy => Vector4(z, x, z, y);
+    zxzz => Vector4(z, x, z, z);
+    zyxx => Vector4(z, y, x, x);
+    zyxy => Vector4(z, y, x, y);
+    zyxz => Vector4(z, y, x, z);
+    zyyx => Vector4(z, y, y, x);
+    zyyy => Vector4(z, y, y, y);
+    zyyz => Vector4(z, y, y, z);
+    zyzx => Vector4(z, y, z, x);
+    zyzy => Vector4(z, y, z, y);
+    zyzz => Vector4(z, y, z, z);
+    zzxx => Vector4(z, z, x, x);
+    zzxy => Vector4(z, z, x, y);
+    zzxz => Vector4(z, z, x, z);
+    zzyx => Vector4(z, z, y, x);
+    zzyy => Vector4(z, z, y, y);
+    zzyz => Vector4(z, z, y, z);
+    zzzx => Vector4(z, z, z, x);
+    zzzy => Vector4(z, z, z, y);
+    zzzz => Vector4(z, z, z, z);
+    ---
+    set_xy: Vector2(x = x, y = y);
+    set_xz: Vector2(x = x, z = y);
+    set_yx: Vector2(y = x, x = y);
+    set_yz: Vector2(y = x, z = y);
+    set_zx: Vector2(z = x, x = y);
+    set_zy: Vector2(z = x, y = y);
+    set_xyz: Vector3(x = x, y = y, z = z);
+    set_xzy: Vector3(x = x, z = y, y = z);
+    set_yxz: Vector3(y = x, x = y, z = z);
+    set_yzx: Vector3(y = x, z = y, x = z);
+    set_zxy: Vector3(z = x, x = y, y = z);
+    set_zyx: Vector3(z = x, y = y, x = z);
+}
+
+swizzles! {
+    Vector4;
+    xx => Vector2(x, x);
+    xy => Vector2(x, y);
+    xz => Vector2(x, z);
+    xw => Vector2(x, w);
+    yx => Vector2(y, x);
+    yy => Vector2(y, y);
+    yz => Vector2(y, z);
+    yw => Vector2(y, w);
+    zx => Vector2(z, x);
+    zy => Vector2(z, y);
+    zz => Vector2(z, z);
+    zw => Vector2(z, w);
+    wx => Vector2(w, x);
+    wy => Vector2(w, y);
+    wz => Vector2(w, z);
+    ww => Vector2(w, w);
+    xxx => Vector3(x, x, x);
+    xxy => Vector3(x, x, y);
+    xxz => Vector3(x, x, z);
+    xxw => Vector3(x, x, w);
+    xyx => Vector3(x, y, x);
+    xyy => Vector3(x, y, y);
+    xyz => Vector3(x, y, z);
+    xyw => Vector3(x, y, w);
+    xzx => Vector3(x, z, x);
+    xzy => Vector3(x, z, y);
+    xzz => Vector3(x, z, z);
+    xzw => Vector3(x, z, w);
+    xwx => Vector3(x, w, x);
+    xwy => Vector3(x, w, y);
+    xwz => Vector3(x, w, z);
+    xww => Vector3(x, w, w);
+    yxx => Vector3(y, x, x);
+    yxy => Vector3(y, x, y);
+    yxz => Vector3(y, x, z);
+    yxw => Vector3(y, x, w);
+    yyx => Vector3(y, y, x);
+    yyy => Vector3(y, y, y);
+    yyz => Vector3(y, y, z);
+    yyw => Vector3(y, y, w);
+    yzx => Vector3(y, z, x);
+    yzy => Vector3(y, z, y);
+    yzz => Vector3(y, z, z);
+    yzw => Vector3(y, z, w);
+    ywx => Vector3(y, w, x);
+    ywy => Vector3(y, w, y);
+    ywz => Vector3(y, w, z);
+    yww => Vector3(y, w, w);
+    zxx => Vector3(z, x, x);
+    zxy => Vector3(z, x, y);
+    zxz => Vector3(z, x, z);
+    zxw => Vector3(z, x, w);
+    zyx => Vector3(z, y, x);
+    zyy => Vector3(z, y, y);
+    zyz => Vector3(z, y, z);
+    zyw => Vector3(z, y, w);
+    zzx => Vector3(z, z, x);
+    zzy => Vector3(z, z, y);
+    zzz => Vector3(z, z, z);
+    zzw => Vector3(z, z, w);
+    zwx => Vector3(z, w, x);
+    zwy => Vector3(z, w, y);
+    zwz => Vector3(z, w, z);
+    zww => Vector3(z, w, w);
+    wxx => Vector3(w, x, x);
+    wxy => Vector3(w, x, y);
+    wxz => Vector3(w, x, z);
+    wxw => Vector3(w, x, w);
+    wyx => Vector3(w, y, x);
+    wyy => Vector3(w, y, y);
+    wyz => Vector3(w, y, z);
+    wyw => Vector3(w, y, w);
+    wzx => Vector3(w, z, x);
+    wzy => Vector3(w, z, y);
+    wzz => Vector3(w, z, z);
+    wzw => Vector3(w, z, w);
+    wwx => Vector3(w, w, x);
+    wwy => Vector3(w, w, y);
+    wwz => Vector3(w, w, z);
+    www => Vector3(w, w, w);
+    xxxx => Vector4(x, x, x, x);
+    xxxy => Vector4(x, x, x, y);
+    xxxz => Vector4(x, x, x, z);
+    xxxw => Vector4(x, x, x, w);
+    xxyx => Vector4(x, x, y, x);
+    xxyy => Vector4(x, x, y, y);
+    xxyz => Vector4(x, x, y, z);
+    xxyw => Vector4(x, x, y, w);
+    xxzx => Vector4(x, x, z, x);
+    xxzy => Vector4(x, x, z, y);
+    xxzz => Vector4(x, x, z, z);
+    xxzw => Vector4(x, x, z, w);
+    xxwx => Vector4(x, x, w, x);
+    xxwy => Vector4(x, x, w, y);
+    xxwz => Vector4(x, x, w, z);
+    xxww => Vector4(x, x, w, w);
+    xyxx => Vector4(x, y, x, x);
+    xyxy => Vector4(x, y, x, y);
+    xyxz => Vector4(x, y, x, z);
+    xyxw => Vector4(x, y, x, w);
+    xyyx => Vector4(x, y, y, x);
+    xyyy => Vector4(x, y, y, y);
+    xyyz => Vector4(x, y, y, z);
+    xyyw => Vector4(x, y, y, w);
+    xyzx => Vector4(x, y, z, x);
+    xyzy => Vector4(x, y, z, y);
+    xyzz => Vector4(x, y, z, z);
+    xyzw => Vector4(x, y, z, w);
+    xywx => Vector4(x, y, w, x);
+    xywy => Vector4(x, y, w, y);
+    xywz => Vector4(x, y, w, z);
+    xyww => Vector4(x, y, w, w);
+    xzxx => Vector4(x, z, x, x);
+    xzxy => Vector4(x, z, x, y);
+    xzxz => Vector4(x, z, x, z);
+    xzxw => Vector4(x, z, x, w);
+    xzyx => Vector4(x, z, y, x);
+    xzyy => Vector4(x, z, y, y);
+    xzyz => Vector4(x, z, y, z);
+    xzyw => Vector4(x, z, y, w);
+    xzzx => Vector4(x, z, z, x);
+    xzzy => Vector4(x, z, z, y);
+    xzzz => Vector4(x, z, z, z);
+    xzzw => Vector4(x, z, z, w);
+    xzwx => Vector4(x, z, w, x);
+    xzwy => Vector4(x, z, w, y);
+    xzwz => Vector4(x, z, w, z);
+    xzww => Vector4(x, z, w, w);
+    xwxx => Vector4(x, w, x, x);
+    xwxy => Vector4(x, w, x, y);
+    xwxz => Vector4(x, w, x, z);
+    xwxw => Vector4(x, w, x, w);
+    xwyx => Vector4(x, w, y, x);
+    xwyy => Vector4(x, w, y, y);
+    xwyz => Vector4(x, w, y, z);
+    xwyw => Vector4(x, w, y, w);
+    xwzx => Vector4(x, w, z, x);
+    xwzy => Vector4(x, w, z, y);
+    xwzz => Vector4(x, w, z, z);
+    xwzw => Vector4(x, w, z, w);
+    xwwx => Vector4(x, w, w, x);
+    xwwy => Vector4(x, w, w, y);
+    xwwz => Vector4(x, w, w, z);
+    xwww => Vector4(x, w, w, w);
+    yxxx => Vector4(y, x, x, x);
+    yxxy => Vector4(y, x, x, y);
+    yxxz => Vector4(y, x, x, z);
+    yxxw => Vector4(y, x, x, w);
+    yxyx => Vector4(y, x, y, x);
+    yxyy => Vector4(y, x, y, y);
+    yxyz => Vector4(y, x, y, z);
+    yxyw => Vector4(y, x, y, w);
+    yxzx => Vector4(y, x, z, x);
+    yxzy => Vector4(y, x, z, y);
+    yxzz => Vector4(y, x, z, z);
+    yxzw => Vector4(y, x, z, w);
+    yxwx => Vector4(y, x, w, x);
+    yxwy => Vector4(y, x, w, y);
+    yxwz => Vector4(y, x, w, z);
+    yxww => Vector4(y, x, w, w);
+    yyxx => Vector4(y, y, x, x);
+    yyxy => Vector4(y, y, x, y);
+    yyxz => Vector4(y, y, x, z);
+    yyxw => Vector4(y, y, x, w);
+    yyyx => Vector4(y, y, y, x);
+    yyyy => Vector4(y, y, y, y);
+    yyyz => Vector4(y, y, y, z);
+    yyyw => Vector4(y, y, y, w);
+    yyzx => Vector4(y, y, z, x);
+    yyzy => Vector4(y, y, z, y);
+    yyzz => Vector4(y, y, z, z);
+    yyzw => Vector4(y, y, z, w);
+    yywx => Vector4(y, y, w, x);
+    yywy => Vector4(y, y, w, y);
+    yywz => Vector4(y, y, w, z);
+    yyww => Vector4(y, y, w, w);
+    yzxx => Vector4(y, z, x, x);
+    yzxy => Vector4(y, z, x, y);
+    yzxz => Vector4(y, z, x, z);
+    yzxw => Vector4(y, z, x, w);
+    yzyx => Vector4(y, z, y, x);
+    yzyy => Vector4(y, z, y, y);
+    yzyz => Vector4(y, z, y, z);
+    yzyw => Vector4(y, z, y, w);
+    yzzx => Vector4(y, z, z, x);
+    yzzy => Vector4(y, z, z, y);
+    yzzz => Vector4(y, z, z, z);
+    yzzw => Vector4(y, z, z, w);
+    yzwx => Vector4(y, z, w, x);
+    yzwy => Vector4(y, z, w, y);
+    yzwz => Vector4(y, z, w, z);
+    yzww => Vector4(y, z, w, w);
+    ywxx => Vector4(y, w, x, x);
+    ywxy => Vector4(y, w, x, y);
+    ywxz => Vector4(y, w, x, z);
+    ywxw => Vector4(y, w, x, w);
+    ywyx => Vector4(y, w, y, x);
+    ywyy => Vector4(y, w, y, y);
+    ywyz => Vector4(y, w, y, z);
+    ywyw => Vector4(y, w, y, w);
+    ywzx => Vector4(y, w, z, x);
+    ywzy => Vector4(y, w, z, y);
+    ywzz => Vector4(y, w, z, z);
+    ywzw => Vector4(y, w, z, w);
+    ywwx => Vector4(y, w, w, x);
+    ywwy => Vector4(y, w, w, y);
+    ywwz => Vector4(y, w, w, z);
+    ywww => Vector4(y, w, w, w);
+    zxxx => Vector4(z, x, x, x);
+    zxxy => Vector4(z, x, x, y);
+    zxxz => Vector4(z, x, x, z);
+    zxxw => Vector4(z, x, x, w);
+    zxyx => Vector4(z, x, y, x);
+    zxyy => Vector4(z, x, y, y);
+    zxyz => Vector4(z, x, y, z);
+    zxyw => Vector4(z, x, y, w);
+    zxzx => Vector4(z, x, z, x);
+    zxzy => Vector4(z, x, z, y);
+    zxzz => Vector4(z, x, z, z);
+    zxzw => Vector4(z, x, z, w);
+    zxwx => Vector4(z, x, w, x);
+    zxwy => Vector4(z, x, w, y);
+    zxwz => Vector4(z, x, w, z);
+    zxww => Vector4(z, x, w, w);
+    zyxx => Vector4(z, y, x, x);
+    zyxy => Vector4(z, y, x, y);
+    zyxz => Vector4(z, y, x, z);
+    zyxw => Vector4(z, y, x, w);
+    zyyx => Vector4(z, y, y, x);
+    zyyy => Vector4(z, y, y, y);
+    zyyz => Vector4(z, y, y, z);
+    zyyw => Vector4(z, y, y, w);
+    zyzx => Vector4(z, y, z, x);
+    zyzy => Vector4(z, y, z, y);
+    zyzz => Vector4(z, y, z, z);
+    zyzw => Vector4(z, y, z, w);
+    zywx => Vector4(z, y, w, x);
+    zywy => Vector4(z, y, w, y);
+    zywz => Vector4(z, y, w, z);
+    zyww => Vector4(z, y, w, w);
+    zzxx => Vector4(z, z, x, x);
+    zzxy => Vector4(z, z, x, y);
+    zzxz => Vector4(z, z, x, z);
+    zzxw => Vector4(z, z, x, w);
+    zzyx => Vector4(z, z, y, x);
+    zzyy => Vector4(z, z, y, y);
+    zzyz => Vector4(z, z, y, z);
+    zzyw => Vector4(z, z, y, w);
+    zzzx => Vector4(z, z, z, x);
+    zzzy => Vector4(z, z, z, y);
+    zzzz => Vector4(z, z, z, z);
+    zzzw => Vector4(z, z, z, w);
+    zzwx => Vector4(z, z, w, x);
+    zzwy => Vector4(z, z, w, y);
+    zzwz => Vector4(z, z, w, z);
+    zzww => Vector4(z, z, w, w);
+    zwxx => Vector4(z, w, x, x);
+    zwxy => Vector4(z, w, x, y);
+    zwxz => Vector4(z, w, x, z);
+    zwxw => Vector4(z, w, x, w);
+    zwyx => Vector4(z, w, y, x);
+    zwyy => Vector4(z, w, y, y);
+    zwyz => Vector4(z, w, y, z);
+    zwyw => Vector4(z, w, y, w);
+    zwzx => Vector4(z, w, z, x);
+    zwzy => Vector4(z, w, z, y);
+    zwzz => Vector4(z, w, z, z);
+    zwzw => Vector4(z, w, z, w);
+    zwwx => Vector4(z, w, w, x);
+    zwwy => Vector4(z, w, w, y);
+    zwwz => Vector4(z, w, w, z);
+    zwww => Vector4(z, w, w, w);
+    wxxx => Vector4(w, x, x, x);
+    wxxy => Vector4(w, x, x, y);
+    wxxz => Vector4(w, x, x, z);
+    wxxw => Vector4(w, x, x, w);
+    wxyx => Vector4(w, x, y, x);
+    wxyy => Vector4(w, x, y, y);
+    wxyz => Vector4(w, x, y, z);
+    wxyw => Vector4(w, x, y, w);
+    wxzx => Vector4(w, x, z, x);
+    wxzy => Vector4(w, x, z, y);
+    wxzz => Vector4(w, x, z, z);
+    wxzw => Vector4(w, x, z, w);
+    wxwx => Vector4(w, x, w, x);
+    wxwy => Vector4(w, x, w, y);
+    wxwz => Vector4(w, x, w, z);
+    wxww => Vector4(w, x, w, w);
+    wyxx => Vector4(w, y, x, x);
+    wyxy => Vector4(w, y, x, y);
+    wyxz => Vector4(w, y, x, z);
+    wyxw => Vector4(w, y, x, w);
+    wyyx => Vector4(w, y, y, x);
+    wyyy => Vector4(w, y, y, y);
+    wyyz => Vector4(w, y, y, z);
+    wyyw => Vector4(w, y, y, w);
+    wyzx => Vector4(w, y, z, x);
+    wyzy => Vector4(w, y, z, y);
+    wyzz => Vector4(w, y, z, z);
+    wyzw => Vector4(w, y, z, w);
+    wywx => Vector4(w, y, w, x);
+    wywy => Vector4(w, y, w, y);
+    wywz => Vector4(w, y, w, z);
+    wyww => Vector4(w, y, w, w);
+    wzxx => Vector4(w, z, x, x);
+    wzxy => Vector4(w, z, x, y);
+    wzxz => Vector4(w, z, x, z);
+    wzxw => Vector4(w, z, x, w);
+    wzyx => Vector4(w, z, y, x);
+    wzyy => Vector4(w, z, y, y);
+    wzyz => Vector4(w, z, y, z);
+    wzyw => Vector4(w, z, y, w);
+    wzzx => Vector4(w, z, z, x);
+    wzzy => Vector4(w, z, z, y);
+    wzzz => Vector4(w, z, z, z);
+    wzzw => Vector4(w, z, z, w);
+    wzwx => Vector4(w, z, w, x);
+    wzwy => Vector4(w, z, w, y);
+    wzwz => Vector4(w, z, w, z);
+    wzww => Vector4(w, z, w, w);
+    wwxx => Vector4(w, w, x, x);
+    wwxy => Vector4(w, w, x, y);
+    wwxz => Vector4(w, w, x, z);
+    wwxw => Vector4(w, w, x, w);
+    wwyx => Vector4(w, w, y, x);
+    wwyy => Vector4(w, w, y, y);
+    wwyz => Vector4(w, w, y, z);
+    wwyw => Vector4(w, w, y, w);
+    wwzx => Vector4(w, w, z, x);
+    wwzy => Vector4(w, w, z, y);
+    wwzz => Vector4(w, w, z, z);
+    wwzw => Vector4(w, w, z, w);
+    wwwx => Vector4(w, w, w, x);
+    wwwy => Vector4(w, w, w, y);
+    wwwz => Vector4(w, w, w, z);
+    wwww => Vector4(w, w, w, w);
+    ---
+    set_xy: Vector2(x = x, y = y);
+    set_xz: Vector2(x = x, z = y);
+    set_xw: Vector2(x = x, w = y);
+    set_yx: Vector2(y = x, x = y);
+    set_yz: Vector2(y = x, z = y);
+    set_yw: Vector2(y = x, w = y);
+    set_zx: Vector2(z = x, x = y);
+    set_zy: Vector2(z = x, y = y);
+    set_zw: Vector2(z = x, w = y);
+    set_wx: Vector2(w = x, x = y);
+    set_wy: Vector2(w = x, y = y);
+    set_wz: Vector2(w = x, z = y);
+    set_xyz: Vector3(x = x, y = y, z = z);
+    set_xyw: Vector3(x = x, y = y, w = z);
+    set_xzy: Vector3(x = x, z = y, y = z);
+    set_xzw: Vector3(x = x, z = y, w = z);
+    set_xwy: Vector3(x = x, w = y, y = z);
+    set_xwz: Vector3(x = x, w = y, z = z);
+    set_yxz: Vector3(y = x, x = y, z = z);
+    set_yxw: Vector3(y = x, x = y, w = z);
+    set_yzx: Vector3(y = x, z = y, x = z);
+    set_yzw: Vector3(y = x, z = y, w = z);
+    set_ywx: Vector3(y = x, w = y, x = z);
+    set_ywz: Vector3(y = x, w = y, z = z);
+    set_zxy: Vector3(z = x, x = y, y = z);
+    set_zxw: Vector3(z = x, x = y, w = z);
+    set_zyx: Vector3(z = x, y = y, x = z);
+    set_zyw: Vector3(z = x, y = y, w = z);
+    set_zwx: Vector3(z = x, w = y, x = z);
+    set_zwy: Vector3(z = x, w = y, y = z);
+    set_wxy: Vector3(w = x, x = y, y = z);
+    set_wxz: Vector3(w = x, x = y, z = z);
+    set_wyx: Vector3(w = x, y = y, x = z);
+    set_wyz: Vector3(w = x, y = y, z = z);
+    set_wzx: Vector3(w = x, z = y, x = z);
+    set_wzy: Vector3(w = x, z = y, y = z);
+    set_xyzw: Vector4(x = x, y = y, z = z, w = w);
+    set_xywz: Vector4(x = x, y = y, w = z, z = w);
+    set_xzyw: Vector4(x = x, z = y, y = z, w = w);
+    set_xzwy: Vector4(x = x, z = y, w = z, y = w);
+    set_xwyz: Vector4(x = x, w = y, y = z, z = w);
+    set_xwzy: Vector4(x = x, w = y, z = z, y = w);
+    set_yxzw: Vector4(y = x, x = y, z = z, w = w);
+    set_yxwz: Vector4(y = x, x = y, w = z, z = w);
+    set_yzxw: Vector4(y = x, z = y, x = z, w = w);
+    set_yzwx: Vector4(y = x, z = y, w = z, x = w);
+    set_ywxz: Vector4(y = x, w = y, x = z, z = w);
+    set_ywzx: Vector4(y = x, w = y, z = z, x = w);
+    set_zxyw: Vector4(z = x, x = y, y = z, w = w);
+    set_zxwy: Vector4(z = x, x = y, w = z, y = w);
+    set_zyxw: Vector4(z = x, y = y, x = z, w = w);
+    set_zywx: Vector4(z = x, y = y, w = z, x = w);
+    set_zwxy: Vector4(z = x, w = y, x = z, y = w);
+    set_zwyx: Vector4(z = x, w = y, y = z, x = w);
+    set_wxyz: Vector4(w = x, x = y, y = z, z = w);
+    set_wxzy: Vector4(w = x, x = y, z = z, y = w);
+    set_wyxz: Vector4(w = x, y = y, x = z, z = w);
+    set_wyzx: Vector4(w = x, y = y, z = z, x = w);
+    set_wzxy: Vector4(w = x, z = y, x = z, y = w);
+    set_wzyx: Vector4(w = x, z = y, y = z, x = w);
+}
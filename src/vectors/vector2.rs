@@ -1,10 +1,18 @@
-use std::ops::{Add, Div, Mul, Sub};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+use crate::error::VexelError;
+use crate::scalar::{Integer, Magnitude, Real, Scalar, Signed};
 
 /// A 2D vector with `x` and `y` components.
 ///
 /// This struct is generic over the type `T`, which allows it to be used with
 /// any numeric type that supports the required operations.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Vector2<T> {
     /// The x-component of the vector.
     pub x: T,
@@ -12,6 +20,44 @@ pub struct Vector2<T> {
     pub y: T,
 }
 
+/// A `Vector2` with `f32` components, the usual choice for GPU-facing code.
+///
+/// All vector operations are computed in `f32` without widening to `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector2::{Vec2f, Vector2};
+///
+/// let v: Vec2f = Vector2::new(3.0, 4.0);
+/// let n: Vec2f = v.normalize();
+/// assert_eq!(n, Vector2::new(0.6, 0.8));
+/// ```
+pub type Vec2f = Vector2<f32>;
+
+/// A `Vector2` with `f64` components.
+pub type Vec2d = Vector2<f64>;
+
+/// A `Vector2` with `i32` components, for grid, voxel and tile coordinates.
+///
+/// Integer vectors are exact and implement `Eq`, `Hash` and `Ord`, so they can
+/// be used as `HashMap` or `BTreeMap` keys. Ordering is lexicographic.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use vexel::vectors::vector2::{Vec2i, Vector2};
+///
+/// let mut chunks: HashMap<Vec2i, &str> = HashMap::new();
+/// chunks.insert(Vector2::new(-1, 3), "loaded");
+/// assert_eq!(chunks.get(&Vector2::new(-1, 3)), Some(&"loaded"));
+/// ```
+pub type Vec2i = Vector2<i32>;
+
+/// A `Vector2` with `u32` components.
+pub type Vec2u = Vector2<u32>;
+
 impl<T> Vector2<T> {
     /// Creates a new `Vector2` with the given `x` and `y` components.
     ///
@@ -38,11 +84,72 @@ impl<T> Vector2<T> {
     /// let v = Vector2::new(3.0, 4.0);
     /// assert_eq!(v.length(), 5.0);
     /// ```
-    pub fn length(&self) -> f64
+    pub fn length(&self) -> T
+    where
+        T: Real,
+    {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// This avoids the square root and is exact for integer vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.length_squared(), 58);
+    /// ```
+    pub fn length_squared(&self) -> T
+    where
+        T: Scalar,
+    {
+        self.dot(self)
+    }
+
+    /// Computes the Manhattan (taxicab) length, the sum of the absolute components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.manhattan_length(), 10);
+    ///
+    /// // Unsigned components are their own absolute values.
+    /// assert_eq!(Vector2::new(7u32, 3).manhattan_length(), 10);
+    /// ```
+    pub fn manhattan_length(&self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Magnitude,
     {
-        (self.x.into().powi(2) + self.y.into().powi(2)).sqrt()
+        self.x.magnitude() + self.y.magnitude()
+    }
+
+    /// Computes the Chebyshev length, the largest absolute component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.chebyshev_length(), 7);
+    /// assert_eq!(Vector2::new(7u32, 3).chebyshev_length(), 7);
+    /// ```
+    pub fn chebyshev_length(&self) -> T
+    where
+        T: Magnitude,
+    {
+        let mut max = self.x.magnitude();
+        if self.y.magnitude() > max {
+            max = self.y.magnitude();
+        }
+        max
     }
 
     /// Computes the dot product of this vector and another.
@@ -56,11 +163,11 @@ impl<T> Vector2<T> {
     /// let v2 = Vector2::new(5.0, 6.0);
     /// assert_eq!(v1.dot(&v2), 39.0);
     /// ```
-    pub fn dot(&self, other: &Self) -> f64
+    pub fn dot(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Scalar,
     {
-        (self.x.into() * other.x.into()) + (self.y.into() * other.y.into())
+        self.x * other.x + self.y * other.y
     }
 
     /// Computes the cross product of this vector and another.
@@ -74,21 +181,25 @@ impl<T> Vector2<T> {
     /// let v2 = Vector2::new(5.0, 6.0);
     /// assert_eq!(v1.cross(&v2), -2.0);
     /// ```
-    pub fn cross(&self, other: &Self) -> f64
+    pub fn cross(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Signed,
     {
-        (self.x.into() * other.y.into()) - (self.y.into() * other.x.into())
+        self.x * other.y - self.y * other.x
     }
 
     /// Normalizes the vector, making it a unit vector.
     ///
+    /// A zero vector is returned unchanged; use
+    /// [`try_normalize`](Self::try_normalize) to catch it.
+    ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::assert_approx_eq;
     /// use vexel::vectors::vector2::Vector2;
     ///
-    /// let v = Vector2::new(3.0, 4.0);
+    /// let v = Vector2::new(3.0_f64, 4.0);
     /// let normalized = v.normalize();
     /// let magnitude = v.length();
     ///
@@ -97,22 +208,90 @@ impl<T> Vector2<T> {
     ///     4.0 / magnitude,
     /// );
     ///
-    /// assert!((normalized.x - expected.x).abs() < 1e-6);
-    /// assert!((normalized.y - expected.y).abs() < 1e-6);
+    /// assert_approx_eq!(normalized, expected, epsilon = 1e-6);
     /// ```
-
     pub fn normalize(&self) -> Self
     where
-        T: Into<f64> + Copy + From<f64>,
+        T: Real,
     {
         let len = self.length();
-        if len == 0.0 {
+        if len == T::ZERO {
             return *self;
         }
         Self {
-            x: (self.x.into() / len).into(),
-            y: (self.y.into() / len).into(),
+            x: self.x / len,
+            y: self.y / len,
+        }
+    }
+
+    /// Normalizes the vector, or returns an error if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(3.0, 4.0).try_normalize(), Ok(Vector2::new(0.6, 0.8)));
+    /// assert_eq!(Vector2::new(0.0, 0.0).try_normalize(), Err(VexelError::ZeroLength));
+    /// assert_eq!(
+    ///     Vector2::new(f64::MAX, f64::MAX).try_normalize(),
+    ///     Err(VexelError::NonFinite)
+    /// );
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return Err(VexelError::ZeroLength);
         }
+        if !len.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        Ok(Self {
+            x: self.x / len,
+            y: self.y / len,
+        })
+    }
+
+    /// Normalizes the vector, or returns `fallback` if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let up = Vector2::new(1.0, 0.0);
+    /// assert_eq!(Vector2::new(3.0, 4.0).normalize_or(up), Vector2::new(0.6, 0.8));
+    /// assert_eq!(Vector2::new(0.0, 0.0).normalize_or(up), up);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self
+    where
+        T: Real,
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalizes the vector, or returns the zero vector if it has zero
+    /// length or its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(f64::MAX, f64::MAX);
+    /// assert_eq!(v.normalize_or_zero(), Vector2::new(0.0, 0.0));
+    /// ```
+    pub fn normalize_or_zero(&self) -> Self
+    where
+        T: Real,
+    {
+        self.normalize_or(Self::new(T::ZERO, T::ZERO))
     }
 
     /// Projects this vector onto another vector.
@@ -130,15 +309,49 @@ impl<T> Vector2<T> {
     /// ```
     pub fn project_onto(&self, other: &Self) -> Self
     where
-        T: Into<f64> + Copy + From<f64>,
+        T: Real,
     {
         let scalar = self.dot(other) / other.dot(other);
         Self {
-            x: (scalar * other.x.into()).into(),
-            y: (scalar * other.y.into()).into(),
+            x: scalar * other.x,
+            y: scalar * other.y,
         }
     }
 
+    /// Projects this vector onto another vector, or returns an error if the
+    /// other vector has zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(3.0, 4.0);
+    /// assert_eq!(v.try_project_onto(&Vector2::new(2.0, 0.0)), Ok(Vector2::new(3.0, 0.0)));
+    /// assert_eq!(
+    ///     v.try_project_onto(&Vector2::new(0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
+    /// ```
+    pub fn try_project_onto(&self, other: &Self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let length_squared = other.dot(other);
+        if length_squared == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !length_squared.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let scalar = self.dot(other) / length_squared;
+        Ok(Self {
+            x: scalar * other.x,
+            y: scalar * other.y,
+        })
+    }
+
     /// Rejects this vector from another vector.
     ///
     /// # Examples
@@ -154,7 +367,7 @@ impl<T> Vector2<T> {
     /// ```
     pub fn reject_from(&self, other: &Self) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Sub<Output = T>,
+        T: Real,
     {
         let projection = self.project_onto(other);
         Self {
@@ -176,9 +389,9 @@ impl<T> Vector2<T> {
     /// assert_eq!(interpolated.x, 2.0);
     /// assert_eq!(interpolated.y, 3.0);
     /// ```
-    pub fn lerp(&self, other: &Self, t: f64) -> Self
+    pub fn lerp(&self, other: &Self, t: T) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+        T: Real,
     {
         Self {
             x: self.x + (other.x - self.x) * t,
@@ -188,6 +401,8 @@ impl<T> Vector2<T> {
 
     /// Computes the angle between this vector and another vector in radians.
     ///
+    /// The angle is NaN if either vector has zero length; use
+    /// [`try_angle_between`](Self::try_angle_between) to catch it.
     ///
     /// # Examples
     ///
@@ -199,35 +414,298 @@ impl<T> Vector2<T> {
     /// let angle = v1.angle_between(&v2);
     /// assert_eq!(angle, std::f64::consts::PI / 2.0); //FIXME: The PI constant should be available in the crate
     /// ```
-    pub fn angle_between(&self, other: &Self) -> f64
+    pub fn angle_between(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Real,
     {
         let dot_product = self.dot(other);
         let magnitude_product = self.length() * other.length();
         (dot_product / magnitude_product).acos()
     }
 
-    /// Swizzles the components of the vector.
+    /// Computes the angle between this vector and another vector in radians,
+    /// or returns an error if either has zero length.
+    ///
+    /// Unlike [`angle_between`](Self::angle_between), rounding can't push
+    /// the result to NaN for nearly parallel vectors.
     ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::error::VexelError;
     /// use vexel::vectors::vector2::Vector2;
     ///
-    /// let v = Vector2::new(1.0, 2.0);
-    /// let swizzled = v.swizzle(1, 0);
-    /// assert_eq!(swizzled.x, v.y);
-    /// assert_eq!(swizzled.y, v.x);
+    /// let a = Vector2::new(1.0, 0.0);
+    /// let b = Vector2::new(0.0, 2.0);
+    /// assert_eq!(a.try_angle_between(&b), Ok(core::f64::consts::FRAC_PI_2));
+    /// assert_eq!(
+    ///     a.try_angle_between(&Vector2::new(0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
+    /// ```
+    pub fn try_angle_between(&self, other: &Self) -> Result<T, VexelError>
+    where
+        T: Real,
+    {
+        let magnitude_product = self.length() * other.length();
+        if magnitude_product == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !magnitude_product.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let cos = self.dot(other) / magnitude_product;
+        Ok(cos.clamp(-T::ONE, T::ONE).acos())
+    }
+
+    /// Divides this vector by another component-wise using Euclidean division.
+    ///
+    /// Unlike `/`, this rounds towards negative infinity for positive divisors,
+    /// which maps a world coordinate to the chunk that contains it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// let d = v.div_euclid(&Vector2::new(2, 2));
+    /// assert_eq!(d, Vector2::new(3, -2));
+    /// ```
+    pub fn div_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.div_euclid(other.x),
+            y: self.y.div_euclid(other.y),
+        }
+    }
+
+    /// Computes the component-wise non-negative remainder of Euclidean division.
+    ///
+    /// This gives the position of a coordinate within its chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// let r = v.rem_euclid(&Vector2::new(2, 2));
+    /// assert_eq!(r, Vector2::new(1, 1));
+    /// ```
+    pub fn rem_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.rem_euclid(other.x),
+            y: self.y.rem_euclid(other.y),
+        }
+    }
+
+    /// Component-wise checked addition, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.checked_add(&Vector2::new(2, 4)), Some(Vector2::new(9, 1)));
+    /// assert_eq!(Vector2::new(i32::MAX, 0).checked_add(&Vector2::new(1, 0)), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+        })
+    }
+
+    /// Component-wise checked subtraction, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.checked_sub(&Vector2::new(2, 4)), Some(Vector2::new(5, -7)));
+    /// assert_eq!(Vector2::new(i32::MIN, 0).checked_sub(&Vector2::new(1, 0)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+        })
+    }
+
+    /// Component-wise checked multiplication, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, -3);
+    /// assert_eq!(v.checked_mul(&Vector2::new(2, 4)), Some(Vector2::new(14, -12)));
+    /// assert_eq!(Vector2::new(i32::MAX, 0).checked_mul(&Vector2::new(2, 1)), None);
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_mul(other.x)?,
+            y: self.y.checked_mul(other.y)?,
+        })
+    }
+
+    /// Component-wise checked division, returning `None` on division by zero or overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(4, 8);
+    /// assert_eq!(v.checked_div(&Vector2::new(2, 4)), Some(Vector2::new(2, 2)));
+    /// assert_eq!(v.checked_div(&Vector2::new(0, 4)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_div(other.x)?,
+            y: self.y.checked_div(other.y)?,
+        })
+    }
+
+    /// Component-wise wrapping addition, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MAX, 0);
+    /// assert_eq!(v.wrapping_add(&Vector2::new(1, 0)), Vector2::new(i32::MIN, 0));
+    /// ```
+    pub fn wrapping_add(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+        }
+    }
+
+    /// Component-wise wrapping subtraction, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MIN, 0);
+    /// assert_eq!(v.wrapping_sub(&Vector2::new(1, 0)), Vector2::new(i32::MAX, 0));
+    /// ```
+    pub fn wrapping_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+        }
+    }
+
+    /// Component-wise wrapping multiplication, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MAX, 0);
+    /// assert_eq!(v.wrapping_mul(&Vector2::new(2, 1)), Vector2::new(-2, 0));
+    /// ```
+    pub fn wrapping_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+        }
+    }
+
+    /// Component-wise saturating addition, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MAX, 0);
+    /// assert_eq!(v.saturating_add(&Vector2::new(1, 0)), Vector2::new(i32::MAX, 0));
     /// ```
-    pub fn swizzle(&self, x: usize, y: usize) -> Self
+    pub fn saturating_add(&self, other: &Self) -> Self
     where
-        T: Copy,
+        T: Integer,
     {
-        let components = [self.x, self.y];
         Self {
-            x: components[x],
-            y: components[y],
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+        }
+    }
+
+    /// Component-wise saturating subtraction, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MIN, 0);
+    /// assert_eq!(v.saturating_sub(&Vector2::new(1, 0)), Vector2::new(i32::MIN, 0));
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+        }
+    }
+
+    /// Component-wise saturating multiplication, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(i32::MAX, 0);
+    /// assert_eq!(v.saturating_mul(&Vector2::new(2, 1)), Vector2::new(i32::MAX, 0));
+    /// ```
+    pub fn saturating_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
         }
     }
 }
@@ -339,3 +817,347 @@ where
         }
     }
 }
+
+impl<T> Neg for Vector2<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    /// Negates every component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(-v, Vector2::new(-1.0, -2.0));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> Rem for Vector2<T>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    /// Computes the remainder of dividing one vector by another
+    /// component-wise.
+    ///
+    /// Like `%` on the components, the result has the sign of the dividend;
+    /// see `rem_euclid` for a remainder that is never negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(7, 8) % Vector2::new(4, 3);
+    /// assert_eq!(v, Vector2::new(3, 2));
+    /// ```
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+        }
+    }
+}
+
+impl<T> Mul<T> for Vector2<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(v * 2.0, Vector2::new(2.0, 4.0));
+    /// assert_eq!(2.0 * v, v * 2.0);
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Vector2<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(v / 2.0, Vector2::new(0.5, 1.0));
+    /// ```
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T> AddAssign for Vector2<T>
+where
+    T: AddAssign,
+{
+    /// Adds another vector to this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v += Vector2::new(3.0, 4.0);
+    /// assert_eq!(v, Vector2::new(4.0, 6.0));
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> SubAssign for Vector2<T>
+where
+    T: SubAssign,
+{
+    /// Subtracts another vector from this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v -= Vector2::new(3.0, 4.0);
+    /// assert_eq!(v, Vector2::new(-2.0, -2.0));
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> MulAssign for Vector2<T>
+where
+    T: MulAssign,
+{
+    /// Multiplies this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v *= Vector2::new(3.0, 4.0);
+    /// assert_eq!(v, Vector2::new(3.0, 8.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl<T> DivAssign for Vector2<T>
+where
+    T: DivAssign,
+{
+    /// Divides this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v /= Vector2::new(3.0, 4.0);
+    /// assert_eq!(v, Vector2::new(0.3333333333333333, 0.5));
+    /// ```
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+impl<T> RemAssign for Vector2<T>
+where
+    T: RemAssign,
+{
+    /// Replaces each component with its remainder after dividing by the
+    /// matching component of another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(7, 8);
+    /// v %= Vector2::new(4, 3);
+    /// assert_eq!(v, Vector2::new(3, 2));
+    /// ```
+    fn rem_assign(&mut self, rhs: Self) {
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+    }
+}
+
+impl<T> MulAssign<T> for Vector2<T>
+where
+    T: MulAssign + Copy,
+{
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v *= 3.0;
+    /// assert_eq!(v, Vector2::new(3.0, 6.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T> DivAssign<T> for Vector2<T>
+where
+    T: DivAssign + Copy,
+{
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v /= 4.0;
+    /// assert_eq!(v, Vector2::new(0.25, 0.5));
+    /// ```
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Vector2<T> {
+    type Output = T;
+
+    /// Returns the component at `index`, counting from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 2 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(v[0], 1.0);
+    /// assert_eq!(v[1], 2.0);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds: Vector2 has 2 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vector2<T> {
+    /// Returns a mutable reference to the component at `index`, counting
+    /// from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 2 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v[1] = 5.0;
+    /// assert_eq!(v.y, 5.0);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of bounds: Vector2 has 2 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> Sum for Vector2<T>
+where
+    T: Scalar,
+{
+    /// Adds up the vectors of an iterator, giving the zero vector for an
+    /// empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let vs = [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    /// let total: Vector2<f64> = vs.iter().sum();
+    /// assert_eq!(total, Vector2::new(4.0, 6.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ZERO, T::ZERO), Add::add)
+    }
+}
+
+impl<T> Product for Vector2<T>
+where
+    T: Scalar,
+{
+    /// Multiplies the vectors of an iterator component-wise, giving a vector
+    /// of ones for an empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector2::Vector2;
+    ///
+    /// let vs = [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    /// let product: Vector2<f64> = vs.into_iter().product();
+    /// assert_eq!(product, Vector2::new(3.0, 8.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ONE, T::ONE), Mul::mul)
+    }
+}
@@ -1,12 +1,59 @@
-use std::ops::{Add, Div, Mul, Sub};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use crate::error::VexelError;
+use crate::scalar::{Integer, Magnitude, Real, Scalar, Signed};
+use crate::vectors::vector4::Vector4;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Vector3<T> {
     pub x: T,
     pub y: T,
     pub z: T,
 }
 
+/// A `Vector3` with `f32` components, the usual choice for GPU-facing code.
+///
+/// All vector operations are computed in `f32` without widening to `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector3::{Vec3f, Vector3};
+///
+/// let v: Vec3f = Vector3::new(0.0, 3.0, 4.0);
+/// let n: Vec3f = v.normalize();
+/// assert_eq!(n, Vector3::new(0.0, 0.6, 0.8));
+/// ```
+pub type Vec3f = Vector3<f32>;
+
+/// A `Vector3` with `f64` components.
+pub type Vec3d = Vector3<f64>;
+
+/// A `Vector3` with `i32` components, for grid, voxel and tile coordinates.
+///
+/// Integer vectors are exact and implement `Eq`, `Hash` and `Ord`, so they can
+/// be used as `HashMap` or `BTreeMap` keys. Ordering is lexicographic.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use vexel::vectors::vector3::{Vec3i, Vector3};
+///
+/// let mut chunks: HashMap<Vec3i, &str> = HashMap::new();
+/// chunks.insert(Vector3::new(-1, 3, 0), "loaded");
+/// assert_eq!(chunks.get(&Vector3::new(-1, 3, 0)), Some(&"loaded"));
+/// ```
+pub type Vec3i = Vector3<i32>;
+
+/// A `Vector3` with `u32` components.
+pub type Vec3u = Vector3<u32>;
+
 impl<T> Vector3<T> {
     /// Creates a new `Vector3` with the given `x`, `y`, and `z` components.
     ///
@@ -24,6 +71,28 @@ impl<T> Vector3<T> {
         Self { x, y, z }
     }
 
+    /// Creates a `Vector4` from this vector and a `w` component.
+    ///
+    /// In homogeneous coordinates a `w` of 1 makes a point, which
+    /// translations move, and a `w` of 0 a direction, which they don't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.extend(1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// assert_eq!(v.extend(1.0).truncate(), v);
+    /// ```
+    pub fn extend(&self, w: T) -> Vector4<T>
+    where
+        T: Copy,
+    {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
     /// Computes the length (magnitude) of the vector.
     ///
     /// # Examples
@@ -34,11 +103,75 @@ impl<T> Vector3<T> {
     /// let v = Vector3::new(3.0, 4.0, 5.0);
     /// assert_eq!(v.length(), 7.0710678118654755);
     /// ```
-    pub fn length(&self) -> f64
+    pub fn length(&self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Real,
     {
-        (self.x.into().powi(2) + self.y.into().powi(2) + self.z.into().powi(2)).sqrt()
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// This avoids the square root and is exact for integer vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.length_squared(), 62);
+    /// ```
+    pub fn length_squared(&self) -> T
+    where
+        T: Scalar,
+    {
+        self.dot(self)
+    }
+
+    /// Computes the Manhattan (taxicab) length, the sum of the absolute components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.manhattan_length(), 12);
+    ///
+    /// // Unsigned components are their own absolute values.
+    /// assert_eq!(Vector3::new(7u32, 3, 2).manhattan_length(), 12);
+    /// ```
+    pub fn manhattan_length(&self) -> T
+    where
+        T: Magnitude,
+    {
+        self.x.magnitude() + self.y.magnitude() + self.z.magnitude()
+    }
+
+    /// Computes the Chebyshev length, the largest absolute component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.chebyshev_length(), 7);
+    /// assert_eq!(Vector3::new(7u32, 3, 2).chebyshev_length(), 7);
+    /// ```
+    pub fn chebyshev_length(&self) -> T
+    where
+        T: Magnitude,
+    {
+        let mut max = self.x.magnitude();
+        if self.y.magnitude() > max {
+            max = self.y.magnitude();
+        }
+        if self.z.magnitude() > max {
+            max = self.z.magnitude();
+        }
+        max
     }
 
     /// Computes the dot product of this vector and another.
@@ -52,13 +185,11 @@ impl<T> Vector3<T> {
     /// let v2 = Vector3::new(6.0, 7.0, 8.0);
     /// assert_eq!(v1.dot(&v2), 86.0);
     /// ```
-    pub fn dot(&self, other: &Self) -> f64
+    pub fn dot(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Scalar,
     {
-        (self.x.into() * other.x.into())
-            + (self.y.into() * other.y.into())
-            + (self.z.into() * other.z.into())
+        self.x * other.x + self.y * other.y + self.z * other.z
     }
 
     /// Computes the cross product of this vector and another.
@@ -75,23 +206,27 @@ impl<T> Vector3<T> {
     /// ```
     pub fn cross(&self, other: &Self) -> Self
     where
-        T: Copy + Into<f64> + From<f64>,
+        T: Signed,
     {
         Self {
-            x: (self.y.into() * other.z.into() - self.z.into() * other.y.into()).into(),
-            y: (self.z.into() * other.x.into() - self.x.into() * other.z.into()).into(),
-            z: (self.x.into() * other.y.into() - self.y.into() * other.x.into()).into(),
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
         }
     }
 
     /// Normalizes the vector, making it a unit vector.
     ///
+    /// A zero vector is returned unchanged; use
+    /// [`try_normalize`](Self::try_normalize) to catch it.
+    ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::assert_approx_eq;
     /// use vexel::vectors::vector3::Vector3;
     ///
-    /// let v = Vector3::new(3.0, 6.0, 12.0);
+    /// let v = Vector3::new(3.0_f64, 6.0, 12.0);
     /// let normalized = v.normalize();
     /// let magnitude = v.length();
     ///
@@ -101,24 +236,92 @@ impl<T> Vector3<T> {
     ///     12.0 / magnitude,
     /// );
     ///
-    /// assert!((normalized.x - expected.x).abs() < 1e-6);
-    /// assert!((normalized.y - expected.y).abs() < 1e-6);
-    /// assert!((normalized.z - expected.z).abs() < 1e-6);
+    /// assert_approx_eq!(normalized, expected, epsilon = 1e-6);
     /// ```
-
     pub fn normalize(&self) -> Self
     where
-        T: Into<f64> + Copy + From<f64>,
+        T: Real,
     {
         let len = self.length();
-        if len == 0.0 {
+        if len == T::ZERO {
             return *self;
         }
         Self {
-            x: (self.x.into() / len).into(),
-            y: (self.y.into() / len).into(),
-            z: (self.z.into() / len).into(),
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+        }
+    }
+
+    /// Normalizes the vector, or returns an error if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(0.0, 3.0, 4.0).try_normalize(), Ok(Vector3::new(0.0, 0.6, 0.8)));
+    /// assert_eq!(Vector3::new(0.0, 0.0, 0.0).try_normalize(), Err(VexelError::ZeroLength));
+    /// assert_eq!(
+    ///     Vector3::new(f64::MAX, 0.0, f64::MAX).try_normalize(),
+    ///     Err(VexelError::NonFinite)
+    /// );
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !len.is_finite() {
+            return Err(VexelError::NonFinite);
         }
+        Ok(Self {
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+        })
+    }
+
+    /// Normalizes the vector, or returns `fallback` if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let up = Vector3::new(0.0, 0.0, 1.0);
+    /// assert_eq!(Vector3::new(0.0, 3.0, 4.0).normalize_or(up), Vector3::new(0.0, 0.6, 0.8));
+    /// assert_eq!(Vector3::new(0.0, 0.0, 0.0).normalize_or(up), up);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self
+    where
+        T: Real,
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalizes the vector, or returns the zero vector if it has zero
+    /// length or its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(f64::MAX, 0.0, f64::MAX);
+    /// assert_eq!(v.normalize_or_zero(), Vector3::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn normalize_or_zero(&self) -> Self
+    where
+        T: Real,
+    {
+        self.normalize_or(Self::new(T::ZERO, T::ZERO, T::ZERO))
     }
 
     /// Projects this vector onto another vector.
@@ -137,21 +340,51 @@ impl<T> Vector3<T> {
     /// ```
     pub fn project_onto(&self, other: &Self) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
+        T: Real,
     {
-        let scalar = (self.x.into() * other.x.into()
-            + self.y.into() * other.y.into()
-            + self.z.into() * other.z.into())
-            / (other.x.into() * other.x.into()
-                + other.y.into() * other.y.into()
-                + other.z.into() * other.z.into());
+        let scalar = self.dot(other) / other.dot(other);
         Self {
-            x: (scalar * other.x.into()).into(),
-            y: (scalar * other.y.into()).into(),
-            z: (scalar * other.z.into()).into(),
+            x: scalar * other.x,
+            y: scalar * other.y,
+            z: scalar * other.z,
         }
     }
 
+    /// Projects this vector onto another vector, or returns an error if the
+    /// other vector has zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(3.0, 4.0, 5.0);
+    /// assert_eq!(v.try_project_onto(&Vector3::new(2.0, 0.0, 0.0)), Ok(Vector3::new(3.0, 0.0, 0.0)));
+    /// assert_eq!(
+    ///     v.try_project_onto(&Vector3::new(0.0, 0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
+    /// ```
+    pub fn try_project_onto(&self, other: &Self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let length_squared = other.dot(other);
+        if length_squared == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !length_squared.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let scalar = self.dot(other) / length_squared;
+        Ok(Self {
+            x: scalar * other.x,
+            y: scalar * other.y,
+            z: scalar * other.z,
+        })
+    }
+
     /// Rejects this vector from another vector.
     ///
     /// # Examples
@@ -168,13 +401,7 @@ impl<T> Vector3<T> {
     /// ```
     pub fn reject_from(&self, other: &Self) -> Self
     where
-        T: Into<f64>
-            + Copy
-            + From<f64>
-            + Mul<Output = T>
-            + Add<Output = T>
-            + Div<Output = T>
-            + Sub<Output = T>,
+        T: Real,
     {
         let projection = self.project_onto(other);
         Self {
@@ -198,9 +425,9 @@ impl<T> Vector3<T> {
     /// assert_eq!(interpolated.y, 3.5);
     /// assert_eq!(interpolated.z, 4.5);
     /// ```
-    pub fn lerp(&self, other: &Self, t: f64) -> Self
+    pub fn lerp(&self, other: &Self, t: T) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+        T: Real,
     {
         Self {
             x: self.x + (other.x - self.x) * t,
@@ -210,6 +437,8 @@ impl<T> Vector3<T> {
     }
     /// Computes the angle between this vector and another vector in radians.
     ///
+    /// The angle is NaN if either vector has zero length; use
+    /// [`try_angle_between`](Self::try_angle_between) to catch it.
     ///
     /// # Examples
     ///
@@ -221,37 +450,310 @@ impl<T> Vector3<T> {
     /// let angle = v1.angle_between(&v2);
     /// assert_eq!(angle, 2.5839938268902563);
     /// ```
-    pub fn angle_between(&self, other: &Self) -> f64
+    pub fn angle_between(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Real,
     {
         let dot_product = self.dot(other);
         let magnitude_product = self.length() * other.length();
         (dot_product / magnitude_product).acos()
     }
 
-    /// Swizzles the components of the vector.
+    /// Computes the angle between this vector and another vector in radians,
+    /// or returns an error if either has zero length.
+    ///
+    /// Unlike [`angle_between`](Self::angle_between), rounding can't push
+    /// the result to NaN for nearly parallel vectors.
     ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::error::VexelError;
     /// use vexel::vectors::vector3::Vector3;
     ///
-    /// let v = Vector3::new(1.0, 2.0, 3.0);
-    /// let swizzled = v.swizzle(1, 2, 0);
-    /// assert_eq!(swizzled.x, v.y);
-    /// assert_eq!(swizzled.y, v.z);
-    /// assert_eq!(swizzled.z, v.x);
+    /// let a = Vector3::new(1.0, 0.0, 0.0);
+    /// let b = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(a.try_angle_between(&b), Ok(core::f64::consts::FRAC_PI_2));
+    /// assert_eq!(
+    ///     a.try_angle_between(&Vector3::new(0.0, 0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
     /// ```
-    pub fn swizzle(&self, x: usize, y: usize, z: usize) -> Self
+    pub fn try_angle_between(&self, other: &Self) -> Result<T, VexelError>
     where
-        T: Copy,
+        T: Real,
+    {
+        let magnitude_product = self.length() * other.length();
+        if magnitude_product == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !magnitude_product.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let cos = self.dot(other) / magnitude_product;
+        Ok(cos.clamp(-T::ONE, T::ONE).acos())
+    }
+
+    /// Divides this vector by another component-wise using Euclidean division.
+    ///
+    /// Unlike `/`, this rounds towards negative infinity for positive divisors,
+    /// which maps a world coordinate to the chunk that contains it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// let d = v.div_euclid(&Vector3::new(2, 2, 2));
+    /// assert_eq!(d, Vector3::new(3, -2, 1));
+    /// ```
+    pub fn div_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.div_euclid(other.x),
+            y: self.y.div_euclid(other.y),
+            z: self.z.div_euclid(other.z),
+        }
+    }
+
+    /// Computes the component-wise non-negative remainder of Euclidean division.
+    ///
+    /// This gives the position of a coordinate within its chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// let r = v.rem_euclid(&Vector3::new(2, 2, 2));
+    /// assert_eq!(r, Vector3::new(1, 1, 0));
+    /// ```
+    pub fn rem_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
     {
-        let components = [self.x, self.y, self.z];
         Self {
-            x: components[x],
-            y: components[y],
-            z: components[z],
+            x: self.x.rem_euclid(other.x),
+            y: self.y.rem_euclid(other.y),
+            z: self.z.rem_euclid(other.z),
+        }
+    }
+
+    /// Component-wise checked addition, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.checked_add(&Vector3::new(2, 4, -5)), Some(Vector3::new(9, 1, -3)));
+    /// assert_eq!(Vector3::new(i32::MAX, 0, 0).checked_add(&Vector3::new(1, 0, 0)), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+            z: self.z.checked_add(other.z)?,
+        })
+    }
+
+    /// Component-wise checked subtraction, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.checked_sub(&Vector3::new(2, 4, -5)), Some(Vector3::new(5, -7, 7)));
+    /// assert_eq!(Vector3::new(i32::MIN, 0, 0).checked_sub(&Vector3::new(1, 0, 0)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+            z: self.z.checked_sub(other.z)?,
+        })
+    }
+
+    /// Component-wise checked multiplication, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, -3, 2);
+    /// assert_eq!(v.checked_mul(&Vector3::new(2, 4, -5)), Some(Vector3::new(14, -12, -10)));
+    /// assert_eq!(Vector3::new(i32::MAX, 0, 0).checked_mul(&Vector3::new(2, 1, 1)), None);
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_mul(other.x)?,
+            y: self.y.checked_mul(other.y)?,
+            z: self.z.checked_mul(other.z)?,
+        })
+    }
+
+    /// Component-wise checked division, returning `None` on division by zero or overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(4, 8, -10);
+    /// assert_eq!(v.checked_div(&Vector3::new(2, 4, -5)), Some(Vector3::new(2, 2, 2)));
+    /// assert_eq!(v.checked_div(&Vector3::new(0, 4, -5)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_div(other.x)?,
+            y: self.y.checked_div(other.y)?,
+            z: self.z.checked_div(other.z)?,
+        })
+    }
+
+    /// Component-wise wrapping addition, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MAX, 0, 0);
+    /// assert_eq!(v.wrapping_add(&Vector3::new(1, 0, 0)), Vector3::new(i32::MIN, 0, 0));
+    /// ```
+    pub fn wrapping_add(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+            z: self.z.wrapping_add(other.z),
+        }
+    }
+
+    /// Component-wise wrapping subtraction, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MIN, 0, 0);
+    /// assert_eq!(v.wrapping_sub(&Vector3::new(1, 0, 0)), Vector3::new(i32::MAX, 0, 0));
+    /// ```
+    pub fn wrapping_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+            z: self.z.wrapping_sub(other.z),
+        }
+    }
+
+    /// Component-wise wrapping multiplication, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MAX, 0, 0);
+    /// assert_eq!(v.wrapping_mul(&Vector3::new(2, 1, 1)), Vector3::new(-2, 0, 0));
+    /// ```
+    pub fn wrapping_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+            z: self.z.wrapping_mul(other.z),
+        }
+    }
+
+    /// Component-wise saturating addition, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MAX, 0, 0);
+    /// assert_eq!(v.saturating_add(&Vector3::new(1, 0, 0)), Vector3::new(i32::MAX, 0, 0));
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+            z: self.z.saturating_add(other.z),
+        }
+    }
+
+    /// Component-wise saturating subtraction, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MIN, 0, 0);
+    /// assert_eq!(v.saturating_sub(&Vector3::new(1, 0, 0)), Vector3::new(i32::MIN, 0, 0));
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+            z: self.z.saturating_sub(other.z),
+        }
+    }
+
+    /// Component-wise saturating multiplication, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(i32::MAX, 0, 0);
+    /// assert_eq!(v.saturating_mul(&Vector3::new(2, 1, 1)), Vector3::new(i32::MAX, 0, 0));
+    /// ```
+    pub fn saturating_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
+            z: self.z.saturating_mul(other.z),
         }
     }
 }
@@ -371,3 +873,360 @@ where
         }
     }
 }
+
+impl<T> Neg for Vector3<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    /// Negates every component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(-v, Vector3::new(-1.0, -2.0, -3.0));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> Rem for Vector3<T>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    /// Computes the remainder of dividing one vector by another
+    /// component-wise.
+    ///
+    /// Like `%` on the components, the result has the sign of the dividend;
+    /// see `rem_euclid` for a remainder that is never negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(7, 8, 9) % Vector3::new(4, 3, 5);
+    /// assert_eq!(v, Vector3::new(3, 2, 4));
+    /// ```
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+            z: self.z % rhs.z,
+        }
+    }
+}
+
+impl<T> Mul<T> for Vector3<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v * 2.0, Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(2.0 * v, v * 2.0);
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Vector3<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v / 2.0, Vector3::new(0.5, 1.0, 1.5));
+    /// ```
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl<T> AddAssign for Vector3<T>
+where
+    T: AddAssign,
+{
+    /// Adds another vector to this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v += Vector3::new(3.0, 4.0, 5.0);
+    /// assert_eq!(v, Vector3::new(4.0, 6.0, 8.0));
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T> SubAssign for Vector3<T>
+where
+    T: SubAssign,
+{
+    /// Subtracts another vector from this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v -= Vector3::new(3.0, 4.0, 5.0);
+    /// assert_eq!(v, Vector3::new(-2.0, -2.0, -2.0));
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> MulAssign for Vector3<T>
+where
+    T: MulAssign,
+{
+    /// Multiplies this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v *= Vector3::new(3.0, 4.0, 5.0);
+    /// assert_eq!(v, Vector3::new(3.0, 8.0, 15.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+impl<T> DivAssign for Vector3<T>
+where
+    T: DivAssign,
+{
+    /// Divides this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v /= Vector3::new(3.0, 4.0, 5.0);
+    /// assert_eq!(v, Vector3::new(0.3333333333333333, 0.5, 0.6));
+    /// ```
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+impl<T> RemAssign for Vector3<T>
+where
+    T: RemAssign,
+{
+    /// Replaces each component with its remainder after dividing by the
+    /// matching component of another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(7, 8, 9);
+    /// v %= Vector3::new(4, 3, 5);
+    /// assert_eq!(v, Vector3::new(3, 2, 4));
+    /// ```
+    fn rem_assign(&mut self, rhs: Self) {
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+        self.z %= rhs.z;
+    }
+}
+
+impl<T> MulAssign<T> for Vector3<T>
+where
+    T: MulAssign + Copy,
+{
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v *= 3.0;
+    /// assert_eq!(v, Vector3::new(3.0, 6.0, 9.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T> DivAssign<T> for Vector3<T>
+where
+    T: DivAssign + Copy,
+{
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v /= 4.0;
+    /// assert_eq!(v, Vector3::new(0.25, 0.5, 0.75));
+    /// ```
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Vector3<T> {
+    type Output = T;
+
+    /// Returns the component at `index`, counting from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 3 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v[0], 1.0);
+    /// assert_eq!(v[2], 3.0);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds: Vector3 has 3 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vector3<T> {
+    /// Returns a mutable reference to the component at `index`, counting
+    /// from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 3 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v[1] = 5.0;
+    /// assert_eq!(v.y, 5.0);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds: Vector3 has 3 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> Sum for Vector3<T>
+where
+    T: Scalar,
+{
+    /// Adds up the vectors of an iterator, giving the zero vector for an
+    /// empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let vs = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(3.0, 4.0, 5.0)];
+    /// let total: Vector3<f64> = vs.iter().sum();
+    /// assert_eq!(total, Vector3::new(4.0, 6.0, 8.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ZERO, T::ZERO, T::ZERO), Add::add)
+    }
+}
+
+impl<T> Product for Vector3<T>
+where
+    T: Scalar,
+{
+    /// Multiplies the vectors of an iterator component-wise, giving a vector
+    /// of ones for an empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    ///
+    /// let vs = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(3.0, 4.0, 5.0)];
+    /// let product: Vector3<f64> = vs.into_iter().product();
+    /// assert_eq!(product, Vector3::new(3.0, 8.0, 15.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ONE, T::ONE, T::ONE), Mul::mul)
+    }
+}
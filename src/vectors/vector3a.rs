@@ -0,0 +1,377 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::scalar::Real;
+use crate::simd::F32x4;
+use crate::vectors::vector3::Vector3;
+
+/// A three-component `f32` vector stored in a SIMD register.
+///
+/// `Vector3A` is laid out like a [`Vector4A`](super::vector4a::Vector4A),
+/// 16 bytes and 16-byte aligned, with a fourth lane that is ignored by every
+/// operation and comparison. Like `Vector4A` it gives bit-for-bit the same
+/// results as the matching [`Vector3<f32>`] operations.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector3::Vector3;
+/// use vexel::vectors::vector3a::Vector3A;
+///
+/// let a = Vector3::new(1.0, 2.0, 3.0);
+/// let b = Vector3::new(-2.0, 0.5, 4.0);
+/// let cross = Vector3A::from(a).cross(&Vector3A::from(b));
+/// assert_eq!(Vector3::from(cross), a.cross(&b));
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vector3A(pub(crate) F32x4);
+
+impl Vector3A {
+    /// Creates a new `Vector3A` with the given `x`, `y`, and `z` components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v = Vector3A::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.to_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(F32x4::new(x, y, z, 0.0))
+    }
+
+    /// Creates a vector with every component set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::splat(2.0), Vector3A::new(2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn splat(value: f32) -> Self {
+        Self::new(value, value, value)
+    }
+
+    /// Creates a vector from an array of its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v = Vector3A::from_array([1.0, 2.0, 3.0]);
+    /// assert_eq!(v, Vector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn from_array([x, y, z]: [f32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+
+    /// Returns the components as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(1.0, 2.0, 3.0).to_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [f32; 3] {
+        let [x, y, z, _] = self.0.to_array();
+        [x, y, z]
+    }
+
+    /// Returns the `x` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(1.0, 2.0, 3.0).x(), 1.0);
+    /// ```
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.0.x()
+    }
+
+    /// Returns the `y` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(1.0, 2.0, 3.0).y(), 2.0);
+    /// ```
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.0.y()
+    }
+
+    /// Returns the `z` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(1.0, 2.0, 3.0).z(), 3.0);
+    /// ```
+    #[inline]
+    pub fn z(&self) -> f32 {
+        self.0.z()
+    }
+
+    /// Computes the dot product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = Vector3A::new(4.0, 5.0, 6.0);
+    /// assert_eq!(v1.dot(&v2), 32.0);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.0.dot3(other.0)
+    }
+
+    /// Computes the cross product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let x = Vector3A::new(1.0, 0.0, 0.0);
+    /// let y = Vector3A::new(0.0, 1.0, 0.0);
+    /// assert_eq!(x.cross(&y), Vector3A::new(0.0, 0.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self(self.0.cross3(other.0))
+    }
+
+    /// Computes the length (magnitude) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(2.0, 3.0, 6.0).length(), 7.0);
+    /// ```
+    #[inline]
+    pub fn length(&self) -> f32 {
+        Real::sqrt(self.dot(self))
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::new(2.0, 3.0, 6.0).length_squared(), 49.0);
+    /// ```
+    #[inline]
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Returns the vector scaled to unit length, or the vector itself if its
+    /// length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v = Vector3A::new(0.0, 3.0, 4.0);
+    /// assert_eq!(v.normalize(), Vector3A::new(0.0, 0.6, 0.8));
+    /// assert_eq!(Vector3A::splat(0.0).normalize(), Vector3A::splat(0.0));
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        if len == 0.0 {
+            return *self;
+        }
+        Self(self.0 / F32x4::splat(len))
+    }
+
+    /// Linearly interpolates between this vector and another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = Vector3A::new(5.0, 6.0, 7.0);
+    /// assert_eq!(v1.lerp(&v2, 0.5), Vector3A::new(3.0, 4.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self(self.0 + (other.0 - self.0) * F32x4::splat(t))
+    }
+}
+
+impl Default for Vector3A {
+    /// Returns the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// assert_eq!(Vector3A::default(), Vector3A::splat(0.0));
+    /// ```
+    fn default() -> Self {
+        Self::splat(0.0)
+    }
+}
+
+impl PartialEq for Vector3A {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl fmt::Debug for Vector3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = self.to_array();
+        f.debug_struct("Vector3A")
+            .field("x", &x)
+            .field("y", &y)
+            .field("z", &z)
+            .finish()
+    }
+}
+
+impl From<Vector3<f32>> for Vector3A {
+    /// Loads a vector into a SIMD register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v = Vector3A::from(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v, Vector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn from(v: Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3A> for Vector3<f32> {
+    /// Stores a SIMD vector as a plain `Vector3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v = Vector3::from(Vector3A::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn from(v: Vector3A) -> Self {
+        let [x, y, z] = v.to_array();
+        Vector3::new(x, y, z)
+    }
+}
+
+impl Add for Vector3A {
+    type Output = Self;
+
+    /// Adds two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = Vector3A::new(4.0, 5.0, 6.0);
+    /// assert_eq!(v1 + v2, Vector3A::new(5.0, 7.0, 9.0));
+    /// ```
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Vector3A {
+    type Output = Self;
+
+    /// Subtracts one vector from another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(5.0, 6.0, 7.0);
+    /// let v2 = Vector3A::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v1 - v2, Vector3A::new(4.0, 4.0, 4.0));
+    /// ```
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Vector3A {
+    type Output = Self;
+
+    /// Multiplies two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(1.0, 2.0, 3.0);
+    /// let v2 = Vector3A::new(2.0, 3.0, 4.0);
+    /// assert_eq!(v1 * v2, Vector3A::new(2.0, 6.0, 12.0));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for Vector3A {
+    type Output = Self;
+
+    /// Divides one vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3a::Vector3A;
+    ///
+    /// let v1 = Vector3A::new(2.0, 6.0, 12.0);
+    /// let v2 = Vector3A::new(2.0, 3.0, 4.0);
+    /// assert_eq!(v1 / v2, Vector3A::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
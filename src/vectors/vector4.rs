@@ -1,6 +1,15 @@
-use std::ops::{Add, Div, Mul, Sub};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use crate::error::VexelError;
+use crate::scalar::{Integer, Magnitude, Real, Scalar, Signed};
+use crate::vectors::vector3::Vector3;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Vector4<T> {
     pub x: T,
     pub y: T,
@@ -8,6 +17,44 @@ pub struct Vector4<T> {
     pub w: T,
 }
 
+/// A `Vector4` with `f32` components, the usual choice for GPU-facing code.
+///
+/// All vector operations are computed in `f32` without widening to `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vector4::{Vec4f, Vector4};
+///
+/// let v: Vec4f = Vector4::new(0.0, 3.0, 0.0, 4.0);
+/// let n: Vec4f = v.normalize();
+/// assert_eq!(n, Vector4::new(0.0, 0.6, 0.0, 0.8));
+/// ```
+pub type Vec4f = Vector4<f32>;
+
+/// A `Vector4` with `f64` components.
+pub type Vec4d = Vector4<f64>;
+
+/// A `Vector4` with `i32` components, for grid, voxel and tile coordinates.
+///
+/// Integer vectors are exact and implement `Eq`, `Hash` and `Ord`, so they can
+/// be used as `HashMap` or `BTreeMap` keys. Ordering is lexicographic.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use vexel::vectors::vector4::{Vec4i, Vector4};
+///
+/// let mut chunks: HashMap<Vec4i, &str> = HashMap::new();
+/// chunks.insert(Vector4::new(-1, 3, 0, 2), "loaded");
+/// assert_eq!(chunks.get(&Vector4::new(-1, 3, 0, 2)), Some(&"loaded"));
+/// ```
+pub type Vec4i = Vector4<i32>;
+
+/// A `Vector4` with `u32` components.
+pub type Vec4u = Vector4<u32>;
+
 impl<T> Vector4<T> {
     /// Creates a new `Vector4` with the given `x`, `y`, `z`, and `w` components.
     ///
@@ -26,6 +73,99 @@ impl<T> Vector4<T> {
         Self { x, y, z, w }
     }
 
+    /// Creates a point in homogeneous coordinates, with a `w` of 1.
+    ///
+    /// Transforming it by a matrix applies the translation as well as the
+    /// rotation and scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let p = Vector4::point(1.0, 2.0, 3.0);
+    /// assert_eq!(p, Vector4::new(1.0, 2.0, 3.0, 1.0));
+    ///
+    /// let translation = Mat4::from_cols_array([
+    ///     1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// assert_eq!(translation * p, Vector4::point(6.0, 2.0, 3.0));
+    /// ```
+    pub fn point(x: T, y: T, z: T) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(x, y, z, T::ONE)
+    }
+
+    /// Creates a direction in homogeneous coordinates, with a `w` of 0.
+    ///
+    /// Transforming it by a matrix applies the rotation and scale but not the
+    /// translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::matrices::mat4::Mat4;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let d = Vector4::direction(1.0, 2.0, 3.0);
+    /// assert_eq!(d, Vector4::new(1.0, 2.0, 3.0, 0.0));
+    ///
+    /// let translation = Mat4::from_cols_array([
+    ///     1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, 0.0, 0.0, 1.0,
+    /// ]);
+    /// assert_eq!(translation * d, d);
+    /// ```
+    pub fn direction(x: T, y: T, z: T) -> Self
+    where
+        T: Scalar,
+    {
+        Self::new(x, y, z, T::ZERO)
+    }
+
+    /// Returns the `x`, `y` and `z` components, dropping `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.truncate(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn truncate(&self) -> Vector3<T>
+    where
+        T: Copy,
+    {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Divides `x`, `y` and `z` by `w`, turning a point in homogeneous
+    /// coordinates, such as a clip-space position, back into a 3D point.
+    ///
+    /// A `w` of 0 marks a direction, or a point at infinity, so the result
+    /// is infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector3::Vector3;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let clip = Vector4::new(2.0, -4.0, 1.0, 2.0);
+    /// assert_eq!(clip.perspective_divide(), Vector3::new(1.0, -2.0, 0.5));
+    /// assert_eq!(Vector4::point(1.0, 2.0, 3.0).perspective_divide(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn perspective_divide(&self) -> Vector3<T>
+    where
+        T: Real,
+    {
+        Vector3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+
     /// Computes the length (magnitude) of the vector.
     ///
     /// # Examples
@@ -36,15 +176,78 @@ impl<T> Vector4<T> {
     /// let v = Vector4::new(1.0, 2.0, 2.0, 2.0);
     /// assert_eq!(v.length(), 3.605551275463989);
     /// ```
-    pub fn length(&self) -> f64
+    pub fn length(&self) -> T
+    where
+        T: Real,
+    {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// This avoids the square root and is exact for integer vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.length_squared(), 63);
+    /// ```
+    pub fn length_squared(&self) -> T
+    where
+        T: Scalar,
+    {
+        self.dot(self)
+    }
+
+    /// Computes the Manhattan (taxicab) length, the sum of the absolute components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.manhattan_length(), 13);
+    ///
+    /// // Unsigned components are their own absolute values.
+    /// assert_eq!(Vector4::new(7u32, 3, 2, 1).manhattan_length(), 13);
+    /// ```
+    pub fn manhattan_length(&self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Magnitude,
     {
-        (self.x.into().powi(2)
-            + self.y.into().powi(2)
-            + self.z.into().powi(2)
-            + self.w.into().powi(2))
-        .sqrt()
+        self.x.magnitude() + self.y.magnitude() + self.z.magnitude() + self.w.magnitude()
+    }
+
+    /// Computes the Chebyshev length, the largest absolute component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.chebyshev_length(), 7);
+    /// assert_eq!(Vector4::new(7u32, 3, 2, 1).chebyshev_length(), 7);
+    /// ```
+    pub fn chebyshev_length(&self) -> T
+    where
+        T: Magnitude,
+    {
+        let mut max = self.x.magnitude();
+        if self.y.magnitude() > max {
+            max = self.y.magnitude();
+        }
+        if self.z.magnitude() > max {
+            max = self.z.magnitude();
+        }
+        if self.w.magnitude() > max {
+            max = self.w.magnitude();
+        }
+        max
     }
 
     /// Computes the dot product of this vector and another.
@@ -58,18 +261,18 @@ impl<T> Vector4<T> {
     /// let v2 = Vector4::new(5.0, 6.0, 7.0, 8.0);
     /// assert_eq!(v1.dot(&v2), 70.0);
     /// ```
-    pub fn dot(&self, other: &Self) -> f64
+    pub fn dot(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Scalar,
     {
-        (self.x.into() * other.x.into())
-            + (self.y.into() * other.y.into())
-            + (self.z.into() * other.z.into())
-            + (self.w.into() * other.w.into())
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
     }
 
-    /// Computes the cross product of this vector and another.
-    /// Note: The cross product is only defined for 3D vectors, so the `w` component is ignored.
+    /// Computes the 3D cross product of the `x`, `y` and `z` parts of this
+    /// vector and another, as a direction with a `w` of 0.
+    ///
+    /// The `w` components are ignored. For the cross product of 4D vectors,
+    /// which takes three operands, see [`cross4`](Self::cross4).
     ///
     /// # Examples
     ///
@@ -84,26 +287,73 @@ impl<T> Vector4<T> {
     /// assert_eq!(cross.z, -4.0);
     /// assert_eq!(cross.w, 0.0);
     /// ```
-    pub fn cross(&self, other: &Self) -> Vector4<f64>
+    pub fn cross(&self, other: &Self) -> Self
+    where
+        T: Signed,
+    {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+            w: T::ZERO,
+        }
+    }
+
+    /// Computes the 4D cross product of this vector and two others: a vector
+    /// perpendicular to all three.
+    ///
+    /// Like the 3D cross product, its length is the volume spanned by the
+    /// three vectors and it is zero when they are linearly dependent. Its dot
+    /// product with a fourth vector `d` is the determinant of the matrix with
+    /// columns `self`, `b`, `c` and `d`, so `x.cross4(y, z)` is `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let x = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    /// let y = Vector4::new(0.0, 1.0, 0.0, 0.0);
+    /// let z = Vector4::new(0.0, 0.0, 1.0, 0.0);
+    /// assert_eq!(x.cross4(&y, &z), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    ///
+    /// let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vector4::new(-2.0, 0.0, 1.0, 1.0);
+    /// let c = Vector4::new(0.0, 3.0, -1.0, 2.0);
+    /// let n = a.cross4(&b, &c);
+    /// assert_eq!([n.dot(&a), n.dot(&b), n.dot(&c)], [0.0, 0.0, 0.0]);
+    /// ```
+    pub fn cross4(&self, b: &Self, c: &Self) -> Self
     where
-        T: Into<f64> + Copy,
+        T: Signed,
     {
-        Vector4::new(
-            self.y.into() * other.z.into() - self.z.into() * other.y.into(),
-            self.z.into() * other.x.into() - self.x.into() * other.z.into(),
-            self.x.into() * other.y.into() - self.y.into() * other.x.into(),
-            0.0,
-        )
+        // The 2x2 minors of `b` and `c`, named by the axes they span.
+        let xy = b.x * c.y - b.y * c.x;
+        let xz = b.x * c.z - b.z * c.x;
+        let xw = b.x * c.w - b.w * c.x;
+        let yz = b.y * c.z - b.z * c.y;
+        let yw = b.y * c.w - b.w * c.y;
+        let zw = b.z * c.w - b.w * c.z;
+        Self {
+            x: self.z * yw - self.y * zw - self.w * yz,
+            y: self.x * zw - self.z * xw + self.w * xz,
+            z: self.y * xw - self.x * yw - self.w * xy,
+            w: self.x * yz - self.y * xz + self.z * xy,
+        }
     }
 
     /// Normalizes the vector, making it a unit vector.
     ///
+    /// A zero vector is returned unchanged; use
+    /// [`try_normalize`](Self::try_normalize) to catch it.
+    ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::assert_approx_eq;
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v = Vector4::new(1.0, 2.0, 2.0, 2.0);
+    /// let v = Vector4::new(1.0_f64, 2.0, 2.0, 2.0);
     /// let normalized = v.normalize();
     /// let magnitude = v.length();
     ///
@@ -114,26 +364,94 @@ impl<T> Vector4<T> {
     ///     2.0 / magnitude,
     /// );
     ///
-    /// assert!((normalized.x - expected.x).abs() < 1e-6);
-    /// assert!((normalized.y - expected.y).abs() < 1e-6);
-    /// assert!((normalized.z - expected.z).abs() < 1e-6);
-    /// assert!((normalized.w - expected.w).abs() < 1e-6);
+    /// assert_approx_eq!(normalized, expected, epsilon = 1e-6);
     /// ```
-
     pub fn normalize(&self) -> Self
     where
-        T: Into<f64> + Copy + From<f64>,
+        T: Real,
     {
         let len = self.length();
-        if len == 0.0 {
+        if len == T::ZERO {
             return *self;
         }
         Self {
-            x: (self.x.into() / len).into(),
-            y: (self.y.into() / len).into(),
-            z: (self.z.into() / len).into(),
-            w: (self.w.into() / len).into(),
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+            w: self.w / len,
+        }
+    }
+
+    /// Normalizes the vector, or returns an error if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// assert_eq!(Vector4::new(0.0, 3.0, 0.0, 4.0).try_normalize(), Ok(Vector4::new(0.0, 0.6, 0.0, 0.8)));
+    /// assert_eq!(Vector4::new(0.0, 0.0, 0.0, 0.0).try_normalize(), Err(VexelError::ZeroLength));
+    /// assert_eq!(
+    ///     Vector4::new(f64::MAX, 0.0, 0.0, f64::MAX).try_normalize(),
+    ///     Err(VexelError::NonFinite)
+    /// );
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !len.is_finite() {
+            return Err(VexelError::NonFinite);
         }
+        Ok(Self {
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+            w: self.w / len,
+        })
+    }
+
+    /// Normalizes the vector, or returns `fallback` if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let up = Vector4::new(0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(Vector4::new(0.0, 3.0, 0.0, 4.0).normalize_or(up), Vector4::new(0.0, 0.6, 0.0, 0.8));
+    /// assert_eq!(Vector4::new(0.0, 0.0, 0.0, 0.0).normalize_or(up), up);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self
+    where
+        T: Real,
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalizes the vector, or returns the zero vector if it has zero
+    /// length or its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(f64::MAX, 0.0, 0.0, f64::MAX);
+    /// assert_eq!(v.normalize_or_zero(), Vector4::new(0.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn normalize_or_zero(&self) -> Self
+    where
+        T: Real,
+    {
+        self.normalize_or(Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO))
     }
 
     /// Projects this vector onto another vector.
@@ -153,22 +471,51 @@ impl<T> Vector4<T> {
     /// ```
     pub fn project_onto(&self, other: &Self) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
+        T: Real,
     {
-        let scalar = (self.x.into() * other.x.into()
-            + self.y.into() * other.y.into()
-            + self.z.into() * other.z.into()
-            + self.w.into() * other.w.into())
-            / (other.x.into() * other.x.into()
-                + other.y.into() * other.y.into()
-                + other.z.into() * other.z.into()
-                + other.w.into() * other.w.into());
+        let scalar = self.dot(other) / other.dot(other);
         Self {
-            x: (scalar * other.x.into()).into(),
-            y: (scalar * other.y.into()).into(),
-            z: (scalar * other.z.into()).into(),
-            w: (scalar * other.w.into()).into(),
+            x: scalar * other.x,
+            y: scalar * other.y,
+            z: scalar * other.z,
+            w: scalar * other.w,
+        }
+    }
+
+    /// Projects this vector onto another vector, or returns an error if the
+    /// other vector has zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(3.0, 4.0, 5.0, 6.0);
+    /// assert_eq!(v.try_project_onto(&Vector4::new(2.0, 0.0, 0.0, 0.0)), Ok(Vector4::new(3.0, 0.0, 0.0, 0.0)));
+    /// assert_eq!(
+    ///     v.try_project_onto(&Vector4::new(0.0, 0.0, 0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
+    /// ```
+    pub fn try_project_onto(&self, other: &Self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let length_squared = other.dot(other);
+        if length_squared == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !length_squared.is_finite() {
+            return Err(VexelError::NonFinite);
         }
+        let scalar = self.dot(other) / length_squared;
+        Ok(Self {
+            x: scalar * other.x,
+            y: scalar * other.y,
+            z: scalar * other.z,
+            w: scalar * other.w,
+        })
     }
 
     /// Rejects this vector from another vector.
@@ -188,13 +535,7 @@ impl<T> Vector4<T> {
     /// ```
     pub fn reject_from(&self, other: &Self) -> Self
     where
-        T: Into<f64>
-            + Copy
-            + From<f64>
-            + Mul<Output = T>
-            + Add<Output = T>
-            + Div<Output = T>
-            + Sub<Output = T>,
+        T: Real,
     {
         let projection = self.project_onto(other);
         Self {
@@ -220,9 +561,9 @@ impl<T> Vector4<T> {
     /// assert_eq!(interpolated.z, 5.0);
     /// assert_eq!(interpolated.w, 6.0);
     /// ```
-    pub fn lerp(&self, other: &Self, t: f64) -> Self
+    pub fn lerp(&self, other: &Self, t: T) -> Self
     where
-        T: Into<f64> + Copy + From<f64> + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+        T: Real,
     {
         Self {
             x: self.x + (other.x - self.x) * t,
@@ -234,6 +575,9 @@ impl<T> Vector4<T> {
 
     /// Computes the angle between this vector and another vector in radians.
     ///
+    /// The angle is NaN if either vector has zero length; use
+    /// [`try_angle_between`](Self::try_angle_between) to catch it.
+    ///
     /// # Examples
     ///
     /// ```
@@ -244,153 +588,436 @@ impl<T> Vector4<T> {
     /// let angle = v1.angle_between(&v2);
     /// assert_eq!(angle, std::f64::consts::FRAC_PI_2);
     /// ```
-    pub fn angle_between(&self, other: &Self) -> f64
+    pub fn angle_between(&self, other: &Self) -> T
     where
-        T: Into<f64> + Copy,
+        T: Real,
     {
         let dot_product = self.dot(other);
         let magnitude_product = self.length() * other.length();
         (dot_product / magnitude_product).acos()
     }
 
-    /// Swizzles the components of the vector.
+    /// Computes the angle between this vector and another vector in radians,
+    /// or returns an error if either has zero length.
+    ///
+    /// Unlike [`angle_between`](Self::angle_between), rounding can't push
+    /// the result to NaN for nearly parallel vectors.
     ///
     /// # Examples
     ///
     /// ```
+    /// use vexel::error::VexelError;
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
-    /// let swizzled = v.swizzle(1, 2, 3, 0);
-    /// assert_eq!(swizzled.x, v.y);
-    /// assert_eq!(swizzled.y, v.z);
-    /// assert_eq!(swizzled.z, v.w);
-    /// assert_eq!(swizzled.w, v.x);
+    /// let a = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    /// let b = Vector4::new(0.0, 2.0, 0.0, 0.0);
+    /// assert_eq!(a.try_angle_between(&b), Ok(core::f64::consts::FRAC_PI_2));
+    /// assert_eq!(
+    ///     a.try_angle_between(&Vector4::new(0.0, 0.0, 0.0, 0.0)),
+    ///     Err(VexelError::ZeroLength)
+    /// );
     /// ```
-    pub fn swizzle(&self, x: usize, y: usize, z: usize, w: usize) -> Self
+    pub fn try_angle_between(&self, other: &Self) -> Result<T, VexelError>
     where
-        T: Copy,
+        T: Real,
     {
-        let components = [self.x, self.y, self.z, self.w];
-        Self {
-            x: components[x],
-            y: components[y],
-            z: components[z],
-            w: components[w],
+        let magnitude_product = self.length() * other.length();
+        if magnitude_product == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !magnitude_product.is_finite() {
+            return Err(VexelError::NonFinite);
         }
+        let cos = self.dot(other) / magnitude_product;
+        Ok(cos.clamp(-T::ONE, T::ONE).acos())
     }
-}
-
-impl<T> Add for Vector4<T>
-where
-    T: Add<Output = T>,
-{
-    type Output = Self;
 
-    /// Adds two vectors component-wise.
+    /// Divides this vector by another component-wise using Euclidean division.
+    ///
+    /// Unlike `/`, this rounds towards negative infinity for positive divisors,
+    /// which maps a world coordinate to the chunk that contains it.
     ///
     /// # Examples
     ///
     /// ```
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
-    /// let v2 = Vector4::new(5.0, 6.0, 7.0, 8.0);
-    /// let result = v1 + v2;
-    /// assert_eq!(result.x, 6.0);
-    /// assert_eq!(result.y, 8.0);
-    /// assert_eq!(result.z, 10.0);
-    /// assert_eq!(result.w, 12.0);
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// let d = v.div_euclid(&Vector4::new(2, 2, 2, 2));
+    /// assert_eq!(d, Vector4::new(3, -2, 1, -1));
     /// ```
-    fn add(self, rhs: Self) -> Self::Output {
+    pub fn div_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
         Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-            w: self.w + rhs.w,
+            x: self.x.div_euclid(other.x),
+            y: self.y.div_euclid(other.y),
+            z: self.z.div_euclid(other.z),
+            w: self.w.div_euclid(other.w),
         }
     }
-}
-
-impl<T> Sub for Vector4<T>
-where
-    T: Sub<Output = T>,
-{
-    type Output = Self;
 
-    /// Subtracts one vector from another component-wise.
+    /// Computes the component-wise non-negative remainder of Euclidean division.
+    ///
+    /// This gives the position of a coordinate within its chunk.
     ///
     /// # Examples
     ///
     /// ```
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v1 = Vector4::new(5.0, 6.0, 7.0, 8.0);
-    /// let v2 = Vector4::new(1.0, 2.0, 3.0, 4.0);
-    /// let result = v1 - v2;
-    /// assert_eq!(result.x, 4.0);
-    /// assert_eq!(result.y, 4.0);
-    /// assert_eq!(result.z, 4.0);
-    /// assert_eq!(result.w, 4.0);
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// let r = v.rem_euclid(&Vector4::new(2, 2, 2, 2));
+    /// assert_eq!(r, Vector4::new(1, 1, 0, 1));
     /// ```
-    fn sub(self, rhs: Self) -> Self::Output {
+    pub fn rem_euclid(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
         Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-            w: self.w - rhs.w,
+            x: self.x.rem_euclid(other.x),
+            y: self.y.rem_euclid(other.y),
+            z: self.z.rem_euclid(other.z),
+            w: self.w.rem_euclid(other.w),
         }
     }
-}
-
-impl<T> Mul for Vector4<T>
-where
-    T: Mul<Output = T>,
-{
-    type Output = Self;
 
-    /// Multiplies two vectors component-wise.
+    /// Component-wise checked addition, returning `None` if any component overflows.
     ///
     /// # Examples
     ///
     /// ```
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
-    /// let v2 = Vector4::new(2.0, 3.0, 4.0, 5.0);
-    /// let result = v1 * v2;
-    /// assert_eq!(result.x, 2.0);
-    /// assert_eq!(result.y, 6.0);
-    /// assert_eq!(result.z, 12.0);
-    /// assert_eq!(result.w, 20.0);
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.checked_add(&Vector4::new(2, 4, -5, 3)), Some(Vector4::new(9, 1, -3, 2)));
+    /// assert_eq!(Vector4::new(i32::MAX, 0, 0, 0).checked_add(&Vector4::new(1, 0, 0, 0)), None);
     /// ```
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
-            z: self.z * rhs.z,
-            w: self.w * rhs.w,
-        }
+    pub fn checked_add(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+            z: self.z.checked_add(other.z)?,
+            w: self.w.checked_add(other.w)?,
+        })
     }
-}
-
-impl<T> Div for Vector4<T>
-where
-    T: Div<Output = T>,
-{
-    type Output = Self;
 
-    /// Divides one vector by another component-wise.
+    /// Component-wise checked subtraction, returning `None` if any component overflows.
     ///
     /// # Examples
     ///
     /// ```
     /// use vexel::vectors::vector4::Vector4;
     ///
-    /// let v1 = Vector4::new(2.0, 6.0, 12.0, 20.0);
-    /// let v2 = Vector4::new(2.0, 3.0, 4.0, 5.0);
-    /// let result = v1 / v2;
-    /// assert_eq!(result.x, 1.0);
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.checked_sub(&Vector4::new(2, 4, -5, 3)), Some(Vector4::new(5, -7, 7, -4)));
+    /// assert_eq!(Vector4::new(i32::MIN, 0, 0, 0).checked_sub(&Vector4::new(1, 0, 0, 0)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+            z: self.z.checked_sub(other.z)?,
+            w: self.w.checked_sub(other.w)?,
+        })
+    }
+
+    /// Component-wise checked multiplication, returning `None` if any component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(7, -3, 2, -1);
+    /// assert_eq!(v.checked_mul(&Vector4::new(2, 4, -5, 3)), Some(Vector4::new(14, -12, -10, -3)));
+    /// assert_eq!(Vector4::new(i32::MAX, 0, 0, 0).checked_mul(&Vector4::new(2, 1, 1, 1)), None);
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_mul(other.x)?,
+            y: self.y.checked_mul(other.y)?,
+            z: self.z.checked_mul(other.z)?,
+            w: self.w.checked_mul(other.w)?,
+        })
+    }
+
+    /// Component-wise checked division, returning `None` on division by zero or overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(4, 8, -10, 6);
+    /// assert_eq!(v.checked_div(&Vector4::new(2, 4, -5, 3)), Some(Vector4::new(2, 2, 2, 2)));
+    /// assert_eq!(v.checked_div(&Vector4::new(0, 4, -5, 3)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Self) -> Option<Self>
+    where
+        T: Integer,
+    {
+        Some(Self {
+            x: self.x.checked_div(other.x)?,
+            y: self.y.checked_div(other.y)?,
+            z: self.z.checked_div(other.z)?,
+            w: self.w.checked_div(other.w)?,
+        })
+    }
+
+    /// Component-wise wrapping addition, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MAX, 0, 0, 0);
+    /// assert_eq!(v.wrapping_add(&Vector4::new(1, 0, 0, 0)), Vector4::new(i32::MIN, 0, 0, 0));
+    /// ```
+    pub fn wrapping_add(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+            z: self.z.wrapping_add(other.z),
+            w: self.w.wrapping_add(other.w),
+        }
+    }
+
+    /// Component-wise wrapping subtraction, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MIN, 0, 0, 0);
+    /// assert_eq!(v.wrapping_sub(&Vector4::new(1, 0, 0, 0)), Vector4::new(i32::MAX, 0, 0, 0));
+    /// ```
+    pub fn wrapping_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+            z: self.z.wrapping_sub(other.z),
+            w: self.w.wrapping_sub(other.w),
+        }
+    }
+
+    /// Component-wise wrapping multiplication, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MAX, 0, 0, 0);
+    /// assert_eq!(v.wrapping_mul(&Vector4::new(2, 1, 1, 1)), Vector4::new(-2, 0, 0, 0));
+    /// ```
+    pub fn wrapping_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+            z: self.z.wrapping_mul(other.z),
+            w: self.w.wrapping_mul(other.w),
+        }
+    }
+
+    /// Component-wise saturating addition, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MAX, 0, 0, 0);
+    /// assert_eq!(v.saturating_add(&Vector4::new(1, 0, 0, 0)), Vector4::new(i32::MAX, 0, 0, 0));
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+            z: self.z.saturating_add(other.z),
+            w: self.w.saturating_add(other.w),
+        }
+    }
+
+    /// Component-wise saturating subtraction, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MIN, 0, 0, 0);
+    /// assert_eq!(v.saturating_sub(&Vector4::new(1, 0, 0, 0)), Vector4::new(i32::MIN, 0, 0, 0));
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+            z: self.z.saturating_sub(other.z),
+            w: self.w.saturating_sub(other.w),
+        }
+    }
+
+    /// Component-wise saturating multiplication, saturating at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(i32::MAX, 0, 0, 0);
+    /// assert_eq!(v.saturating_mul(&Vector4::new(2, 1, 1, 1)), Vector4::new(i32::MAX, 0, 0, 0));
+    /// ```
+    pub fn saturating_mul(&self, other: &Self) -> Self
+    where
+        T: Integer,
+    {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
+            z: self.z.saturating_mul(other.z),
+            w: self.w.saturating_mul(other.w),
+        }
+    }
+}
+
+impl<T> Add for Vector4<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    /// Adds two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = Vector4::new(5.0, 6.0, 7.0, 8.0);
+    /// let result = v1 + v2;
+    /// assert_eq!(result.x, 6.0);
+    /// assert_eq!(result.y, 8.0);
+    /// assert_eq!(result.z, 10.0);
+    /// assert_eq!(result.w, 12.0);
+    /// ```
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<T> Sub for Vector4<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    /// Subtracts one vector from another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v1 = Vector4::new(5.0, 6.0, 7.0, 8.0);
+    /// let v2 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let result = v1 - v2;
+    /// assert_eq!(result.x, 4.0);
+    /// assert_eq!(result.y, 4.0);
+    /// assert_eq!(result.z, 4.0);
+    /// assert_eq!(result.w, 4.0);
+    /// ```
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<T> Mul for Vector4<T>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Multiplies two vectors component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = Vector4::new(2.0, 3.0, 4.0, 5.0);
+    /// let result = v1 * v2;
+    /// assert_eq!(result.x, 2.0);
+    /// assert_eq!(result.y, 6.0);
+    /// assert_eq!(result.z, 12.0);
+    /// assert_eq!(result.w, 20.0);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z,
+            w: self.w * rhs.w,
+        }
+    }
+}
+
+impl<T> Div for Vector4<T>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    /// Divides one vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v1 = Vector4::new(2.0, 6.0, 12.0, 20.0);
+    /// let v2 = Vector4::new(2.0, 3.0, 4.0, 5.0);
+    /// let result = v1 / v2;
+    /// assert_eq!(result.x, 1.0);
     /// assert_eq!(result.y, 2.0);
     /// assert_eq!(result.z, 3.0);
     /// assert_eq!(result.w, 4.0);
@@ -404,3 +1031,373 @@ where
         }
     }
 }
+
+impl<T> Neg for Vector4<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    /// Negates every component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(-v, Vector4::new(-1.0, -2.0, -3.0, -4.0));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl<T> Rem for Vector4<T>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    /// Computes the remainder of dividing one vector by another
+    /// component-wise.
+    ///
+    /// Like `%` on the components, the result has the sign of the dividend;
+    /// see `rem_euclid` for a remainder that is never negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(7, 8, 9, 10) % Vector4::new(4, 3, 5, 6);
+    /// assert_eq!(v, Vector4::new(3, 2, 4, 4));
+    /// ```
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+            z: self.z % rhs.z,
+            w: self.w % rhs.w,
+        }
+    }
+}
+
+impl<T> Mul<T> for Vector4<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v * 2.0, Vector4::new(2.0, 4.0, 6.0, 8.0));
+    /// assert_eq!(2.0 * v, v * 2.0);
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Vector4<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v / 2.0, Vector4::new(0.5, 1.0, 1.5, 2.0));
+    /// ```
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+            w: self.w / rhs,
+        }
+    }
+}
+
+impl<T> AddAssign for Vector4<T>
+where
+    T: AddAssign,
+{
+    /// Adds another vector to this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v += Vector4::new(3.0, 4.0, 5.0, 6.0);
+    /// assert_eq!(v, Vector4::new(4.0, 6.0, 8.0, 10.0));
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+impl<T> SubAssign for Vector4<T>
+where
+    T: SubAssign,
+{
+    /// Subtracts another vector from this one component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v -= Vector4::new(3.0, 4.0, 5.0, 6.0);
+    /// assert_eq!(v, Vector4::new(-2.0, -2.0, -2.0, -2.0));
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+impl<T> MulAssign for Vector4<T>
+where
+    T: MulAssign,
+{
+    /// Multiplies this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v *= Vector4::new(3.0, 4.0, 5.0, 6.0);
+    /// assert_eq!(v, Vector4::new(3.0, 8.0, 15.0, 24.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
+    }
+}
+
+impl<T> DivAssign for Vector4<T>
+where
+    T: DivAssign,
+{
+    /// Divides this vector by another component-wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v /= Vector4::new(3.0, 4.0, 5.0, 6.0);
+    /// assert_eq!(v, Vector4::new(0.3333333333333333, 0.5, 0.6, 0.6666666666666666));
+    /// ```
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
+    }
+}
+
+impl<T> RemAssign for Vector4<T>
+where
+    T: RemAssign,
+{
+    /// Replaces each component with its remainder after dividing by the
+    /// matching component of another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(7, 8, 9, 10);
+    /// v %= Vector4::new(4, 3, 5, 6);
+    /// assert_eq!(v, Vector4::new(3, 2, 4, 4));
+    /// ```
+    fn rem_assign(&mut self, rhs: Self) {
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+        self.z %= rhs.z;
+        self.w %= rhs.w;
+    }
+}
+
+impl<T> MulAssign<T> for Vector4<T>
+where
+    T: MulAssign + Copy,
+{
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v *= 3.0;
+    /// assert_eq!(v, Vector4::new(3.0, 6.0, 9.0, 12.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+impl<T> DivAssign<T> for Vector4<T>
+where
+    T: DivAssign + Copy,
+{
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v /= 4.0;
+    /// assert_eq!(v, Vector4::new(0.25, 0.5, 0.75, 1.0));
+    /// ```
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Vector4<T> {
+    type Output = T;
+
+    /// Returns the component at `index`, counting from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 4 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v[0], 1.0);
+    /// assert_eq!(v[3], 4.0);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds: Vector4 has 4 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vector4<T> {
+    /// Returns a mutable reference to the component at `index`, counting
+    /// from `x` at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 4 or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// v[1] = 5.0;
+    /// assert_eq!(v.y, 5.0);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds: Vector4 has 4 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> Sum for Vector4<T>
+where
+    T: Scalar,
+{
+    /// Adds up the vectors of an iterator, giving the zero vector for an
+    /// empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let vs = [Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(3.0, 4.0, 5.0, 6.0)];
+    /// let total: Vector4<f64> = vs.iter().sum();
+    /// assert_eq!(total, Vector4::new(4.0, 6.0, 8.0, 10.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO), Add::add)
+    }
+}
+
+impl<T> Product for Vector4<T>
+where
+    T: Scalar,
+{
+    /// Multiplies the vectors of an iterator component-wise, giving a vector
+    /// of ones for an empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vector4::Vector4;
+    ///
+    /// let vs = [Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(3.0, 4.0, 5.0, 6.0)];
+    /// let product: Vector4<f64> = vs.into_iter().product();
+    /// assert_eq!(product, Vector4::new(3.0, 8.0, 15.0, 24.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ONE, T::ONE, T::ONE, T::ONE), Mul::mul)
+    }
+}
@@ -0,0 +1,879 @@
+use core::array;
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+use crate::error::VexelError;
+use crate::scalar::{Real, Scalar};
+use crate::vectors::vector2::Vector2;
+use crate::vectors::vector3::Vector3;
+use crate::vectors::vector4::Vector4;
+
+/// A vector with any number of components, fixed at compile time.
+///
+/// `VectorN` covers the dimensions that [`Vector2`], [`Vector3`] and
+/// [`Vector4`] don't, such as the 6-, 9- or 12-dimensional states of a
+/// filter, with the same operations. For two to four components it gives
+/// bit-for-bit the same results as the fixed-size types, and converts to and
+/// from them with `From`.
+///
+/// Mixing vectors of different sizes is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use vexel::vectors::vectorn::VectorN;
+///
+/// let position = VectorN::new([1.0, 2.0, 3.0, 0.5, 0.0, -0.5]);
+/// let velocity = VectorN::new([0.5, 0.0, 1.0, 0.0, 0.0, 0.0]);
+/// let next = position + velocity * 2.0;
+/// assert_eq!(next, VectorN::new([2.0, 2.0, 5.0, 0.5, 0.0, -0.5]));
+/// ```
+///
+/// ```compile_fail
+/// use vexel::vectors::vectorn::VectorN;
+///
+/// let a = VectorN::new([1.0, 2.0, 3.0]);
+/// let b = VectorN::new([1.0, 2.0]);
+/// let _ = a + b;
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VectorN<T, const N: usize> {
+    /// The components, from the first axis to the last.
+    pub components: [T; N],
+}
+
+impl<T, const N: usize> VectorN<T, N> {
+    /// Creates a new `VectorN` from its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(v[4], 5.0);
+    /// ```
+    pub fn new(components: [T; N]) -> Self {
+        Self { components }
+    }
+
+    /// Creates a vector whose component along each axis is `f(axis)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v: VectorN<f64, 4> = VectorN::from_fn(|axis| axis as f64 * 2.0);
+    /// assert_eq!(v, VectorN::new([0.0, 2.0, 4.0, 6.0]));
+    /// ```
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self::new(array::from_fn(f))
+    }
+
+    /// Creates a vector with every component set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// assert_eq!(VectorN::<_, 3>::splat(2.0), VectorN::new([2.0, 2.0, 2.0]));
+    /// ```
+    pub fn splat(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Self::new([value; N])
+    }
+
+    /// Returns the vector with every component set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::<f64, 6>::zero();
+    /// assert_eq!(v.components, [0.0; 6]);
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: Scalar,
+    {
+        Self::splat(T::ZERO)
+    }
+
+    /// Computes the length (magnitude) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+    /// assert_eq!(v.length(), 3.0);
+    /// ```
+    pub fn length(&self) -> T
+    where
+        T: Real,
+    {
+        self.length_squared().sqrt()
+    }
+
+    /// Computes the squared length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1, 2, 3, 4, 5]);
+    /// assert_eq!(v.length_squared(), 55);
+    /// ```
+    pub fn length_squared(&self) -> T
+    where
+        T: Scalar,
+    {
+        self.dot(self)
+    }
+
+    /// Computes the dot product of this vector and another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let a = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let b = VectorN::new([1.0, 0.0, -1.0, 0.0, 1.0, 0.0]);
+    /// assert_eq!(a.dot(&b), 3.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T
+    where
+        T: Scalar,
+    {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .map(|(&a, &b)| a * b)
+            .reduce(Add::add)
+            .unwrap_or(T::ZERO)
+    }
+
+    /// Normalizes the vector, making it a unit vector.
+    ///
+    /// A zero vector is returned unchanged; use
+    /// [`try_normalize`](Self::try_normalize) to catch it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([0.0, 3.0, 0.0, 0.0, 4.0]);
+    /// assert_eq!(v.normalize(), VectorN::new([0.0, 0.6, 0.0, 0.0, 0.8]));
+    /// ```
+    pub fn normalize(&self) -> Self
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return *self;
+        }
+        Self::from_fn(|i| self.components[i] / len)
+    }
+
+    /// Normalizes the vector, or returns an error if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([0.0, 3.0, 0.0, 0.0, 4.0]);
+    /// assert_eq!(v.try_normalize(), Ok(VectorN::new([0.0, 0.6, 0.0, 0.0, 0.8])));
+    /// assert_eq!(VectorN::<f64, 5>::zero().try_normalize(), Err(VexelError::ZeroLength));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let len = self.length();
+        if len == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !len.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        Ok(Self::from_fn(|i| self.components[i] / len))
+    }
+
+    /// Normalizes the vector, or returns `fallback` if it has zero length or
+    /// its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let fallback = VectorN::new([1.0, 0.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(VectorN::zero().normalize_or(fallback), fallback);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self
+    where
+        T: Real,
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Normalizes the vector, or returns the zero vector if it has zero
+    /// length or its length is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([f64::NAN, 1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(v.normalize_or_zero(), VectorN::zero());
+    /// ```
+    pub fn normalize_or_zero(&self) -> Self
+    where
+        T: Real,
+    {
+        self.normalize_or(Self::zero())
+    }
+
+    /// Projects this vector onto another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([3.0, 4.0, 5.0, 6.0, 7.0]);
+    /// let axis = VectorN::new([0.0, 0.0, 2.0, 0.0, 0.0]);
+    /// assert_eq!(v.project_onto(&axis), VectorN::new([0.0, 0.0, 5.0, 0.0, 0.0]));
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Self
+    where
+        T: Real,
+    {
+        let scalar = self.dot(other) / other.dot(other);
+        Self::from_fn(|i| scalar * other.components[i])
+    }
+
+    /// Projects this vector onto another vector, or returns an error if the
+    /// other vector has zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([3.0, 4.0, 5.0, 6.0, 7.0]);
+    /// assert_eq!(v.try_project_onto(&VectorN::zero()), Err(VexelError::ZeroLength));
+    /// ```
+    pub fn try_project_onto(&self, other: &Self) -> Result<Self, VexelError>
+    where
+        T: Real,
+    {
+        let length_squared = other.dot(other);
+        if length_squared == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !length_squared.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let scalar = self.dot(other) / length_squared;
+        Ok(Self::from_fn(|i| scalar * other.components[i]))
+    }
+
+    /// Rejects this vector from another vector: the part of it perpendicular
+    /// to the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([3.0, 4.0, 5.0, 6.0, 7.0]);
+    /// let axis = VectorN::new([0.0, 0.0, 2.0, 0.0, 0.0]);
+    /// assert_eq!(v.reject_from(&axis), VectorN::new([3.0, 4.0, 0.0, 6.0, 7.0]));
+    /// ```
+    pub fn reject_from(&self, other: &Self) -> Self
+    where
+        T: Real,
+    {
+        *self - self.project_onto(other)
+    }
+
+    /// Linearly interpolates between this vector and another vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let a = VectorN::new([0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    /// let b = VectorN::new([2.0, 2.0, 2.0, 2.0, 2.0, 2.0]);
+    /// assert_eq!(a.lerp(&b, 0.5), VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: T) -> Self
+    where
+        T: Real,
+    {
+        Self::from_fn(|i| {
+            let (a, b) = (self.components[i], other.components[i]);
+            a + (b - a) * t
+        })
+    }
+
+    /// Computes the angle between this vector and another vector in radians.
+    ///
+    /// The angle is NaN if either vector has zero length; use
+    /// [`try_angle_between`](Self::try_angle_between) to catch it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let a = VectorN::new([1.0, 0.0, 0.0, 0.0, 0.0]);
+    /// let b = VectorN::new([0.0, 0.0, 0.0, 0.0, 3.0]);
+    /// assert_eq!(a.angle_between(&b), core::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn angle_between(&self, other: &Self) -> T
+    where
+        T: Real,
+    {
+        let dot_product = self.dot(other);
+        let magnitude_product = self.length() * other.length();
+        (dot_product / magnitude_product).acos()
+    }
+
+    /// Computes the angle between this vector and another vector in radians,
+    /// or returns an error if either has zero length.
+    ///
+    /// Unlike [`angle_between`](Self::angle_between), rounding can't push
+    /// the result to NaN for nearly parallel vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::error::VexelError;
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let a = VectorN::new([1.0, 0.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(a.try_angle_between(&a), Ok(0.0));
+    /// assert_eq!(a.try_angle_between(&VectorN::zero()), Err(VexelError::ZeroLength));
+    /// ```
+    pub fn try_angle_between(&self, other: &Self) -> Result<T, VexelError>
+    where
+        T: Real,
+    {
+        let magnitude_product = self.length() * other.length();
+        if magnitude_product == T::ZERO {
+            return Err(VexelError::ZeroLength);
+        }
+        if !magnitude_product.is_finite() {
+            return Err(VexelError::NonFinite);
+        }
+        let cos = self.dot(other) / magnitude_product;
+        Ok(cos.clamp(-T::ONE, T::ONE).acos())
+    }
+}
+
+/// Implements a component-wise operator between two vectors.
+macro_rules! impl_componentwise_op {
+    ($(
+        $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident,
+        $op:tt, $assign_op:tt, $expected:literal, $doc:literal;
+    )+) => {
+        $(
+            impl<T, const N: usize> $trait for VectorN<T, N>
+            where
+                T: $trait<Output = T> + Copy,
+            {
+                type Output = Self;
+
+                #[doc = $doc]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use vexel::vectors::vectorn::VectorN;
+                ///
+                /// let a = VectorN::new([6, 8, 10, 12, 14]);
+                /// let b = VectorN::new([1, 2, 3, 4, 5]);
+                #[doc = concat!("assert_eq!(a ", stringify!($op), " b, VectorN::new(", $expected, "));")]
+                /// ```
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Self::from_fn(|i| $trait::$method(self.components[i], rhs.components[i]))
+                }
+            }
+
+            impl<T, const N: usize> $assign_trait for VectorN<T, N>
+            where
+                T: $assign_trait,
+            {
+                #[doc = $doc]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use vexel::vectors::vectorn::VectorN;
+                ///
+                /// let mut a = VectorN::new([6, 8, 10, 12, 14]);
+                #[doc = concat!("a ", stringify!($assign_op), " VectorN::new([1, 2, 3, 4, 5]);")]
+                #[doc = concat!("assert_eq!(a, VectorN::new(", $expected, "));")]
+                /// ```
+                fn $assign_method(&mut self, rhs: Self) {
+                    for (a, b) in self.components.iter_mut().zip(rhs.components) {
+                        $assign_trait::$assign_method(a, b);
+                    }
+                }
+            }
+
+            impl<'a, T: Copy, const N: usize> $trait<&'a VectorN<T, N>> for VectorN<T, N>
+            where
+                VectorN<T, N>: $trait<Output = VectorN<T, N>>,
+            {
+                type Output = VectorN<T, N>;
+
+                #[inline]
+                fn $method(self, rhs: &'a VectorN<T, N>) -> VectorN<T, N> {
+                    $trait::$method(self, *rhs)
+                }
+            }
+
+            impl<T: Copy, const N: usize> $trait<VectorN<T, N>> for &VectorN<T, N>
+            where
+                VectorN<T, N>: $trait<Output = VectorN<T, N>>,
+            {
+                type Output = VectorN<T, N>;
+
+                #[inline]
+                fn $method(self, rhs: VectorN<T, N>) -> VectorN<T, N> {
+                    $trait::$method(*self, rhs)
+                }
+            }
+
+            impl<'a, T: Copy, const N: usize> $trait<&'a VectorN<T, N>> for &VectorN<T, N>
+            where
+                VectorN<T, N>: $trait<Output = VectorN<T, N>>,
+            {
+                type Output = VectorN<T, N>;
+
+                #[inline]
+                fn $method(self, rhs: &'a VectorN<T, N>) -> VectorN<T, N> {
+                    $trait::$method(*self, *rhs)
+                }
+            }
+
+            impl<'a, T: Copy, const N: usize> $assign_trait<&'a VectorN<T, N>> for VectorN<T, N>
+            where
+                VectorN<T, N>: $assign_trait,
+            {
+                #[inline]
+                fn $assign_method(&mut self, rhs: &'a VectorN<T, N>) {
+                    $assign_trait::$assign_method(self, *rhs);
+                }
+            }
+        )+
+    };
+}
+
+impl_componentwise_op! {
+    Add::add, AddAssign::add_assign, +, +=, "[7, 10, 13, 16, 19]",
+    "Adds two vectors component-wise.";
+    Sub::sub, SubAssign::sub_assign, -, -=, "[5, 6, 7, 8, 9]",
+    "Subtracts one vector from another component-wise.";
+    Mul::mul, MulAssign::mul_assign, *, *=, "[6, 16, 30, 48, 70]",
+    "Multiplies two vectors component-wise.";
+    Div::div, DivAssign::div_assign, /, /=, "[6, 4, 3, 3, 2]",
+    "Divides one vector by another component-wise.";
+    Rem::rem, RemAssign::rem_assign, %, %=, "[0, 0, 1, 0, 4]",
+    "Computes the remainder of two vectors component-wise.";
+}
+
+impl<T, const N: usize> Neg for VectorN<T, N>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Negates every component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, -2.0, 3.0, -4.0, 5.0]);
+    /// assert_eq!(-v, VectorN::new([-1.0, 2.0, -3.0, 4.0, -5.0]));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self::from_fn(|i| -self.components[i])
+    }
+}
+
+impl<T: Copy, const N: usize> Neg for &VectorN<T, N>
+where
+    VectorN<T, N>: Neg<Output = VectorN<T, N>>,
+{
+    type Output = VectorN<T, N>;
+
+    #[inline]
+    fn neg(self) -> VectorN<T, N> {
+        -*self
+    }
+}
+
+impl<T, const N: usize> Mul<T> for VectorN<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(v * 2.0, VectorN::new([2.0, 4.0, 6.0, 8.0, 10.0]));
+    /// assert_eq!(2.0 * v, v * 2.0);
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::from_fn(|i| self.components[i] * rhs)
+    }
+}
+
+impl<T, const N: usize> Div<T> for VectorN<T, N>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(v / 2.0, VectorN::new([0.5, 1.0, 1.5, 2.0, 2.5]));
+    /// ```
+    fn div(self, rhs: T) -> Self::Output {
+        Self::from_fn(|i| self.components[i] / rhs)
+    }
+}
+
+impl<T, const N: usize> MulAssign<T> for VectorN<T, N>
+where
+    T: MulAssign + Copy,
+{
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let mut v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// v *= 3.0;
+    /// assert_eq!(v, VectorN::new([3.0, 6.0, 9.0, 12.0, 15.0]));
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        for component in &mut self.components {
+            *component *= rhs;
+        }
+    }
+}
+
+impl<T, const N: usize> DivAssign<T> for VectorN<T, N>
+where
+    T: DivAssign + Copy,
+{
+    /// Divides every component by a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let mut v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// v /= 4.0;
+    /// assert_eq!(v, VectorN::new([0.25, 0.5, 0.75, 1.0, 1.25]));
+    /// ```
+    fn div_assign(&mut self, rhs: T) {
+        for component in &mut self.components {
+            *component /= rhs;
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Mul<T> for &VectorN<T, N>
+where
+    VectorN<T, N>: Mul<T, Output = VectorN<T, N>>,
+{
+    type Output = VectorN<T, N>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> VectorN<T, N> {
+        *self * rhs
+    }
+}
+
+impl<T: Copy, const N: usize> Div<T> for &VectorN<T, N>
+where
+    VectorN<T, N>: Div<T, Output = VectorN<T, N>>,
+{
+    type Output = VectorN<T, N>;
+
+    #[inline]
+    fn div(self, rhs: T) -> VectorN<T, N> {
+        *self / rhs
+    }
+}
+
+/// Implements multiplication and division with the scalar on the left for
+/// each primitive type.
+macro_rules! impl_scalar_lhs_ops {
+    ($($t:ty),+) => {
+        $(
+            impl<const N: usize> Mul<VectorN<$t, N>> for $t {
+                type Output = VectorN<$t, N>;
+
+                #[inline]
+                fn mul(self, rhs: VectorN<$t, N>) -> VectorN<$t, N> {
+                    VectorN::from_fn(|i| self * rhs.components[i])
+                }
+            }
+
+            impl<const N: usize> Mul<&VectorN<$t, N>> for $t {
+                type Output = VectorN<$t, N>;
+
+                #[inline]
+                fn mul(self, rhs: &VectorN<$t, N>) -> VectorN<$t, N> {
+                    self * *rhs
+                }
+            }
+
+            impl<const N: usize> Div<VectorN<$t, N>> for $t {
+                type Output = VectorN<$t, N>;
+
+                #[inline]
+                fn div(self, rhs: VectorN<$t, N>) -> VectorN<$t, N> {
+                    VectorN::from_fn(|i| self / rhs.components[i])
+                }
+            }
+
+            impl<const N: usize> Div<&VectorN<$t, N>> for $t {
+                type Output = VectorN<$t, N>;
+
+                #[inline]
+                fn div(self, rhs: &VectorN<$t, N>) -> VectorN<$t, N> {
+                    self / *rhs
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_lhs_ops!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+
+    /// Returns the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `N` or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(v[5], 6.0);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    /// Returns a mutable reference to the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `N` or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let mut v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// v[5] = 0.0;
+    /// assert_eq!(v.components, [1.0, 2.0, 3.0, 4.0, 5.0, 0.0]);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<T, const N: usize> Sum for VectorN<T, N>
+where
+    T: Scalar,
+{
+    /// Adds up the vectors of an iterator, giving the zero vector for an
+    /// empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let vs = [VectorN::new([1, 2, 3, 4, 5]), VectorN::new([5, 4, 3, 2, 1])];
+    /// let total: VectorN<i32, 5> = vs.iter().sum();
+    /// assert_eq!(total, VectorN::splat(6));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<T, const N: usize> Product for VectorN<T, N>
+where
+    T: Scalar,
+{
+    /// Multiplies the vectors of an iterator component-wise, giving a vector
+    /// of ones for an empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let vs = [VectorN::new([1, 2, 3, 4, 5]), VectorN::new([5, 4, 3, 2, 1])];
+    /// let product: VectorN<i32, 5> = vs.into_iter().product();
+    /// assert_eq!(product, VectorN::new([5, 8, 9, 8, 5]));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::splat(T::ONE), Mul::mul)
+    }
+}
+
+impl<'a, T: Scalar, const N: usize> Sum<&'a VectorN<T, N>> for VectorN<T, N> {
+    fn sum<I: Iterator<Item = &'a VectorN<T, N>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<'a, T: Scalar, const N: usize> Product<&'a VectorN<T, N>> for VectorN<T, N> {
+    fn product<I: Iterator<Item = &'a VectorN<T, N>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VectorN<T, N> {
+    /// Creates a vector from an array of its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let v: VectorN<f64, 6> = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into();
+    /// assert_eq!(v[0], 1.0);
+    /// ```
+    fn from(components: [T; N]) -> Self {
+        Self::new(components)
+    }
+}
+
+impl<T, const N: usize> From<VectorN<T, N>> for [T; N] {
+    /// Returns the components as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexel::vectors::vectorn::VectorN;
+    ///
+    /// let array: [f64; 6] = VectorN::splat(1.0).into();
+    /// assert_eq!(array, [1.0; 6]);
+    /// ```
+    fn from(v: VectorN<T, N>) -> Self {
+        v.components
+    }
+}
+
+/// Implements conversions in both directions between a fixed-size vector
+/// and the `VectorN` of the same dimension.
+macro_rules! impl_fixed_size_conversions {
+    ($($vector:ident, $module:ident, $n:literal { $($field:ident),+ }, $args:literal;)+) => {
+        $(
+            impl<T> From<$vector<T>> for VectorN<T, $n> {
+                #[doc = concat!("Converts a `", stringify!($vector), "` into a `VectorN`.")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                #[doc = concat!(
+                    "use vexel::vectors::", stringify!($module), "::", stringify!($vector), ";"
+                )]
+                /// use vexel::vectors::vectorn::VectorN;
+                ///
+                #[doc = concat!("let v = VectorN::from(", stringify!($vector), "::new(", $args, "));")]
+                #[doc = concat!("assert_eq!(v, VectorN::new([", $args, "]));")]
+                /// ```
+                fn from(v: $vector<T>) -> Self {
+                    Self::new([$(v.$field),+])
+                }
+            }
+
+            impl<T> From<VectorN<T, $n>> for $vector<T> {
+                #[doc = concat!("Converts a `VectorN` into a `", stringify!($vector), "`.")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                #[doc = concat!(
+                    "use vexel::vectors::", stringify!($module), "::", stringify!($vector), ";"
+                )]
+                /// use vexel::vectors::vectorn::VectorN;
+                ///
+                #[doc = concat!("let v = ", stringify!($vector), "::from(VectorN::new([", $args, "]));")]
+                #[doc = concat!("assert_eq!(v, ", stringify!($vector), "::new(", $args, "));")]
+                /// ```
+                fn from(v: VectorN<T, $n>) -> Self {
+                    let [$($field),+] = v.components;
+                    Self { $($field),+ }
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_size_conversions! {
+    Vector2, vector2, 2 { x, y }, "1.0, 2.0";
+    Vector3, vector3, 3 { x, y, z }, "1.0, 2.0, 3.0";
+    Vector4, vector4, 4 { x, y, z, w }, "1.0, 2.0, 3.0, 4.0";
+}
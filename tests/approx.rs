@@ -0,0 +1,182 @@
+use std::panic;
+
+use vexel::approx::ApproxEq;
+use vexel::matrices::mat2::Mat2;
+use vexel::matrices::mat3::Mat3;
+use vexel::matrices::mat4::Mat4;
+use vexel::quaternion::Quaternion;
+use vexel::transforms::affine2::Affine2;
+use vexel::transforms::affine3::Affine3;
+use vexel::transforms::transform::Transform;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+use vexel::{assert_approx_eq, assert_relative_eq};
+
+/// Returns the float `steps` representable values above `x`.
+fn ulps_above(x: f64, steps: u64) -> f64 {
+    f64::from_bits(x.to_bits() + steps)
+}
+
+/// Runs `f`, which must panic, and returns its panic message.
+fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(f).expect_err("the assertion should fail");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+fn absolute_tolerance() {
+    assert!(1.0.abs_diff_eq(&1.0, 0.0));
+    assert!(1.0.abs_diff_eq(&1.1, 0.1 + 1e-12));
+    assert!(!1.0.abs_diff_eq(&1.1, 0.09));
+    assert!(0.0.abs_diff_eq(&-0.0, 0.0));
+    assert!(f64::INFINITY.abs_diff_eq(&f64::INFINITY, 0.0));
+    assert!(!f64::INFINITY.abs_diff_eq(&f64::NEG_INFINITY, f64::MAX));
+    assert!(!f64::NAN.abs_diff_eq(&f64::NAN, f64::INFINITY));
+    assert!(!1.0.abs_diff_eq(&f64::NAN, f64::INFINITY));
+    assert_eq!(2.0.max_abs_diff(&-1.0), 3.0);
+}
+
+#[test]
+fn relative_tolerance_scales_with_magnitude() {
+    for scale in [1e-30, 1e-6, 1.0, 1e6, 1e30] {
+        let a = 1.0 * scale;
+        assert!(a.relative_eq(&(1.000_000_9 * scale), 0.0, 1e-6));
+        assert!(!a.relative_eq(&(1.000_001_1 * scale), 0.0, 1e-6));
+        assert!((-a).relative_eq(&(-1.000_000_9 * scale), 0.0, 1e-6));
+    }
+
+    // Near zero only the absolute tolerance helps.
+    assert!(!1e-20.relative_eq(&0.0, 0.0, 0.5));
+    assert!(1e-20.relative_eq(&0.0, 1e-12, 0.5));
+
+    assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0.0, 0.0));
+    assert!(!f64::INFINITY.relative_eq(&f64::MAX, 0.0, 1.0));
+    assert!(!f64::NAN.relative_eq(&f64::NAN, 1.0, 1.0));
+}
+
+#[test]
+fn ulps_tolerance_counts_representable_values() {
+    for x in [1e-300, 0.1, 1.0, 3.5, 1e300] {
+        assert!(x.ulps_eq(&ulps_above(x, 4), 0.0, 4));
+        assert!(!x.ulps_eq(&ulps_above(x, 5), 0.0, 4));
+        assert!(ulps_above(x, 4).ulps_eq(&x, 0.0, 4));
+    }
+
+    // The count carries on across a power of two.
+    let below_two = f64::from_bits(2.0f64.to_bits() - 1);
+    assert!(below_two.ulps_eq(&2.0, 0.0, 1));
+
+    // Values of opposite sign are only equal within epsilon, even though the
+    // smallest subnormals are adjacent.
+    let tiny = f64::from_bits(1);
+    assert!(!tiny.ulps_eq(&-tiny, 0.0, u32::MAX));
+    assert!(tiny.ulps_eq(&-tiny, f64::EPSILON, 0));
+    assert!(0.0.ulps_eq(&-0.0, 0.0, 0));
+
+    assert!(f64::INFINITY.ulps_eq(&f64::INFINITY, 0.0, 0));
+    assert!(f64::MAX.ulps_eq(&f64::INFINITY, 0.0, 1));
+    assert!(!f64::NAN.ulps_eq(&f64::NAN, 0.0, u32::MAX));
+
+    let one = 1.0f32;
+    assert!(one.ulps_eq(&f32::from_bits(one.to_bits() + 3), 0.0, 3));
+    assert!(!one.ulps_eq(&f32::from_bits(one.to_bits() + 3), 0.0, 2));
+}
+
+#[test]
+fn compound_types_compare_every_component() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    for axis in 0..4 {
+        let mut components = [1.0, 2.0, 3.0, 4.0];
+        components[axis] += 0.5;
+        let nudged = Vector4::new(components[0], components[1], components[2], components[3]);
+        assert!(!v.abs_diff_eq(&nudged, 0.4));
+        assert!(v.abs_diff_eq(&nudged, 0.5));
+        assert_eq!(v.max_abs_diff(&nudged), 0.5);
+    }
+    assert!(Vector2::new(1.0, 1e9).relative_eq(&Vector2::new(1.0, 1e9 + 1.0), 0.0, 1e-8));
+    assert!(!Vector3::new(1.0, 2.0, f64::NAN).abs_diff_eq(&Vector3::new(1.0, 2.0, f64::NAN), 1.0));
+    assert!(Vector3::new(0.0, 0.0, f64::NAN)
+        .max_abs_diff(&Vector3::new(5.0, 0.0, 0.0))
+        .is_nan());
+    assert!(Vector3::new(f64::NAN, 0.0, 0.0)
+        .max_abs_diff(&Vector3::new(0.0, 5.0, 0.0))
+        .is_nan());
+
+    let m = Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let mut array = m.to_cols_array();
+    array[7] += 1e-9;
+    let nudged = Mat3::from_cols_array(array);
+    assert!(m.abs_diff_eq(&nudged, 1e-8));
+    assert!(!m.abs_diff_eq(&nudged, 1e-10));
+    assert!(m.to_cols_array().abs_diff_eq(&array, 1e-8));
+    assert_approx_eq!(Mat2::<f64>::identity(), Mat2::identity());
+    assert_approx_eq!(Mat4::<f64>::identity(), Mat4::identity());
+
+    let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.5);
+    assert!(q.abs_diff_eq(&q.normalize(), 1e-12));
+    assert!(!q.abs_diff_eq(&Quaternion::identity(), 0.1));
+
+    let a = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    assert!(!a.abs_diff_eq(&Affine2::identity(), 1.0));
+    assert!(a.abs_diff_eq(&Affine2::identity(), 2.0));
+    let b = Affine3::from_translation(Vector3::new(0.0, 0.0, 1e-9));
+    assert!(b.abs_diff_eq(&Affine3::identity(), 1e-8));
+    let t = Transform::new(Vector3::new(1.0, 0.0, 0.0), q, Vector3::new(2.0, 2.0, 2.0));
+    assert!(t.ulps_eq(&t, 0.0, 0));
+    assert_eq!(
+        t.max_abs_diff(&Transform::new(
+            t.translation,
+            q,
+            Vector3::new(2.0, 2.0, 2.5)
+        )),
+        0.5
+    );
+}
+
+#[test]
+fn assertion_macros_accept_every_form() {
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    assert_approx_eq!(1.0, 1.05, epsilon = 0.1);
+    assert_approx_eq!(1.0, ulps_above(1.0, 3), ulps = 3);
+    assert_approx_eq!(1e-20, -1e-20, epsilon = 1e-12, ulps = 0);
+    assert_approx_eq!(
+        Vector2::new(1.0, 2.0),
+        Vector2::new(1.0, 2.0 + 1e-9),
+        epsilon = 1e-6,
+    );
+
+    assert_relative_eq!(1e10, 1e10 + 1e-6);
+    assert_relative_eq!(1e10, 1.00001e10, max_relative = 1e-4);
+    assert_relative_eq!(0.0, 1e-20, epsilon = 1e-12, max_relative = 0.0);
+
+    // The operands are only borrowed.
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_approx_eq!(v, v);
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn failures_show_both_sides_and_the_difference() {
+    let message = panic_message(|| assert_approx_eq!(1.0, 1.5, epsilon = 0.25));
+    assert_eq!(
+        message,
+        "assertion `left \u{2248} right` failed\n  left: 1.0\n right: 1.5\n  diff: 0.5 (epsilon: 0.25)"
+    );
+
+    let message = panic_message(|| {
+        assert_approx_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 4.0), ulps = 8)
+    });
+    assert!(message.contains("  left: Vector2 { x: 1.0, y: 2.0 }"));
+    assert!(message.contains(" right: Vector2 { x: 1.0, y: 4.0 }"));
+    assert!(message.contains("diff: 2.0 (epsilon: 2.220446049250313e-16, max_ulps: 8)"));
+
+    let message = panic_message(|| assert_relative_eq!(100.0, 101.0, max_relative = 1e-3));
+    assert!(message.contains("diff: 1.0 (epsilon: 2.220446049250313e-16, max_relative: 0.001)"));
+
+    let message = panic_message(|| assert_approx_eq!(f64::NAN, 0.0, epsilon = 1.0));
+    assert!(message.contains("diff: NaN"));
+}
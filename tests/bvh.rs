@@ -0,0 +1,199 @@
+mod common;
+
+use common::{point, v};
+use vexel::geometry::aabb::Aabb;
+use vexel::geometry::bvh::{BuildStrategy, Bvh};
+use vexel::geometry::ray::Ray;
+use vexel::geometry::triangle::Triangle;
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+
+fn triangle(rng: &mut Pcg32) -> Triangle<f64> {
+    let center = point(rng, 20.0);
+    Triangle::new(
+        center + point(rng, 1.0),
+        center + point(rng, 1.0),
+        center + point(rng, 1.0),
+    )
+}
+
+fn aabb(rng: &mut Pcg32) -> Aabb<f64> {
+    let center = point(rng, 20.0);
+    let half = v(
+        rng.range(0.1, 2.0),
+        rng.range(0.1, 2.0),
+        rng.range(0.1, 2.0),
+    );
+    Aabb::from_center_half_extents(center, half)
+}
+
+fn bounds(triangle: &Triangle<f64>) -> Aabb<f64> {
+    Aabb::from_points(&[triangle.a, triangle.b, triangle.c]).unwrap()
+}
+
+fn overlapping(bvh: &Bvh<f64>, query: &Aabb<f64>) -> Vec<usize> {
+    let mut found = Vec::new();
+    bvh.query_aabb(query, |i| found.push(i));
+    found.sort();
+    found
+}
+
+fn brute_overlapping(boxes: &[Option<Aabb<f64>>], query: &Aabb<f64>) -> Vec<usize> {
+    boxes
+        .iter()
+        .enumerate()
+        .filter(|(_, aabb)| aabb.is_some_and(|aabb| aabb.overlaps(query)))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[test]
+fn raycasts_match_brute_force_over_a_mesh() {
+    let mut rng = Pcg32::new(1);
+    let triangles: Vec<_> = (0..2000).map(|_| triangle(&mut rng)).collect();
+    let boxes: Vec<_> = triangles.iter().map(bounds).collect();
+    for strategy in [BuildStrategy::SurfaceArea, BuildStrategy::Median] {
+        let bvh = Bvh::build(&boxes, strategy);
+        let mut hits = 0;
+        for _ in 0..300 {
+            let origin = point(&mut rng, 25.0);
+            let ray = Ray::new(origin, point(&mut rng, 15.0) - origin);
+            let mut tested = 0;
+            let found = bvh.cast_ray(&ray, |i| {
+                tested += 1;
+                ray.intersect_triangle(&triangles[i])
+                    .map(|hit| hit.distance)
+            });
+            let expected = triangles
+                .iter()
+                .enumerate()
+                .filter_map(|(i, t)| Some((i, ray.intersect_triangle(t)?.distance)))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            match (found, expected) {
+                (Some((_, a)), Some((_, b))) => {
+                    assert_eq!(a, b);
+                    hits += 1;
+                }
+                (None, None) => {}
+                _ => panic!("{:?} vs {:?}", found, expected),
+            }
+            assert!(tested < triangles.len() / 4, "tested {}", tested);
+        }
+        assert!(hits > 50);
+    }
+}
+
+#[test]
+fn overlap_and_nearest_queries_match_brute_force() {
+    let mut rng = Pcg32::new(2);
+    let boxes: Vec<_> = (0..1000).map(|_| aabb(&mut rng)).collect();
+    let all: Vec<_> = boxes.iter().copied().map(Some).collect();
+    for strategy in [BuildStrategy::SurfaceArea, BuildStrategy::Median] {
+        let bvh = Bvh::build(&boxes, strategy);
+        for _ in 0..100 {
+            let query = aabb(&mut rng);
+            assert_eq!(overlapping(&bvh, &query), brute_overlapping(&all, &query));
+
+            let point = point(&mut rng, 30.0);
+            let (_, distance) = bvh
+                .nearest(point, |i| boxes[i].distance_to_point(point))
+                .unwrap();
+            let expected = boxes
+                .iter()
+                .map(|aabb| aabb.distance_to_point(point))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(distance, expected);
+        }
+    }
+}
+
+#[test]
+fn refit_follows_moving_items() {
+    let mut rng = Pcg32::new(3);
+    let mut boxes: Vec<_> = (0..500).map(|_| aabb(&mut rng)).collect();
+    let mut bvh = Bvh::build(&boxes, BuildStrategy::SurfaceArea);
+    for _ in 0..5 {
+        for (i, aabb) in boxes.iter_mut().enumerate() {
+            let offset = point(&mut rng, 3.0);
+            *aabb = Aabb::new(aabb.min + offset, aabb.max + offset);
+            bvh.set_aabb(i, *aabb);
+        }
+        bvh.refit();
+        let all: Vec<_> = boxes.iter().copied().map(Some).collect();
+        for _ in 0..50 {
+            let query = aabb(&mut rng);
+            assert_eq!(overlapping(&bvh, &query), brute_overlapping(&all, &query));
+        }
+        let enclosing = boxes[1..].iter().fold(boxes[0], |b, a| b.union(a));
+        assert_eq!(bvh.bounds(), Some(enclosing));
+    }
+}
+
+#[test]
+fn incremental_insert_and_remove() {
+    let mut rng = Pcg32::new(4);
+    let mut bvh = Bvh::new();
+    let mut boxes: Vec<Option<Aabb<f64>>> = Vec::new();
+    for step in 0..3000 {
+        let live: Vec<_> = (0..boxes.len()).filter(|&i| boxes[i].is_some()).collect();
+        if live.is_empty() || rng.uniform::<f64>() < 0.6 {
+            let aabb = aabb(&mut rng);
+            let item = bvh.insert(aabb);
+            if item == boxes.len() {
+                boxes.push(Some(aabb));
+            } else {
+                assert!(boxes[item].is_none());
+                boxes[item] = Some(aabb);
+            }
+        } else {
+            let item = live[rng.below(live.len() as u64) as usize];
+            assert_eq!(bvh.remove(item), boxes[item].take());
+            assert_eq!(bvh.remove(item), None);
+        }
+        assert_eq!(bvh.len(), boxes.iter().flatten().count());
+
+        if step % 100 == 0 {
+            for _ in 0..20 {
+                let query = aabb(&mut rng);
+                assert_eq!(overlapping(&bvh, &query), brute_overlapping(&boxes, &query));
+            }
+            let expected = boxes.iter().flatten().copied().reduce(|a, b| a.union(&b));
+            assert_eq!(bvh.bounds(), expected);
+        }
+    }
+
+    for item in 0..boxes.len() {
+        bvh.remove(item);
+    }
+    assert!(bvh.is_empty());
+    assert_eq!(bvh.len(), 0);
+    assert!(bvh
+        .cast_ray(&Ray::new(v(0.0, 0.0, 0.0), v(1.0, 0.0, 0.0)), |_| Some(0.0))
+        .is_none());
+}
+
+#[test]
+fn moving_an_item_by_reinserting_keeps_its_index() {
+    let mut rng = Pcg32::new(5);
+    let boxes: Vec<_> = (0..50).map(|_| aabb(&mut rng)).collect();
+    let mut bvh = Bvh::build(&boxes, BuildStrategy::Median);
+    let far = Aabb::new(v(100.0, 100.0, 100.0), v(101.0, 101.0, 101.0));
+    bvh.remove(17);
+    assert_eq!(bvh.insert(far), 17);
+    let point = v(100.5, 100.5, 100.5);
+    let nearest = bvh.nearest(point, |i| bvh.aabb(i).unwrap().distance_to_point(point));
+    assert_eq!(nearest, Some((17, 0.0)));
+}
+
+#[test]
+fn identical_boxes_still_build() {
+    let unit = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    let boxes = vec![unit; 100];
+    for strategy in [BuildStrategy::SurfaceArea, BuildStrategy::Median] {
+        let bvh = Bvh::build(&boxes, strategy);
+        assert_eq!(bvh.len(), 100);
+        let all: Vec<_> = (0..100).collect();
+        assert_eq!(overlapping(&bvh, &unit), all);
+    }
+    assert!(Bvh::<f64>::build(&[], BuildStrategy::SurfaceArea).is_empty());
+}
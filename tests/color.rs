@@ -0,0 +1,197 @@
+use vexel::assert_approx_eq;
+use vexel::color::hsv::{Hsl, Hsv};
+use vexel::color::oklab::{Oklab, Oklch};
+use vexel::color::rgb::{PremultipliedRgba, Rgb, Rgba, Srgb, Srgba};
+use vexel::color::xyz::{Lab, Xyz};
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+fn random_rgb(rng: &mut Pcg32) -> Rgb<f64> {
+    Rgb::new(rng.uniform(), rng.uniform(), rng.uniform())
+}
+
+#[test]
+fn every_space_round_trips_through_linear_rgb() {
+    let mut rng = Pcg32::new(1);
+    for _ in 0..1000 {
+        let c = random_rgb(&mut rng);
+        assert_approx_eq!(Rgb::from(Srgb::from(c)), c, epsilon = 1e-12);
+        assert_approx_eq!(Rgb::from(Hsv::from(c)), c, epsilon = 1e-12);
+        assert_approx_eq!(Rgb::from(Hsl::from(c)), c, epsilon = 1e-12);
+        assert_approx_eq!(Rgb::from(Xyz::from(c)), c, epsilon = 1e-12);
+        assert_approx_eq!(Rgb::from(Lab::from(c)), c, epsilon = 1e-9);
+        assert_approx_eq!(Rgb::from(Oklab::from(c)), c, epsilon = 1e-9);
+        assert_approx_eq!(Rgb::from(Oklch::from(c)), c, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn out_of_gamut_colors_survive_round_trips() {
+    let wide = Rgb::new(1.5, -0.2, 0.3);
+    assert_approx_eq!(Rgb::from(Srgb::from(wide)), wide, epsilon = 1e-12);
+    assert_approx_eq!(Rgb::from(Lab::from(wide)), wide, epsilon = 1e-9);
+    assert_approx_eq!(Rgb::from(Oklab::from(wide)), wide, epsilon = 1e-9);
+}
+
+#[test]
+fn matches_reference_values() {
+    // The sRGB transfer function at mid grey.
+    let grey = Rgb::from(Srgb::new(0.5_f64, 0.5, 0.5));
+    assert!((grey.r - 0.214_041_140_482_232_52).abs() < 1e-12);
+
+    // White is exact in every encoding.
+    let white = Rgb::new(1.0_f64, 1.0, 1.0);
+    assert_eq!(Srgb::from(white), Srgb::new(1.0, 1.0, 1.0));
+    let lab = Lab::from(white);
+    assert!((lab.l - 100.0).abs() < 1e-9 && lab.a.abs() < 1e-9 && lab.b.abs() < 1e-9);
+    let oklab = Oklab::from(white);
+    assert!((oklab.l - 1.0).abs() < 1e-6 && oklab.a.abs() < 1e-6 && oklab.b.abs() < 1e-6);
+
+    // Red, from the reference tables for each space.
+    let red = Rgb::new(1.0_f64, 0.0, 0.0);
+    let xyz = Xyz::from(red);
+    assert!((xyz.x - 0.4124).abs() < 1e-4 && (xyz.y - 0.2126).abs() < 1e-4);
+    let lab = Lab::from(red);
+    assert!((lab.l - 53.24).abs() < 1e-2 && (lab.a - 80.09).abs() < 1e-2);
+    assert!((lab.b - 67.20).abs() < 1e-2);
+    let oklab = Oklab::from(red);
+    assert!((oklab.l - 0.6280).abs() < 1e-4 && (oklab.a - 0.2249).abs() < 1e-4);
+    assert!((oklab.b - 0.1258).abs() < 1e-4);
+    let oklch = Oklch::from(red);
+    assert!((oklch.c - 0.2577).abs() < 1e-4 && (oklch.h - 29.23).abs() < 1e-2);
+
+    assert!((red.luminance() - 0.2126).abs() < 1e-4);
+}
+
+#[test]
+fn cylindrical_spaces_agree() {
+    let mut rng = Pcg32::new(2);
+    for _ in 0..1000 {
+        let c = Srgb::from(random_rgb(&mut rng));
+        let hsv = Hsv::from(c);
+        let hsl = Hsl::from(c);
+        let via = Hsl::from(hsv);
+        assert!((via.h - hsl.h).abs() < 1e-9);
+        assert!((via.s - hsl.s).abs() < 1e-9 && (via.l - hsl.l).abs() < 1e-9);
+        let back = Hsv::from(hsl);
+        assert!((back.s - hsv.s).abs() < 1e-9 && (back.v - hsv.v).abs() < 1e-9);
+        assert!((0.0..360.0).contains(&hsv.h));
+    }
+
+    for (degrees, expected) in [
+        (0.0, Srgb::new(1.0, 0.0, 0.0)),
+        (60.0, Srgb::new(1.0, 1.0, 0.0)),
+        (120.0, Srgb::new(0.0, 1.0, 0.0)),
+        (180.0, Srgb::new(0.0, 1.0, 1.0)),
+        (240.0, Srgb::new(0.0, 0.0, 1.0)),
+        (300.0, Srgb::new(1.0, 0.0, 1.0)),
+        (360.0, Srgb::new(1.0, 0.0, 0.0)),
+        (-60.0, Srgb::new(1.0, 0.0, 1.0)),
+    ] {
+        assert_eq!(Srgb::from(Hsv::new(degrees, 1.0, 1.0)), expected);
+        assert_eq!(Srgb::from(Hsl::new(degrees, 1.0, 0.5)), expected);
+    }
+}
+
+#[test]
+fn bytes_round_trip() {
+    for byte in 0..=255u8 {
+        let c = Srgb::<f64>::from_u8(byte, 255 - byte, byte / 2);
+        assert_eq!(c.to_u8(), [byte, 255 - byte, byte / 2]);
+        let linear = Rgb::from(c);
+        assert_eq!(Srgb::from(linear).to_u8(), [byte, 255 - byte, byte / 2]);
+        let with_alpha = Srgba::<f32>::from_u8(byte, 0, 255, byte);
+        assert_eq!(with_alpha.to_u8(), [byte, 0, 255, byte]);
+    }
+    assert_eq!(Srgb::new(1.5, -0.5, 0.5).to_u8(), [255, 0, 128]);
+}
+
+#[test]
+fn premultiplied_compositing() {
+    let mut rng = Pcg32::new(3);
+    for _ in 0..1000 {
+        let (top, top_alpha) = (random_rgb(&mut rng), rng.uniform());
+        let (below, below_alpha) = (random_rgb(&mut rng), rng.uniform());
+        let composite = PremultipliedRgba::from(top.with_alpha(top_alpha))
+            .over(&PremultipliedRgba::from(below.with_alpha(below_alpha)));
+        let straight = Rgba::from(composite);
+
+        // The straight-alpha form of the Porter-Duff over operator.
+        let alpha = top_alpha + below_alpha * (1.0 - top_alpha);
+        assert!((straight.a - alpha).abs() < 1e-12);
+        let blend = |a: f64, b: f64| (a * top_alpha + b * below_alpha * (1.0 - top_alpha)) / alpha;
+        let expected = Rgb::new(
+            blend(top.r, below.r),
+            blend(top.g, below.g),
+            blend(top.b, below.b),
+        );
+        assert_approx_eq!(straight.rgb(), expected, epsilon = 1e-9);
+    }
+
+    let opaque = PremultipliedRgba::new(0.2, 0.4, 0.6, 1.0);
+    assert_eq!(
+        opaque.over(&PremultipliedRgba::new(1.0, 1.0, 1.0, 1.0)),
+        opaque
+    );
+    let clear = PremultipliedRgba::default();
+    assert_eq!(clear.over(&opaque), opaque);
+    assert_eq!(Rgba::from(clear), Rgba::new(0.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn perceptual_mixing() {
+    let black = Rgb::new(0.0_f64, 0.0, 0.0);
+    let white = Rgb::new(1.0, 1.0, 1.0);
+
+    // Halfway in Oklab looks halfway between black and white, much darker in
+    // linear light than the linear midpoint.
+    let mid = black.mix(&white, 0.5);
+    assert!((Oklab::from(mid).l - 0.5).abs() < 1e-6);
+    assert!(mid.r < black.lerp(&white, 0.5).r);
+
+    let mut rng = Pcg32::new(4);
+    for _ in 0..100 {
+        let (a, b) = (random_rgb(&mut rng), random_rgb(&mut rng));
+        assert_approx_eq!(a.mix(&b, 0.0), a, epsilon = 1e-9);
+        assert_approx_eq!(a.mix(&b, 1.0), b, epsilon = 1e-9);
+
+        // Steps along the mix are evenly spaced in Oklab.
+        let steps: Vec<_> = (0..=4)
+            .map(|i| Oklab::from(a.mix(&b, i as f64 / 4.0)))
+            .collect();
+        let total = steps[0].distance(&steps[4]);
+        for pair in steps.windows(2) {
+            assert!((pair[0].distance(&pair[1]) - total / 4.0).abs() < 1e-9);
+        }
+    }
+
+    // Oklch keeps its chroma across the hue wheel.
+    let a = Oklch::new(0.7_f64, 0.15, 300.0);
+    let b = Oklch::new(0.7, 0.15, 60.0);
+    let half = a.lerp(&b, 0.5);
+    assert!(half.h < 1e-9 || 360.0 - half.h < 1e-9);
+    assert_eq!(half.c, 0.15);
+}
+
+#[test]
+fn vector_conversions() {
+    let v = Vector3::new(0.1, 0.2, 0.3);
+    assert_eq!(Vector3::from(Rgb::from(v)), v);
+    assert_eq!(Vector3::from(Srgb::from(v)), v);
+    assert_eq!(Vector3::from(Hsv::from(v)), v);
+    assert_eq!(Vector3::from(Hsl::from(v)), v);
+    assert_eq!(Vector3::from(Xyz::from(v)), v);
+    assert_eq!(Vector3::from(Lab::from(v)), v);
+    assert_eq!(Vector3::from(Oklab::from(v)), v);
+    assert_eq!(Vector3::from(Oklch::from(v)), v);
+    assert_eq!(Rgb::from(v), Rgb::new(0.1, 0.2, 0.3));
+    assert_eq!(Hsv::from(v), Hsv::new(0.1, 0.2, 0.3));
+
+    let w = Vector4::new(0.1, 0.2, 0.3, 0.4);
+    assert_eq!(Vector4::from(Rgba::from(w)), w);
+    assert_eq!(Vector4::from(Srgba::from(w)), w);
+    assert_eq!(Vector4::from(PremultipliedRgba::from(w)), w);
+    assert_eq!(Rgba::from(w), Rgba::new(0.1, 0.2, 0.3, 0.4));
+}
@@ -0,0 +1,20 @@
+//! Helpers shared by the integration tests.
+//!
+//! Every test file compiles its own copy of this module and uses only part
+//! of it, hence the `dead_code` allowance.
+#![allow(dead_code)]
+
+use vexel::random::pcg::Pcg32;
+use vexel::random::sample;
+use vexel::vectors::vector3::Vector3;
+
+/// Shorthand for a double-precision [`Vector3`].
+pub fn v(x: f64, y: f64, z: f64) -> Vector3<f64> {
+    Vector3::new(x, y, z)
+}
+
+/// Returns a random point in the cube of half-width `extent` about the
+/// origin.
+pub fn point(rng: &mut Pcg32, extent: f64) -> Vector3<f64> {
+    sample::in_box(rng, v(-extent, -extent, -extent), v(extent, extent, extent))
+}
@@ -0,0 +1,364 @@
+mod common;
+
+use common::{point, v};
+use vexel::curves::arc_length::ArcLength;
+use vexel::curves::bezier::{CubicBezier, QuadraticBezier};
+use vexel::curves::bspline::BSpline;
+use vexel::curves::catmull_rom::{CatmullRom, Parameterization};
+use vexel::curves::hermite::CubicHermite;
+use vexel::curves::Curve;
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+fn random_points(rng: &mut Pcg32, n: usize) -> Vec<Vector3<f64>> {
+    (0..n).map(|_| point(rng, 5.0)).collect()
+}
+
+/// Parameters spread over the inside of the domain, away from the ends so
+/// central differences stay inside it, and off the knots of uniform splines.
+fn samples<C: Curve<f64>>(curve: &C, count: usize) -> Vec<f64> {
+    let (start, end) = curve.domain();
+    (0..count)
+        .map(|i| start + (end - start) * (0.0123 + 0.9711 * i as f64 / (count - 1) as f64))
+        .collect()
+}
+
+fn assert_derivatives_match<C: Curve<f64, Point = Vector3<f64>>>(curve: &C) {
+    let h = 1e-5;
+    for t in samples(curve, 41) {
+        let numeric = (curve.position(t + h) - curve.position(t - h)) * (0.5 / h);
+        let analytic = curve.derivative(t);
+        assert!(
+            (numeric - analytic).length() < 1e-5 * (1.0 + analytic.length()),
+            "t = {}: {:?} vs {:?}",
+            t,
+            numeric,
+            analytic
+        );
+        let numeric = (curve.derivative(t + h) - curve.derivative(t - h)) * (0.5 / h);
+        let analytic = curve.second_derivative(t);
+        assert!(
+            (numeric - analytic).length() < 1e-4 * (1.0 + analytic.length()),
+            "t = {}: {:?} vs {:?}",
+            t,
+            numeric,
+            analytic
+        );
+    }
+}
+
+fn assert_bounds_enclose<C: Curve<f64, Point = Vector3<f64>>>(
+    curve: &C,
+    (min, max): (Vector3<f64>, Vector3<f64>),
+    tight: bool,
+) {
+    let mut low = v(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    let mut high = v(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    let (start, end) = curve.domain();
+    for i in 0..=2000 {
+        let p = curve.position(start + (end - start) * i as f64 / 2000.0);
+        let eps = 1e-12;
+        assert!(p.x >= min.x - eps && p.y >= min.y - eps && p.z >= min.z - eps);
+        assert!(p.x <= max.x + eps && p.y <= max.y + eps && p.z <= max.z + eps);
+        low = v(low.x.min(p.x), low.y.min(p.y), low.z.min(p.z));
+        high = v(high.x.max(p.x), high.y.max(p.y), high.z.max(p.z));
+    }
+    if tight {
+        assert!((low - min).length() < 1e-4, "{:?} vs {:?}", low, min);
+        assert!((high - max).length() < 1e-4, "{:?} vs {:?}", high, max);
+    }
+}
+
+#[test]
+fn derivatives_match_finite_differences() {
+    let mut rng = Pcg32::new(1);
+    let p = random_points(&mut rng, 8);
+    assert_derivatives_match(&QuadraticBezier::new(p[0], p[1], p[2]));
+    assert_derivatives_match(&CubicBezier::new(p[0], p[1], p[2], p[3]));
+    assert_derivatives_match(&CubicHermite::new(p[0], p[1], p[2], p[3]));
+    for parameterization in [
+        Parameterization::Uniform,
+        Parameterization::Centripetal,
+        Parameterization::Chordal,
+    ] {
+        let spline = CatmullRom::new(p.clone(), parameterization).unwrap();
+        for i in 0..spline.segment_count() {
+            assert_derivatives_match(&spline.segment(i));
+        }
+    }
+    for degree in 1..=4 {
+        assert_derivatives_match(&BSpline::uniform(degree, p.clone()).unwrap());
+    }
+    let knots = vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.5, 0.55, 0.9, 1.0, 1.0, 1.0, 1.0];
+    assert_derivatives_match(&BSpline::new(3, p.clone(), knots).unwrap());
+}
+
+#[test]
+fn split_pieces_retrace_the_curve() {
+    let mut rng = Pcg32::new(2);
+    for _ in 0..20 {
+        let p = random_points(&mut rng, 4);
+        let at = rng.range(0.05, 0.95);
+
+        let quadratic = QuadraticBezier::new(p[0], p[1], p[2]);
+        let (a, b) = quadratic.split(at);
+        let cubic = CubicBezier::new(p[0], p[1], p[2], p[3]);
+        let (c, d) = cubic.split(at);
+        let hermite = CubicHermite::new(p[0], p[1], p[2], p[3]);
+        let (e, f) = hermite.split(at);
+        for i in 0..=10 {
+            let u = i as f64 / 10.0;
+            let before = at * u;
+            let after = at + (1.0 - at) * u;
+            assert!((a.position(u) - quadratic.position(before)).length() < 1e-12);
+            assert!((b.position(u) - quadratic.position(after)).length() < 1e-12);
+            assert!((c.position(u) - cubic.position(before)).length() < 1e-12);
+            assert!((d.position(u) - cubic.position(after)).length() < 1e-12);
+            assert!((e.position(u) - hermite.position(before)).length() < 1e-12);
+            assert!((f.position(u) - hermite.position(after)).length() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn bspline_split_keeps_the_shape() {
+    let mut rng = Pcg32::new(3);
+    let points = random_points(&mut rng, 7);
+    let knots = vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 3.5, 4.0, 4.0, 4.0];
+    let clamped = BSpline::new(2, points.clone(), knots).unwrap();
+    for spline in [clamped, BSpline::uniform(3, points).unwrap()] {
+        let (start, end) = spline.domain();
+        // Splitting on an existing knot and between knots.
+        for at in [start + 1.0, start + 1.7, end - 0.25] {
+            let (before, after) = spline.split(at).unwrap();
+            assert_eq!(before.domain(), (start, at));
+            assert_eq!(after.domain(), (at, end));
+            for t in samples(&before, 15) {
+                assert!((before.position(t) - spline.position(t)).length() < 1e-9);
+            }
+            for t in samples(&after, 15) {
+                assert!((after.position(t) - spline.position(t)).length() < 1e-9);
+            }
+        }
+        assert!(spline.split(start).is_none());
+        assert!(spline.split(end).is_none());
+    }
+}
+
+#[test]
+fn bounds_enclose_the_curves() {
+    let mut rng = Pcg32::new(4);
+    for _ in 0..10 {
+        let p = random_points(&mut rng, 6);
+        let quadratic = QuadraticBezier::new(p[0], p[1], p[2]);
+        assert_bounds_enclose(&quadratic, quadratic.bounds(), true);
+        let cubic = CubicBezier::new(p[0], p[1], p[2], p[3]);
+        assert_bounds_enclose(&cubic, cubic.bounds(), true);
+        let hermite = CubicHermite::new(p[0], p[1], p[2], p[3]);
+        assert_bounds_enclose(&hermite, hermite.bounds(), true);
+        let spline = CatmullRom::new(p.clone(), Parameterization::Centripetal).unwrap();
+        assert_bounds_enclose(&spline, spline.bounds(), true);
+        let spline = BSpline::uniform(3, p.clone()).unwrap();
+        assert_bounds_enclose(&spline, spline.bounds(), false);
+    }
+}
+
+#[test]
+fn catmull_rom_interpolates_with_a_continuous_tangent() {
+    let mut rng = Pcg32::new(5);
+    let points = random_points(&mut rng, 9);
+    for parameterization in [
+        Parameterization::Uniform,
+        Parameterization::Centripetal,
+        Parameterization::Chordal,
+    ] {
+        let spline = CatmullRom::new(points.clone(), parameterization).unwrap();
+        for i in 0..=spline.segment_count() {
+            let t = i as f64;
+            assert!((spline.position(t) - points[i + 1]).length() < 1e-12);
+        }
+        for i in 1..spline.segment_count() {
+            let arriving = spline.segment(i - 1).m1.normalize();
+            let leaving = spline.segment(i).m0.normalize();
+            assert!((arriving - leaving).length() < 1e-12);
+        }
+    }
+
+    // Uniform splines are continuous in velocity too.
+    let spline = CatmullRom::new(points, Parameterization::Uniform).unwrap();
+    for i in 1..spline.segment_count() {
+        let t = i as f64;
+        assert!((spline.derivative(t - 1e-12) - spline.derivative(t)).length() < 1e-9);
+    }
+}
+
+#[test]
+fn catmull_rom_tolerates_repeated_points() {
+    let points = vec![
+        v(0.0, 0.0, 0.0),
+        v(0.0, 0.0, 0.0),
+        v(1.0, 0.0, 0.0),
+        v(1.0, 0.0, 0.0),
+        v(2.0, 1.0, 0.0),
+    ];
+    for parameterization in [Parameterization::Centripetal, Parameterization::Chordal] {
+        let spline = CatmullRom::new(points.clone(), parameterization).unwrap();
+        for t in samples(&spline, 20) {
+            let p = spline.position(t);
+            assert!(p.x.is_finite() && p.y.is_finite() && p.z.is_finite());
+        }
+    }
+}
+
+#[test]
+fn bspline_properties() {
+    let mut rng = Pcg32::new(6);
+    // The basis sums to one, so equal control points give a constant curve.
+    let constant = BSpline::uniform(3, vec![v(1.0, 2.0, 3.0); 6]).unwrap();
+    for t in samples(&constant, 10) {
+        assert!((constant.position(t) - v(1.0, 2.0, 3.0)).length() < 1e-12);
+        assert!(constant.derivative(t).length() < 1e-12);
+    }
+
+    // A cubic is continuous up to its second derivative at simple knots.
+    let spline = BSpline::uniform(3, random_points(&mut rng, 8)).unwrap();
+    let (start, end) = spline.domain();
+    let mut knot = start + 1.0;
+    while knot < end {
+        let h = 1e-9;
+        assert!((spline.position(knot - h) - spline.position(knot + h)).length() < 1e-7);
+        assert!((spline.derivative(knot - h) - spline.derivative(knot + h)).length() < 1e-6);
+        assert!(
+            (spline.second_derivative(knot - h) - spline.second_derivative(knot + h)).length()
+                < 1e-5
+        );
+        knot += 1.0;
+    }
+
+    // Clamped end knots pin the ends to the first and last control points.
+    let points = random_points(&mut rng, 5);
+    let knots = vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0];
+    let clamped = BSpline::new(3, points.clone(), knots).unwrap();
+    assert!((clamped.position(0.0) - points[0]).length() < 1e-12);
+    assert!((clamped.position(1.0) - points[4]).length() < 1e-12);
+
+    // Degree one is the polyline through the control points.
+    let polyline = BSpline::uniform(1, points.clone()).unwrap();
+    for (i, p) in points.iter().enumerate() {
+        assert!((polyline.position(i as f64 + 1.0) - *p).length() < 1e-12);
+    }
+
+    assert!(BSpline::uniform(3, random_points(&mut rng, 3)).is_none());
+    assert!(BSpline::new(1, random_points(&mut rng, 3), vec![0.0, 1.0, 0.5, 2.0, 3.0]).is_none());
+    assert!(BSpline::new(1, random_points(&mut rng, 3), vec![0.0, 1.0, 2.0, 3.0]).is_none());
+}
+
+#[test]
+fn bspline_derivatives_match_the_derivative_spline() {
+    let mut rng = Pcg32::new(9);
+    let knots = vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.3, 0.7, 1.0, 1.0, 1.0, 1.0];
+    let splines = [
+        BSpline::new(3, random_points(&mut rng, 7), knots).unwrap(),
+        BSpline::uniform(4, random_points(&mut rng, 9)).unwrap(),
+        BSpline::uniform(2, random_points(&mut rng, 5)).unwrap(),
+    ];
+    for spline in &splines {
+        let velocity = spline.derivative_spline().unwrap();
+        let acceleration = velocity.derivative_spline().unwrap();
+        for t in samples(spline, 50) {
+            assert_eq!(spline.derivative(t), velocity.position(t));
+            assert_eq!(spline.second_derivative(t), acceleration.position(t));
+        }
+    }
+
+    // Past the degree every derivative vanishes.
+    let polyline = BSpline::uniform(1, random_points(&mut rng, 4)).unwrap();
+    assert_eq!(polyline.second_derivative(2.5), v(0.0, 0.0, 0.0));
+    let steps = BSpline::uniform(0, random_points(&mut rng, 4)).unwrap();
+    assert_eq!(steps.derivative(1.5), v(0.0, 0.0, 0.0));
+}
+
+/// Returns the length of the polyline through `steps + 1` evenly spaced
+/// points of `curve` between `start` and `end`.
+fn polyline<C: Curve<f64, Point = Vector3<f64>> + ?Sized>(
+    curve: &C,
+    start: f64,
+    end: f64,
+    steps: usize,
+) -> f64 {
+    let mut total = 0.0;
+    let mut previous = curve.position(start);
+    for i in 1..=steps {
+        let p = curve.position(start + (end - start) * i as f64 / steps as f64);
+        total += (p - previous).length();
+        previous = p;
+    }
+    total
+}
+
+#[test]
+fn arc_length_matches_a_fine_polyline() {
+    let mut rng = Pcg32::new(7);
+    let points = random_points(&mut rng, 7);
+    let curves: Vec<Box<dyn Curve<f64, Point = Vector3<f64>>>> = vec![
+        Box::new(CubicBezier::new(points[0], points[1], points[2], points[3])),
+        Box::new(CatmullRom::new(points.clone(), Parameterization::Centripetal).unwrap()),
+        Box::new(BSpline::uniform(3, points.clone()).unwrap()),
+    ];
+    for curve in &curves {
+        let (start, end) = curve.domain();
+        let total = polyline(&**curve, start, end, 100_000);
+        let measured = ArcLength::new(&**curve, 32);
+        assert!(
+            (measured.length() - total).abs() < 1e-6 * total,
+            "{} vs {}",
+            measured.length(),
+            total
+        );
+
+        // Equal steps in distance land equally far apart along the curve.
+        let count = 20;
+        let step = measured.length() / count as f64;
+        let mut previous = start;
+        for i in 1..count {
+            let t = measured.parameter_at(step * i as f64);
+            assert!(t > previous);
+            let travelled = polyline(&**curve, previous, t, 10_000);
+            assert!(
+                (travelled - step).abs() < 1e-6 * total,
+                "{} vs {}",
+                travelled,
+                step
+            );
+            assert_eq!(measured.position_at(step * i as f64), curve.position(t));
+            previous = t;
+        }
+        assert_eq!(measured.parameter_at(-1.0), start);
+        assert_eq!(measured.parameter_at(measured.length() + 1.0), end);
+    }
+}
+
+#[test]
+fn works_in_two_and_four_dimensions() {
+    let flat = CubicBezier::new(
+        Vector2::new(0.0_f64, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, -1.0),
+        Vector2::new(3.0, 0.0),
+    );
+    let lifted = CubicBezier::new(
+        Vector4::new(0.0, 0.0, 0.0, 0.0),
+        Vector4::new(1.0, 1.0, 0.0, 0.0),
+        Vector4::new(2.0, -1.0, 0.0, 0.0),
+        Vector4::new(3.0, 0.0, 0.0, 0.0),
+    );
+    let a = ArcLength::new(flat, 16);
+    let b = ArcLength::new(lifted, 16);
+    assert!((a.length() - b.length()).abs() < 1e-12);
+    let (min, max) = flat.bounds();
+    let (min4, max4) = lifted.bounds();
+    assert!((min.y - min4.y).abs() < 1e-12 && (max.y - max4.y).abs() < 1e-12);
+}
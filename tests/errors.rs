@@ -0,0 +1,203 @@
+use vexel::assert_approx_eq;
+use vexel::error::VexelError;
+use vexel::matrices::mat2::Mat2;
+use vexel::matrices::mat3::Mat3;
+use vexel::matrices::mat4::Mat4;
+use vexel::quaternion::Quaternion;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+#[test]
+fn try_normalize_rejects_degenerate_vectors() {
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    assert_eq!(zero.try_normalize(), Err(VexelError::ZeroLength));
+    assert_eq!(
+        Vector3::new(-0.0, 0.0, -0.0).try_normalize(),
+        Err(VexelError::ZeroLength)
+    );
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            Vector3::new(1.0, bad, 0.0).try_normalize(),
+            Err(VexelError::NonFinite)
+        );
+        assert_eq!(
+            Vector2::new(bad, 0.0).try_normalize(),
+            Err(VexelError::NonFinite)
+        );
+        assert_eq!(
+            Vector4::new(0.0, 0.0, 0.0, bad).try_normalize(),
+            Err(VexelError::NonFinite)
+        );
+    }
+
+    // The squared length of tiny vectors underflows, just as `normalize`
+    // sees it.
+    let tiny = Vector3::new(1e-200, 0.0, 0.0);
+    assert_eq!(tiny.try_normalize(), Err(VexelError::ZeroLength));
+    assert_eq!(tiny.normalize(), tiny);
+    let small = Vector3::new(1e-150, 0.0, 0.0);
+    assert_eq!(small.try_normalize(), Ok(Vector3::new(1.0, 0.0, 0.0)));
+    // The length of this overflows f32 even though the components don't.
+    assert_eq!(
+        Vector2::new(f32::MAX, f32::MAX).try_normalize(),
+        Err(VexelError::NonFinite)
+    );
+
+    let v = Vector3::new(1.0, 2.0, 2.0);
+    assert_eq!(v.try_normalize(), Ok(v.normalize()));
+    assert_approx_eq!(v.try_normalize().unwrap().length(), 1.0, ulps = 1);
+}
+
+#[test]
+fn normalize_fallbacks() {
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).normalize_or(up), up);
+    assert_eq!(Vector3::new(f64::NAN, 0.0, 0.0).normalize_or(up), up);
+    assert_eq!(
+        Vector3::new(0.0, 0.0, -2.0).normalize_or(up),
+        Vector3::new(0.0, 0.0, -1.0)
+    );
+
+    assert_eq!(
+        Vector2::new(f64::INFINITY, 1.0).normalize_or_zero(),
+        Vector2::new(0.0, 0.0)
+    );
+    assert_eq!(
+        Vector4::new(0.0, 0.0, 0.0, 0.0).normalize_or_zero(),
+        Vector4::new(0.0, 0.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        Vector4::new(0.0, 0.0, 0.0, 3.0).normalize_or_zero(),
+        Vector4::new(0.0, 0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn projection_and_angles_reject_zero_vectors() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    assert_eq!(v.try_project_onto(&zero), Err(VexelError::ZeroLength));
+    assert_eq!(v.try_angle_between(&zero), Err(VexelError::ZeroLength));
+    assert_eq!(zero.try_angle_between(&v), Err(VexelError::ZeroLength));
+    assert_eq!(
+        v.try_angle_between(&Vector3::new(f64::NAN, 0.0, 0.0)),
+        Err(VexelError::NonFinite)
+    );
+    assert!(v.project_onto(&zero).x.is_nan());
+    assert!(v.angle_between(&zero).is_nan());
+
+    let axis = Vector2::new(0.0, 3.0);
+    assert_eq!(
+        Vector2::new(4.0, 5.0).try_project_onto(&axis),
+        Ok(Vector2::new(0.0, 5.0))
+    );
+    assert_eq!(
+        Vector4::new(1.0, 2.0, 3.0, 4.0).try_project_onto(&Vector4::new(0.0, 0.0, 0.0, 0.0)),
+        Err(VexelError::ZeroLength)
+    );
+}
+
+#[test]
+fn angles_of_parallel_vectors_are_never_nan() {
+    // Rounding makes the cosine of these slightly larger than one.
+    let mut found = false;
+    for i in 1..1000 {
+        let v = Vector3::new(0.1 * i as f64, 0.3, 0.7);
+        let w = Vector3::new(v.x * 3.0, v.y * 3.0, v.z * 3.0);
+        found |= v.angle_between(&w).is_nan();
+        let angle = v.try_angle_between(&w).unwrap();
+        assert!((0.0..1e-7).contains(&angle), "{angle}");
+        let opposite = Vector3::new(-w.x, -w.y, -w.z);
+        assert_approx_eq!(
+            v.try_angle_between(&opposite).unwrap(),
+            core::f64::consts::PI,
+            epsilon = 1e-7
+        );
+    }
+    assert!(found, "expected angle_between to give NaN for some pair");
+}
+
+#[test]
+fn try_inverse_reports_singular_matrices() {
+    assert_eq!(
+        Mat2::<f64>::zero().try_inverse(),
+        Err(VexelError::SingularMatrix)
+    );
+    assert_eq!(
+        Mat3::<f64>::zero().try_inverse(),
+        Err(VexelError::SingularMatrix)
+    );
+    assert_eq!(
+        Mat4::<f64>::zero().try_inverse(),
+        Err(VexelError::SingularMatrix)
+    );
+
+    let m = Mat3::from_cols_array([1.0, 0.0, 5.0, 2.0, 1.0, 6.0, 3.0, 4.0, 0.0]);
+    assert_eq!(m.try_inverse(), Ok(m.inverse().unwrap()));
+    assert_eq!(Mat4::<f64>::identity().try_inverse(), Ok(Mat4::identity()));
+    assert_eq!(Mat2::<f64>::identity().try_inverse(), Ok(Mat2::identity()));
+}
+
+#[test]
+fn inverse_rejects_rank_deficient_matrices_with_rounded_determinants() {
+    // The last column is a float combination of the others, so the matrix
+    // is singular but rounding usually leaves a tiny nonzero determinant.
+    let mut found = false;
+    for i in 1..100 {
+        let a = Vector4::new(0.1 * i as f64, 0.3, 0.7, 1.1);
+        let b = Vector4::new(0.2, 0.1 * i as f64, 1.3, 0.9);
+        let c = Vector4::new(0.6, 0.4, 0.1 * i as f64, 0.3);
+        let d = a * 0.1 + b * 0.7 + c * 0.3;
+        let m = Mat4::from_cols(a, b, c, d);
+        found |= m.determinant() != 0.0;
+        assert_eq!(m.inverse(), None, "{m:?}");
+        assert_eq!(m.try_inverse(), Err(VexelError::SingularMatrix));
+
+        let (a, b) = (a.truncate(), b.truncate());
+        let m = Mat3::from_cols(a, b, a * 0.1 + b * 0.7);
+        assert_eq!(m.inverse(), None, "{m:?}");
+        let a = Vector2::new(a.x, a.y);
+        let m = Mat2::from_cols(a, a * 0.3);
+        assert_eq!(m.inverse(), None, "{m:?}");
+    }
+    assert!(
+        found,
+        "expected a rank-deficient Mat4 with a nonzero determinant"
+    );
+}
+
+#[test]
+fn quaternions_reject_zero_length() {
+    assert_eq!(
+        Quaternion::new(0.0, 0.0, 0.0, 0.0).try_normalize(),
+        Err(VexelError::ZeroLength)
+    );
+    assert_eq!(
+        Quaternion::new(0.0, f64::NAN, 0.0, 1.0).try_normalize(),
+        Err(VexelError::NonFinite)
+    );
+    assert_eq!(
+        Quaternion::new(0.0, 0.0, 0.0, 2.0).try_normalize(),
+        Ok(Quaternion::identity())
+    );
+}
+
+#[test]
+fn errors_display_and_propagate() {
+    fn direction(from: Vector3<f64>, to: Vector3<f64>) -> Result<Vector3<f64>, VexelError> {
+        Vector3::new(to.x - from.x, to.y - from.y, to.z - from.z).try_normalize()
+    }
+    fn boxed(p: Vector3<f64>) -> Result<Vector3<f64>, Box<dyn std::error::Error>> {
+        Ok(direction(p, p)?)
+    }
+
+    let p = Vector3::new(1.0, 1.0, 1.0);
+    let error = boxed(p).unwrap_err();
+    assert_eq!(error.to_string(), "vector has zero length");
+    assert_eq!(
+        VexelError::NonFinite.to_string(),
+        "value is infinite or NaN"
+    );
+    assert_eq!(VexelError::SingularMatrix.to_string(), "matrix is singular");
+}
@@ -0,0 +1,102 @@
+//! The documentation examples use `f64`; these mirror them for `f32` to make
+//! sure every operation is available and computed in single precision.
+
+use vexel::vectors::vector2::{Vec2f, Vector2};
+use vexel::vectors::vector3::{Vec3f, Vector3};
+use vexel::vectors::vector4::{Vec4f, Vector4};
+
+#[test]
+fn vector2_f32() {
+    let v1: Vec2f = Vector2::new(3.0, 4.0);
+    let v2: Vec2f = Vector2::new(5.0, 6.0);
+
+    assert_eq!(v1.length(), 5.0f32);
+    assert_eq!(v1.dot(&v2), 39.0f32);
+    assert_eq!(v1.cross(&v2), -2.0f32);
+    assert_eq!(v1.normalize(), Vector2::new(0.6, 0.8));
+
+    let x_axis: Vec2f = Vector2::new(1.0, 0.0);
+    assert_eq!(v1.project_onto(&x_axis), Vector2::new(3.0, 0.0));
+    assert_eq!(v1.reject_from(&x_axis), Vector2::new(0.0, 4.0));
+
+    let a: Vec2f = Vector2::new(1.0, 2.0);
+    let b: Vec2f = Vector2::new(3.0, 4.0);
+    assert_eq!(a.lerp(&b, 0.5), Vector2::new(2.0, 3.0));
+    assert_eq!(
+        x_axis.angle_between(&Vector2::new(0.0, 1.0)),
+        std::f32::consts::FRAC_PI_2
+    );
+
+    assert_eq!(a + b, Vector2::new(4.0, 6.0));
+    assert_eq!(b - a, Vector2::new(2.0, 2.0));
+    assert_eq!(a * b, Vector2::new(3.0, 8.0));
+    assert_eq!(b / a, Vector2::new(3.0, 2.0));
+}
+
+#[test]
+fn vector3_f32() {
+    let v1: Vec3f = Vector3::new(3.0, 4.0, 5.0);
+    let v2: Vec3f = Vector3::new(6.0, 7.0, 8.0);
+
+    assert_eq!(v1.length(), 50.0f32.sqrt());
+    assert_eq!(v1.dot(&v2), 86.0f32);
+    assert_eq!(v1.cross(&v2), Vector3::new(-3.0, 6.0, -3.0));
+
+    let n = Vector3::new(3.0f32, 6.0, 12.0).normalize();
+    assert!((n.length() - 1.0).abs() < f32::EPSILON * 2.0);
+
+    let x_axis: Vec3f = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(v1.project_onto(&x_axis), Vector3::new(3.0, 0.0, 0.0));
+    assert_eq!(v1.reject_from(&x_axis), Vector3::new(0.0, 4.0, 5.0));
+
+    let a: Vec3f = Vector3::new(1.0, 2.0, 3.0);
+    let b: Vec3f = Vector3::new(4.0, 5.0, 6.0);
+    assert_eq!(a.lerp(&b, 0.5), Vector3::new(2.5, 3.5, 4.5));
+
+    let angle = Vector3::new(1.0f32, 0.0, -3.0).angle_between(&Vector3::new(0.0, 1.0, 2.0));
+    assert!((angle - 2.583_994).abs() < 1e-6);
+
+    assert_eq!(a + b, Vector3::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vector3::new(3.0, 3.0, 3.0));
+    assert_eq!(a * b, Vector3::new(4.0, 10.0, 18.0));
+    assert_eq!(b / a, Vector3::new(4.0, 2.5, 2.0));
+}
+
+#[test]
+fn vector4_f32() {
+    let v1: Vec4f = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let v2: Vec4f = Vector4::new(5.0, 6.0, 7.0, 8.0);
+
+    assert_eq!(Vector4::new(1.0f32, 2.0, 2.0, 2.0).length(), 13.0f32.sqrt());
+    assert_eq!(v1.dot(&v2), 70.0f32);
+    assert_eq!(v1.cross(&v2), Vector4::new(-4.0, 8.0, -4.0, 0.0));
+
+    let n = Vector4::new(1.0f32, 2.0, 2.0, 2.0).normalize();
+    assert!((n.length() - 1.0).abs() < f32::EPSILON * 2.0);
+
+    let x_axis: Vec4f = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    assert_eq!(v1.project_onto(&x_axis), Vector4::new(1.0, 0.0, 0.0, 0.0));
+    assert_eq!(v1.reject_from(&x_axis), Vector4::new(0.0, 2.0, 3.0, 4.0));
+    assert_eq!(v1.lerp(&v2, 0.5), Vector4::new(3.0, 4.0, 5.0, 6.0));
+    assert_eq!(
+        x_axis.angle_between(&Vector4::new(0.0, 1.0, 0.0, 0.0)),
+        std::f32::consts::FRAC_PI_2
+    );
+
+    assert_eq!(v1 + v2, Vector4::new(6.0, 8.0, 10.0, 12.0));
+    assert_eq!(v2 - v1, Vector4::new(4.0, 4.0, 4.0, 4.0));
+    assert_eq!(v1 * v2, Vector4::new(5.0, 12.0, 21.0, 32.0));
+    assert_eq!(v2 / v1, Vector4::new(5.0, 3.0, 7.0 / 3.0, 2.0));
+}
+
+#[test]
+fn f32_results_are_not_widened() {
+    // Each of these only type-checks if the operation returns `f32`.
+    let v: Vec3f = Vector3::new(1.0, 2.0, 3.0);
+    let _: f32 = v.length();
+    let _: f32 = v.dot(&v);
+    let _: f32 = v.angle_between(&Vector3::new(0.0, 0.0, 1.0));
+    let _: Vec3f = v.cross(&v);
+    let _: Vec3f = v.normalize();
+    let _: Vec3f = v.lerp(&v, 0.25);
+}
@@ -0,0 +1,231 @@
+mod common;
+
+use common::v;
+use vexel::assert_approx_eq;
+use vexel::geometry::aabb::Aabb;
+use vexel::geometry::frustum::{Containment, Frustum};
+use vexel::geometry::sphere::Sphere;
+use vexel::matrices::mat4::Mat4;
+use vexel::matrices::projection::DepthRange;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+const FOV: f64 = 1.1;
+const ASPECT: f64 = 1.6;
+const NEAR: f64 = 0.5;
+const FAR: f64 = 40.0;
+
+fn camera() -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+    (v(3.0, 2.0, -1.0), v(-1.0, -0.2, -0.5), v(0.0, 1.0, 0.0))
+}
+
+fn assert_same_frustum(a: &Frustum<f64>, b: &Frustum<f64>) {
+    for (p, q) in a.planes.iter().zip(&b.planes) {
+        assert_approx_eq!(p.normal, q.normal, epsilon = 1e-9);
+        assert_approx_eq!(p.d, q.d, epsilon = 1e-9);
+    }
+}
+
+fn grid() -> Vec<Vector3<f64>> {
+    let mut points = Vec::new();
+    for i in -10..=10 {
+        for j in -10..=10 {
+            for k in -10..=10 {
+                points.push(v(i as f64 * 2.5, j as f64 * 2.5, k as f64 * 2.5));
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn camera_parameters_match_matrix_extraction() {
+    let (eye, direction, up) = camera();
+    let view = Mat4::look_to_rh(eye, direction, up);
+    let expected = Frustum::from_perspective(eye, direction, up, FOV, ASPECT, NEAR, FAR);
+    for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        let projection = Mat4::perspective_rh(FOV, ASPECT, NEAR, FAR, depth);
+        let extracted = Frustum::from_view_projection(&(projection * view), depth);
+        assert_same_frustum(&extracted, &expected);
+    }
+}
+
+#[test]
+fn extraction_agrees_with_clip_space() {
+    let (eye, direction, up) = camera();
+    let view = Mat4::look_to_rh(eye, direction, up);
+    let cases = [
+        (
+            Mat4::perspective_lh(FOV, ASPECT, NEAR, FAR, DepthRange::ZeroToOne)
+                * Mat4::look_at_lh(eye, eye + direction, up),
+            DepthRange::ZeroToOne,
+        ),
+        (
+            Mat4::orthographic_rh(
+                -8.0,
+                6.0,
+                -4.0,
+                5.0,
+                1.0,
+                30.0,
+                DepthRange::NegativeOneToOne,
+            ) * view,
+            DepthRange::NegativeOneToOne,
+        ),
+        (
+            Mat4::perspective_rh(FOV, ASPECT, NEAR, FAR, DepthRange::NegativeOneToOne) * view,
+            DepthRange::NegativeOneToOne,
+        ),
+    ];
+    for (view_projection, depth) in cases {
+        let frustum = Frustum::from_view_projection(&view_projection, depth);
+        let low = match depth {
+            DepthRange::NegativeOneToOne => -1.0,
+            DepthRange::ZeroToOne => 0.0,
+        };
+        for point in grid() {
+            let clip = view_projection * Vector4::new(point.x, point.y, point.z, 1.0);
+            let inside = clip.w > 0.0
+                && clip.x.abs() <= clip.w
+                && clip.y.abs() <= clip.w
+                && clip.z >= low * clip.w
+                && clip.z <= clip.w;
+            assert_eq!(frustum.contains_point(point), inside, "{:?}", point);
+        }
+    }
+}
+
+#[test]
+fn corners_match_unprojected_clip_corners() {
+    let (eye, direction, up) = camera();
+    let depth = DepthRange::ZeroToOne;
+    let view_projection =
+        Mat4::perspective_rh(FOV, ASPECT, NEAR, FAR, depth) * Mat4::look_to_rh(eye, direction, up);
+    let inverse = view_projection.inverse().unwrap();
+    let corners = Frustum::from_view_projection(&view_projection, depth)
+        .corners()
+        .unwrap();
+    let ndc = [
+        (-1.0, -1.0, 0.0),
+        (1.0, -1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (-1.0, 1.0, 0.0),
+        (-1.0, -1.0, 1.0),
+        (1.0, -1.0, 1.0),
+        (1.0, 1.0, 1.0),
+        (-1.0, 1.0, 1.0),
+    ];
+    for (corner, (x, y, z)) in corners.iter().zip(ndc) {
+        let world = inverse * Vector4::new(x, y, z, 1.0);
+        let expected = v(world.x / world.w, world.y / world.w, world.z / world.w);
+        assert!(
+            (*corner - expected).length() < 1e-6,
+            "{:?} vs {:?}",
+            corner,
+            expected
+        );
+    }
+
+    let from_camera = Frustum::from_perspective(eye, direction, up, FOV, ASPECT, NEAR, FAR);
+    for (a, b) in corners.iter().zip(from_camera.corners().unwrap()) {
+        assert!((*a - b).length() < 1e-6);
+    }
+}
+
+#[test]
+fn infinite_and_reverse_z_projections() {
+    let forward = v(0.0, 0.0, -1.0);
+    let infinite = Frustum::from_view_projection(
+        &Mat4::perspective_infinite_rh(FOV, ASPECT, NEAR, DepthRange::ZeroToOne),
+        DepthRange::ZeroToOne,
+    );
+    assert!(infinite.contains_point(v(0.0, 0.0, -1.0e6)));
+    assert!(!infinite.contains_point(v(0.0, 0.0, -0.25)));
+
+    let reverse = Frustum::from_view_projection(
+        &Mat4::perspective_reverse_z_rh(FOV, ASPECT, NEAR, FAR),
+        DepthRange::ZeroToOne,
+    );
+    assert!(reverse.contains_point(v(0.0, 0.0, -10.0)));
+    assert!(!reverse.contains_point(v(0.0, 0.0, -50.0)));
+    assert!(!reverse.contains_point(v(0.0, 0.0, -0.25)));
+    // The roles of the last two planes swap.
+    assert!((reverse.planes[4].normal - v(0.0, 0.0, 1.0)).length() < 1e-9);
+    assert!((reverse.planes[5].normal - forward).length() < 1e-9);
+
+    let reverse_infinite = Frustum::from_view_projection(
+        &Mat4::perspective_infinite_reverse_z_rh(FOV, ASPECT, NEAR),
+        DepthRange::ZeroToOne,
+    );
+    assert!(reverse_infinite.contains_point(v(0.0, 0.0, -1.0e6)));
+    assert!(!reverse_infinite.contains_point(v(0.0, 0.0, -0.25)));
+
+    // An infinite far plane meets none of the others.
+    assert_eq!(infinite.corners(), None);
+    assert_eq!(reverse_infinite.corners(), None);
+    let corners = reverse.corners().unwrap();
+    assert!((corners[0].z + FAR).abs() < 1e-9, "{:?}", corners[0]);
+    assert!((corners[4].z + NEAR).abs() < 1e-9, "{:?}", corners[4]);
+}
+
+#[test]
+fn sphere_culling_is_consistent_with_points() {
+    let (eye, direction, up) = camera();
+    let frustum = Frustum::from_perspective(eye, direction, up, FOV, ASPECT, NEAR, FAR);
+    let offsets = [
+        v(1.0, 0.0, 0.0),
+        v(-1.0, 0.0, 0.0),
+        v(0.0, 1.0, 0.0),
+        v(0.0, -1.0, 0.0),
+        v(0.0, 0.0, 1.0),
+        v(0.0, 0.0, -1.0),
+    ];
+    let (mut inside, mut outside, mut crossing) = (0, 0, 0);
+    for center in grid() {
+        let sphere = Sphere::new(center, 2.0);
+        let surface = offsets.map(|o| center + o * v(2.0, 2.0, 2.0));
+        match frustum.intersects_sphere(&sphere) {
+            Containment::Inside => {
+                inside += 1;
+                assert!(surface.iter().all(|&p| frustum.contains_point(p)));
+            }
+            Containment::Outside => {
+                outside += 1;
+                assert!(!surface.iter().any(|&p| frustum.contains_point(p)));
+                assert!(!frustum.contains_point(center));
+            }
+            Containment::Intersecting => crossing += 1,
+        }
+    }
+    assert!(inside > 0 && outside > 0 && crossing > 0);
+}
+
+#[test]
+fn aabb_culling_is_consistent_with_corners() {
+    let (eye, direction, up) = camera();
+    let frustum = Frustum::from_perspective(eye, direction, up, FOV, ASPECT, NEAR, FAR);
+    let (mut inside, mut outside, mut crossing) = (0, 0, 0);
+    for center in grid() {
+        let aabb = Aabb::from_center_half_extents(center, v(1.5, 1.0, 2.0));
+        let mut corners = Vec::new();
+        for x in [aabb.min.x, aabb.max.x] {
+            for y in [aabb.min.y, aabb.max.y] {
+                for z in [aabb.min.z, aabb.max.z] {
+                    corners.push(v(x, y, z));
+                }
+            }
+        }
+        match frustum.intersects_aabb(&aabb) {
+            Containment::Inside => {
+                inside += 1;
+                assert!(corners.iter().all(|&p| frustum.contains_point(p)));
+            }
+            Containment::Outside => {
+                outside += 1;
+                assert!(!corners.iter().any(|&p| frustum.contains_point(p)));
+            }
+            Containment::Intersecting => crossing += 1,
+        }
+    }
+    assert!(inside > 0 && outside > 0 && crossing > 0);
+}
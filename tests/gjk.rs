@@ -0,0 +1,248 @@
+mod common;
+
+use common::{point, v};
+use vexel::geometry::aabb::Aabb;
+use vexel::geometry::capsule::Capsule;
+use vexel::geometry::cone::Cone;
+use vexel::geometry::cylinder::Cylinder;
+use vexel::geometry::gjk;
+use vexel::geometry::obb::Obb;
+use vexel::geometry::segment::Segment;
+use vexel::geometry::sphere::Sphere;
+use vexel::geometry::support::{MinkowskiSum, SupportMap};
+use vexel::geometry::triangle::Triangle;
+use vexel::quaternion::Quaternion;
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::vectors::vector3::Vector3;
+
+#[test]
+fn supports_are_the_furthest_points_of_each_shape() {
+    let directions = [
+        v(1.0, 0.0, 0.0),
+        v(0.0, -1.0, 0.0),
+        v(0.3, 0.4, -0.5),
+        v(-2.0, 1.0, 3.0),
+    ];
+    let cylinder = Cylinder::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 0.0), 0.5);
+    let cone = Cone::new(v(0.0, 3.0, 0.0), v(0.0, 0.0, 0.0), 1.0);
+    let capsule = Capsule::new(v(-1.0, 0.0, 0.0), v(1.0, 0.0, 0.0), 0.25);
+
+    let mut rng = Pcg32::new(5);
+    let samples: Vec<_> = (0..20000).map(|_| point(&mut rng, 3.0)).collect();
+    for d in directions {
+        let p = cylinder.support(d);
+        assert!(cylinder.contains_point(p.lerp(&v(0.5, 1.0, 0.0), 1e-9)));
+        for q in samples.iter().filter(|q| cylinder.contains_point(**q)) {
+            assert!(p.dot(&d) >= q.dot(&d) - 1e-12);
+        }
+
+        let p = cone.support(d);
+        assert!(cone.contains_point(p.lerp(&v(0.0, 1.0, 0.0), 1e-9)));
+        for q in samples.iter().filter(|q| cone.contains_point(**q)) {
+            assert!(p.dot(&d) >= q.dot(&d) - 1e-12);
+        }
+
+        assert!(capsule.distance_to_point(capsule.support(d)) < 1e-12);
+    }
+    assert_eq!(cone.support(v(0.0, 1.0, 0.0)), v(0.0, 3.0, 0.0));
+    assert_eq!(cylinder.support(v(0.0, 0.0, 1.0)).z, 0.5);
+}
+
+#[test]
+fn point_cloud_of_box_corners_matches_the_box() {
+    let aabb = Aabb::new(v(-1.0, -2.0, -0.5), v(2.0, 1.0, 0.5));
+    let mut corners = Vec::new();
+    for x in [aabb.min.x, aabb.max.x] {
+        for y in [aabb.min.y, aabb.max.y] {
+            for z in [aabb.min.z, aabb.max.z] {
+                corners.push(v(x, y, z));
+            }
+        }
+    }
+    let sphere = Sphere::new(v(4.0, 3.0, 0.0), 1.0);
+    let from_box = gjk::distance(&aabb, &sphere);
+    let from_cloud = gjk::distance(corners.as_slice(), &sphere);
+    assert!((from_box - from_cloud).abs() < 1e-6);
+    assert!((from_box - (8.0_f64.sqrt() - 1.0)).abs() < 1e-6);
+}
+
+#[test]
+fn sphere_sphere_matches_the_closed_form() {
+    let mut rng = Pcg32::new(7);
+    for _ in 0..200 {
+        let a = Sphere::new(point(&mut rng, 3.0), rng.range(0.2, 2.0));
+        let b = Sphere::new(point(&mut rng, 3.0), rng.range(0.2, 2.0));
+        let gap = (b.center - a.center).length() - a.radius - b.radius;
+        if gap.abs() < 1e-3 {
+            continue;
+        }
+        if gap > 0.0 {
+            assert!((gjk::distance(&a, &b) - gap).abs() < 1e-5, "gap {}", gap);
+            assert!(gjk::penetration(&a, &b).is_none());
+        } else {
+            assert!(gjk::intersects(&a, &b));
+            let contact = gjk::penetration(&a, &b).unwrap();
+            let expected = a.intersect_sphere(&b).unwrap();
+            assert!((contact.depth - expected.depth).abs() < 1e-3);
+            if (b.center - a.center).length() > 1e-3 {
+                assert!((contact.normal - expected.normal).length() < 1e-2);
+            }
+        }
+    }
+}
+
+#[test]
+fn nearly_concentric_spheres_converge_within_the_epa_step_limit() {
+    // The Minkowski difference is almost centred on the origin, so EPA has
+    // to refine most of it before its bounds on the depth meet.
+    let a = Sphere::new(v(0.0, 0.0, 0.0), 1.5);
+    let b = Sphere::new(v(0.6, 0.3, 0.4), 1.85);
+    let expected = a.intersect_sphere(&b).unwrap();
+    let contact = gjk::penetration(&a, &b).unwrap();
+    assert!((contact.depth - expected.depth).abs() <= 1e-5 * expected.depth);
+    assert!((contact.normal - expected.normal).length() < 1e-2);
+
+    let a = Sphere::new(Vector3::new(0.0_f32, 0.0, 0.0), 1.5);
+    let b = Sphere::new(Vector3::new(0.6_f32, 0.3, 0.4), 1.85);
+    let expected = a.intersect_sphere(&b).unwrap();
+    let contact = gjk::penetration(&a, &b).unwrap();
+    let tolerance = f32::EPSILON.sqrt();
+    assert!((contact.depth - expected.depth).abs() <= tolerance * expected.depth);
+    assert!((contact.normal - expected.normal).length() < 5e-2);
+}
+
+#[test]
+fn box_box_penetration_matches_sat() {
+    let mut rng = Pcg32::new(11);
+    let mut checked = 0;
+    for _ in 0..200 {
+        let axis = point(&mut rng, 1.0).normalize();
+        let a = Obb::from_rotation(
+            point(&mut rng, 0.5),
+            v(
+                rng.range(0.3, 1.5),
+                rng.range(0.3, 1.5),
+                rng.range(0.3, 1.5),
+            ),
+            Quaternion::from_axis_angle(axis, rng.range(0.0, 3.0)),
+        );
+        let axis = point(&mut rng, 1.0).normalize();
+        let b = Obb::from_rotation(
+            point(&mut rng, 2.0),
+            v(
+                rng.range(0.3, 1.5),
+                rng.range(0.3, 1.5),
+                rng.range(0.3, 1.5),
+            ),
+            Quaternion::from_axis_angle(axis, rng.range(0.0, 3.0)),
+        );
+        match (a.intersect_obb(&b), gjk::penetration(&a, &b)) {
+            (Some(sat), Some(epa)) => {
+                assert!(
+                    (sat.depth - epa.depth).abs() < 1e-4,
+                    "{} vs {}",
+                    sat.depth,
+                    epa.depth
+                );
+                checked += 1;
+            }
+            (None, None) => assert!(gjk::distance(&a, &b) > 0.0),
+            (sat, epa) => {
+                // Only a graze can make the two disagree.
+                let depth = sat.or(epa).unwrap().depth;
+                assert!(depth < 1e-6, "sat {:?} epa {:?}", sat, epa);
+            }
+        }
+    }
+    assert!(checked > 20);
+}
+
+#[test]
+fn separating_along_the_normal_clears_the_overlap() {
+    let a = Cylinder::new(v(0.0, 0.0, 0.0), v(0.0, 2.0, 0.0), 1.0);
+    let b = Cone::new(v(1.5, 1.0, 0.3), v(0.2, 1.4, -0.1), 0.8);
+    let contact = gjk::penetration(&a, &b).unwrap();
+    let s = contact.depth + 1e-4;
+    let offset = v(
+        contact.normal.x * s,
+        contact.normal.y * s,
+        contact.normal.z * s,
+    );
+    let moved = Cone::new(b.apex + offset, b.base + offset, b.radius);
+    assert!(!gjk::intersects(&a, &moved));
+    assert!(gjk::distance(&a, &moved) < 1e-3);
+}
+
+#[test]
+fn capsule_distance_matches_segments() {
+    let mut rng = Pcg32::new(3);
+    for _ in 0..100 {
+        let a = Capsule::new(
+            point(&mut rng, 3.0),
+            point(&mut rng, 3.0),
+            rng.range(0.1, 0.5),
+        );
+        let b = Capsule::new(
+            point(&mut rng, 3.0),
+            point(&mut rng, 3.0),
+            rng.range(0.1, 0.5),
+        );
+        let (p, q) = a.segment().closest_points(&b.segment());
+        let gap = (q - p).length() - a.radius - b.radius;
+        if gap > 1e-3 {
+            assert!((gjk::distance(&a, &b) - gap).abs() < 1e-5);
+        } else if gap < -1e-3 {
+            assert!(gjk::intersects(&a, &b));
+        }
+    }
+}
+
+#[test]
+fn minkowski_sum_of_segment_and_sphere_is_a_capsule() {
+    let segment = Segment::new(v(0.0, 0.0, 0.0), v(0.0, 3.0, 0.0));
+    let swept = MinkowskiSum::new(segment, Sphere::new(v(0.0, 0.0, 0.0), 0.5));
+    let capsule = Capsule::new(segment.start, segment.end, 0.5);
+    let target = Aabb::new(v(1.0, -1.0, -1.0), v(2.0, 5.0, 1.0));
+    assert!((gjk::distance(&swept, &target) - 0.5).abs() < 1e-6);
+    assert!((gjk::distance(&capsule, &target) - 0.5).abs() < 1e-6);
+
+    let closer = Aabb::new(v(0.25, -1.0, -1.0), v(2.0, 5.0, 1.0));
+    let contact = gjk::penetration(&swept, &closer).unwrap();
+    assert!((contact.depth - 0.25).abs() < 1e-6);
+    assert!((contact.normal - v(1.0, 0.0, 0.0)).length() < 1e-6);
+}
+
+#[test]
+fn closest_points_lie_on_the_shapes() {
+    let a = Obb::from_rotation(
+        v(0.0, 0.0, 0.0),
+        v(1.0, 0.5, 0.25),
+        Quaternion::from_axis_angle(v(0.0, 1.0, 0.0), 0.6),
+    );
+    let b = Triangle::new(v(3.0, -1.0, 0.0), v(4.0, 1.0, 0.5), v(2.5, 0.5, -1.0));
+    let (p, q) = gjk::closest_points(&a, &b).unwrap();
+    assert!(a.distance_to_point(p) < 1e-6);
+    assert!(b.distance_to_point(q) < 1e-6);
+    assert!(((q - p).length() - gjk::distance(&a, &b)).abs() < 1e-12);
+}
+
+#[test]
+fn concentric_and_touching_shapes() {
+    let a = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+    let b = Aabb::new(v(-0.25, -0.25, -0.25), v(0.25, 0.25, 0.25));
+    let contact = gjk::penetration(&a, &b).unwrap();
+    assert!((contact.depth - 1.25).abs() < 1e-3);
+
+    let touching = Sphere::new(v(2.0, 0.0, 0.0), 1.0);
+    assert!(gjk::distance(&a, &touching) < 1e-6);
+}
+
+#[test]
+fn coplanar_triangles_report_zero_depth() {
+    let a = Triangle::new(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0));
+    let b = Triangle::new(v(0.5, 0.5, 0.0), v(3.0, 0.5, 0.0), v(0.5, 3.0, 0.0));
+    assert!(gjk::intersects(&a, &b));
+    let contact = gjk::penetration(&a, &b).unwrap();
+    assert!(contact.depth.abs() < 1e-9);
+}
@@ -0,0 +1,118 @@
+use vexel::assert_approx_eq;
+use vexel::matrices::mat4::Mat4;
+use vexel::matrices::projection::{DepthRange, Viewport};
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::transforms::affine3::Affine3;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+fn random_vector(rng: &mut Pcg32) -> Vector4<f64> {
+    Vector4::new(
+        rng.range(-10.0, 10.0),
+        rng.range(-10.0, 10.0),
+        rng.range(-10.0, 10.0),
+        rng.range(-10.0, 10.0),
+    )
+}
+
+#[test]
+fn points_move_and_directions_do_not() {
+    let transform = Affine3::from_translation(Vector3::new(1.0, -2.0, 3.0)).to_mat4();
+    let p = Vector4::point(4.0, 5.0, 6.0);
+    let d = Vector4::direction(4.0, 5.0, 6.0);
+    assert_eq!(transform * p, Vector4::point(5.0, 3.0, 9.0));
+    assert_eq!(transform * d, d);
+
+    let v = Vector3::new(4.0, 5.0, 6.0);
+    assert_eq!(v.extend(1.0), p);
+    assert_eq!(v.extend(0.0), d);
+    assert_eq!(p.truncate(), v);
+    assert_eq!(d.truncate(), v);
+    assert_eq!((transform * p).truncate(), Vector3::new(5.0, 3.0, 9.0));
+}
+
+#[test]
+fn perspective_divide_scales_by_w() {
+    let v = Vector3::new(1.5, -2.0, 8.0);
+    for w in [1.0, 2.0, -0.5, 1e-3] {
+        let homogeneous = Vector4::new(v.x * w, v.y * w, v.z * w, w);
+        assert_approx_eq!(homogeneous.perspective_divide(), v, ulps = 1);
+    }
+    assert_eq!(
+        Vector4::point(1.0, 2.0, 3.0).perspective_divide(),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+
+    let at_infinity = Vector4::direction(1.0, -1.0, 0.0).perspective_divide();
+    assert_eq!(at_infinity.x, f64::INFINITY);
+    assert_eq!(at_infinity.y, f64::NEG_INFINITY);
+    assert!(at_infinity.z.is_nan());
+}
+
+#[test]
+fn viewport_round_trips_through_the_homogeneous_helpers() {
+    let projection = Mat4::perspective_rh(1.2_f64, 1.5, 0.1, 100.0, DepthRange::ZeroToOne);
+    let viewport = Viewport::new(0.0, 0.0, 1200.0, 800.0);
+    let point = Vector3::new(0.25, -0.5, -3.0);
+
+    let window = viewport
+        .project(point, &projection, DepthRange::ZeroToOne)
+        .unwrap();
+    let clip = projection * point.extend(1.0);
+    let ndc = clip.perspective_divide();
+    assert_approx_eq!(window.x, (ndc.x + 1.0) * 600.0, epsilon = 1e-9);
+    assert_approx_eq!(window.y, (ndc.y + 1.0) * 400.0, epsilon = 1e-9);
+
+    let back = viewport
+        .unproject(window, &projection, DepthRange::ZeroToOne)
+        .unwrap();
+    assert_approx_eq!(back, point, epsilon = 1e-9);
+}
+
+#[test]
+fn cross4_is_perpendicular_and_matches_the_determinant() {
+    let mut rng = Pcg32::new(1);
+    for _ in 0..1000 {
+        let (a, b, c, d) = (
+            random_vector(&mut rng),
+            random_vector(&mut rng),
+            random_vector(&mut rng),
+            random_vector(&mut rng),
+        );
+        let n = a.cross4(&b, &c);
+        let scale = a.length() * b.length() * c.length();
+        for v in [a, b, c] {
+            assert_approx_eq!(n.dot(&v) / scale, 0.0, epsilon = 1e-12);
+        }
+        let det = Mat4::from_cols(a, b, c, d).determinant();
+        assert_approx_eq!(n.dot(&d), det, epsilon = 1e-9 * scale * d.length());
+
+        // Swapping two operands flips the sign, like a determinant. Swapping
+        // the last two negates every intermediate exactly.
+        assert_approx_eq!(b.cross4(&a, &c), -n, epsilon = 1e-12 * scale);
+        assert_eq!(a.cross4(&c, &b), -n);
+    }
+}
+
+#[test]
+fn cross4_of_basis_vectors_is_the_fourth() {
+    let x = Vector4::new(1, 0, 0, 0);
+    let y = Vector4::new(0, 1, 0, 0);
+    let z = Vector4::new(0, 0, 1, 0);
+    let w = Vector4::new(0, 0, 0, 1);
+    assert_eq!(x.cross4(&y, &z), w);
+    assert_eq!(y.cross4(&z, &w), -x);
+    assert_eq!(z.cross4(&w, &x), y);
+    assert_eq!(w.cross4(&x, &y), -z);
+
+    // Dependent operands span no volume.
+    let a = Vector4::new(1, 2, 3, 4);
+    assert_eq!(a.cross4(&x, &a), Vector4::new(0, 0, 0, 0));
+    assert_eq!(a.cross4(&(a * 2), &y), Vector4::new(0, 0, 0, 0));
+
+    // The 3D cross product ignores `w` and is a different operation.
+    let p = Vector4::new(1, 0, 0, 7);
+    let q = Vector4::new(0, 1, 0, 9);
+    assert_eq!(p.cross(&q), Vector4::direction(0, 0, 1));
+}
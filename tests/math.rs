@@ -0,0 +1,190 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use vexel::approx::ApproxEq;
+use vexel::math;
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::scalar::Real;
+
+const SAMPLES: usize = 100_000;
+
+/// Values where the functions have to treat signs, limits and NaN specially.
+const SPECIAL: [f64; 11] = [
+    0.0,
+    -0.0,
+    0.5,
+    -0.5,
+    1.0,
+    -1.0,
+    2.5,
+    -2.5,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+/// The smallest and largest floats.
+const EXTREME: [f64; 3] = [5e-324, f64::MIN_POSITIVE, f64::MAX];
+
+/// Asserts that `ours` is within `max_ulps` representable values of the
+/// standard library's result, counting any two NaNs as equal.
+#[track_caller]
+fn assert_close<T: Real + ApproxEq<Scalar = T>>(
+    input: impl std::fmt::Debug,
+    ours: T,
+    std: T,
+    max_ulps: u32,
+) {
+    assert!(
+        (ours.is_nan() && std.is_nan()) || ours.ulps_eq(&std, T::ZERO, max_ulps),
+        "for {input:?}: got {ours:?}, std gives {std:?}"
+    );
+}
+
+/// Returns a float with a random sign and exponent, spread over the whole
+/// finite range including subnormals.
+fn any_float(rng: &mut Pcg32) -> f64 {
+    let exponent = rng.below(0x7ff) << 52;
+    f64::from_bits(rng.next_u64() & !(0x7ff << 52) | exponent)
+}
+
+#[test]
+fn exact_functions_match_std() {
+    let mut rng = Pcg32::new(1);
+    let inputs = (0..SAMPLES)
+        .map(|_| any_float(&mut rng))
+        // Scaled down so that plenty have a fractional part.
+        .flat_map(|x| [x, x * 1e-290])
+        .chain(SPECIAL)
+        .chain(EXTREME);
+    for x in inputs {
+        assert_close(x, math::sqrt(x.abs()), x.abs().sqrt(), 0);
+        assert_close(x, math::floor(x), x.floor(), 0);
+        assert_close(x, math::ceil(x), x.ceil(), 0);
+        assert_close(x, math::round(x), x.round(), 0);
+        assert_close(x, math::trunc(x), x.trunc(), 0);
+        // Matching to the bit includes the sign of zero.
+        assert_eq!(
+            math::trunc(x).is_sign_negative(),
+            x.trunc().is_sign_negative()
+        );
+        assert_eq!(
+            math::ceil(x).is_sign_negative(),
+            x.ceil().is_sign_negative()
+        );
+    }
+    assert!(math::sqrt(-1.0).is_nan());
+    assert_eq!(math::sqrt(-0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(math::round(0.49999999999999994), 0.0);
+}
+
+#[test]
+fn trigonometry_is_accurate() {
+    let mut rng = Pcg32::new(2);
+    for range in [1.0, 10.0, 1e6] {
+        for _ in 0..SAMPLES {
+            let x: f64 = rng.range(-range, range);
+            assert_close(x, math::sin(x), x.sin(), 2);
+            assert_close(x, math::cos(x), x.cos(), 2);
+            assert_close(x, math::tan(x), x.tan(), 3);
+            assert_eq!(math::sin_cos(x), (math::sin(x), math::cos(x)));
+        }
+    }
+
+    // Near multiples of π/2 the reduction must not lose the small remainder.
+    for k in 1..1000 {
+        let x = k as f64 * FRAC_PI_2;
+        assert_close(x, math::sin(x), x.sin(), 2);
+        assert_close(x, math::cos(x), x.cos(), 2);
+    }
+
+    for x in SPECIAL {
+        assert_close(x, math::sin(x), x.sin(), 2);
+        assert_close(x, math::cos(x), x.cos(), 2);
+    }
+    assert_eq!(math::sin(-0.0).to_bits(), (-0.0f64).to_bits());
+
+    // Huge arguments lose accuracy but still give a sine and a cosine.
+    for x in [1e10, 1e100, f64::MAX, -f64::MAX] {
+        let (sin, cos) = math::sin_cos(x);
+        assert!((sin * sin + cos * cos - 1.0).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn inverse_trigonometry_is_accurate() {
+    let mut rng = Pcg32::new(3);
+    for _ in 0..SAMPLES {
+        let x: f64 = rng.range(-1.0, 1.0);
+        assert_close(x, math::asin(x), x.asin(), 2);
+        assert_close(x, math::acos(x), x.acos(), 2);
+        let t = x * 10f64.powi(rng.below(20) as i32 - 10);
+        assert_close(t, math::atan(t), t.atan(), 1);
+        let (y, x) = (rng.range(-10.0, 10.0), rng.range(-10.0, 10.0));
+        assert_close((y, x), math::atan2(y, x), y.atan2(x), 1);
+    }
+
+    // Every combination of signed zeros, infinities and NaN.
+    for y in SPECIAL.into_iter().chain(EXTREME) {
+        for x in SPECIAL.into_iter().chain(EXTREME) {
+            assert_close((y, x), math::atan2(y, x), y.atan2(x), 2);
+        }
+        assert_close(y, math::asin(y), y.asin(), 2);
+        assert_close(y, math::acos(y), y.acos(), 2);
+        assert_close(y, math::atan(y), y.atan(), 1);
+    }
+    assert_eq!(math::acos(-1.0), PI);
+}
+
+#[test]
+fn exponentials_are_accurate() {
+    let mut rng = Pcg32::new(4);
+    for _ in 0..SAMPLES {
+        let x: f64 = rng.range(-745.0, 710.0);
+        assert_close(x, math::exp(x), x.exp(), 1);
+        let x = any_float(&mut rng).abs();
+        assert_close(x, math::ln(x), x.ln(), 1);
+
+        // The error of pow grows with |y·ln x|, which is at most 23 here.
+        let (x, y): (f64, f64) = (rng.range(0.0, 10.0), rng.range(-10.0, 10.0));
+        assert_close((x, y), math::pow(x, y), x.powf(y), 64);
+        let n = rng.below(41) as i32 - 20;
+        let x: f64 = rng.range(-4.0, 4.0);
+        assert_close((x, n), math::powi(x, n), x.powi(n), 16);
+    }
+
+    for x in SPECIAL.into_iter().chain(EXTREME) {
+        assert_close(x, math::exp(x), x.exp(), 1);
+        assert_close(x, math::ln(x), x.ln(), 1);
+    }
+    for x in SPECIAL {
+        for y in SPECIAL {
+            assert_close((x, y), math::pow(x, y), x.powf(y), 2);
+        }
+    }
+    assert_eq!(math::pow(-2.0, 3.0), -8.0);
+    assert_eq!(math::pow(-0.0, -3.0), f64::NEG_INFINITY);
+    assert_eq!(math::exp(1e-300), 1.0);
+}
+
+#[test]
+fn single_precision_functions_are_accurate() {
+    let mut rng = Pcg32::new(5);
+    for _ in 0..SAMPLES {
+        let x: f32 = rng.range(-100.0, 100.0);
+        assert_close(x, math::sqrtf(x.abs()), x.abs().sqrt(), 0);
+        assert_close(x, math::floorf(x), x.floor(), 0);
+        assert_close(x, math::roundf(x), x.round(), 0);
+        assert_close(x, math::sinf(x), x.sin(), 1);
+        assert_close(x, math::cosf(x), x.cos(), 1);
+        assert_close(x, math::tanf(x), x.tan(), 1);
+        assert_close(x, math::atanf(x), x.atan(), 1);
+        assert_close(x, math::expf(x), x.exp(), 1);
+        assert_close(x, math::lnf(x.abs()), x.abs().ln(), 1);
+        let y: f32 = rng.range(-1.0, 1.0);
+        assert_close(y, math::asinf(y), y.asin(), 1);
+        assert_close(y, math::acosf(y), y.acos(), 1);
+        assert_close((y, x), math::atan2f(y, x), y.atan2(x), 1);
+        assert_close((x, y), math::powf(x.abs(), y), x.abs().powf(y), 1);
+    }
+}
@@ -0,0 +1,356 @@
+use vexel::noise::fractal::{Fbm, Ridged, Turbulence};
+use vexel::noise::perlin::Perlin;
+use vexel::noise::simplex::Simplex;
+use vexel::noise::value::Value;
+use vexel::noise::worley::{Distance, Feature, Worley};
+use vexel::noise::{Noise, NoisePoint, Permutation};
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+/// Returns a random point in the cube of half-width `extent` about the
+/// origin.
+fn random_point<P: NoisePoint<Scalar = f64>>(rng: &mut Pcg32, extent: f64) -> P {
+    P::from_components(|_| rng.range(-extent, extent))
+}
+
+/// Returns the point `h` along `axis` from `p`.
+fn nudge<P: NoisePoint<Scalar = f64>>(p: P, axis: usize, h: f64) -> P {
+    p + P::from_components(|i| if i == axis { h } else { 0.0 })
+}
+
+/// Checks the analytic gradient of `noise` against central differences at
+/// random points where `smooth` holds.
+fn assert_gradient<P, N>(noise: &N, rng: &mut Pcg32, smooth: impl Fn(P) -> bool)
+where
+    P: NoisePoint<Scalar = f64> + std::fmt::Debug,
+    N: Noise<P>,
+{
+    let h = 1e-6;
+    let mut checked = 0;
+    for _ in 0..500 {
+        let p: P = random_point(rng, 20.0);
+        if !smooth(p) {
+            continue;
+        }
+        let (value, gradient) = noise.sample_with_gradient(p);
+        assert_eq!(value, noise.sample(p));
+        for axis in 0..P::DIMENSIONS {
+            let numeric =
+                (noise.sample(nudge(p, axis, h)) - noise.sample(nudge(p, axis, -h))) / (2.0 * h);
+            let analytic = gradient.component(axis);
+            assert!(
+                (numeric - analytic).abs() < 1e-5 * (1.0 + analytic.abs()),
+                "{:?} along {}: {} vs {}",
+                p,
+                axis,
+                numeric,
+                analytic
+            );
+        }
+        checked += 1;
+    }
+    assert!(checked > 100);
+}
+
+/// Checks that `noise` stays within `[low, high]` and spreads over most of
+/// that range.
+fn assert_range<P, N>(noise: &N, rng: &mut Pcg32, low: f64, high: f64)
+where
+    P: NoisePoint<Scalar = f64>,
+    N: Noise<P>,
+{
+    let (mut least, mut most) = (f64::INFINITY, f64::NEG_INFINITY);
+    for _ in 0..20_000 {
+        let n = noise.sample(random_point::<P>(rng, 50.0));
+        least = least.min(n);
+        most = most.max(n);
+    }
+    assert!(least >= low && most <= high, "{} to {}", least, most);
+    assert!(most - least > 0.5 * (high - low), "{} to {}", least, most);
+}
+
+#[test]
+fn noise_is_bit_for_bit_reproducible() {
+    fn bits<N>(noise: &N) -> [u64; 4]
+    where
+        N: Noise<f64> + Noise<Vector2<f64>> + Noise<Vector3<f64>> + Noise<Vector4<f64>>,
+    {
+        [
+            noise.sample(0.3).to_bits(),
+            noise.sample(Vector2::new(0.3, -1.7)).to_bits(),
+            noise.sample(Vector3::new(0.3, -1.7, 2.9)).to_bits(),
+            noise.sample(Vector4::new(0.3, -1.7, 2.9, 4.1)).to_bits(),
+        ]
+    }
+
+    // Any change to these means noise saved by users would change too.
+    assert_eq!(
+        bits(&Perlin::new(1)),
+        [
+            0x3f777b7c7820a320,
+            0xbfbb5a532a06dae4,
+            0xbfd0079814fb706b,
+            0x3f5cbf417d3a27ca
+        ]
+    );
+    assert_eq!(
+        bits(&Simplex::new(1)),
+        [
+            0x3fa6b9708db32f71,
+            0xbf6ce5072e2327ec,
+            0xbfd1c1a917bbde5b,
+            0x3fe4a37de6f144dd
+        ]
+    );
+    assert_eq!(
+        bits(&Value::new(1)),
+        [
+            0xbfd4febe5abd5f14,
+            0x3fb8c2ae7e9d8eaa,
+            0xbfb054c86568dcc4,
+            0xbfe2ff60f40535db
+        ]
+    );
+    assert_eq!(
+        bits(&Worley::new(1)),
+        [
+            0x3fd2a1f333333333,
+            0x3fd49aed0fcef352,
+            0x3fd9642e6002369e,
+            0x3fd0bc34f659b9af
+        ]
+    );
+
+    assert_ne!(bits(&Perlin::new(2)), bits(&Perlin::new(1)));
+    assert_ne!(bits(&Simplex::new(2)), bits(&Simplex::new(1)));
+}
+
+#[test]
+fn noise_stays_in_range() {
+    let mut rng = Pcg32::new(1);
+    macro_rules! check {
+        ($noise:expr, $low:expr, $high:expr) => {
+            assert_range::<f64, _>(&$noise, &mut rng, $low, $high);
+            assert_range::<Vector2<f64>, _>(&$noise, &mut rng, $low, $high);
+            assert_range::<Vector3<f64>, _>(&$noise, &mut rng, $low, $high);
+            assert_range::<Vector4<f64>, _>(&$noise, &mut rng, $low, $high);
+        };
+    }
+    check!(Perlin::new(1), -1.0, 1.0);
+    check!(Simplex::new(1), -1.0, 1.0);
+    check!(Value::new(1), -1.0, 1.0);
+    check!(Fbm::new(Simplex::new(1), 5), -1.0, 1.0);
+    check!(Turbulence::new(Simplex::new(1), 5), 0.0, 1.0);
+    check!(Ridged::new(Simplex::new(1), 5), 0.0, 1.0);
+}
+
+#[test]
+fn gradients_match_finite_differences() {
+    let mut rng = Pcg32::new(2);
+    macro_rules! check {
+        ($noise:expr) => {
+            assert_gradient::<f64, _>(&$noise, &mut rng, |_| true);
+            assert_gradient::<Vector2<f64>, _>(&$noise, &mut rng, |_| true);
+            assert_gradient::<Vector3<f64>, _>(&$noise, &mut rng, |_| true);
+            assert_gradient::<Vector4<f64>, _>(&$noise, &mut rng, |_| true);
+        };
+    }
+    check!(Perlin::new(3));
+    check!(Simplex::new(3));
+    check!(Value::new(3));
+    check!(Fbm::new(Perlin::new(3), 4));
+    check!(Fbm::new(Simplex::new(3), 4));
+
+    // Turbulence and ridges crease where an octave crosses zero, so only
+    // check points well away from the creases of every octave.
+    let source = Simplex::new(3);
+    let turbulence = Turbulence::new(&source, 3);
+    let ridged = Ridged::new(&source, 3);
+    let uncreased = |p: Vector3<f64>| {
+        (0..3).all(|octave| {
+            let n: f64 = source.sample(p.scale(2f64.powi(octave)));
+            n.abs() > 1e-3
+        })
+    };
+    assert_gradient(&turbulence, &mut rng, uncreased);
+    assert_gradient(&ridged, &mut rng, uncreased);
+}
+
+#[test]
+fn worley_gradients_match_finite_differences() {
+    let mut rng = Pcg32::new(3);
+    let mut cells = Worley::new(3);
+    for feature in [Feature::F1, Feature::F2MinusF1] {
+        cells.feature = feature;
+        // Away from the walls between cells the nearest features are fixed.
+        let inside = |p: Vector3<f64>| {
+            let (f1, f2) = cells.distances(p);
+            f2 - f1 > 1e-3
+        };
+        assert_gradient(&cells, &mut rng, inside);
+        let inside = |p: Vector2<f64>| {
+            let (f1, f2) = cells.distances(p);
+            f2 - f1 > 1e-3
+        };
+        assert_gradient(&cells, &mut rng, inside);
+    }
+}
+
+#[test]
+fn perlin_vanishes_on_the_lattice() {
+    let perlin = Perlin::new(4);
+    for x in -5..5 {
+        for y in -5..5 {
+            let p = Vector3::new(x as f64, y as f64, 3.0);
+            assert_eq!(perlin.sample(p), 0.0);
+            assert_eq!(perlin.sample(Vector2::new(x as f64, y as f64)), 0.0);
+        }
+    }
+}
+
+#[test]
+fn value_noise_is_flat_on_the_lattice() {
+    let noise = Value::new(4);
+    for x in -5..5 {
+        for y in -5..5 {
+            let (value, gradient) = noise.sample_with_gradient(Vector2::new(x as f64, y as f64));
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(gradient, Vector2::new(0.0, 0.0));
+        }
+    }
+}
+
+#[test]
+fn lattice_noise_tiles_every_256_cells() {
+    let mut rng = Pcg32::new(5);
+    let perlin = Perlin::new(6);
+    let value = Value::new(6);
+    for _ in 0..100 {
+        // Multiples of 1/64 keep the shifted points exact.
+        let p: Vector2<f64> = random_point(&mut rng, 100.0);
+        let p = Vector2::new((p.x * 64.0).round() / 64.0, (p.y * 64.0).round() / 64.0);
+        let shifted = p + Vector2::new(256.0, -512.0);
+        assert_eq!(perlin.sample(p), perlin.sample(shifted));
+        assert_eq!(value.sample(p), value.sample(shifted));
+    }
+}
+
+#[test]
+fn worley_matches_a_regular_grid_without_jitter() {
+    let mut rng = Pcg32::new(7);
+    let mut grid = Worley::new(7);
+    grid.jitter = 0.0;
+    for distance in [
+        Distance::Euclidean,
+        Distance::Manhattan,
+        Distance::Chebyshev,
+    ] {
+        grid.distance = distance;
+        for _ in 0..200 {
+            let p: Vector3<f64> = random_point(&mut rng, 10.0);
+            let mut found = Vec::new();
+            for dx in -2..=2 {
+                for dy in -2..=2 {
+                    for dz in -2..=2 {
+                        let centre = Vector3::new(
+                            p.x.floor() + dx as f64 + 0.5,
+                            p.y.floor() + dy as f64 + 0.5,
+                            p.z.floor() + dz as f64 + 0.5,
+                        );
+                        let d = p - centre;
+                        let (x, y, z) = (d.x.abs(), d.y.abs(), d.z.abs());
+                        found.push(match distance {
+                            Distance::Euclidean => (x * x + y * y + z * z).sqrt(),
+                            Distance::Manhattan => x + y + z,
+                            Distance::Chebyshev => x.max(y).max(z),
+                        });
+                    }
+                }
+            }
+            found.sort_by(f64::total_cmp);
+            let (f1, f2) = grid.distances(p);
+            assert!((f1 - found[0]).abs() < 1e-12);
+            assert!((f2 - found[1]).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn worley_finds_the_nearest_jittered_features() {
+    // Every feature that is nearest or second nearest to some point of a
+    // dense grid can be read back from the Euclidean gradients. None of the
+    // collected features may be closer than what the noise reports.
+    let mut cells = Worley::new(8);
+    let mut features = Vec::new();
+    for i in 0..=120 {
+        for j in 0..=120 {
+            let p = Vector2::new(i as f64 / 20.0, j as f64 / 20.0);
+            for feature in [Feature::F1, Feature::F2] {
+                cells.feature = feature;
+                let (d, g) = cells.sample_with_gradient(p);
+                let found = p - g.scale(d);
+                if features
+                    .iter()
+                    .all(|&known: &Vector2<f64>| (known - found).length() > 1e-9)
+                {
+                    features.push(found);
+                }
+            }
+        }
+    }
+    let mut rng = Pcg32::new(8);
+    for _ in 0..500 {
+        let p = Vector2::new(rng.range(1.5, 4.5), rng.range(1.5, 4.5));
+        let mut distances: Vec<f64> = features.iter().map(|&f| (p - f).length()).collect();
+        distances.sort_by(f64::total_cmp);
+        let (f1, f2) = cells.distances(p);
+        assert!(f1 <= distances[0] + 1e-9);
+        assert!(f2 <= distances[1] + 1e-9);
+        assert!(f1 <= f2);
+    }
+}
+
+#[test]
+fn fractal_combinators() {
+    let source = Perlin::new(9);
+    let p = Vector2::new(0.37, -4.12);
+
+    let mut fbm = Fbm::new(&source, 0);
+    assert_eq!(fbm.sample(p), 0.0);
+    fbm.octaves = 4;
+    fbm.gain = 0.0;
+    assert_eq!(fbm.sample_with_gradient(p), source.sample_with_gradient(p));
+
+    // With a gain of one every octave weighs the same.
+    fbm.gain = 1.0;
+    let expected = (0..4)
+        .map(|octave| source.sample(p.scale(2f64.powi(octave))))
+        .sum::<f64>()
+        / 4.0;
+    assert!((fbm.sample(p) - expected).abs() < 1e-15);
+
+    let turbulence = Turbulence::new(&source, 1);
+    assert_eq!(turbulence.sample(p), source.sample(p).abs());
+    let ridged = Ridged::new(&source, 1);
+    let n: f64 = source.sample(p);
+    assert!((ridged.sample(p) - (1.0 - n.abs()).powi(2)).abs() < 1e-15);
+}
+
+#[test]
+fn permutation_tables() {
+    let mut table = *Permutation::new(10).table();
+    assert!(Permutation::from_table(table).is_some());
+    assert_eq!(
+        Perlin::from_permutation(Permutation::from_table(table).unwrap()),
+        Perlin::new(10)
+    );
+    table.swap(3, 200);
+    let swapped = Perlin::from_permutation(Permutation::from_table(table).unwrap());
+    assert_ne!(swapped, Perlin::new(10));
+    table[3] = table[4];
+    assert!(Permutation::from_table(table).is_none());
+    assert_eq!(Permutation::default(), Permutation::new(0));
+}
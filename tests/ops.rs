@@ -0,0 +1,154 @@
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+#[test]
+fn scalar_operators_work_in_both_orders() {
+    let v = Vector3::new(1.0, -2.0, 4.0);
+    assert_eq!(v * 0.5, Vector3::new(0.5, -1.0, 2.0));
+    assert_eq!(0.5 * v, v * 0.5);
+    assert_eq!(v / 2.0, Vector3::new(0.5, -1.0, 2.0));
+    assert_eq!(4.0 / v, Vector3::new(4.0, -2.0, 1.0));
+    assert_eq!(
+        1.0 / Vector2::new(0.0, -0.0),
+        Vector2::new(f64::INFINITY, f64::NEG_INFINITY)
+    );
+
+    let i = Vector4::new(1, 2, 3, 4);
+    assert_eq!(3 * i, Vector4::new(3, 6, 9, 12));
+    assert_eq!(12 / i, Vector4::new(12, 6, 4, 3));
+    assert_eq!(i / 2, Vector4::new(0, 1, 1, 2));
+    assert_eq!(2u8 * Vector2::new(3u8, 4), Vector2::new(6, 8));
+    assert_eq!(-2i64 * Vector2::new(3, -4), Vector2::new(-6, 8));
+}
+
+#[test]
+fn negation_and_remainder() {
+    let v = Vector4::new(1.0, -0.0, f64::INFINITY, 2.5);
+    let n = -v;
+    assert_eq!(n, Vector4::new(-1.0, 0.0, f64::NEG_INFINITY, -2.5));
+    assert!(n.y.is_sign_positive());
+    assert_eq!(-(-v), v);
+
+    // Like `%` on the components, the remainder takes the sign of the
+    // dividend, unlike `rem_euclid`.
+    let a = Vector3::new(7, -7, 7);
+    let b = Vector3::new(3, 3, -3);
+    assert_eq!(a % b, Vector3::new(1, -1, 1));
+    assert_eq!(a.rem_euclid(&b), Vector3::new(1, 2, 1));
+    assert_eq!(
+        Vector2::new(5.5, -5.5) % Vector2::new(2.0, 2.0),
+        Vector2::new(1.5, -1.5)
+    );
+}
+
+#[test]
+fn compound_assignment_matches_the_binary_operators() {
+    let a = Vector3::new(1.5, -2.0, 8.0);
+    let b = Vector3::new(0.5, 4.0, -3.0);
+
+    let mut v = a;
+    v += b;
+    assert_eq!(v, a + b);
+    v -= b;
+    assert_eq!(v, a + b - b);
+
+    let mut v = a;
+    v *= b;
+    assert_eq!(v, a * b);
+    let mut v = a;
+    v /= b;
+    assert_eq!(v, a / b);
+    let mut v = a;
+    v %= b;
+    assert_eq!(v, a % b);
+
+    let mut v = a;
+    v *= 3.0;
+    assert_eq!(v, a * 3.0);
+    v /= 3.0;
+    assert_eq!(v, a * 3.0 / 3.0);
+
+    let mut v = Vector4::new(1, 2, 3, 4);
+    v += &Vector4::new(1, 1, 1, 1);
+    v *= &Vector4::new(2, 2, 2, 2);
+    v -= &Vector4::new(1, 1, 1, 1);
+    v /= &Vector4::new(1, 1, 1, 3);
+    v %= &Vector4::new(2, 2, 2, 2);
+    assert_eq!(v, Vector4::new(1, 1, 1, 1));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn borrowed_operands_match_owned_ones() {
+    let a = Vector2::new(3.0, -1.0);
+    let b = Vector2::new(0.5, 2.0);
+    for (owned, borrowed) in [
+        (a + b, [&a + &b, a + &b, &a + b]),
+        (a - b, [&a - &b, a - &b, &a - b]),
+        (a * b, [&a * &b, a * &b, &a * b]),
+        (a / b, [&a / &b, a / &b, &a / b]),
+        (a % b, [&a % &b, a % &b, &a % b]),
+    ] {
+        assert_eq!(borrowed, [owned; 3]);
+    }
+    assert_eq!(-&a, -a);
+    assert_eq!(&a * 2.0, a * 2.0);
+    assert_eq!(&a / 2.0, a / 2.0);
+    assert_eq!(2.0 * &a, 2.0 * a);
+    assert_eq!(2.0 / &a, 2.0 / a);
+}
+
+#[test]
+fn indexing_reads_and_writes_components() {
+    let mut v = Vector4::new(1, 2, 3, 4);
+    assert_eq!([v[0], v[1], v[2], v[3]], [1, 2, 3, 4]);
+    for i in 0..4 {
+        v[i] *= 10;
+    }
+    assert_eq!(v, Vector4::new(10, 20, 30, 40));
+
+    let mut w = Vector3::new(0.0, 0.0, 0.0);
+    w[2] = 1.0;
+    assert_eq!(w, Vector3::new(0.0, 0.0, 1.0));
+    let mut u = Vector2::new(1, 2);
+    u[0] = u[1];
+    assert_eq!(u, Vector2::new(2, 2));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: Vector3 has 3 components but the index is 3")]
+fn indexing_past_the_last_component_panics() {
+    let v = Vector3::new(1, 2, 3);
+    let _ = v[3];
+}
+
+#[test]
+fn iterators_sum_and_multiply() {
+    let vs = [
+        Vector3::new(1, 2, 3),
+        Vector3::new(4, 5, 6),
+        Vector3::new(-1, 0, 2),
+    ];
+    assert_eq!(vs.iter().sum::<Vector3<i32>>(), Vector3::new(4, 7, 11));
+    assert_eq!(vs.into_iter().sum::<Vector3<i32>>(), Vector3::new(4, 7, 11));
+    assert_eq!(vs.iter().product::<Vector3<i32>>(), Vector3::new(-4, 0, 36));
+    assert_eq!(
+        vs.into_iter().product::<Vector3<i32>>(),
+        Vector3::new(-4, 0, 36)
+    );
+
+    let empty: [Vector2<f64>; 0] = [];
+    assert_eq!(empty.iter().sum::<Vector2<f64>>(), Vector2::new(0.0, 0.0));
+    assert_eq!(
+        empty.iter().product::<Vector2<f64>>(),
+        Vector2::new(1.0, 1.0)
+    );
+
+    let points = [
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4::new(3.0, 2.0, 1.0, 0.0),
+    ];
+    let centroid = points.iter().sum::<Vector4<f64>>() / points.len() as f64;
+    assert_eq!(centroid, Vector4::new(2.0, 2.0, 2.0, 2.0));
+}
@@ -0,0 +1,323 @@
+mod common;
+
+use common::v;
+use vexel::geometry::aabb::Aabb;
+use vexel::geometry::capsule::Capsule;
+use vexel::geometry::obb::Obb;
+use vexel::geometry::overlap::{Contact, PlaneSide};
+use vexel::geometry::plane::Plane;
+use vexel::geometry::sphere::Sphere;
+use vexel::geometry::triangle::Triangle;
+use vexel::quaternion::Quaternion;
+use vexel::vectors::vector3::Vector3;
+
+/// Returns how far to move the second shape so that it only just separates.
+fn push(contact: &Contact<f64>) -> Vector3<f64> {
+    let s = contact.depth + 1e-6;
+    v(
+        contact.normal.x * s,
+        contact.normal.y * s,
+        contact.normal.z * s,
+    )
+}
+
+fn obb_moved(obb: &Obb<f64>, by: Vector3<f64>) -> Obb<f64> {
+    Obb::new(obb.center + by, obb.half_extents, obb.axes)
+}
+
+#[test]
+fn sphere_sphere_touching_and_concentric() {
+    let a = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+    let touching = Sphere::new(v(0.0, 0.0, 3.0), 2.0);
+    let contact = a.intersect_sphere(&touching).unwrap();
+    assert_eq!(contact.depth, 0.0);
+    assert_eq!(contact.normal, v(0.0, 0.0, 1.0));
+    assert_eq!(contact.point, v(0.0, 0.0, 1.0));
+
+    let concentric = Sphere::new(v(0.0, 0.0, 0.0), 0.5);
+    let contact = a.intersect_sphere(&concentric).unwrap();
+    assert_eq!(contact.depth, 1.5);
+    assert!((contact.normal.length() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn sphere_sphere_push_separates() {
+    let a = Sphere::new(v(1.0, -2.0, 0.5), 1.5);
+    let b = Sphere::new(v(2.0, -1.0, 1.0), 0.75);
+    let contact = a.intersect_sphere(&b).unwrap();
+    let moved = Sphere::new(b.center + push(&contact), b.radius);
+    assert!(a.intersect_sphere(&moved).is_none());
+}
+
+#[test]
+fn sphere_aabb_outside_face_edge_and_corner() {
+    let aabb = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+
+    let face = Sphere::new(v(0.5, 1.5, 0.5), 1.0);
+    let contact = face.intersect_aabb(&aabb).unwrap();
+    assert_eq!(contact.normal, v(0.0, -1.0, 0.0));
+    assert_eq!(contact.depth, 0.5);
+    assert_eq!(contact.point, v(0.5, 0.75, 0.5));
+
+    let corner = Sphere::new(v(1.5, 1.5, 1.5), 1.0);
+    let contact = corner.intersect_aabb(&aabb).unwrap();
+    let diagonal = -1.0 / 3.0_f64.sqrt();
+    assert!((contact.normal - v(diagonal, diagonal, diagonal)).length() < 1e-12);
+    assert!((contact.depth - (1.0 - 0.75_f64.sqrt())).abs() < 1e-12);
+
+    let past_corner = Sphere::new(v(1.6, 1.6, 1.6), 1.0);
+    assert!(past_corner.intersect_aabb(&aabb).is_none());
+
+    let edge = Sphere::new(v(1.5, 1.5, 0.5), 1.0);
+    let contact = edge.intersect_aabb(&aabb).unwrap();
+    assert!(contact.normal.z.abs() < 1e-12);
+}
+
+#[test]
+fn sphere_aabb_center_inside_uses_nearest_face() {
+    let aabb = Aabb::new(v(0.0, 0.0, 0.0), v(4.0, 4.0, 4.0));
+    let sphere = Sphere::new(v(2.0, 3.5, 2.0), 1.0);
+    let contact = sphere.intersect_aabb(&aabb).unwrap();
+    assert_eq!(contact.normal, v(0.0, -1.0, 0.0));
+    assert_eq!(contact.depth, 1.5);
+
+    let moved = Aabb::new(aabb.min + push(&contact), aabb.max + push(&contact));
+    assert!(sphere.intersect_aabb(&moved).is_none());
+}
+
+#[test]
+fn aabb_aabb_touching_and_contained() {
+    let a = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    let touching = Aabb::new(v(1.0, 0.0, 0.0), v(2.0, 1.0, 1.0));
+    let contact = a.intersect_aabb(&touching).unwrap();
+    assert_eq!(contact.depth, 0.0);
+    assert_eq!(contact.normal, v(1.0, 0.0, 0.0));
+
+    let outer = Aabb::new(v(0.0, 0.0, 0.0), v(10.0, 10.0, 10.0));
+    let inner = Aabb::new(v(1.0, 4.0, 4.0), v(2.0, 6.0, 6.0));
+    let contact = outer.intersect_aabb(&inner).unwrap();
+    assert_eq!(contact.normal, v(-1.0, 0.0, 0.0));
+    assert_eq!(contact.depth, 2.0);
+    let moved = Aabb::new(inner.min + push(&contact), inner.max + push(&contact));
+    assert!(outer.intersect_aabb(&moved).is_none());
+}
+
+#[test]
+fn aabb_aabb_separated_on_each_axis() {
+    let a = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    for offset in [v(1.1, 0.0, 0.0), v(0.0, -1.1, 0.0), v(0.0, 0.0, 1.1)] {
+        let b = Aabb::new(a.min + offset, a.max + offset);
+        assert!(a.intersect_aabb(&b).is_none());
+    }
+}
+
+#[test]
+fn obb_obb_agrees_with_aabb_when_axis_aligned() {
+    let identity = Quaternion::identity();
+    let a = Obb::from_rotation(v(0.0, 0.0, 0.0), v(1.0, 2.0, 1.0), identity);
+    let b = Obb::from_rotation(v(1.5, 0.5, -0.25), v(1.0, 1.0, 1.0), identity);
+    let contact = a.intersect_obb(&b).unwrap();
+    assert_eq!(contact.normal, v(1.0, 0.0, 0.0));
+    assert_eq!(contact.depth, 0.5);
+
+    let apart = obb_moved(&b, v(0.6, 0.0, 0.0));
+    assert!(a.intersect_obb(&apart).is_none());
+}
+
+#[test]
+fn obb_obb_separated_only_by_an_edge_axis() {
+    // Two cubes turned so their edges cross like an X; no face normal
+    // separates them but the cross product of the two edges does.
+    let half = v(1.0, 1.0, 1.0);
+    let a_rotation = Quaternion::from_axis_angle(v(1.0, 0.0, 0.0), std::f64::consts::FRAC_PI_4);
+    let b_rotation = Quaternion::from_axis_angle(v(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    let a = Obb::from_rotation(v(0.0, 0.0, 0.0), half, a_rotation);
+    let reach = 2.0_f64.sqrt() * 2.0;
+
+    let apart = Obb::from_rotation(v(0.0, reach + 0.01, 0.0), half, b_rotation);
+    assert!(a.intersect_obb(&apart).is_none());
+
+    let crossing = Obb::from_rotation(v(0.0, reach - 0.01, 0.0), half, b_rotation);
+    let contact = a.intersect_obb(&crossing).unwrap();
+    assert!((contact.depth - 0.01).abs() < 1e-9);
+    assert!((contact.normal - v(0.0, 1.0, 0.0)).length() < 1e-9);
+}
+
+#[test]
+fn obb_obb_push_separates() {
+    let a = Obb::from_rotation(
+        v(0.0, 0.0, 0.0),
+        v(1.0, 0.5, 2.0),
+        Quaternion::from_axis_angle(v(1.0, 1.0, 0.0).normalize(), 0.7),
+    );
+    let b = Obb::from_rotation(
+        v(1.2, 0.4, 0.8),
+        v(0.5, 1.5, 0.5),
+        Quaternion::from_axis_angle(v(0.0, 1.0, 1.0).normalize(), -1.1),
+    );
+    let contact = a.intersect_obb(&b).unwrap();
+    assert!(contact.depth > 0.0);
+    assert!(a.intersect_obb(&obb_moved(&b, push(&contact))).is_none());
+}
+
+#[test]
+fn triangle_aabb_face_and_edge_cases() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+
+    let slicing = Triangle::new(v(-3.0, 0.2, -3.0), v(3.0, 0.2, -3.0), v(0.0, 0.2, 3.0));
+    let contact = slicing.intersect_aabb(&aabb).unwrap();
+    assert_eq!(contact.normal, v(0.0, -1.0, 0.0));
+    assert!((contact.depth - 0.8).abs() < 1e-12);
+
+    // Neither the box faces nor the triangle's plane separate these; only
+    // the cross product of a triangle edge with a box axis does.
+    let beyond_edge = Triangle::new(v(-1.6, 0.3, -0.8), v(0.6, 0.8, -2.6), v(-2.9, 2.0, -1.4));
+    assert!(beyond_edge.intersect_aabb(&aabb).is_none());
+
+    let inside = Triangle::new(v(-0.5, 0.0, 0.0), v(0.5, 0.0, 0.0), v(0.0, 0.5, 0.0));
+    let contact = inside.intersect_aabb(&aabb).unwrap();
+    assert!(contact.depth > 0.0);
+}
+
+#[test]
+fn triangle_aabb_push_separates() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let triangle = Triangle::new(v(0.5, 0.8, -2.0), v(2.0, 0.3, 1.0), v(0.9, 2.0, 0.4));
+    let contact = triangle.intersect_aabb(&aabb).unwrap();
+    let moved = Aabb::new(aabb.min + push(&contact), aabb.max + push(&contact));
+    assert!(triangle.intersect_aabb(&moved).is_none());
+}
+
+#[test]
+fn sphere_triangle_face_edge_vertex_and_coplanar() {
+    let triangle = Triangle::new(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 0.0, 2.0));
+
+    let edge = Sphere::new(v(1.0, 0.0, -0.5), 1.0);
+    let contact = edge.intersect_triangle(&triangle).unwrap();
+    assert_eq!(contact.normal, v(0.0, 0.0, 1.0));
+    assert_eq!(contact.depth, 0.5);
+
+    let vertex = Sphere::new(v(-0.6, 0.0, -0.8), 1.0);
+    let contact = vertex.intersect_triangle(&triangle).unwrap();
+    assert!((contact.normal - v(0.6, 0.0, 0.8)).length() < 1e-12);
+    assert!(contact.depth.abs() < 1e-12);
+
+    let coplanar = Sphere::new(v(0.5, 0.0, 0.5), 0.25);
+    let contact = coplanar.intersect_triangle(&triangle).unwrap();
+    assert_eq!(contact.depth, 0.25);
+    assert_eq!(contact.normal, triangle.normal() * v(-1.0, -1.0, -1.0));
+
+    let above = Sphere::new(v(0.5, 1.5, 0.5), 1.0);
+    assert!(above.intersect_triangle(&triangle).is_none());
+}
+
+#[test]
+fn capsule_capsule_parallel_crossing_and_end_to_end() {
+    let a = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 2.0, 0.0), 0.5);
+
+    let parallel = Capsule::new(v(0.8, 1.0, 0.0), v(0.8, 5.0, 0.0), 0.5);
+    let contact = a.intersect_capsule(&parallel).unwrap();
+    assert_eq!(contact.normal, v(1.0, 0.0, 0.0));
+    assert!((contact.depth - 0.2).abs() < 1e-12);
+
+    let crossing = Capsule::new(v(-1.0, 1.0, 0.0), v(1.0, 1.0, 0.0), 0.25);
+    let contact = a.intersect_capsule(&crossing).unwrap();
+    assert_eq!(contact.depth, 0.75);
+    assert!(contact.normal.dot(&v(0.0, 1.0, 0.0)).abs() < 1e-12);
+    assert!(contact.normal.dot(&v(1.0, 0.0, 0.0)).abs() < 1e-12);
+
+    let end_to_end = Capsule::new(v(0.0, 2.75, 0.0), v(0.0, 4.0, 0.0), 0.5);
+    let contact = a.intersect_capsule(&end_to_end).unwrap();
+    assert_eq!(contact.normal, v(0.0, 1.0, 0.0));
+    assert_eq!(contact.depth, 0.25);
+
+    let apart = Capsule::new(v(0.0, 3.1, 0.0), v(0.0, 4.0, 0.0), 0.5);
+    assert!(a.intersect_capsule(&apart).is_none());
+}
+
+#[test]
+fn plane_classification() {
+    let plane = Plane::from_point_normal(v(0.0, 1.0, 0.0), v(0.0, 1.0, 0.0));
+
+    let touching = Sphere::new(v(0.0, 2.0, 0.0), 1.0);
+    assert_eq!(plane.classify_sphere(&touching), PlaneSide::Intersecting);
+    let below = Sphere::new(v(0.0, -0.5, 0.0), 1.0);
+    assert_eq!(plane.classify_sphere(&below), PlaneSide::Back);
+
+    let aabb = Aabb::new(v(0.0, 1.5, 0.0), v(1.0, 2.0, 1.0));
+    assert_eq!(plane.classify_aabb(&aabb), PlaneSide::Front);
+    let across = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 2.0, 1.0));
+    assert_eq!(plane.classify_aabb(&across), PlaneSide::Intersecting);
+
+    let turned = Quaternion::from_axis_angle(v(1.0, 0.0, 0.0), std::f64::consts::FRAC_PI_4);
+    let obb = Obb::from_rotation(v(0.0, 3.0, 0.0), v(1.0, 1.0, 1.0), turned);
+    assert_eq!(plane.classify_obb(&obb), PlaneSide::Front);
+    let lower = obb_moved(&obb, v(0.0, -0.7, 0.0));
+    assert_eq!(plane.classify_obb(&lower), PlaneSide::Intersecting);
+
+    let capsule = Capsule::new(v(0.0, -3.0, 0.0), v(5.0, 0.0, 0.0), 0.5);
+    assert_eq!(plane.classify_capsule(&capsule), PlaneSide::Back);
+    let tilted = Capsule::new(v(0.0, -3.0, 0.0), v(5.0, 0.6, 0.0), 0.5);
+    assert_eq!(plane.classify_capsule(&tilted), PlaneSide::Intersecting);
+}
+
+#[test]
+fn plane_half_space_contacts() {
+    let plane = Plane::from_point_normal(v(0.0, 0.0, 0.0), v(1.0, 1.0, 0.0));
+    let n = plane.normal;
+
+    let sphere = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+    let contact = plane.intersect_sphere(&sphere).unwrap();
+    assert_eq!(contact.normal, n);
+    assert!((contact.depth - 1.0).abs() < 1e-12);
+
+    let deep = Sphere::new(v(-5.0, -5.0, 0.0), 1.0);
+    let contact = plane.intersect_sphere(&deep).unwrap();
+    assert!((contact.depth - (50.0_f64.sqrt() + 1.0)).abs() < 1e-12);
+
+    let aabb = Aabb::new(v(-0.5, 0.0, 0.0), v(2.0, 2.0, 2.0));
+    let contact = plane.intersect_aabb(&aabb).unwrap();
+    assert!((contact.depth - 0.5 / 2.0_f64.sqrt()).abs() < 1e-12);
+    let moved = Aabb::new(aabb.min + push(&contact), aabb.max + push(&contact));
+    assert!(plane.intersect_aabb(&moved).is_none());
+
+    let capsule = Capsule::new(v(3.0, 3.0, 0.0), v(4.0, 4.0, 0.0), 1.0);
+    assert!(plane.intersect_capsule(&capsule).is_none());
+
+    let obb = Obb::from_rotation(v(1.0, 1.0, 0.0), v(1.0, 1.0, 1.0), Quaternion::identity());
+    let contact = plane.intersect_obb(&obb).unwrap();
+    assert!(plane
+        .intersect_obb(&obb_moved(&obb, push(&contact)))
+        .is_none());
+}
+
+#[test]
+fn triangle_aabb_small_triangle_separated_only_by_its_face() {
+    // A triangle with centimetre edges hovering just off the corner of the
+    // box, facing it. Only the triangle's own plane separates the two.
+    let aabb = Aabb::new(
+        Vector3::new(-1.0f32, -1.0, -1.0),
+        Vector3::new(1.0, 1.0, 1.0),
+    );
+    let corner = Vector3::new(1.0f32, 1.0, 1.0);
+    let n = corner.normalize();
+    let u = Vector3::new(1.0f32, -1.0, 0.0).normalize();
+    let w = n.cross(&u);
+    for (scale, gap) in [(1.0f32, 1e-2), (1e-2, 1.7e-3), (1e-3, 1e-4)] {
+        let center = corner + n * gap;
+        let radius = scale / 3.0f32.sqrt();
+        let vertex = |angle: f32| center + (u * angle.cos() + w * angle.sin()) * radius;
+        let triangle = Triangle::new(vertex(0.0), vertex(2.094_395), vertex(4.188_79));
+        assert!(
+            triangle.intersect_aabb(&aabb).is_none(),
+            "edges of {scale} at {gap} from the corner"
+        );
+
+        let touching = Triangle::new(
+            triangle.a - n * (2.0 * gap),
+            triangle.b - n * (2.0 * gap),
+            triangle.c - n * (2.0 * gap),
+        );
+        assert!(touching.intersect_aabb(&aabb).is_some());
+    }
+}
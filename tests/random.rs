@@ -0,0 +1,225 @@
+use vexel::quaternion::Quaternion;
+use vexel::random::pcg::Pcg32;
+use vexel::random::sample;
+use vexel::random::xoshiro::Xoshiro256;
+use vexel::random::Rng;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+
+const SAMPLES: usize = 100_000;
+
+/// A generator that always returns the same bits, to probe edge cases.
+struct Constant(u64);
+
+impl Rng for Constant {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+
+/// Returns the mean of `values`.
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+    sum / count as f64
+}
+
+#[test]
+fn generators_match_reference_outputs() {
+    // From the demo program of the PCG reference implementation.
+    let mut pcg = Pcg32::with_stream(42, 54);
+    let outputs: Vec<u32> = (0..6).map(|_| pcg.next_u32()).collect();
+    assert_eq!(
+        outputs,
+        [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+    );
+
+    let mut xoshiro = Xoshiro256::from_state([1, 2, 3, 4]).unwrap();
+    let outputs: Vec<u64> = (0..4).map(|_| xoshiro.next_u64()).collect();
+    assert_eq!(
+        outputs,
+        [0x2800001, 0x3800067, 0xcc00003800067, 0xcc201994400b2]
+    );
+}
+
+#[test]
+fn seeds_and_streams_are_reproducible() {
+    let draw = |mut rng: Pcg32| -> Vec<u64> { (0..8).map(|_| rng.next_u64()).collect() };
+    assert_eq!(draw(Pcg32::new(7)), draw(Pcg32::new(7)));
+    assert_ne!(draw(Pcg32::new(7)), draw(Pcg32::new(8)));
+    assert_ne!(
+        draw(Pcg32::with_stream(7, 1)),
+        draw(Pcg32::with_stream(7, 2))
+    );
+
+    let mut a = Xoshiro256::new(7);
+    let mut b = a.clone();
+    for _ in 0..8 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+    assert_ne!(Xoshiro256::new(7), Xoshiro256::new(8));
+
+    // Generators can be borrowed without losing their place.
+    let mut rng = Pcg32::new(3);
+    let mut copy = rng.clone();
+    sample::on_sphere::<f64, _>(&mut &mut rng);
+    sample::on_sphere::<f64, _>(&mut copy);
+    assert_eq!(rng, copy);
+}
+
+#[test]
+fn uniform_values_stay_in_range() {
+    assert_eq!(Constant(0).uniform::<f64>(), 0.0);
+    assert!(Constant(u64::MAX).uniform::<f64>() < 1.0);
+    assert!(Constant(u64::MAX).uniform::<f32>() < 1.0);
+
+    let mut rng = Xoshiro256::new(1);
+    let mut buckets = [0usize; 10];
+    for _ in 0..SAMPLES {
+        let x: f64 = rng.uniform();
+        assert!((0.0..1.0).contains(&x));
+        buckets[(x * 10.0) as usize] += 1;
+    }
+    for count in buckets {
+        assert!((count as f64 / SAMPLES as f64 - 0.1).abs() < 0.005);
+    }
+
+    let mut rng = Pcg32::new(1);
+    let mut counts = [0usize; 7];
+    for _ in 0..SAMPLES {
+        counts[rng.below(7) as usize] += 1;
+    }
+    for count in counts {
+        assert!((count as f64 / SAMPLES as f64 - 1.0 / 7.0).abs() < 0.005);
+    }
+    assert_eq!(rng.below(1), 0);
+    assert!(rng.below(u64::MAX) < u64::MAX);
+}
+
+#[test]
+fn gaussian_moments() {
+    let mut rng = Pcg32::new(2);
+    let values: Vec<f64> = (0..SAMPLES).map(|_| rng.gaussian()).collect();
+    let mu = mean(values.iter().copied());
+    let variance = mean(values.iter().map(|v| (v - mu) * (v - mu)));
+    assert!(mu.abs() < 0.01);
+    assert!((variance - 1.0).abs() < 0.02);
+    // About 68% of a normal distribution lies within one standard deviation.
+    let within = values.iter().filter(|v| v.abs() < 1.0).count();
+    assert!((within as f64 / SAMPLES as f64 - 0.6827).abs() < 0.005);
+
+    let vectors: Vec<Vector3<f64>> = (0..SAMPLES).map(|_| sample::gaussian3(&mut rng)).collect();
+    assert!(mean(vectors.iter().map(|v| v.x * v.y)).abs() < 0.01);
+    assert!((mean(vectors.iter().map(|v| v.length_squared())) - 3.0).abs() < 0.05);
+    let planar: Vector2<f64> = sample::gaussian2(&mut rng);
+    assert!(planar.x.is_finite() && planar.y.is_finite());
+}
+
+#[test]
+fn single_precision_draws_round_the_double_precision_ones() {
+    // Both are computed in `f64` with the crate's own math functions, so an
+    // `f32` draw is the `f64` draw rounded, whatever the platform.
+    let (mut a, mut b) = (Pcg32::new(4), Pcg32::new(4));
+    for _ in 0..1000 {
+        assert_eq!(a.gaussian::<f32>(), b.gaussian::<f64>() as f32);
+        let (p, q): (Vector2<f32>, Vector2<f64>) =
+            (sample::on_circle(&mut a), sample::on_circle(&mut b));
+        assert_eq!(p, Vector2::new(q.x as f32, q.y as f32));
+    }
+}
+
+#[test]
+fn points_fill_boxes_discs_and_balls() {
+    let mut rng = Pcg32::new(3);
+    let (min, max) = (Vector3::new(-1.0, 2.0, 5.0), Vector3::new(1.0, 3.0, 9.0));
+    let points: Vec<_> = (0..SAMPLES)
+        .map(|_| sample::in_box(&mut rng, min, max))
+        .collect();
+    for p in &points {
+        assert!((min.x..max.x).contains(&p.x));
+        assert!((min.y..max.y).contains(&p.y));
+        assert!((min.z..max.z).contains(&p.z));
+    }
+    assert!((mean(points.iter().map(|p| p.z)) - 7.0).abs() < 0.02);
+
+    let corner = sample::in_rect(&mut rng, Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
+    assert_eq!(corner.x, 0.0);
+
+    // The share of points within half the radius is the share of the area.
+    let discs: Vec<Vector2<f64>> = (0..SAMPLES).map(|_| sample::in_disc(&mut rng)).collect();
+    assert!(discs.iter().all(|p| p.length() <= 1.0));
+    let inner = discs.iter().filter(|p| p.length() < 0.5).count();
+    assert!((inner as f64 / SAMPLES as f64 - 0.25).abs() < 0.005);
+    let right = discs.iter().filter(|p| p.x > 0.0).count();
+    assert!((right as f64 / SAMPLES as f64 - 0.5).abs() < 0.005);
+
+    let balls: Vec<Vector3<f64>> = (0..SAMPLES).map(|_| sample::in_sphere(&mut rng)).collect();
+    assert!(balls.iter().all(|p| p.length() <= 1.0));
+    let inner = balls.iter().filter(|p| p.length() < 0.5).count();
+    assert!((inner as f64 / SAMPLES as f64 - 0.125).abs() < 0.005);
+}
+
+#[test]
+fn directions_are_uniform() {
+    let mut rng = Xoshiro256::new(4);
+    let circle: Vec<Vector2<f64>> = (0..SAMPLES).map(|_| sample::on_circle(&mut rng)).collect();
+    assert!(circle.iter().all(|d| (d.length() - 1.0).abs() < 1e-12));
+    assert!(mean(circle.iter().map(|d| d.x)).abs() < 0.01);
+    assert!((mean(circle.iter().map(|d| d.y * d.y)) - 0.5).abs() < 0.01);
+
+    let sphere: Vec<Vector3<f64>> = (0..SAMPLES).map(|_| sample::on_sphere(&mut rng)).collect();
+    assert!(sphere.iter().all(|d| (d.length() - 1.0).abs() < 1e-12));
+    for axis in [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ] {
+        assert!(mean(sphere.iter().map(|d| d.dot(&axis))).abs() < 0.01);
+        assert!((mean(sphere.iter().map(|d| d.dot(&axis).powi(2))) - 1.0 / 3.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn cosine_weighted_hemisphere() {
+    let mut rng = Pcg32::new(5);
+    for normal in [
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(1.0, 2.0, -2.0).normalize(),
+        Vector3::new(-0.6, 0.0, 0.8),
+    ] {
+        let directions: Vec<Vector3<f64>> = (0..SAMPLES)
+            .map(|_| sample::cosine_hemisphere(&mut rng, normal))
+            .collect();
+        for d in &directions {
+            assert!((d.length() - 1.0).abs() < 1e-9);
+            assert!(d.dot(&normal) >= -1e-12);
+        }
+        // For a density proportional to cos θ, the mean of cos θ is 2/3 and
+        // the share within 60° of the normal is 1 - cos²60° = 3/4.
+        let cosines: Vec<f64> = directions.iter().map(|d| d.dot(&normal)).collect();
+        assert!((mean(cosines.iter().copied()) - 2.0 / 3.0).abs() < 0.005);
+        let steep = cosines.iter().filter(|&&c| c > 0.5).count();
+        assert!((steep as f64 / SAMPLES as f64 - 0.75).abs() < 0.005);
+    }
+}
+
+#[test]
+fn rotations_are_uniform() {
+    let mut rng = Pcg32::new(6);
+    let rotations: Vec<Quaternion<f64>> =
+        (0..SAMPLES).map(|_| sample::rotation(&mut rng)).collect();
+    assert!(rotations.iter().all(|q| (q.length() - 1.0).abs() < 1e-12));
+
+    // A uniform rotation carries any fixed direction to a uniform one.
+    let v = Vector3::new(0.0, 0.6, 0.8);
+    let rotated: Vec<_> = rotations.iter().map(|q| q.rotate(v)).collect();
+    assert!(mean(rotated.iter().map(|d| d.x)).abs() < 0.01);
+    assert!(mean(rotated.iter().map(|d| d.z)).abs() < 0.01);
+    assert!((mean(rotated.iter().map(|d| d.y * d.y)) - 1.0 / 3.0).abs() < 0.01);
+
+    // The rotation angle θ of a uniform rotation has density (1 - cos θ) / π,
+    // so its mean is π/2 + 2/π.
+    let angles = rotations.iter().map(|q| 2.0 * q.w.abs().min(1.0).acos());
+    let expected = std::f64::consts::FRAC_PI_2 + 2.0 / std::f64::consts::PI;
+    assert!((mean(angles) - expected).abs() < 0.01);
+}
@@ -0,0 +1,459 @@
+mod common;
+
+use common::v;
+use vexel::assert_approx_eq;
+use vexel::geometry::aabb::Aabb;
+use vexel::geometry::capsule::Capsule;
+use vexel::geometry::cone::Cone;
+use vexel::geometry::cylinder::Cylinder;
+use vexel::geometry::obb::Obb;
+use vexel::geometry::plane::Plane;
+use vexel::geometry::ray::Ray;
+use vexel::geometry::sphere::Sphere;
+use vexel::geometry::triangle::Triangle;
+use vexel::matrices::mat3::Mat3;
+use vexel::quaternion::Quaternion;
+use vexel::vectors::vector3::Vector3;
+
+#[test]
+fn plane_hits_from_both_sides() {
+    let plane = Plane::from_point_normal(v(0.0, 2.0, 0.0), v(0.0, 1.0, 0.0));
+
+    let from_above = Ray::new(v(0.0, 5.0, 0.0), v(0.0, -1.0, 0.0));
+    let hit = from_above.intersect_plane(&plane).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, v(0.0, 1.0, 0.0));
+
+    let from_below = Ray::new(v(0.0, -1.0, 0.0), v(0.0, 1.0, 0.0));
+    let hit = from_below.intersect_plane(&plane).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, v(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn plane_rejects_parallel_and_receding_rays() {
+    let plane = Plane::from_point_normal(v(0.0, 0.0, 0.0), v(0.0, 1.0, 0.0));
+    let parallel = Ray::new(v(0.0, 1.0, 0.0), v(1.0, 0.0, 1.0));
+    assert!(parallel.intersect_plane(&plane).is_none());
+    let in_plane = Ray::new(v(0.0, 0.0, 0.0), v(1.0, 0.0, 0.0));
+    assert!(in_plane.intersect_plane(&plane).is_none());
+    let receding = Ray::new(v(0.0, 1.0, 0.0), v(0.0, 1.0, 0.0));
+    assert!(receding.intersect_plane(&plane).is_none());
+}
+
+#[test]
+fn plane_hit_at_origin_on_plane() {
+    let plane = Plane::from_point_normal(v(0.0, 0.0, 0.0), v(0.0, 1.0, 0.0));
+    let ray = Ray::new(v(3.0, 0.0, 0.0), v(0.0, -1.0, 0.0));
+    let hit = ray.intersect_plane(&plane).unwrap();
+    assert_eq!(hit.distance, 0.0);
+    assert_eq!(hit.point, v(3.0, 0.0, 0.0));
+}
+
+#[test]
+fn sphere_hit_miss_and_behind() {
+    let sphere = Sphere::new(v(0.0, 0.0, 5.0), 2.0);
+    let ray = Ray::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, 1.0));
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.point, v(0.0, 0.0, 3.0));
+    assert_eq!(hit.normal, v(0.0, 0.0, -1.0));
+
+    let behind = Ray::new(v(0.0, 0.0, 0.0), v(0.0, 0.0, -1.0));
+    assert!(behind.intersect_sphere(&sphere).is_none());
+
+    let miss = Ray::new(v(2.5, 0.0, 0.0), v(0.0, 0.0, 1.0));
+    assert!(miss.intersect_sphere(&sphere).is_none());
+}
+
+#[test]
+fn sphere_grazing_hit() {
+    let sphere = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+    let ray = Ray::new(v(-5.0, 1.0, 0.0), v(1.0, 0.0, 0.0));
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+    assert_eq!(hit.distance, 5.0);
+    assert_eq!(hit.point, v(0.0, 1.0, 0.0));
+    assert_eq!(hit.normal, v(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn sphere_origin_inside() {
+    let sphere = Sphere::new(v(0.0, 0.0, 0.0), 1.0);
+    let ray = Ray::new(v(0.2, 0.0, 0.0), v(1.0, 0.0, 0.0));
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+    assert_eq!(hit.distance, 0.0);
+    assert_eq!(hit.point, v(0.2, 0.0, 0.0));
+    assert_eq!(hit.normal, v(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn aabb_hits_each_face_with_its_normal() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let cases = [
+        (v(5.0, 0.0, 0.0), v(1.0, 0.0, 0.0)),
+        (v(-5.0, 0.0, 0.0), v(-1.0, 0.0, 0.0)),
+        (v(0.0, 5.0, 0.0), v(0.0, 1.0, 0.0)),
+        (v(0.0, -5.0, 0.0), v(0.0, -1.0, 0.0)),
+        (v(0.0, 0.0, 5.0), v(0.0, 0.0, 1.0)),
+        (v(0.0, 0.0, -5.0), v(0.0, 0.0, -1.0)),
+    ];
+    for (origin, normal) in cases {
+        let ray = Ray::new(origin, v(-origin.x, -origin.y, -origin.z));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, normal);
+        assert_approx_eq!(hit.point, normal, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn aabb_miss_and_behind() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let miss = Ray::new(v(-5.0, 2.0, 0.0), v(1.0, 0.0, 0.0));
+    assert!(miss.intersect_aabb(&aabb).is_none());
+    let behind = Ray::new(v(5.0, 0.0, 0.0), v(1.0, 0.0, 0.0));
+    assert!(behind.intersect_aabb(&aabb).is_none());
+    let diagonal_miss = Ray::new(v(-3.0, 0.0, 0.0), v(1.0, 1.0, 0.0));
+    assert!(diagonal_miss.intersect_aabb(&aabb).is_none());
+}
+
+#[test]
+fn aabb_parallel_ray_along_face() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let grazing = Ray::new(v(-5.0, 1.0, 0.0), v(1.0, 0.0, 0.0));
+    let hit = grazing.intersect_aabb(&aabb).unwrap();
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.normal, v(-1.0, 0.0, 0.0));
+
+    let just_outside = Ray::new(v(-5.0, 1.0 + 1e-9, 0.0), v(1.0, 0.0, 0.0));
+    assert!(just_outside.intersect_aabb(&aabb).is_none());
+}
+
+#[test]
+fn aabb_edge_and_corner_hits() {
+    let aabb = Aabb::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    let corner = Ray::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let hit = corner.intersect_aabb(&aabb).unwrap();
+    assert!((hit.distance - 3.0_f64.sqrt()).abs() < 1e-12);
+    assert_approx_eq!(hit.point, v(0.0, 0.0, 0.0), epsilon = 1e-9);
+
+    let edge = Ray::new(v(-1.0, 0.0, -1.0), v(1.0, 0.0, 1.0));
+    let hit = edge.intersect_aabb(&aabb).unwrap();
+    assert_approx_eq!(hit.point, v(0.0, 0.0, 0.0), epsilon = 1e-9);
+}
+
+#[test]
+fn aabb_origin_inside_and_on_surface() {
+    let aabb = Aabb::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+    let inside = Ray::new(v(0.0, 0.5, 0.0), v(0.0, 1.0, 0.0));
+    let hit = inside.intersect_aabb(&aabb).unwrap();
+    assert_eq!(hit.distance, 0.0);
+    assert_eq!(hit.point, v(0.0, 0.5, 0.0));
+    assert_eq!(hit.normal, v(0.0, -1.0, 0.0));
+
+    let on_face = Ray::new(v(1.0, 0.0, 0.0), v(-1.0, 0.0, 0.0));
+    let hit = on_face.intersect_aabb(&aabb).unwrap();
+    assert_eq!(hit.distance, 0.0);
+    assert_eq!(hit.normal, v(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn aabb_flat_box() {
+    let aabb = Aabb::new(v(-1.0, 0.0, -1.0), v(1.0, 0.0, 1.0));
+    let ray = Ray::new(v(0.0, 3.0, 0.0), v(0.0, -1.0, 0.0));
+    let hit = ray.intersect_aabb(&aabb).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, v(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn obb_matches_aabb_when_axis_aligned() {
+    let obb = Obb::new(v(1.0, 2.0, 3.0), v(1.0, 2.0, 0.5), Mat3::identity());
+    let aabb = Aabb::from_center_half_extents(v(1.0, 2.0, 3.0), v(1.0, 2.0, 0.5));
+    let rays = [
+        Ray::new(v(-4.0, 1.0, 3.2), v(1.0, 0.1, 0.0)),
+        Ray::new(v(1.0, 9.0, 3.0), v(0.0, -1.0, 0.1)),
+        Ray::new(v(1.5, 2.5, 3.0), v(0.3, -0.2, 1.0)),
+        Ray::new(v(5.0, 5.0, 5.0), v(1.0, 1.0, 1.0)),
+    ];
+    for ray in rays {
+        assert_eq!(ray.intersect_obb(&obb), ray.intersect_aabb(&aabb));
+    }
+}
+
+#[test]
+fn obb_rotated_normal_is_a_box_axis() {
+    let rotation = Quaternion::from_axis_angle(v(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    let obb = Obb::from_rotation(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation);
+    let ray = Ray::new(v(5.0, 0.5, 0.0), v(-1.0, 0.0, 0.0));
+    let hit = ray.intersect_obb(&obb).unwrap();
+    let axis = rotation.rotate(v(1.0, 0.0, 0.0));
+    assert!(hit.normal.dot(&axis).abs() > 1.0 - 1e-9 || hit.normal.dot(&axis).abs() < 1e-9);
+    assert!(hit.normal.x > 0.0);
+    assert!(obb.distance_to_point(hit.point) < 1e-9);
+
+    let miss = Ray::new(v(5.0, 2.0, 0.0), v(-1.0, 0.0, 0.0));
+    assert!(miss.intersect_obb(&obb).is_none());
+
+    let inside = Ray::new(v(0.1, 0.1, 0.1), v(0.0, 1.0, 0.0));
+    assert_eq!(inside.intersect_obb(&obb).unwrap().distance, 0.0);
+}
+
+#[test]
+fn triangle_front_and_back_hits() {
+    let triangle = Triangle::new(v(0.0, 0.0, 0.0), v(1.0, 0.0, 0.0), v(0.0, 1.0, 0.0));
+    let front = Ray::new(v(0.25, 0.25, 1.0), v(0.0, 0.0, -1.0));
+    let hit = front.intersect_triangle(&triangle).unwrap();
+    assert_eq!(hit.distance, 1.0);
+    assert_eq!(hit.normal, v(0.0, 0.0, 1.0));
+    assert_eq!(hit.barycentric, v(0.5, 0.25, 0.25));
+
+    let back = Ray::new(v(0.25, 0.25, -1.0), v(0.0, 0.0, 1.0));
+    let hit = back.intersect_triangle(&triangle).unwrap();
+    assert_eq!(hit.distance, 1.0);
+    assert_eq!(hit.normal, v(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn triangle_barycentrics_reconstruct_the_hit_point() {
+    let triangle = Triangle::new(v(1.0, 0.0, 2.0), v(3.0, 1.0, 0.0), v(0.0, 4.0, 1.0));
+    let ray = Ray::new(v(-3.0, -2.0, -5.0), v(4.5, 4.0, 6.0));
+    let hit = ray.intersect_triangle(&triangle).unwrap();
+    let b = hit.barycentric;
+    let rebuilt = v(
+        triangle.a.x * b.x + triangle.b.x * b.y + triangle.c.x * b.z,
+        triangle.a.y * b.x + triangle.b.y * b.y + triangle.c.y * b.z,
+        triangle.a.z * b.x + triangle.b.z * b.y + triangle.c.z * b.z,
+    );
+    assert_approx_eq!(rebuilt, hit.point, epsilon = 1e-9);
+    assert!((b.x + b.y + b.z - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn triangle_edges_and_vertices_count_as_hits() {
+    let triangle = Triangle::new(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0));
+    let down = v(0.0, 0.0, -1.0);
+    for point in [
+        v(0.0, 0.0, 1.0),
+        v(2.0, 0.0, 1.0),
+        v(0.0, 2.0, 1.0),
+        v(1.0, 0.0, 1.0),
+        v(1.0, 1.0, 1.0),
+    ] {
+        assert!(Ray::new(point, down)
+            .intersect_triangle(&triangle)
+            .is_some());
+    }
+    for point in [v(-0.01, 0.5, 1.0), v(1.01, 1.0, 1.0), v(0.5, -0.01, 1.0)] {
+        assert!(Ray::new(point, down)
+            .intersect_triangle(&triangle)
+            .is_none());
+    }
+}
+
+#[test]
+fn triangle_parallel_behind_and_coplanar() {
+    let triangle = Triangle::new(v(0.0, 0.0, 0.0), v(1.0, 0.0, 0.0), v(0.0, 1.0, 0.0));
+    let parallel = Ray::new(v(0.0, 0.0, 1.0), v(1.0, 1.0, 0.0));
+    assert!(parallel.intersect_triangle(&triangle).is_none());
+    let coplanar = Ray::new(v(-1.0, 0.25, 0.0), v(1.0, 0.0, 0.0));
+    assert!(coplanar.intersect_triangle(&triangle).is_none());
+    let behind = Ray::new(v(0.25, 0.25, 1.0), v(0.0, 0.0, 1.0));
+    assert!(behind.intersect_triangle(&triangle).is_none());
+}
+
+#[test]
+fn capsule_body_caps_and_inside() {
+    let capsule = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 4.0, 0.0), 1.0);
+
+    let body = Ray::new(v(-5.0, 2.0, 0.0), v(1.0, 0.0, 0.0));
+    let hit = body.intersect_capsule(&capsule).unwrap();
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.normal, v(-1.0, 0.0, 0.0));
+
+    let bottom = Ray::new(v(0.0, -5.0, 0.0), v(0.0, 1.0, 0.0));
+    let hit = bottom.intersect_capsule(&capsule).unwrap();
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.normal, v(0.0, -1.0, 0.0));
+
+    let cap_side = Ray::new(v(-5.0, 4.5, 0.0), v(1.0, 0.0, 0.0));
+    let hit = cap_side.intersect_capsule(&capsule).unwrap();
+    assert!((hit.point.x + 0.75_f64.sqrt()).abs() < 1e-12);
+    assert!(capsule.distance_to_point(hit.point) < 1e-9);
+
+    let inside = Ray::new(v(0.0, 2.0, 0.5), v(1.0, 0.0, 0.0));
+    assert_eq!(inside.intersect_capsule(&capsule).unwrap().distance, 0.0);
+}
+
+#[test]
+fn capsule_grazing_and_miss() {
+    let capsule = Capsule::new(v(0.0, 0.0, 0.0), v(0.0, 4.0, 0.0), 1.0);
+    let grazing = Ray::new(v(1.0, -5.0, 0.0), v(0.0, 1.0, 0.0));
+    let hit = grazing.intersect_capsule(&capsule).unwrap();
+    assert_eq!(hit.point, v(1.0, 0.0, 0.0));
+    assert_eq!(hit.normal, v(1.0, 0.0, 0.0));
+
+    let miss = Ray::new(v(1.5, -5.0, 0.0), v(0.0, 1.0, 0.0));
+    assert!(miss.intersect_capsule(&capsule).is_none());
+    let behind = Ray::new(v(-5.0, 2.0, 0.0), v(-1.0, 0.0, 0.0));
+    assert!(behind.intersect_capsule(&capsule).is_none());
+}
+
+#[test]
+fn capsule_with_zero_length_is_a_sphere() {
+    let capsule = Capsule::new(v(1.0, 1.0, 1.0), v(1.0, 1.0, 1.0), 0.5);
+    let sphere = Sphere::new(v(1.0, 1.0, 1.0), 0.5);
+    let ray = Ray::new(v(-3.0, 1.2, 0.9), v(1.0, 0.0, 0.0));
+    let a = ray.intersect_capsule(&capsule).unwrap();
+    let b = ray.intersect_sphere(&sphere).unwrap();
+    assert!((a.distance - b.distance).abs() < 1e-12);
+    assert_approx_eq!(a.normal, b.normal, epsilon = 1e-9);
+}
+
+#[test]
+fn cylinder_side_caps_and_inside() {
+    let cylinder = Cylinder::new(v(0.0, 0.0, 0.0), v(0.0, 2.0, 0.0), 1.0);
+
+    let side = Ray::new(v(0.0, 1.0, -5.0), v(0.0, 0.0, 1.0));
+    let hit = side.intersect_cylinder(&cylinder).unwrap();
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.normal, v(0.0, 0.0, -1.0));
+
+    let bottom = Ray::new(v(0.5, -3.0, 0.0), v(0.0, 1.0, 0.0));
+    let hit = bottom.intersect_cylinder(&cylinder).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, v(0.0, -1.0, 0.0));
+
+    let slanted = Ray::new(v(-3.0, 5.0, 0.0), v(1.0, -1.0, 0.0));
+    let hit = slanted.intersect_cylinder(&cylinder).unwrap();
+    assert_approx_eq!(hit.point, v(0.0, 2.0, 0.0), epsilon = 1e-9);
+    assert_eq!(hit.normal, v(0.0, 1.0, 0.0));
+
+    let inside = Ray::new(v(0.0, 1.0, 0.0), v(0.0, 1.0, 0.0));
+    assert_eq!(inside.intersect_cylinder(&cylinder).unwrap().distance, 0.0);
+}
+
+#[test]
+fn cylinder_parallel_and_past_the_caps() {
+    let cylinder = Cylinder::new(v(0.0, 0.0, 0.0), v(0.0, 2.0, 0.0), 1.0);
+    let parallel_outside = Ray::new(v(2.0, -5.0, 0.0), v(0.0, 1.0, 0.0));
+    assert!(parallel_outside.intersect_cylinder(&cylinder).is_none());
+    let above = Ray::new(v(-5.0, 2.5, 0.0), v(1.0, 0.0, 0.0));
+    assert!(above.intersect_cylinder(&cylinder).is_none());
+    let grazing = Ray::new(v(-5.0, 1.0, 1.0), v(1.0, 0.0, 0.0));
+    let hit = grazing.intersect_cylinder(&cylinder).unwrap();
+    assert_eq!(hit.point, v(0.0, 1.0, 1.0));
+}
+
+#[test]
+fn cylinder_along_arbitrary_axis() {
+    let cylinder = Cylinder::new(v(1.0, 1.0, 1.0), v(3.0, 3.0, 3.0), 0.5);
+    let ray = Ray::new(v(2.0, 2.0, -5.0), v(0.0, 0.0, 1.0));
+    let hit = ray.intersect_cylinder(&cylinder).unwrap();
+    let axis_point = v(2.0, 2.0, 2.0);
+    let radial = hit.point - axis_point;
+    let along = radial.dot(&v(1.0, 1.0, 1.0).normalize());
+    let distance = (radial.length_squared() - along * along).sqrt();
+    assert!((distance - 0.5).abs() < 1e-9);
+    assert!(hit.normal.dot(&ray.direction) < 0.0);
+}
+
+#[test]
+fn cone_side_base_apex_and_inside() {
+    let cone = Cone::new(v(0.0, 2.0, 0.0), v(0.0, 0.0, 0.0), 2.0);
+
+    let side = Ray::new(v(-5.0, 1.0, 0.0), v(1.0, 0.0, 0.0));
+    let hit = side.intersect_cone(&cone).unwrap();
+    assert_approx_eq!(hit.point, v(-1.0, 1.0, 0.0), epsilon = 1e-9);
+    assert_approx_eq!(hit.normal, v(-1.0, 1.0, 0.0).normalize(), epsilon = 1e-9);
+
+    let base = Ray::new(v(0.5, -3.0, 0.0), v(0.0, 1.0, 0.0));
+    let hit = base.intersect_cone(&cone).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, v(0.0, -1.0, 0.0));
+
+    let apex = Ray::new(v(0.0, 6.0, 0.0), v(0.0, -1.0, 0.0));
+    let hit = apex.intersect_cone(&cone).unwrap();
+    assert_eq!(hit.point, v(0.0, 2.0, 0.0));
+    assert_eq!(hit.normal, v(0.0, 1.0, 0.0));
+
+    let inside = Ray::new(v(0.0, 0.5, 0.0), v(1.0, 0.0, 0.0));
+    assert_eq!(inside.intersect_cone(&cone).unwrap().distance, 0.0);
+}
+
+#[test]
+fn cone_ignores_the_mirrored_nappe() {
+    let cone = Cone::new(v(0.0, 2.0, 0.0), v(0.0, 0.0, 0.0), 2.0);
+    let above = Ray::new(v(-5.0, 3.0, 0.0), v(1.0, 0.0, 0.0));
+    assert!(above.intersect_cone(&cone).is_none());
+    let below = Ray::new(v(-5.0, -0.5, 0.0), v(1.0, 0.0, 0.0));
+    assert!(below.intersect_cone(&cone).is_none());
+}
+
+#[test]
+fn cone_ray_parallel_to_the_surface() {
+    let cone = Cone::new(v(0.0, 2.0, 0.0), v(0.0, 0.0, 0.0), 2.0);
+    let ray = Ray::new(v(-3.0, 2.0, 0.0), v(1.0, -1.0, 0.0));
+    let hit = ray.intersect_cone(&cone).unwrap();
+    assert_approx_eq!(hit.point, v(-1.5, 0.5, 0.0), epsilon = 1e-9);
+    assert_approx_eq!(hit.normal, v(-1.0, 1.0, 0.0).normalize(), epsilon = 1e-9);
+}
+
+#[test]
+fn hits_lie_on_the_surface() {
+    let directions = [
+        v(1.0, 0.2, -0.3),
+        v(-0.4, 1.0, 0.1),
+        v(0.3, -0.2, 1.0),
+        v(-1.0, -1.0, -1.0),
+    ];
+    let capsule = Capsule::new(v(-1.0, 0.0, 0.0), v(1.0, 0.5, 0.0), 0.75);
+    let aabb = Aabb::new(v(-1.0, -0.5, -2.0), v(1.0, 0.5, 2.0));
+    for d in directions {
+        let origin = v(-d.x * 6.0, -d.y * 6.0, -d.z * 6.0);
+        let ray = Ray::new(origin, d);
+        let hit = ray.intersect_capsule(&capsule).unwrap();
+        assert!(capsule.distance_to_point(hit.point) < 1e-9);
+        assert!(hit.normal.dot(&ray.direction) <= 0.0);
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        assert!(aabb.distance_to_point(hit.point) < 1e-9);
+        assert!(hit.normal.dot(&ray.direction) < 0.0);
+    }
+}
+
+#[test]
+fn hits_sub_millimetre_shapes_in_single_precision() {
+    // Edges of 0.1 mm at metre scale give a Möller–Trumbore determinant
+    // below the f32 epsilon, though the ray hits the triangle head-on.
+    let triangle = Triangle::new(
+        Vector3::new(0.0f32, 0.0, 0.0),
+        Vector3::new(1e-4, 0.0, 0.0),
+        Vector3::new(0.0, 1e-4, 0.0),
+    );
+    let ray = Ray::new(
+        Vector3::new(2.5e-5f32, 2.5e-5, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+    );
+    let hit = ray.intersect_triangle(&triangle).unwrap();
+    assert_eq!(hit.distance, 1.0);
+    assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(hit.barycentric.x, 0.5, epsilon = 1e-6);
+    let beside = Ray::new(
+        Vector3::new(2e-4f32, 2e-4, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+    );
+    assert!(beside.intersect_triangle(&triangle).is_none());
+
+    let cylinder = Cylinder::new(
+        Vector3::new(0.0f32, 0.0, 0.0),
+        Vector3::new(0.0, 5e-8, 0.0),
+        1e-8,
+    );
+    let ray = Ray::new(
+        Vector3::new(-4e-8f32, 2.5e-8, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+    let hit = ray.intersect_cylinder(&cylinder).unwrap();
+    assert_approx_eq!(hit.distance, 3e-8, epsilon = 1e-13);
+    assert_eq!(hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+}
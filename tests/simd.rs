@@ -0,0 +1,284 @@
+use std::mem::{align_of, size_of};
+
+use vexel::matrices::dmat4a::DMat4A;
+use vexel::matrices::mat4::Mat4;
+use vexel::matrices::mat4a::Mat4A;
+use vexel::random::pcg::Pcg32;
+use vexel::random::Rng;
+use vexel::scalar::Real;
+use vexel::vectors::dvector3a::DVector3A;
+use vexel::vectors::dvector4a::DVector4A;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector3a::Vector3A;
+use vexel::vectors::vector4::Vector4;
+use vexel::vectors::vector4a::Vector4A;
+
+const SAMPLES: usize = 10_000;
+
+/// Values that exercise rounding, signed zeros, subnormals and overflow.
+const SPECIAL: [f32; 10] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    f32::MIN_POSITIVE,
+    1e-40,
+    -3.4e38,
+    f32::MAX,
+    f32::INFINITY,
+    f32::EPSILON,
+];
+
+/// The same for double precision.
+const SPECIAL_F64: [f64; 10] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    f64::MIN_POSITIVE,
+    1e-310,
+    -1.7e308,
+    f64::MAX,
+    f64::INFINITY,
+    f64::EPSILON,
+];
+
+/// Returns a float of random sign and magnitude, or now and then one of the
+/// special values.
+fn component<T: Real>(rng: &mut Pcg32, special: &[T]) -> T {
+    if rng.below(16) == 0 {
+        return special[rng.below(special.len() as u64) as usize];
+    }
+    let exponent = rng.range(-20.0, 20.0f64).round();
+    T::from_f64(rng.range(-1.0, 1.0) * exponent.exp2())
+}
+
+fn vector3<T: Real>(rng: &mut Pcg32, special: &[T]) -> Vector3<T> {
+    Vector3::new(
+        component(rng, special),
+        component(rng, special),
+        component(rng, special),
+    )
+}
+
+fn vector4<T: Real>(rng: &mut Pcg32, special: &[T]) -> Vector4<T> {
+    Vector4::new(
+        component(rng, special),
+        component(rng, special),
+        component(rng, special),
+        component(rng, special),
+    )
+}
+
+fn mat4<T: Real>(rng: &mut Pcg32, special: &[T]) -> Mat4<T> {
+    Mat4::from_cols(
+        vector4(rng, special),
+        vector4(rng, special),
+        vector4(rng, special),
+        vector4(rng, special),
+    )
+}
+
+/// Asserts that two floats have the same bits, treating all NaNs as equal.
+///
+/// Widening to `f64` is exact, so comparing the widened bits works for both
+/// precisions.
+#[track_caller]
+fn assert_same_bits<T: Real>(simd: &[T], scalar: &[T]) {
+    assert_eq!(simd.len(), scalar.len());
+    for (a, b) in simd.iter().zip(scalar) {
+        assert!(
+            a.to_f64().to_bits() == b.to_f64().to_bits() || (a.is_nan() && b.is_nan()),
+            "SIMD gave {simd:?}, scalar gave {scalar:?}"
+        );
+    }
+}
+
+fn array3<T: Real>(v: Vector3<T>) -> [T; 3] {
+    [v.x, v.y, v.z]
+}
+
+fn array4<T: Real>(v: Vector4<T>) -> [T; 4] {
+    [v.x, v.y, v.z, v.w]
+}
+
+#[test]
+fn types_are_register_sized_and_aligned() {
+    assert_eq!(size_of::<Vector3A>(), 16);
+    assert_eq!(align_of::<Vector3A>(), 16);
+    assert_eq!(size_of::<Vector4A>(), 16);
+    assert_eq!(align_of::<Vector4A>(), 16);
+    assert_eq!(size_of::<Mat4A>(), 64);
+    assert_eq!(align_of::<Mat4A>(), 16);
+    assert_eq!(size_of::<DVector3A>(), 32);
+    assert_eq!(align_of::<DVector3A>(), 16);
+    assert_eq!(size_of::<DVector4A>(), 32);
+    assert_eq!(align_of::<DVector4A>(), 16);
+    assert_eq!(size_of::<DMat4A>(), 128);
+    assert_eq!(align_of::<DMat4A>(), 16);
+}
+
+#[test]
+fn vector4_matches_the_scalar_path() {
+    let mut rng = Pcg32::new(1);
+    for _ in 0..SAMPLES {
+        let (a, b) = (vector4(&mut rng, &SPECIAL), vector4(&mut rng, &SPECIAL));
+        let (sa, sb) = (Vector4A::from(a), Vector4A::from(b));
+        let t = rng.range(-0.5f32, 1.5);
+
+        assert_same_bits(&(sa + sb).to_array(), &array4(a + b));
+        assert_same_bits(&(sa - sb).to_array(), &array4(a - b));
+        assert_same_bits(&(sa * sb).to_array(), &array4(a * b));
+        assert_same_bits(&(sa / sb).to_array(), &array4(a / b));
+        assert_same_bits(&[sa.dot(&sb)], &[a.dot(&b)]);
+        assert_same_bits(&[sa.length()], &[a.length()]);
+        assert_same_bits(&[sa.length_squared()], &[a.length_squared()]);
+        assert_same_bits(&sa.normalize().to_array(), &array4(a.normalize()));
+        assert_same_bits(&sa.lerp(&sb, t).to_array(), &array4(a.lerp(&b, t)));
+    }
+}
+
+#[test]
+fn vector3_matches_the_scalar_path() {
+    let mut rng = Pcg32::new(2);
+    for _ in 0..SAMPLES {
+        let (a, b) = (vector3(&mut rng, &SPECIAL), vector3(&mut rng, &SPECIAL));
+        let (sa, sb) = (Vector3A::from(a), Vector3A::from(b));
+        let t = rng.range(-0.5f32, 1.5);
+
+        assert_same_bits(&(sa + sb).to_array(), &array3(a + b));
+        assert_same_bits(&(sa - sb).to_array(), &array3(a - b));
+        assert_same_bits(&(sa * sb).to_array(), &array3(a * b));
+        assert_same_bits(&(sa / sb).to_array(), &array3(a / b));
+        assert_same_bits(&[sa.dot(&sb)], &[a.dot(&b)]);
+        assert_same_bits(&sa.cross(&sb).to_array(), &array3(a.cross(&b)));
+        assert_same_bits(&[sa.length()], &[a.length()]);
+        assert_same_bits(&[sa.length_squared()], &[a.length_squared()]);
+        assert_same_bits(&sa.normalize().to_array(), &array3(a.normalize()));
+        assert_same_bits(&sa.lerp(&sb, t).to_array(), &array3(a.lerp(&b, t)));
+    }
+}
+
+#[test]
+fn padding_lane_never_leaks() {
+    // Dividing by a vector fills the unused lane with 0 / 0, which must not
+    // reach the dot product, the length or comparisons.
+    let v = Vector3A::new(3.0, 4.0, 12.0) / Vector3A::splat(1.0);
+    assert_eq!(v.length(), 13.0);
+    assert_eq!(v.dot(&v), 169.0);
+    assert_eq!(v, Vector3A::new(3.0, 4.0, 12.0));
+    assert_eq!(format!("{v:?}"), "Vector3A { x: 3.0, y: 4.0, z: 12.0 }");
+
+    let n = (Vector3A::splat(0.0) / Vector3A::splat(1.0)).normalize();
+    assert_eq!(n, Vector3A::splat(0.0));
+
+    let v = DVector3A::new(3.0, 4.0, 12.0) / DVector3A::splat(1.0);
+    assert_eq!(v.length(), 13.0);
+    assert_eq!(v.dot(&v), 169.0);
+    assert_eq!(v, DVector3A::new(3.0, 4.0, 12.0));
+    assert_eq!(format!("{v:?}"), "DVector3A { x: 3.0, y: 4.0, z: 12.0 }");
+    let n = (DVector3A::splat(0.0) / DVector3A::splat(1.0)).normalize();
+    assert_eq!(n, DVector3A::splat(0.0));
+}
+
+#[test]
+fn mat4_products_match_the_scalar_path() {
+    let mut rng = Pcg32::new(3);
+    for _ in 0..SAMPLES {
+        let (a, b) = (mat4(&mut rng, &SPECIAL), mat4(&mut rng, &SPECIAL));
+        let v = vector4(&mut rng, &SPECIAL);
+        let (sa, sb) = (Mat4A::from(a), Mat4A::from(b));
+
+        assert_same_bits(&(sa * Vector4A::from(v)).to_array(), &array4(a * v));
+        assert_same_bits(&(sa * sb).to_cols_array(), &(a * b).to_cols_array());
+        assert_same_bits(
+            &sa.transpose().to_cols_array(),
+            &a.transpose().to_cols_array(),
+        );
+    }
+}
+
+#[test]
+fn double_precision_vectors_match_the_scalar_path() {
+    let mut rng = Pcg32::new(5);
+    for _ in 0..SAMPLES {
+        let (a, b) = (
+            vector4(&mut rng, &SPECIAL_F64),
+            vector4(&mut rng, &SPECIAL_F64),
+        );
+        let (sa, sb) = (DVector4A::from(a), DVector4A::from(b));
+        let t = rng.range(-0.5, 1.5);
+
+        assert_same_bits(&(sa + sb).to_array(), &array4(a + b));
+        assert_same_bits(&(sa - sb).to_array(), &array4(a - b));
+        assert_same_bits(&(sa * sb).to_array(), &array4(a * b));
+        assert_same_bits(&(sa / sb).to_array(), &array4(a / b));
+        assert_same_bits(&[sa.dot(&sb)], &[a.dot(&b)]);
+        assert_same_bits(&[sa.length()], &[a.length()]);
+        assert_same_bits(&sa.normalize().to_array(), &array4(a.normalize()));
+        assert_same_bits(&sa.lerp(&sb, t).to_array(), &array4(a.lerp(&b, t)));
+        assert_same_bits(&[sa.x(), sa.y(), sa.z(), sa.w()], &array4(a));
+
+        let (a, b) = (
+            vector3(&mut rng, &SPECIAL_F64),
+            vector3(&mut rng, &SPECIAL_F64),
+        );
+        let (sa, sb) = (DVector3A::from(a), DVector3A::from(b));
+
+        assert_same_bits(&(sa + sb).to_array(), &array3(a + b));
+        assert_same_bits(&(sa / sb).to_array(), &array3(a / b));
+        assert_same_bits(&[sa.dot(&sb)], &[a.dot(&b)]);
+        assert_same_bits(&sa.cross(&sb).to_array(), &array3(a.cross(&b)));
+        assert_same_bits(&[sa.length()], &[a.length()]);
+        assert_same_bits(&sa.normalize().to_array(), &array3(a.normalize()));
+        assert_same_bits(&sa.lerp(&sb, t).to_array(), &array3(a.lerp(&b, t)));
+        assert_same_bits(&[sa.x(), sa.y(), sa.z()], &array3(a));
+    }
+}
+
+#[test]
+fn double_precision_products_match_the_scalar_path() {
+    let mut rng = Pcg32::new(6);
+    for _ in 0..SAMPLES {
+        let (a, b) = (mat4(&mut rng, &SPECIAL_F64), mat4(&mut rng, &SPECIAL_F64));
+        let v = vector4(&mut rng, &SPECIAL_F64);
+        let (sa, sb) = (DMat4A::from(a), DMat4A::from(b));
+
+        assert_same_bits(&(sa * DVector4A::from(v)).to_array(), &array4(a * v));
+        assert_same_bits(&(sa * sb).to_cols_array(), &(a * b).to_cols_array());
+        assert_same_bits(
+            &sa.transpose().to_cols_array(),
+            &a.transpose().to_cols_array(),
+        );
+    }
+}
+
+#[test]
+fn conversions_round_trip() {
+    let mut rng = Pcg32::new(4);
+    for _ in 0..100 {
+        let v = vector3(&mut rng, &SPECIAL);
+        assert_same_bits(&array3(Vector3::from(Vector3A::from(v))), &array3(v));
+        let v = vector4(&mut rng, &SPECIAL);
+        assert_same_bits(&array4(Vector4::from(Vector4A::from(v))), &array4(v));
+        let m = mat4(&mut rng, &SPECIAL);
+        assert_same_bits(
+            &Mat4::from(Mat4A::from(m)).to_cols_array(),
+            &m.to_cols_array(),
+        );
+        assert_same_bits(
+            &Mat4A::from_cols_array(m.to_cols_array()).to_cols_array(),
+            &m.to_cols_array(),
+        );
+
+        let v = vector3(&mut rng, &SPECIAL_F64);
+        assert_same_bits(&array3(Vector3::from(DVector3A::from(v))), &array3(v));
+        let v = vector4(&mut rng, &SPECIAL_F64);
+        assert_same_bits(&array4(Vector4::from(DVector4A::from(v))), &array4(v));
+        let m = mat4(&mut rng, &SPECIAL_F64);
+        assert_same_bits(
+            &Mat4::from(DMat4A::from(m)).to_cols_array(),
+            &m.to_cols_array(),
+        );
+    }
+}
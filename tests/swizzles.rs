@@ -0,0 +1,45 @@
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+
+#[test]
+fn getters_pick_components_in_order() {
+    let v = Vector2::new(1, 2);
+    assert_eq!(v.yx(), Vector2::new(2, 1));
+    assert_eq!(v.xxy(), Vector3::new(1, 1, 2));
+    assert_eq!(v.yyxx(), Vector4::new(2, 2, 1, 1));
+
+    let v = Vector3::new(1, 2, 3);
+    assert_eq!(v.xy(), Vector2::new(1, 2));
+    assert_eq!(v.zx(), Vector2::new(3, 1));
+    assert_eq!(v.zyx(), Vector3::new(3, 2, 1));
+    assert_eq!(v.xyzz(), Vector4::new(1, 2, 3, 3));
+
+    let v = Vector4::new(1, 2, 3, 4);
+    assert_eq!(v.xyz(), Vector3::new(1, 2, 3));
+    assert_eq!(v.ww(), Vector2::new(4, 4));
+    assert_eq!(v.wzyx(), Vector4::new(4, 3, 2, 1));
+    assert_eq!(v.xyzw(), v);
+}
+
+#[test]
+fn getters_round_trip_through_setters() {
+    let mut v = Vector4::new(1, 2, 3, 4);
+    v.set_wzyx(v.xyzw());
+    assert_eq!(v, Vector4::new(4, 3, 2, 1));
+    v.set_yw(Vector2::new(7, 8));
+    assert_eq!(v, Vector4::new(4, 7, 2, 8));
+    v.set_zxy(v.xyz());
+    assert_eq!(v, Vector4::new(7, 2, 4, 8));
+
+    let mut v = Vector3::new(1, 2, 3);
+    let original = v;
+    v.set_zx(v.xz());
+    assert_eq!(v, Vector3::new(3, 2, 1));
+    v.set_zyx(v.xyz());
+    assert_eq!(v, original);
+
+    let mut v = Vector2::new(1, 2);
+    v.set_yx(v.xy());
+    assert_eq!(v, Vector2::new(2, 1));
+}
@@ -0,0 +1,276 @@
+use vexel::assert_approx_eq;
+use vexel::error::VexelError;
+use vexel::matrices::mat2::Mat2;
+use vexel::matrices::mat3::Mat3;
+use vexel::matrices::mat4::Mat4;
+use vexel::matrices::matrixmn::MatrixMN;
+use vexel::random::pcg::Pcg32;
+use vexel::random::sample;
+use vexel::random::Rng;
+use vexel::vectors::vector2::Vector2;
+use vexel::vectors::vector3::Vector3;
+use vexel::vectors::vector4::Vector4;
+use vexel::vectors::vectorn::VectorN;
+
+fn random_vector<const N: usize>(rng: &mut Pcg32) -> VectorN<f64, N> {
+    VectorN::from_fn(|_| rng.range(-10.0, 10.0))
+}
+
+fn random_matrix<const R: usize, const C: usize>(rng: &mut Pcg32) -> MatrixMN<f64, R, C> {
+    MatrixMN::from_fn(|_, _| rng.range(-10.0, 10.0))
+}
+
+#[test]
+fn matches_the_fixed_size_vectors_exactly() {
+    let mut rng = Pcg32::new(1);
+    for _ in 0..1000 {
+        let (a, b): (VectorN<f64, 3>, VectorN<f64, 3>) =
+            (random_vector(&mut rng), random_vector(&mut rng));
+        let (u, v) = (Vector3::from(a), Vector3::from(b));
+        let t = rng.range(0.0, 1.0);
+        assert_eq!(a.dot(&b), u.dot(&v));
+        assert_eq!(a.length(), u.length());
+        assert_eq!(Vector3::from(a.normalize()), u.normalize());
+        assert_eq!(Vector3::from(a.lerp(&b, t)), u.lerp(&v, t));
+        assert_eq!(Vector3::from(a.project_onto(&b)), u.project_onto(&v));
+        assert_eq!(Vector3::from(a.reject_from(&b)), u.reject_from(&v));
+        assert_eq!(a.try_angle_between(&b), u.try_angle_between(&v));
+        assert_eq!(Vector3::from(a + b * t), u + v * t);
+
+        let (a, b): (VectorN<f64, 4>, VectorN<f64, 4>) =
+            (random_vector(&mut rng), random_vector(&mut rng));
+        let (u, v) = (Vector4::from(a), Vector4::from(b));
+        assert_eq!(a.dot(&b), u.dot(&v));
+        assert_eq!(Vector4::from(a.normalize()), u.normalize());
+        assert_eq!(Vector4::from(a.project_onto(&b)), u.project_onto(&v));
+    }
+
+    let v = Vector2::new(1.5, -2.0);
+    assert_eq!(Vector2::from(VectorN::from(v)), v);
+    assert_eq!(VectorN::from(v).components, [1.5, -2.0]);
+}
+
+#[test]
+fn high_dimensional_vectors() {
+    let state = VectorN::new([3.0, 4.0, 0.0, 0.0, 0.0, 12.0]);
+    assert_eq!(state.length(), 13.0);
+    assert_approx_eq!(state.normalize().length(), 1.0, ulps = 1);
+    assert_eq!(
+        VectorN::<f64, 9>::zero().try_normalize(),
+        Err(VexelError::ZeroLength)
+    );
+    assert_eq!(
+        VectorN::<f64, 12>::splat(f64::NAN).try_normalize(),
+        Err(VexelError::NonFinite)
+    );
+
+    let mut rng = Pcg32::new(2);
+    for _ in 0..100 {
+        let (a, b): (VectorN<f64, 12>, VectorN<f64, 12>) =
+            (random_vector(&mut rng), random_vector(&mut rng));
+        let rejection = a.reject_from(&b);
+        assert_approx_eq!(rejection.dot(&b), 0.0, epsilon = 1e-10 * a.length_squared());
+        assert_approx_eq!(a.project_onto(&b) + rejection, a, epsilon = 1e-12);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_approx_eq!(a.lerp(&b, 1.0), b, epsilon = 1e-12);
+    }
+
+    let samples = [
+        VectorN::splat(1.0),
+        VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+    ];
+    let mean = samples.iter().sum::<VectorN<f64, 6>>() / 2.0;
+    assert_eq!(mean, VectorN::new([1.0, 1.5, 2.0, 2.5, 3.0, 3.5]));
+}
+
+#[test]
+fn matrices_match_the_fixed_size_ones_exactly() {
+    let mut rng = Pcg32::new(3);
+    for _ in 0..1000 {
+        let (a, b): (MatrixMN<f64, 4, 4>, MatrixMN<f64, 4, 4>) =
+            (random_matrix(&mut rng), random_matrix(&mut rng));
+        let (m, n) = (Mat4::from(a), Mat4::from(b));
+        let v: VectorN<f64, 4> = random_vector(&mut rng);
+        assert_eq!(Vector4::from(a * v), m * Vector4::from(v));
+        assert_eq!(Mat4::from(a * b), m * n);
+        assert_eq!(Mat4::from(a.transpose()), m.transpose());
+        for i in 0..4 {
+            assert_eq!(a.row(i), m.row(i).into());
+        }
+
+        let c: MatrixMN<f64, 3, 3> = random_matrix(&mut rng);
+        assert_eq!(MatrixMN::from(Mat3::from(c)), c);
+    }
+    assert_eq!(
+        Mat3::from(MatrixMN::<f64, 3, 3>::identity()),
+        Mat3::identity()
+    );
+}
+
+#[test]
+fn rectangular_matrices_compose() {
+    // A constant-velocity model: position and velocity in 3D, observed
+    // through the position alone.
+    let dt = 0.5;
+    let transition: MatrixMN<f64, 6, 6> =
+        MatrixMN::from_fn(|row, col| match col as isize - row as isize {
+            0 => 1.0,
+            3 => dt,
+            _ => 0.0,
+        });
+    let observe: MatrixMN<f64, 3, 6> = MatrixMN::from_fn(|row, col| (row == col) as u8 as f64);
+
+    let state = VectorN::new([1.0, 2.0, 3.0, 2.0, 0.0, -2.0]);
+    let predicted = transition * state;
+    assert_eq!(predicted, VectorN::new([2.0, 2.0, 2.0, 2.0, 0.0, -2.0]));
+    assert_eq!(observe * predicted, VectorN::new([2.0, 2.0, 2.0]));
+    assert_eq!(
+        (observe * transition) * state,
+        observe * (transition * state)
+    );
+
+    // The observation covariance picks the position block out of the state
+    // covariance.
+    let covariance = MatrixMN::from_diagonal(VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    let projected: MatrixMN<f64, 3, 3> = observe * covariance * observe.transpose();
+    assert_eq!(
+        Mat3::from(projected),
+        Mat3::from_diagonal(Vector3::new(1.0, 2.0, 3.0))
+    );
+
+    let rows = MatrixMN::from_rows([VectorN::new([1, 2, 3]), VectorN::new([4, 5, 6])]);
+    assert_eq!(rows.col(2), VectorN::new([3, 6]));
+    assert_eq!(rows.transpose().row(2), VectorN::new([3, 6]));
+    assert_eq!(rows * MatrixMN::<i32, 3, 3>::identity(), rows);
+}
+
+#[test]
+fn determinant_and_inverse_match_the_fixed_size_ones() {
+    let mut rng = Pcg32::new(4);
+    for _ in 0..1000 {
+        let a: MatrixMN<f64, 4, 4> = random_matrix(&mut rng);
+        let m = Mat4::from(a);
+        let det = m.determinant();
+        assert_approx_eq!(a.determinant(), det, epsilon = 1e-9 * det.abs().max(1.0));
+        let (inv, expected) = (a.inverse().unwrap(), m.inverse().unwrap());
+        let scale = expected
+            .to_cols_array()
+            .iter()
+            .fold(1.0, |s: f64, x| s.max(x.abs()));
+        assert_approx_eq!(Mat4::from(inv), expected, epsilon = 1e-9 * scale);
+        assert_eq!(a.try_inverse(), Ok(inv));
+
+        let b: MatrixMN<f64, 3, 3> = random_matrix(&mut rng);
+        let det = Mat3::from(b).determinant();
+        assert_approx_eq!(b.determinant(), det, epsilon = 1e-9 * det.abs().max(1.0));
+    }
+}
+
+#[test]
+fn inverts_high_dimensional_covariances() {
+    let mut rng = Pcg32::new(5);
+    for _ in 0..100 {
+        // `J Jᵀ + I` is symmetric and positive definite, like a covariance.
+        let jacobian: MatrixMN<f64, 12, 12> = random_matrix(&mut rng);
+        let mut covariance = jacobian * jacobian.transpose();
+        for i in 0..12 {
+            covariance.cols[i][i] += 1.0;
+        }
+        let inv = covariance.try_inverse().unwrap();
+        assert_approx_eq!(covariance * inv, MatrixMN::identity(), epsilon = 1e-9);
+        assert_approx_eq!(inv * covariance, MatrixMN::identity(), epsilon = 1e-9);
+        assert!(covariance.determinant() > 0.0);
+
+        let m: MatrixMN<f64, 6, 6> = random_matrix(&mut rng);
+        let inv = m.inverse().unwrap();
+        assert_approx_eq!(inv.inverse().unwrap(), m, epsilon = 1e-8);
+        assert_approx_eq!(inv.determinant() * m.determinant(), 1.0, epsilon = 1e-9);
+    }
+
+    // A repeated column makes the matrix singular, however it is rounded.
+    let mut m: MatrixMN<f64, 9, 9> = random_matrix(&mut rng);
+    m.cols[7] = m.cols[2] * 3.0;
+    assert_eq!(m.try_inverse(), Err(VexelError::SingularMatrix));
+    // Hadamard's bound on the determinant sets the scale of the rounding.
+    let bound: f64 = m.cols.iter().map(|col| col.length()).product();
+    assert_approx_eq!(m.determinant(), 0.0, epsilon = 1e-12 * bound);
+    assert_eq!(MatrixMN::<f64, 6, 6>::zero().determinant(), 0.0);
+    assert_eq!(MatrixMN::<f64, 6, 6>::zero().inverse(), None);
+
+    let cols = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    assert_eq!(MatrixMN::from_cols_array(cols).to_cols_array(), cols);
+}
+
+#[test]
+fn near_singular_matrices_agree_with_the_fixed_size_ones() {
+    let mut rng = Pcg32::new(7);
+    let mut found = false;
+    for i in 0..1000 {
+        // Nudge a rank-deficient matrix by amounts either side of rounding.
+        let a = sample::on_sphere::<f32, _>(&mut rng);
+        let b = sample::on_sphere::<f32, _>(&mut rng);
+        let nudge = sample::on_sphere::<f32, _>(&mut rng) * 10f32.powi(-(i % 9));
+        let m = Mat3::from_cols(a, b, a * 0.3 + b * 0.6 + nudge);
+        let n = MatrixMN::from(m);
+        assert_eq!(n.inverse().is_some(), m.inverse().is_some(), "{m:?}");
+        assert_eq!(Mat3::from(n).inverse().is_some(), m.inverse().is_some());
+        found |= m.inverse().is_none() && m.determinant() != 0.0;
+    }
+    assert!(found, "expected a singular Mat3 with a nonzero determinant");
+}
+
+#[test]
+fn inverts_badly_scaled_matrices() {
+    let m = MatrixMN::from_diagonal(VectorN::new([1e10, 1e-10]));
+    let inv = m.inverse().unwrap();
+    assert_eq!(inv, MatrixMN::from_diagonal(VectorN::new([1e-10, 1e10])));
+    assert_eq!(Mat2::from(inv), Mat2::from(m).inverse().unwrap());
+
+    // Position and velocity variances in very different units.
+    let covariance =
+        MatrixMN::<f32, 6, 6>::from_diagonal(VectorN::new([100.0, 100.0, 100.0, 1e-5, 1e-5, 1e-5]));
+    let inv = covariance.try_inverse().unwrap();
+    assert_approx_eq!(covariance * inv, MatrixMN::identity(), epsilon = 1e-6);
+
+    // Scaling the rows and columns of a well-conditioned matrix by wildly
+    // different amounts scales its inverse the same way.
+    let mut rng = Pcg32::new(6);
+    let scales = VectorN::new([1e-12, 1e-6, 1.0, 1e6, 1e12]);
+    for _ in 0..100 {
+        let m: MatrixMN<f64, 5, 5> = random_matrix(&mut rng);
+        let scale = MatrixMN::from_diagonal(scales);
+        let inv = (scale * m * scale).inverse().unwrap();
+        let expected = m.inverse().unwrap();
+        let size = expected
+            .cols
+            .iter()
+            .fold(1.0, |s: f64, col| s.max(col.length()));
+        assert_approx_eq!(scale * inv * scale, expected, epsilon = 1e-9 * size);
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: MatrixMN has 3 rows but the row index is 3")]
+fn row_past_the_last_panics() {
+    let m = MatrixMN::<f64, 3, 9>::zero();
+    let _ = m.row(3);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: MatrixMN has 9 columns but the column index is 9")]
+fn column_past_the_last_panics() {
+    let m = MatrixMN::<f64, 3, 9>::zero();
+    let _ = m.col(9);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: Mat4 has 4 rows but the row index is 4")]
+fn fixed_size_row_past_the_last_panics() {
+    let _ = Mat4::<f64>::identity().row(4);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: Mat3 has 3 columns but the column index is 3")]
+fn fixed_size_column_past_the_last_panics() {
+    let _ = Mat3::<f64>::identity().col(3);
+}